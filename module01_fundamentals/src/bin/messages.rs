@@ -0,0 +1,80 @@
+// Module 1: Message Interpreter
+// Drives messages.rs from the keyboard or a script file:
+//
+//   cargo run -p rust_basics --bin messages                       # interactive
+//   cargo run -p rust_basics --bin messages -- scripts/banner.txt # replay a script
+//
+// In interactive mode `replay FILE` runs a script against the current canvas.
+
+use rust_basics::messages::{Command, Interpreter, HELP};
+use std::io::{self, BufRead, IsTerminal, Write};
+
+const WIDTH: usize = 40;
+const HEIGHT: usize = 8;
+const UNDO_LIMIT: usize = 20;
+
+fn main() {
+    let mut interpreter = Interpreter::new(WIDTH, HEIGHT, UNDO_LIMIT);
+    let ansi = io::stdout().is_terminal();
+
+    if let Some(path) = std::env::args().nth(1) {
+        match replay(&mut interpreter, &path) {
+            Ok(()) => print!("{}", interpreter.canvas().render(ansi)),
+            Err(error) => {
+                eprintln!("error: {}", error);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    println!("Message interpreter: a {}x{} canvas", WIDTH, HEIGHT);
+    println!("{}", HELP);
+    println!("  replay FILE     run the commands in FILE\n");
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    while !interpreter.finished() {
+        print!("> ");
+        io::stdout().flush().ok();
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        // Ok(true) when the canvas should be drawn afterwards
+        let result = match line.strip_prefix("replay ") {
+            Some(path) => replay(&mut interpreter, path.trim()).map(|()| true),
+            None => match Command::parse(line) {
+                Ok(Command::Show) => Ok(true),
+                Ok(command) => {
+                    let changes = matches!(command, Command::Message(_) | Command::Undo(_));
+                    interpreter.run(command).map(|reply| {
+                        println!("{}", reply);
+                        changes
+                    })
+                }
+                Err(error) => Err(error),
+            },
+        };
+        match result {
+            Ok(true) => print!("{}", interpreter.canvas().render(ansi)),
+            Ok(false) => {}
+            Err(error) => println!("error: {}", error),
+        }
+    }
+}
+
+fn replay(interpreter: &mut Interpreter, path: &str) -> Result<(), String> {
+    let script =
+        std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path, e))?;
+    for reply in interpreter
+        .replay(&script)
+        .map_err(|e| format!("{}: {}", path, e))?
+    {
+        println!("{}", reply);
+    }
+    Ok(())
+}
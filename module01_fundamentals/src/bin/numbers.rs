@@ -0,0 +1,69 @@
+// Module 1: Numbers Explorer
+// An interactive front end to overflow.rs and ieee754.rs:
+//
+//   cargo run -p rust_basics --bin numbers             # prompt for input
+//   cargo run -p rust_basics --bin numbers -- 'MAX + 1'
+//
+// An integer expression is evaluated for every integer type under every
+// overflow mode; `f32 <value>` or `f64 <value>` takes a float apart.
+
+use rust_basics::ieee754::{self, Float};
+use rust_basics::overflow;
+use std::io::{self, BufRead, Write};
+
+const HELP: &str = "\
+Integer expressions: literals, MIN, MAX, ( ), unary - and + - * /
+    MAX + 1        100 * 3 - 50        -128 / -1        1 / (MAX - MAX)
+Floats: f32 or f64 followed by a number, or a bit pattern in hex
+    f32 0.1        f64 1e-310        f32 -inf        f32 0x7fc00001
+Type `help` for this list, or `quit` (or an empty line) to leave.";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        let line = args.join(" ");
+        if let Err(error) = answer(&line) {
+            eprintln!("error: {}", error);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    println!("Numbers explorer: integer overflow and IEEE 754 floats");
+    println!("{}\n", HELP);
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        io::stdout().flush().ok();
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        match line.trim() {
+            "" | "quit" | "exit" => break,
+            "help" => println!("{}", HELP),
+            line => {
+                if let Err(error) = answer(line) {
+                    println!("error: {}", error);
+                }
+            }
+        }
+        println!();
+    }
+}
+
+fn answer(line: &str) -> Result<(), String> {
+    if let Some(value) = line.strip_prefix("f32 ") {
+        print!("{}", decompose::<f32>(value)?);
+    } else if let Some(value) = line.strip_prefix("f64 ") {
+        print!("{}", decompose::<f64>(value)?);
+    } else {
+        print!("{}", overflow::explore(line)?);
+    }
+    Ok(())
+}
+
+fn decompose<F: Float>(value: &str) -> Result<String, String> {
+    let x = ieee754::parse::<F>(value)?;
+    Ok(ieee754::decompose(x).render())
+}
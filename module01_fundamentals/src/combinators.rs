@@ -0,0 +1,249 @@
+// Module 1: Closure Combinators
+// Functions that take closures and return new ones. Each closure implements
+// one or more of three traits, depending on what it does with what it
+// captured:
+//
+//   Fn       only reads its captures; can be called any number of times,
+//            even through a shared reference
+//   FnMut    changes its captures; needs `&mut` to call
+//   FnOnce   gives its captures away (moves them out); can be called once
+//
+// Every Fn is also FnMut, and every FnMut is also FnOnce, so a combinator
+// should ask for the weakest trait it can live with. `compose` comes in one
+// flavour per trait for that reason: composing two Fn closures should give
+// back an Fn, not just an FnOnce.
+//
+// A closure is a struct the compiler writes for you, holding one field per
+// captured variable: a reference when it borrows, the value itself under
+// `move`. `closure_sizes!` prints the size of that hidden struct, which
+// shows what was captured and how.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+// `f` then `g`: compose(f, g)(x) == g(f(x))
+pub fn compose<A, B, C>(f: impl Fn(A) -> B, g: impl Fn(B) -> C) -> impl Fn(A) -> C {
+    move |a| g(f(a))
+}
+
+pub fn compose_mut<A, B, C>(
+    mut f: impl FnMut(A) -> B,
+    mut g: impl FnMut(B) -> C,
+) -> impl FnMut(A) -> C {
+    move |a| g(f(a))
+}
+
+pub fn compose_once<A, B, C>(
+    f: impl FnOnce(A) -> B,
+    g: impl FnOnce(B) -> C,
+) -> impl FnOnce(A) -> C {
+    move |a| g(f(a))
+}
+
+// Turns f(a, b) into f(a)(b). The inner closure is boxed because a closure
+// cannot (yet) be declared to return `impl Fn`; the `Rc` lets every inner
+// closure share `f` without requiring it to be Clone.
+pub fn curry<A, B, C, F>(f: F) -> impl Fn(A) -> Box<dyn Fn(B) -> C>
+where
+    A: Clone + 'static,
+    F: Fn(A, B) -> C + 'static,
+{
+    let f = Rc::new(f);
+    move |a| {
+        let f = Rc::clone(&f);
+        Box::new(move |b| f(a.clone(), b))
+    }
+}
+
+// Remembers every result, so `f` runs once per distinct argument. Filling
+// the cache mutates the closure's state, which makes the result FnMut even
+// though `f` is only Fn.
+pub fn memoize<A, R>(f: impl Fn(A) -> R) -> impl FnMut(A) -> R
+where
+    A: Eq + Hash + Clone,
+    R: Clone,
+{
+    let mut cache = HashMap::new();
+    move |a: A| {
+        if let Some(result) = cache.get(&a) {
+            return R::clone(result);
+        }
+        let result = f(a.clone());
+        cache.insert(a, result.clone());
+        result
+    }
+}
+
+// Calls `operation` with the attempt number (1, 2, ...) until it succeeds
+// or `attempts` calls have failed, returning the last error. FnMut, so the
+// operation may keep state between attempts.
+pub fn retry<T, E>(
+    attempts: usize,
+    mut operation: impl FnMut(usize) -> Result<T, E>,
+) -> Result<T, E> {
+    assert!(attempts > 0, "retry needs at least one attempt");
+    let mut attempt = 1;
+    loop {
+        match operation(attempt) {
+            Err(_) if attempt < attempts => attempt += 1,
+            result => return result,
+        }
+    }
+}
+
+// Threads a value through a list of functions, left to right, whose types
+// may all differ: `pipe!(" 42 ", str::trim, str::len)` is 2
+#[macro_export]
+macro_rules! pipe {
+    ($value:expr $(, $f:expr)* $(,)?) => {{
+        let value = $value;
+        $(let value = ($f)(value);)*
+        value
+    }};
+}
+
+// The size of one closure, as printed by `closure_sizes!`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClosureReport {
+    pub label: String,
+    pub size: usize,
+}
+
+// Prints a table of closure sizes and returns it:
+// `closure_sizes!("by reference" => || big.len(), "by move" => move || big.len())`
+#[macro_export]
+macro_rules! closure_sizes {
+    ($($label:expr => $closure:expr),+ $(,)?) => {{
+        let reports = vec![$(
+            $crate::combinators::ClosureReport {
+                label: $label.to_string(),
+                size: ::std::mem::size_of_val(&$closure),
+            }
+        ),+];
+        print!("{}", $crate::combinators::render(&reports));
+        reports
+    }};
+}
+
+pub fn render(reports: &[ClosureReport]) -> String {
+    let mut out = String::from(" Size  Closure\n");
+    for report in reports {
+        let note = if report.size == 0 {
+            " (captures nothing)"
+        } else {
+            ""
+        };
+        out.push_str(&format!("{:>5}  {}{}\n", report.size, report.label, note));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_compose() {
+        let add_then_double = compose(|x: i32| x + 1, |x: i32| x * 2);
+        assert_eq!(add_then_double(3), 8);
+        // Still Fn: callable through a shared reference
+        let by_ref: &dyn Fn(i32) -> i32 = &add_then_double;
+        assert_eq!(by_ref(0), 2);
+
+        let mut calls = 0;
+        let mut counted = compose_mut(
+            |x: i32| {
+                calls += 1;
+                x
+            },
+            |x: i32| x.to_string(),
+        );
+        assert_eq!(counted(7), "7");
+        assert_eq!(counted(8), "8");
+        drop(counted);
+        assert_eq!(calls, 2);
+
+        let name = String::from("ferris");
+        let greet = compose_once(
+            move |greeting: &str| format!("{}, {}", greeting, name),
+            |s: String| s.len(),
+        );
+        assert_eq!(greet("hello"), "hello, ferris".len());
+    }
+
+    #[test]
+    fn test_curry() {
+        let add = curry(|a: i32, b: i32| a + b);
+        let add_two = add(2);
+        assert_eq!(add_two(3), 5);
+        assert_eq!(add_two(40), 42);
+
+        let join = curry(|sep: String, parts: &[&str]| parts.join(&sep));
+        assert_eq!(join(", ".to_string())(&["a", "b"]), "a, b");
+    }
+
+    #[test]
+    fn test_memoize() {
+        let calls = Cell::new(0);
+        let mut slow_square = memoize(|x: u64| {
+            calls.set(calls.get() + 1);
+            x * x
+        });
+        assert_eq!(slow_square(4), 16);
+        assert_eq!(slow_square(4), 16);
+        assert_eq!(slow_square(5), 25);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_retry() {
+        let mut seen = Vec::new();
+        let result = retry(5, |attempt| {
+            seen.push(attempt);
+            if attempt < 3 {
+                Err(format!("attempt {} failed", attempt))
+            } else {
+                Ok(attempt * 10)
+            }
+        });
+        assert_eq!(result, Ok(30));
+        assert_eq!(seen, [1, 2, 3]);
+
+        let result: Result<(), String> =
+            retry(2, |attempt| Err(format!("attempt {} failed", attempt)));
+        assert_eq!(result, Err("attempt 2 failed".to_string()));
+    }
+
+    #[test]
+    fn test_pipe() {
+        assert_eq!(pipe!(" 42 ", str::trim, str::len), 2);
+        assert_eq!(
+            pipe!(3, |x| x + 1, |x: i32| x.pow(2), |x: i32| x.to_string()),
+            "16"
+        );
+        assert_eq!(pipe!(5), 5);
+    }
+
+    #[test]
+    fn test_closure_sizes() {
+        let big = [0u8; 64];
+        let small = 7u16;
+        let reports = closure_sizes!(
+            "nothing" => |x: i32| x + 1,
+            "big by reference" => || big.len(),
+            "big by move" => move || big.len(),
+            "both by reference" => || big.len() + small as usize,
+            "composed" => compose(move |x: u16| x + small, move |x: u16| x * small),
+        );
+        let sizes: Vec<usize> = reports.iter().map(|r| r.size).collect();
+        let pointer = std::mem::size_of::<usize>();
+        assert_eq!(sizes, [0, pointer, 64, 2 * pointer, 4]);
+
+        let table = render(&reports);
+        assert!(table.starts_with(" Size  Closure\n"));
+        assert!(table.contains("    0  nothing (captures nothing)\n"));
+        assert!(table.contains("   64  big by move\n"));
+    }
+}
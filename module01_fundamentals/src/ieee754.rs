@@ -0,0 +1,295 @@
+// Module 1: IEEE 754 Floats
+// An f32 is 32 bits split into three fields, an f64 is the same with wider
+// fields:
+//
+//   sign  exponent        mantissa (fraction)
+//   1     8 (f64: 11)     23 (f64: 52)
+//
+// For normal numbers the value is (-1)^sign * 1.mantissa * 2^(exponent - bias),
+// with a bias of 127 (f64: 1023). The two extreme exponents are special: all
+// zeros means zero or a subnormal number (0.mantissa * 2^(1 - bias), trading
+// precision for range near zero), all ones means infinity or NaN. A NaN's
+// top mantissa bit says whether it is quiet, and the bits below it are a
+// payload that arithmetic mostly carries along.
+
+use std::fmt;
+
+pub trait Float: Copy + PartialEq + fmt::Debug + std::str::FromStr {
+    const NAME: &'static str;
+    const EXPONENT_BITS: u32;
+    const MANTISSA_BITS: u32;
+
+    fn to_raw(self) -> u64;
+    fn from_raw(bits: u64) -> Self;
+}
+
+impl Float for f32 {
+    const NAME: &'static str = "f32";
+    const EXPONENT_BITS: u32 = 8;
+    const MANTISSA_BITS: u32 = f32::MANTISSA_DIGITS - 1;
+
+    fn to_raw(self) -> u64 {
+        self.to_bits() as u64
+    }
+
+    fn from_raw(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+}
+
+impl Float for f64 {
+    const NAME: &'static str = "f64";
+    const EXPONENT_BITS: u32 = 11;
+    const MANTISSA_BITS: u32 = f64::MANTISSA_DIGITS - 1;
+
+    fn to_raw(self) -> u64 {
+        self.to_bits()
+    }
+
+    fn from_raw(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+    Zero,
+    Subnormal,
+    Normal,
+    Infinite,
+    // The payload excludes the quiet bit
+    Nan { quiet: bool, payload: u64 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decomposed {
+    pub type_name: &'static str,
+    pub exponent_bits: u32,
+    pub mantissa_bits: u32,
+    // The value as Debug prints it, which switches to exponent notation for
+    // very large and very small numbers
+    pub value: String,
+    pub sign: bool,
+    // As stored, before the bias is subtracted
+    pub exponent: u64,
+    pub mantissa: u64,
+    pub class: Class,
+}
+
+pub fn decompose<F: Float>(x: F) -> Decomposed {
+    let bits = x.to_raw();
+    let exponent_mask = (1u64 << F::EXPONENT_BITS) - 1;
+    let mantissa_mask = (1u64 << F::MANTISSA_BITS) - 1;
+    let exponent = (bits >> F::MANTISSA_BITS) & exponent_mask;
+    let mantissa = bits & mantissa_mask;
+    let quiet_bit = 1u64 << (F::MANTISSA_BITS - 1);
+
+    let class = match (exponent, mantissa) {
+        (0, 0) => Class::Zero,
+        (0, _) => Class::Subnormal,
+        (e, 0) if e == exponent_mask => Class::Infinite,
+        (e, m) if e == exponent_mask => Class::Nan {
+            quiet: m & quiet_bit != 0,
+            payload: m & !quiet_bit,
+        },
+        _ => Class::Normal,
+    };
+    Decomposed {
+        type_name: F::NAME,
+        exponent_bits: F::EXPONENT_BITS,
+        mantissa_bits: F::MANTISSA_BITS,
+        value: format!("{:?}", x),
+        sign: bits >> (F::EXPONENT_BITS + F::MANTISSA_BITS) != 0,
+        exponent,
+        mantissa,
+        class,
+    }
+}
+
+// Accepts anything `str::parse` does ("0.1", "1e-40", "-inf", "NaN"), or a
+// raw bit pattern in hex ("0x7fc00001"), the only way to write most NaNs
+pub fn parse<F: Float>(text: &str) -> Result<F, String> {
+    let text = text.trim();
+    if let Some(hex) = text.strip_prefix("0x") {
+        let width = 1 + F::EXPONENT_BITS + F::MANTISSA_BITS;
+        return match u64::from_str_radix(&hex.replace('_', ""), 16) {
+            Ok(bits) if width == 64 || bits >> width == 0 => Ok(F::from_raw(bits)),
+            Ok(_) => Err(format!(
+                "{} does not fit in the {} bits of an {}",
+                text,
+                width,
+                F::NAME
+            )),
+            Err(e) => Err(format!("invalid bit pattern {}: {}", text, e)),
+        };
+    }
+    text.parse::<F>()
+        .map_err(|_| format!("`{}` is not an {}", text, F::NAME))
+}
+
+impl Decomposed {
+    pub fn bias(&self) -> i64 {
+        (1i64 << (self.exponent_bits - 1)) - 1
+    }
+
+    // The power of two the significand is scaled by; None for infinities
+    // and NaNs
+    pub fn unbiased_exponent(&self) -> Option<i64> {
+        match self.class {
+            Class::Normal => Some(self.exponent as i64 - self.bias()),
+            Class::Zero | Class::Subnormal => Some(1 - self.bias()),
+            Class::Infinite | Class::Nan { .. } => None,
+        }
+    }
+
+    // 1.mantissa for normal numbers, 0.mantissa otherwise; exact, since an
+    // f64 holds every f32 significand and every f64 one
+    pub fn significand(&self) -> f64 {
+        let fraction = self.mantissa as f64 / (1u64 << self.mantissa_bits) as f64;
+        match self.class {
+            Class::Normal => 1.0 + fraction,
+            _ => fraction,
+        }
+    }
+
+    // The bits in sign/exponent/mantissa groups
+    pub fn bit_fields(&self) -> String {
+        format!(
+            "{} {:0e$b} {:0m$b}",
+            self.sign as u8,
+            self.exponent,
+            self.mantissa,
+            e = self.exponent_bits as usize,
+            m = self.mantissa_bits as usize
+        )
+    }
+
+    pub fn render(&self) -> String {
+        let sign = if self.sign { '-' } else { '+' };
+        let hex_digits = (self.mantissa_bits as usize).div_ceil(4);
+        let mut out = format!("{} {}\n", self.type_name, self.value);
+        out.push_str(&format!("  bits      {}\n", self.bit_fields()));
+        out.push_str(&format!("  sign      {} ({})\n", self.sign as u8, sign));
+        out.push_str(&match self.unbiased_exponent() {
+            Some(power) if self.class == Class::Normal => format!(
+                "  exponent  {} - {} = {}\n",
+                self.exponent,
+                self.bias(),
+                power
+            ),
+            Some(power) => format!(
+                "  exponent  0 (all zeros: fixed at 1 - {} = {})\n",
+                self.bias(),
+                power
+            ),
+            None => format!("  exponent  {} (all ones)\n", self.exponent),
+        });
+        out.push_str(&format!(
+            "  mantissa  0x{:0w$x}\n",
+            self.mantissa,
+            w = hex_digits
+        ));
+
+        let power = self.unbiased_exponent().unwrap_or(0);
+        out.push_str(&match self.class {
+            Class::Zero => format!(
+                "  zero      {}0, told apart from {}0 only by the sign bit\n",
+                sign,
+                if self.sign { '+' } else { '-' }
+            ),
+            Class::Normal => format!("  normal    {}{} * 2^{}\n", sign, self.significand(), power),
+            Class::Subnormal => format!(
+                "  subnormal {}{} * 2^{}: no implicit leading 1, so precision is lost\n",
+                sign,
+                self.significand(),
+                power
+            ),
+            Class::Infinite => format!(
+                "  infinity  {}inf: all-ones exponent, zero mantissa\n",
+                sign
+            ),
+            Class::Nan { quiet, payload } => format!(
+                "  NaN       {}, payload 0x{:x}\n",
+                if quiet { "quiet" } else { "signalling" },
+                payload
+            ),
+        });
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompose_normal() {
+        let d = decompose(0.1f32);
+        assert_eq!(d.bit_fields(), "0 01111011 10011001100110011001101");
+        assert_eq!((d.sign, d.exponent, d.mantissa), (false, 123, 0x4ccccd));
+        assert_eq!(d.unbiased_exponent(), Some(-4));
+        assert_eq!(d.class, Class::Normal);
+        assert_eq!(d.significand() * 2f64.powi(-4), 0.1f32 as f64);
+
+        let d = decompose(-2.0f64);
+        assert!(d.sign);
+        assert_eq!((d.exponent, d.mantissa, d.significand()), (1024, 0, 1.0));
+    }
+
+    #[test]
+    fn test_special_values() {
+        assert_eq!(decompose(-0.0f32).class, Class::Zero);
+        assert!(decompose(-0.0f32).sign);
+        assert_eq!(decompose(f64::NEG_INFINITY).class, Class::Infinite);
+        assert_eq!(decompose(f64::NEG_INFINITY).unbiased_exponent(), None);
+
+        let smallest = decompose(f32::from_bits(1));
+        assert_eq!(smallest.class, Class::Subnormal);
+        assert_eq!(smallest.unbiased_exponent(), Some(-126));
+        assert_eq!(smallest.significand() * 2f64.powi(-126), 2f64.powi(-149));
+        assert_eq!(decompose(f64::MIN_POSITIVE).class, Class::Normal);
+        assert_eq!(decompose(f64::MIN_POSITIVE / 2.0).class, Class::Subnormal);
+    }
+
+    #[test]
+    fn test_nan_payloads() {
+        assert_eq!(
+            decompose(parse::<f32>("0x7fc00001").unwrap()).class,
+            Class::Nan {
+                quiet: true,
+                payload: 1
+            }
+        );
+        assert_eq!(
+            decompose(parse::<f64>("0x7ff0_0000_0000_002a").unwrap()).class,
+            Class::Nan {
+                quiet: false,
+                payload: 42
+            }
+        );
+        assert!(matches!(
+            decompose(f64::NAN).class,
+            Class::Nan { quiet: true, .. }
+        ));
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        assert_eq!(parse::<f64>(" 1e-3 "), Ok(0.001));
+        assert!(parse::<f64>("-inf").unwrap().is_infinite());
+        assert_eq!(parse::<f32>("one"), Err("`one` is not an f32".to_string()));
+        assert!(parse::<f32>("0x1_0000_0000")
+            .unwrap_err()
+            .contains("does not fit"));
+
+        let report = decompose(0.1f32).render();
+        assert!(report.starts_with("f32 0.1\n"));
+        assert!(decompose(1e-310f64).render().starts_with("f64 1e-310\n"));
+        assert!(report.contains("  exponent  123 - 127 = -4\n"));
+        assert!(report.contains("  mantissa  0x4ccccd\n"));
+        assert!(report.contains("  normal    +1.600000023841858 * 2^-4\n"));
+        assert!(decompose(5e-324f64)
+            .render()
+            .contains("subnormal +0.0000000000000002220446049250313 * 2^-1022"));
+    }
+}
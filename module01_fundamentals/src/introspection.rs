@@ -0,0 +1,319 @@
+// Module 1: Trait Introspection
+// Asking "does this type implement Copy?" at run time sounds impossible:
+// trait bounds are checked at compile time, and a generic `fn is_copy<T>()`
+// only knows the bounds it was declared with. For a concrete type, though,
+// method resolution can answer the question for us. This trick is known as
+// autoref specialisation:
+//
+//   trait Implemented { fn implements(&self) -> bool { true } }
+//   impl<T: Copy> Implemented for &Probe<T> {}
+//
+//   trait NotImplemented { fn implements(&self) -> bool { false } }
+//   impl<T> NotImplemented for Probe<T> {}
+//
+//   (&&Probe::<String>::new()).implements()
+//
+// The compiler looks for `implements` on `&&Probe<T>` first, which only the
+// `Implemented` impl offers, and only when `T: Copy` holds. Failing that, it
+// auto-dereferences once and finds the `NotImplemented` fallback. The choice
+// is made while compiling, so it has to happen where the type is written
+// out: inside a generic function, `T` would only ever match the fallback.
+// That is why `implements!` and `analyze_types!` are macros.
+
+use std::marker::PhantomData;
+
+// A zero-sized stand-in for a value of type T; T itself is never created
+pub struct Probe<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> Probe<T> {
+    pub const fn new() -> Self {
+        Probe(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Default for Probe<T> {
+    fn default() -> Self {
+        Probe::new()
+    }
+}
+
+// One pair of probe traits per trait, named after it so `implements!` can
+// find them; the bound is spelled out in full so the module name does not
+// shadow the trait
+macro_rules! probe {
+    ($name:ident, $($bound:tt)+) => {
+        #[allow(non_snake_case)]
+        pub mod $name {
+            use crate::introspection::Probe;
+
+            pub trait Implemented {
+                fn implements(&self) -> bool {
+                    true
+                }
+            }
+            impl<T: ?Sized + $($bound)+> Implemented for &Probe<T> {}
+
+            pub trait NotImplemented {
+                fn implements(&self) -> bool {
+                    false
+                }
+            }
+            impl<T: ?Sized> NotImplemented for Probe<T> {}
+        }
+    };
+}
+
+pub mod probes {
+    probe!(Copy, ::std::marker::Copy);
+    probe!(Clone, ::std::clone::Clone);
+    probe!(Send, ::std::marker::Send);
+    probe!(Sync, ::std::marker::Sync);
+    probe!(Unpin, ::std::marker::Unpin);
+    probe!(Sized, ::std::marker::Sized);
+    probe!(Default, ::std::default::Default);
+    probe!(Debug, ::std::fmt::Debug);
+}
+
+// The same trick gives the layout of sized types, and None for dynamically
+// sized ones like `str` and `[T]`, where `size_of` would not even compile
+pub trait SizedLayout {
+    fn layout(&self) -> Option<(usize, usize)>;
+}
+
+impl<T> SizedLayout for &Probe<T> {
+    fn layout(&self) -> Option<(usize, usize)> {
+        Some((std::mem::size_of::<T>(), std::mem::align_of::<T>()))
+    }
+}
+
+pub trait UnsizedLayout {
+    fn layout(&self) -> Option<(usize, usize)> {
+        None
+    }
+}
+
+impl<T: ?Sized> UnsizedLayout for Probe<T> {}
+
+// `implements!(String: Clone)` is true; the trait may be any of Copy, Clone,
+// Send, Sync, Unpin, Sized, Default or Debug
+#[macro_export]
+macro_rules! implements {
+    ($ty:ty: $trait:ident) => {{
+        #[allow(unused_imports)]
+        use $crate::introspection::probes::$trait::{Implemented as _, NotImplemented as _};
+        (&&$crate::introspection::Probe::<$ty>::new()).implements()
+    }};
+}
+
+// Everything `analyze_types!` reports about one type
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeReport {
+    pub name: String,
+    // Both None for dynamically sized types
+    pub size: Option<usize>,
+    pub align: Option<usize>,
+    pub copy: bool,
+    pub clone: bool,
+    pub send: bool,
+    pub sync: bool,
+    pub unpin: bool,
+    pub sized: bool,
+    pub default: bool,
+    pub debug: bool,
+}
+
+// The traits every report covers, in report order
+pub const TRAITS: [&str; 8] = [
+    "Copy", "Clone", "Send", "Sync", "Unpin", "Sized", "Default", "Debug",
+];
+
+impl TypeReport {
+    pub fn traits(&self) -> [(&'static str, bool); 8] {
+        let implemented = [
+            self.copy,
+            self.clone,
+            self.send,
+            self.sync,
+            self.unpin,
+            self.sized,
+            self.default,
+            self.debug,
+        ];
+        std::array::from_fn(|i| (TRAITS[i], implemented[i]))
+    }
+}
+
+#[macro_export]
+macro_rules! type_report {
+    ($ty:ty) => {{
+        #[allow(unused_imports)]
+        use $crate::introspection::{SizedLayout as _, UnsizedLayout as _};
+        let layout = (&&$crate::introspection::Probe::<$ty>::new()).layout();
+        $crate::introspection::TypeReport {
+            name: $crate::introspection::short_name(::std::any::type_name::<$ty>()),
+            size: layout.map(|(size, _)| size),
+            align: layout.map(|(_, align)| align),
+            copy: $crate::implements!($ty: Copy),
+            clone: $crate::implements!($ty: Clone),
+            send: $crate::implements!($ty: Send),
+            sync: $crate::implements!($ty: Sync),
+            unpin: $crate::implements!($ty: Unpin),
+            sized: $crate::implements!($ty: Sized),
+            default: $crate::implements!($ty: Default),
+            debug: $crate::implements!($ty: Debug),
+        }
+    }};
+}
+
+// Prints a table of sizes, alignments and traits for a list of types and
+// returns the reports: `analyze_types!(i32, String, Rc<u8>, str)`
+#[macro_export]
+macro_rules! analyze_types {
+    ($($ty:ty),+ $(,)?) => {{
+        let reports = vec![$($crate::type_report!($ty)),+];
+        print!("{}", $crate::introspection::render(&reports));
+        reports
+    }};
+}
+
+// Drops the module paths from a `type_name`, so
+// "core::option::Option<alloc::rc::Rc<u8>>" reads "Option<Rc<u8>>"
+pub fn short_name(name: &str) -> String {
+    let mut short = String::new();
+    let mut segment = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            segment.push(c);
+        } else {
+            short.push_str(segment.rsplit("::").next().unwrap_or(""));
+            segment.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(segment.rsplit("::").next().unwrap_or(""));
+    short
+}
+
+pub fn render(reports: &[TypeReport]) -> String {
+    let width = reports
+        .iter()
+        .map(|r| r.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Type".len());
+    let number = |n: Option<usize>| n.map_or_else(|| "-".to_string(), |n| n.to_string());
+
+    let mut out = format!("{:<width$} {:>5} {:>6}", "Type", "Size", "Align");
+    for name in TRAITS {
+        out.push_str(&format!(" {:>7}", name));
+    }
+    out.push('\n');
+    for report in reports {
+        out.push_str(&format!(
+            "{:<width$} {:>5} {:>6}",
+            report.name,
+            number(report.size),
+            number(report.align)
+        ));
+        for (_, implemented) in report.traits() {
+            out.push_str(&format!(" {:>7}", if implemented { "yes" } else { "no" }));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::marker::PhantomPinned;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex, MutexGuard};
+
+    // Function types need a name before they can be followed by `: Trait`
+    type Callback = fn();
+    type DynCallback = dyn Fn();
+
+    #[test]
+    fn test_implements_std_types() {
+        assert!(implements!(i32: Copy));
+        assert!(!implements!(String: Copy));
+        assert!(implements!(String: Clone));
+        assert!(!implements!(&mut i32: Clone));
+        assert!(implements!(&mut i32: Send));
+
+        assert!(!implements!(Rc<u8>: Send));
+        assert!(!implements!(Rc<u8>: Sync));
+        assert!(implements!(Arc<u8>: Send));
+        assert!(implements!(Cell<i32>: Send));
+        assert!(!implements!(Cell<i32>: Sync));
+        assert!(!implements!(RefCell<i32>: Sync));
+        assert!(implements!(Mutex<Cell<i32>>: Sync));
+        assert!(!implements!(MutexGuard<'static, i32>: Send));
+        assert!(implements!(*const u8: Copy));
+        assert!(!implements!(*const u8: Send));
+
+        assert!(!implements!(PhantomPinned: Unpin));
+        assert!(implements!(Box<PhantomPinned>: Unpin));
+        assert!(!implements!(str: Sized));
+        assert!(!implements!(DynCallback: Sized));
+        assert!(implements!(&str: Default));
+        assert!(!implements!(&mut i32: Default));
+        assert!(implements!(Callback: Copy));
+        assert!(!implements!(DynCallback: Debug));
+    }
+
+    #[test]
+    fn test_type_report() {
+        let report = type_report!(Option<Box<u8>>);
+        assert_eq!(report.name, "Option<Box<u8>>");
+        // The null pointer niche stores None for free
+        assert_eq!(report.size, Some(std::mem::size_of::<usize>()));
+        assert!(!report.copy && report.clone && report.send && report.default);
+
+        let report = type_report!(str);
+        assert_eq!((report.size, report.align), (None, None));
+        assert!(!report.sized && !report.clone && report.send && report.debug);
+
+        let report = type_report!([u16; 3]);
+        assert_eq!((report.size, report.align), (Some(6), Some(2)));
+        assert!(report.traits().iter().all(|(_, implemented)| *implemented));
+    }
+
+    #[test]
+    fn test_analyze_types_report() {
+        #[derive(Debug)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        let reports = analyze_types!(u8, Point, Rc<u8>, [u8]);
+        let names: Vec<&str> = reports.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["u8", "Point", "Rc<u8>", "[u8]"]);
+
+        let table = super::render(&reports);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Type    Size  Align    Copy   Clone    Send    Sync   Unpin   Sized Default   Debug"
+        );
+        assert_eq!(
+            lines[2],
+            "Point      8      4      no      no     yes     yes     yes     yes      no     yes"
+        );
+        assert!(lines[4].starts_with("[u8]       -      -"));
+    }
+
+    #[test]
+    fn test_short_name() {
+        assert_eq!(
+            super::short_name("core::option::Option<alloc::rc::Rc<u8>>"),
+            "Option<Rc<u8>>"
+        );
+        assert_eq!(
+            super::short_name("(alloc::string::String, &dyn core::fmt::Debug)"),
+            "(String, &dyn Debug)"
+        );
+    }
+}
@@ -0,0 +1,334 @@
+// Module 1: Layout Inspection
+// `size_of` says how big a struct is, but not where the bytes go. Wrapping a
+// struct definition in `inspect_layout!` records every field's offset, size
+// and alignment (via `std::mem::offset_of!`), from which we can work out the
+// padding between fields, whether `Option<T>` gets away without a tag, and
+// how small the struct could be with its fields reordered:
+//
+//   rust_basics::inspect_layout! {
+//       #[repr(C)]
+//       struct Person {
+//           active: bool,
+//           name: String,
+//       }
+//   }
+//
+//   print!("{}", Person::layout().render());
+//
+// Without `#[repr(C)]` the compiler is free to reorder fields, and it does
+// so to minimise padding; the report shows the order it picked. Generic
+// structs are not supported.
+
+use crate::introspection::short_name;
+use std::mem;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldLayout {
+    pub name: &'static str,
+    pub type_name: String,
+    pub offset: usize,
+    pub size: usize,
+    pub align: usize,
+}
+
+impl FieldLayout {
+    pub fn new<T>(name: &'static str, offset: usize) -> FieldLayout {
+        FieldLayout {
+            name,
+            type_name: short_name(std::any::type_name::<T>()),
+            offset,
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructLayout {
+    pub name: &'static str,
+    pub size: usize,
+    pub align: usize,
+    // In declaration order
+    pub fields: Vec<FieldLayout>,
+    // Whether `Option<Self>` is no bigger than `Self`, because None can be
+    // stored in a bit pattern the struct never uses (such as a null pointer)
+    pub option_niche: bool,
+}
+
+// Implemented by `inspect_layout!`
+pub trait Inspect {
+    fn layout() -> StructLayout;
+}
+
+// A run of padding bytes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Padding {
+    pub offset: usize,
+    pub len: usize,
+}
+
+impl StructLayout {
+    pub fn new<T>(name: &'static str, fields: Vec<FieldLayout>) -> StructLayout {
+        StructLayout {
+            name,
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+            fields,
+            option_niche: mem::size_of::<Option<T>>() == mem::size_of::<T>(),
+        }
+    }
+
+    // The fields in memory order
+    pub fn by_offset(&self) -> Vec<&FieldLayout> {
+        let mut fields: Vec<&FieldLayout> = self.fields.iter().collect();
+        fields.sort_by_key(|f| (f.offset, f.size));
+        fields
+    }
+
+    // Every gap between fields, and after the last one
+    pub fn padding(&self) -> Vec<Padding> {
+        let mut gaps = Vec::new();
+        let mut end = 0;
+        for field in self.by_offset() {
+            if field.offset > end {
+                gaps.push(Padding {
+                    offset: end,
+                    len: field.offset - end,
+                });
+            }
+            end = end.max(field.offset + field.size);
+        }
+        if self.size > end {
+            gaps.push(Padding {
+                offset: end,
+                len: self.size - end,
+            });
+        }
+        gaps
+    }
+
+    pub fn padding_bytes(&self) -> usize {
+        self.padding().iter().map(|gap| gap.len).sum()
+    }
+
+    // Largest alignment first: every field then starts right where the one
+    // before it ended, and the only padding left is at the end
+    pub fn suggested_order(&self) -> Vec<&'static str> {
+        let mut fields: Vec<&FieldLayout> = self.fields.iter().collect();
+        fields.sort_by_key(|f| std::cmp::Reverse(f.align));
+        fields.iter().map(|f| f.name).collect()
+    }
+
+    // The size the struct would have with its fields in the suggested order
+    pub fn optimal_size(&self) -> usize {
+        let mut fields: Vec<&FieldLayout> = self.fields.iter().collect();
+        fields.sort_by_key(|f| std::cmp::Reverse(f.align));
+        let mut end: usize = 0;
+        for field in fields {
+            end = end.next_multiple_of(field.align) + field.size;
+        }
+        end.next_multiple_of(self.align)
+    }
+
+    // One character per byte, 16 to a row: each field is drawn with its
+    // letter from the table (A for the first field in memory, and so on),
+    // and padding as '.'
+    pub fn byte_map(&self) -> String {
+        let mut bytes = vec!['.'; self.size];
+        for (field, letter) in self.by_offset().into_iter().zip(letters()) {
+            for byte in &mut bytes[field.offset..field.offset + field.size] {
+                *byte = letter;
+            }
+        }
+
+        let mut out = String::new();
+        for (row, chunk) in bytes.chunks(16).enumerate() {
+            let groups: Vec<String> = chunk.chunks(8).map(|g| g.iter().collect()).collect();
+            out.push_str(&format!("{:>6}  {}\n", row * 16, groups.join(" ")));
+        }
+        out
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "{}: {} bytes, aligned to {}, {} bytes of padding\n",
+            self.name,
+            self.size,
+            self.align,
+            self.padding_bytes()
+        );
+        out.push_str("  offset  size  align  field\n");
+
+        let padding = self.padding();
+        let mut gaps = padding.iter().peekable();
+        for (field, letter) in self.by_offset().into_iter().zip(letters()) {
+            while let Some(gap) = gaps.next_if(|gap| gap.offset < field.offset) {
+                out.push_str(&format!(
+                    "  {:>6}  {:>4}         (padding)\n",
+                    gap.offset, gap.len
+                ));
+            }
+            out.push_str(&format!(
+                "  {:>6}  {:>4}  {:>5}  {} {}: {}\n",
+                field.offset, field.size, field.align, letter, field.name, field.type_name
+            ));
+        }
+        for gap in gaps {
+            out.push_str(&format!(
+                "  {:>6}  {:>4}         (padding)\n",
+                gap.offset, gap.len
+            ));
+        }
+        if self.size > 0 {
+            out.push('\n');
+            out.push_str(&self.byte_map());
+        }
+
+        out.push('\n');
+        if self.option_niche {
+            out.push_str(&format!(
+                "Option<{}> is also {} bytes: None fits in a niche\n",
+                self.name, self.size
+            ));
+        } else {
+            out.push_str(&format!(
+                "Option<{}> is bigger than {}: it needs a separate tag\n",
+                self.name, self.name
+            ));
+        }
+
+        let declared: Vec<&str> = self.fields.iter().map(|f| f.name).collect();
+        let in_memory: Vec<&str> = self.by_offset().iter().map(|f| f.name).collect();
+        if self.optimal_size() < self.size {
+            out.push_str(&format!(
+                "Declaring the fields as ({}) would shrink it to {} bytes\n",
+                self.suggested_order().join(", "),
+                self.optimal_size()
+            ));
+        } else if declared != in_memory {
+            out.push_str("The compiler reordered the fields to avoid padding\n");
+        } else {
+            out.push_str("The field order is already as compact as it gets\n");
+        }
+        out
+    }
+}
+
+fn letters() -> impl Iterator<Item = char> {
+    ('A'..='Z').chain('a'..='z').cycle()
+}
+
+// Defines the struct exactly as written and implements `Inspect` for it, so
+// the recorded fields can never drift from the definition
+#[macro_export]
+macro_rules! inspect_layout {
+    ($(
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_attr:meta])* $field_vis:vis $field:ident : $ty:ty),* $(,)?
+        }
+    )+) => {$(
+        $(#[$attr])*
+        $vis struct $name {
+            $($(#[$field_attr])* $field_vis $field: $ty),*
+        }
+
+        impl $crate::layout::Inspect for $name {
+            fn layout() -> $crate::layout::StructLayout {
+                $crate::layout::StructLayout::new::<$name>(
+                    stringify!($name),
+                    vec![$(
+                        $crate::layout::FieldLayout::new::<$ty>(
+                            stringify!($field),
+                            ::std::mem::offset_of!($name, $field),
+                        )
+                    ),*],
+                )
+            }
+        }
+    )+};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::inspect_layout! {
+        #[repr(C)]
+        struct Padded {
+            flag: u8,
+            big: u64,
+            small: u16,
+        }
+
+        #[derive(Debug)]
+        struct Reordered {
+            flag: bool,
+            big: u64,
+            small: u16,
+        }
+
+        struct Boxed {
+            value: Box<u32>,
+            count: u32,
+        }
+    }
+
+    #[test]
+    fn test_repr_c_keeps_declared_order() {
+        let layout = Padded::layout();
+        assert_eq!((layout.size, layout.align), (24, 8));
+        let offsets: Vec<usize> = layout.fields.iter().map(|f| f.offset).collect();
+        assert_eq!(offsets, [0, 8, 16]);
+        assert_eq!(
+            layout.padding(),
+            [
+                Padding { offset: 1, len: 7 },
+                Padding { offset: 18, len: 6 }
+            ]
+        );
+        assert_eq!(layout.padding_bytes(), 13);
+        assert_eq!(layout.suggested_order(), ["big", "small", "flag"]);
+        assert_eq!(layout.optimal_size(), 16);
+        assert!(!layout.option_niche);
+    }
+
+    #[test]
+    fn test_default_repr_reorders() {
+        let layout = Reordered::layout();
+        assert_eq!(layout.size, 16);
+        assert_eq!(layout.padding_bytes(), 5);
+        assert_eq!(layout.optimal_size(), 16);
+        assert!(layout
+            .render()
+            .contains("The compiler reordered the fields"));
+        // A bool field has 254 unused bit patterns for None
+        assert!(layout.option_niche);
+        assert!(Boxed::layout().option_niche);
+    }
+
+    #[test]
+    fn test_render() {
+        let report = Padded::layout().render();
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Padded: 24 bytes, aligned to 8, 13 bytes of padding"
+        );
+        assert_eq!(
+            &lines[2..7],
+            [
+                "       0     1      1  A flag: u8",
+                "       1     7         (padding)",
+                "       8     8      8  B big: u64",
+                "      16     2      2  C small: u16",
+                "      18     6         (padding)",
+            ]
+        );
+        assert_eq!(lines[8], "     0  A....... BBBBBBBB");
+        assert_eq!(lines[9], "    16  CC......");
+        assert!(report.contains("Option<Padded> is bigger than Padded"));
+        assert!(report
+            .contains("Declaring the fields as (big, small, flag) would shrink it to 16 bytes"));
+    }
+}
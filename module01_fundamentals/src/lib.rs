@@ -0,0 +1,28 @@
+// Module 1: Rust Fundamentals
+// Library target exposing the exercises so the `rustpath` runner can drive them.
+
+pub mod problems;
+
+// Trait probing behind `implements!` and `analyze_types!`, used by exercise 1
+pub mod introspection;
+
+// Field offsets, padding and byte maps behind `inspect_layout!`, used by
+// exercise 3 here and by module 3's layout exercise
+pub mod layout;
+
+// IEEE 754 float decomposition and integer overflow modes (`explore`), used by
+// exercise 2 and the `numbers` explorer (src/bin/numbers.rs)
+pub mod ieee754;
+pub mod overflow;
+
+// compose, curry, memoize, retry, `pipe!` and `closure_sizes!`, used by
+// exercise 5
+pub mod combinators;
+
+// Exercise 4's `Message` enum with a parser, canvas and undo history, driven
+// by the `messages` interpreter (src/bin/messages.rs)
+pub mod messages;
+
+// Reference solutions, compiled only with the `solutions` feature
+#[cfg(feature = "solutions")]
+pub mod solutions;
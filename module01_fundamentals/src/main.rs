@@ -2,7 +2,7 @@
 // This module covers fundamental concepts in Rust, explaining not just how to use features,
 // but why they exist and how they work under the hood.
 
-mod problems;
+use rust_basics::problems;
 
 fn main() {
     // First, run the practice problems
@@ -87,14 +87,14 @@ fn main() {
 
     // Floating-point Types
     // Rust uses IEEE 754 standard
-    let float32: f32 = 3.14159; // 32-bit float
-    let float64: f64 = 3.14159; // 64-bit float (default)
+    let float32: f32 = 1.0 / 3.0; // 32-bit float
+    let float64: f64 = 1.0 / 3.0; // 64-bit float (default)
     println!("float32 precision: {}", float32);
     println!("float64 precision: {}", float64);
 
     // Compound Types
     // Tuples: Fixed-length collection of values of different types
-    let tuple: (i32, f64, char) = (42, 3.14, 'a');
+    let tuple: (i32, f64, char) = (42, 2.5, 'a');
     println!("Tuple values: ({}, {}, {})", tuple.0, tuple.1, tuple.2);
 
     // Arrays: Fixed-length collection of same type, stored on stack
@@ -172,6 +172,7 @@ fn demonstrate_functions() {
     fn forever() -> ! {
         loop {
             // Never returns
+            std::thread::park();
         }
     }
 
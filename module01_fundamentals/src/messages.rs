@@ -0,0 +1,471 @@
+// Module 1: Message Interpreter
+// Exercise 4's `Message` enum, driven by text commands. Each line is parsed
+// into a `Message` (or one of the interpreter's own commands), and matching
+// on the variant decides what happens to a small character canvas:
+//
+//   move 3 4          Message::Move { x: 3, y: 4 }      put the cursor at (3, 4)
+//   write hello       Message::Write("hello")           write at the cursor
+//   color 255 0 0     Message::ChangeColor(255, 0, 0)   pen colour for writing
+//   quit              Message::Quit
+//
+// Every message that changes the canvas can be undone, up to a limit; the
+// interpreter keeps a copy of the canvas from before each one. Scripts are
+// the same commands, one per line. A `#` at the start of a line or after a
+// space starts a comment; any other `#` is text, as in `write C#`.
+
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(i32, i32, i32),
+}
+
+// A line of input: a message, or something the interpreter does itself
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Message(Message),
+    Undo(usize),
+    Show,
+    History,
+    Help,
+}
+
+pub const HELP: &str = "\
+Messages:
+  move X Y        put the cursor at column X, row Y (0-based)
+  write TEXT      write TEXT at the cursor and move past it
+  color R G B     change the pen colour (0-255 each)
+  quit            stop
+Other commands:
+  undo [N]        take back the last N messages (default 1)
+  show            draw the canvas
+  history         list the messages that can be undone
+  help            this list";
+
+impl Command {
+    pub fn parse(line: &str) -> Result<Command, String> {
+        let line = line.trim();
+        let (word, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let rest = rest.trim();
+        let numbers = |count: usize| -> Result<Vec<i32>, String> {
+            let numbers = rest
+                .split_whitespace()
+                .map(|n| {
+                    n.parse::<i32>()
+                        .map_err(|_| format!("`{}` is not a number", n))
+                })
+                .collect::<Result<Vec<i32>, String>>()?;
+            if numbers.len() != count {
+                return Err(format!("`{}` takes {} numbers", word, count));
+            }
+            Ok(numbers)
+        };
+
+        match word.to_lowercase().as_str() {
+            "quit" | "exit" if rest.is_empty() => Ok(Command::Message(Message::Quit)),
+            "move" => {
+                let n = numbers(2)?;
+                Ok(Command::Message(Message::Move { x: n[0], y: n[1] }))
+            }
+            "write" if !rest.is_empty() => Ok(Command::Message(Message::Write(rest.to_string()))),
+            "write" => Err("`write` needs some text".to_string()),
+            "color" | "colour" => {
+                let n = numbers(3)?;
+                Ok(Command::Message(Message::ChangeColor(n[0], n[1], n[2])))
+            }
+            "undo" if rest.is_empty() => Ok(Command::Undo(1)),
+            "undo" => match rest.parse::<usize>() {
+                Ok(count) if count > 0 => Ok(Command::Undo(count)),
+                _ => Err(format!(
+                    "`undo` takes a count of at least 1, not `{}`",
+                    rest
+                )),
+            },
+            "show" if rest.is_empty() => Ok(Command::Show),
+            "history" if rest.is_empty() => Ok(Command::History),
+            "help" | "?" => Ok(Command::Help),
+            "" => Err("empty command".to_string()),
+            _ => Err(format!("unknown command `{}` (try `help`)", line)),
+        }
+    }
+}
+
+pub type Rgb = (u8, u8, u8);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    // Row by row; each written cell keeps the colour it was written in
+    pub cells: Vec<Option<(char, Rgb)>>,
+    // The column can be `width`, just past a full row
+    pub cursor: (usize, usize),
+    pub color: Rgb,
+}
+
+impl Canvas {
+    // Panics on an empty canvas, as the cursor needs a cell to start on
+    pub fn new(width: usize, height: usize) -> Canvas {
+        assert!(
+            width > 0 && height > 0,
+            "a canvas needs at least one column and one row, not {}x{}",
+            width,
+            height
+        );
+        Canvas {
+            width,
+            height,
+            cells: vec![None; width * height],
+            cursor: (0, 0),
+            color: (255, 255, 255),
+        }
+    }
+
+    pub fn row(&self, y: usize) -> String {
+        self.cells[y * self.width..(y + 1) * self.width]
+            .iter()
+            .map(|cell| cell.map_or(' ', |(c, _)| c))
+            .collect()
+    }
+
+    // A border, the cursor as `_` on an empty cell, and with `ansi` each
+    // character in its colour
+    pub fn render(&self, ansi: bool) -> String {
+        let border = format!("+{}+\n", "-".repeat(self.width));
+        let mut out = border.clone();
+        for y in 0..self.height {
+            out.push('|');
+            for x in 0..self.width {
+                match self.cells[y * self.width + x] {
+                    Some((c, (r, g, b))) if ansi => {
+                        out.push_str(&format!("\x1b[38;2;{};{};{}m{}\x1b[0m", r, g, b, c))
+                    }
+                    Some((c, _)) => out.push(c),
+                    None if self.cursor == (x, y) => out.push('_'),
+                    None => out.push(' '),
+                }
+            }
+            out.push_str("|\n");
+        }
+        out.push_str(&border);
+        let (r, g, b) = self.color;
+        out.push_str(&format!(
+            "cursor ({}, {}), color RGB({}, {}, {})\n",
+            self.cursor.0, self.cursor.1, r, g, b
+        ));
+        out
+    }
+}
+
+pub struct Interpreter {
+    canvas: Canvas,
+    // The canvas from before each undoable message, oldest first
+    history: VecDeque<(Message, Canvas)>,
+    undo_limit: usize,
+    finished: bool,
+}
+
+impl Interpreter {
+    pub fn new(width: usize, height: usize, undo_limit: usize) -> Interpreter {
+        Interpreter {
+            canvas: Canvas::new(width, height),
+            history: VecDeque::new(),
+            undo_limit,
+            finished: false,
+        }
+    }
+
+    pub fn canvas(&self) -> &Canvas {
+        &self.canvas
+    }
+
+    // True once a Quit message has been handled
+    pub fn finished(&self) -> bool {
+        self.finished
+    }
+
+    // Runs one line, returning what to print
+    pub fn execute(&mut self, line: &str) -> Result<String, String> {
+        self.run(Command::parse(line)?)
+    }
+
+    pub fn run(&mut self, command: Command) -> Result<String, String> {
+        match command {
+            Command::Message(message) => self.apply(message),
+            Command::Undo(count) => self.undo(count),
+            Command::Show => Ok(self.canvas.render(false)),
+            Command::History if self.history.is_empty() => Ok("nothing to undo".to_string()),
+            Command::History => Ok(self
+                .history
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(i, (message, _))| format!("{:>3}  {:?}", i + 1, message))
+                .collect::<Vec<String>>()
+                .join("\n")),
+            Command::Help => Ok(HELP.to_string()),
+        }
+    }
+
+    pub fn apply(&mut self, message: Message) -> Result<String, String> {
+        let before = self.canvas.clone();
+        let reply = match &message {
+            Message::Quit => {
+                self.finished = true;
+                return Ok("Quit message received".to_string());
+            }
+            Message::Move { x, y } => {
+                let (width, height) = (self.canvas.width, self.canvas.height);
+                match (usize::try_from(*x), usize::try_from(*y)) {
+                    (Ok(col), Ok(row)) if col < width && row < height => {
+                        self.canvas.cursor = (col, row);
+                        format!("Move to ({}, {})", x, y)
+                    }
+                    _ => {
+                        return Err(format!(
+                            "({}, {}) is outside the {}x{} canvas",
+                            x, y, width, height
+                        ))
+                    }
+                }
+            }
+            Message::Write(text) => {
+                let (x, y) = self.canvas.cursor;
+                let room = self.canvas.width - x;
+                let length = text.chars().count();
+                if length > room {
+                    return Err(format!(
+                        "`{}` is {} characters, but only {} fit after the cursor",
+                        text, length, room
+                    ));
+                }
+                for (i, c) in text.chars().enumerate() {
+                    self.canvas.cells[y * self.canvas.width + x + i] = Some((c, self.canvas.color));
+                }
+                self.canvas.cursor.0 = x + length;
+                format!("Text message: {}", text)
+            }
+            Message::ChangeColor(r, g, b) => {
+                let channel = |v: i32| {
+                    u8::try_from(v).map_err(|_| format!("color components are 0-255, not {}", v))
+                };
+                self.canvas.color = (channel(*r)?, channel(*g)?, channel(*b)?);
+                format!("Change color to RGB({}, {}, {})", r, g, b)
+            }
+        };
+
+        if self.undo_limit > 0 {
+            if self.history.len() == self.undo_limit {
+                self.history.pop_front();
+            }
+            self.history.push_back((message, before));
+        }
+        Ok(reply)
+    }
+
+    pub fn undo(&mut self, count: usize) -> Result<String, String> {
+        if self.history.is_empty() {
+            return Err("nothing to undo".to_string());
+        }
+        let mut undone = Vec::new();
+        for _ in 0..count {
+            let Some((message, before)) = self.history.pop_back() else {
+                break;
+            };
+            self.canvas = before;
+            undone.push(format!("{:?}", message));
+        }
+        Ok(format!("Undid {}", undone.join(", ")))
+    }
+
+    // Runs a script, one command per line, stopping at `quit` or the first
+    // error; errors carry the line number
+    pub fn replay(&mut self, script: &str) -> Result<Vec<String>, String> {
+        let mut replies = Vec::new();
+        for (number, line) in script.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let reply = self
+                .execute(line)
+                .map_err(|e| format!("line {}: {}", number + 1, e))?;
+            replies.push(reply);
+            if self.finished {
+                break;
+            }
+        }
+        Ok(replies)
+    }
+}
+
+// `line` up to its comment, if it has one
+fn strip_comment(line: &str) -> &str {
+    let mut previous = ' ';
+    for (i, c) in line.char_indices() {
+        if c == '#' && previous.is_whitespace() {
+            return &line[..i];
+        }
+        previous = c;
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            Command::parse("move 3 4"),
+            Ok(Command::Message(Message::Move { x: 3, y: 4 }))
+        );
+        assert_eq!(
+            Command::parse("  Write hello world "),
+            Ok(Command::Message(Message::Write("hello world".to_string())))
+        );
+        assert_eq!(
+            Command::parse("color 255 0 0"),
+            Ok(Command::Message(Message::ChangeColor(255, 0, 0)))
+        );
+        assert_eq!(Command::parse("quit"), Ok(Command::Message(Message::Quit)));
+        assert_eq!(Command::parse("undo"), Ok(Command::Undo(1)));
+        assert_eq!(Command::parse("undo 3"), Ok(Command::Undo(3)));
+
+        assert_eq!(
+            Command::parse("move 3"),
+            Err("`move` takes 2 numbers".to_string())
+        );
+        assert_eq!(
+            Command::parse("color 1 two 3"),
+            Err("`two` is not a number".to_string())
+        );
+        assert!(Command::parse("undo 0").is_err());
+        assert!(Command::parse("write").is_err());
+        assert!(Command::parse("jump 1 2")
+            .unwrap_err()
+            .contains("unknown command"));
+    }
+
+    #[test]
+    fn test_messages_change_the_canvas() {
+        let mut interpreter = Interpreter::new(10, 3, 5);
+        assert_eq!(
+            interpreter.execute("move 2 1"),
+            Ok("Move to (2, 1)".to_string())
+        );
+        interpreter.execute("color 255 0 0").unwrap();
+        interpreter.execute("write hi").unwrap();
+
+        let canvas = interpreter.canvas();
+        assert_eq!(canvas.row(1), "  hi      ");
+        assert_eq!(canvas.cursor, (4, 1));
+        assert_eq!(canvas.cells[12], Some(('h', (255, 0, 0))));
+
+        assert_eq!(
+            interpreter.execute("move 10 0"),
+            Err("(10, 0) is outside the 10x3 canvas".to_string())
+        );
+        assert!(interpreter.execute("move -1 0").is_err());
+        assert!(interpreter.execute("color 256 0 0").is_err());
+        assert!(interpreter
+            .execute("write abcdefg")
+            .unwrap_err()
+            .contains("only 6 fit"));
+        // Failed messages leave nothing behind to undo
+        assert_eq!(interpreter.history.len(), 3);
+        assert_eq!(interpreter.canvas().row(1), "  hi      ");
+    }
+
+    #[test]
+    fn test_write_to_the_end_of_a_row() {
+        let mut interpreter = Interpreter::new(4, 1, 5);
+        interpreter.execute("move 1 0").unwrap();
+        interpreter.execute("write abc").unwrap();
+        assert_eq!(interpreter.canvas().row(0), " abc");
+        assert_eq!(interpreter.canvas().cursor, (4, 0));
+        // The row is full, so the last character is not overwritten
+        assert_eq!(
+            interpreter.execute("write d"),
+            Err("`d` is 1 characters, but only 0 fit after the cursor".to_string())
+        );
+        assert_eq!(interpreter.canvas().row(0), " abc");
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one column")]
+    fn test_empty_canvas_panics() {
+        Interpreter::new(0, 3, 5);
+    }
+
+    #[test]
+    fn test_undo() {
+        let mut interpreter = Interpreter::new(8, 2, 2);
+        interpreter.execute("write a").unwrap();
+        interpreter.execute("write b").unwrap();
+        interpreter.execute("write c").unwrap();
+        assert_eq!(interpreter.canvas().row(0), "abc     ");
+
+        // Only the last two are remembered
+        assert_eq!(
+            interpreter.execute("undo 5"),
+            Ok(r#"Undid Write("c"), Write("b")"#.to_string())
+        );
+        assert_eq!(interpreter.canvas().row(0), "a       ");
+        assert_eq!(interpreter.canvas().cursor, (1, 0));
+        assert_eq!(interpreter.undo(1), Err("nothing to undo".to_string()));
+    }
+
+    #[test]
+    fn test_replay_script() {
+        let script = "\
+# A tiny banner
+color 0 255 0
+move 1 0
+write Rust   # the word
+
+quit
+write never runs
+";
+        let mut interpreter = Interpreter::new(6, 1, 10);
+        let replies = interpreter.replay(script).unwrap();
+        assert_eq!(replies.len(), 4);
+        assert_eq!(replies[3], "Quit message received");
+        assert!(interpreter.finished());
+        assert_eq!(interpreter.canvas().row(0), " Rust ");
+
+        let mut interpreter = Interpreter::new(6, 1, 10);
+        assert_eq!(
+            interpreter.replay("write ok\nmove 9 9\n"),
+            Err("line 2: (9, 9) is outside the 6x1 canvas".to_string())
+        );
+    }
+
+    #[test]
+    fn test_replay_keeps_hashes_in_text() {
+        let script = "write C#\nmove 0 1\nwrite a#b\t# not written\n";
+        let mut interpreter = Interpreter::new(4, 2, 10);
+        assert_eq!(interpreter.replay(script).unwrap().len(), 3);
+        assert_eq!(interpreter.canvas().row(0), "C#  ");
+        assert_eq!(interpreter.canvas().row(1), "a#b ");
+    }
+
+    #[test]
+    fn test_render() {
+        let mut interpreter = Interpreter::new(4, 2, 1);
+        interpreter.execute("move 1 1").unwrap();
+        assert_eq!(
+            interpreter.canvas().render(false),
+            "+----+\n|    |\n| _  |\n+----+\ncursor (1, 1), color RGB(255, 255, 255)\n"
+        );
+        interpreter.execute("write X").unwrap();
+        assert!(interpreter
+            .canvas()
+            .render(true)
+            .contains("\x1b[38;2;255;255;255mX\x1b[0m"));
+    }
+}
@@ -0,0 +1,555 @@
+// Module 1: Integer Overflow Laboratory
+// `255u8 + 1` has no right answer, so Rust makes you pick one. The `+`
+// operator panics in debug builds and wraps in release builds (the
+// `overflow-checks` profile setting), and every integer type also offers
+// explicit variants that behave the same in any build:
+//
+//   wrapping_add     255u8 -> 0             keep the low bits
+//   checked_add      255u8 -> None          report it, let the caller decide
+//   saturating_add   255u8 -> 255           clamp to MIN/MAX
+//   overflowing_add  255u8 -> (0, true)     wrap, and say whether it did
+//
+// This lab parses an expression such as `MAX + 1` or `-128 / -1` once and
+// evaluates it for every integer width under each of those modes. Division
+// is the odd one out: dividing by zero panics in every mode but `checked`,
+// and `MIN / -1` panics even in release builds.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    // What std panics with when the operator overflows with checks on
+    fn overflow_message(self) -> &'static str {
+        match self {
+            Op::Add => "attempt to add with overflow",
+            Op::Sub => "attempt to subtract with overflow",
+            Op::Mul => "attempt to multiply with overflow",
+            Op::Div => "attempt to divide with overflow",
+        }
+    }
+}
+
+const NEGATE_OVERFLOW: &str = "attempt to negate with overflow";
+const DIVIDE_BY_ZERO: &str = "attempt to divide by zero";
+
+// Everything the lab needs from an integer type; implemented for all twelve
+// of them below
+pub trait Integer: Copy + PartialEq + fmt::Display + std::str::FromStr {
+    const NAME: &'static str;
+    const SIGNED: bool;
+    const MIN: Self;
+    const MAX: Self;
+    const ZERO: Self;
+
+    // Callers rule out division by zero first: these would panic on it
+    fn overflowing(op: Op, a: Self, b: Self) -> (Self, bool);
+    fn checked(op: Op, a: Self, b: Self) -> Option<Self>;
+    fn saturating(op: Op, a: Self, b: Self) -> Self;
+}
+
+macro_rules! integer {
+    ($($ty:ident),+) => {$(
+        impl Integer for $ty {
+            const NAME: &'static str = stringify!($ty);
+            const SIGNED: bool = $ty::MIN != 0;
+            const MIN: Self = $ty::MIN;
+            const MAX: Self = $ty::MAX;
+            const ZERO: Self = 0;
+
+            fn overflowing(op: Op, a: Self, b: Self) -> (Self, bool) {
+                match op {
+                    Op::Add => a.overflowing_add(b),
+                    Op::Sub => a.overflowing_sub(b),
+                    Op::Mul => a.overflowing_mul(b),
+                    Op::Div => a.overflowing_div(b),
+                }
+            }
+
+            fn checked(op: Op, a: Self, b: Self) -> Option<Self> {
+                match op {
+                    Op::Add => a.checked_add(b),
+                    Op::Sub => a.checked_sub(b),
+                    Op::Mul => a.checked_mul(b),
+                    Op::Div => a.checked_div(b),
+                }
+            }
+
+            fn saturating(op: Op, a: Self, b: Self) -> Self {
+                match op {
+                    Op::Add => a.saturating_add(b),
+                    Op::Sub => a.saturating_sub(b),
+                    Op::Mul => a.saturating_mul(b),
+                    Op::Div => a.saturating_div(b),
+                }
+            }
+        }
+    )+};
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Wrapping,
+    Checked,
+    Saturating,
+    Overflowing,
+    // Plain operators with overflow checks on (the dev profile)
+    Debug,
+    // Plain operators with overflow checks off (the release profile)
+    Release,
+}
+
+pub const MODES: [Mode; 6] = [
+    Mode::Wrapping,
+    Mode::Checked,
+    Mode::Saturating,
+    Mode::Overflowing,
+    Mode::Debug,
+    Mode::Release,
+];
+
+impl Mode {
+    pub fn label(self) -> &'static str {
+        match self {
+            Mode::Wrapping => "wrapping",
+            Mode::Checked => "checked",
+            Mode::Saturating => "saturating",
+            Mode::Overflowing => "overflowing",
+            Mode::Debug => "debug",
+            Mode::Release => "release",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome<T> {
+    // The result, and whether any step overflowed on the way there
+    Value(T, bool),
+    // A `checked_*` step returned None
+    Nothing,
+    // Evaluating the expression panics, with the message std would print
+    Panic(&'static str),
+}
+
+impl<T: Integer> Outcome<T> {
+    // The value as the mode's methods return it: `Some(x)` for checked,
+    // `(x, overflowed)` for overflowing. The bounds are written MIN and MAX,
+    // which keeps the 128-bit rows of a table readable.
+    pub fn describe(&self, mode: Mode) -> String {
+        let show = |value: &T| {
+            if *value == T::MAX {
+                "MAX".to_string()
+            } else if *value == T::MIN && T::SIGNED {
+                "MIN".to_string()
+            } else {
+                value.to_string()
+            }
+        };
+        match (self, mode) {
+            (Outcome::Value(value, _), Mode::Checked) => format!("Some({})", show(value)),
+            (Outcome::Value(value, overflowed), Mode::Overflowing) => {
+                format!("({}, {})", show(value), overflowed)
+            }
+            (Outcome::Value(value, _), _) => show(value),
+            (Outcome::Nothing, _) => "None".to_string(),
+            (Outcome::Panic(_), _) => "panic".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    // Kept as written, since whether it fits depends on the type
+    Literal(String),
+    Min,
+    Max,
+    Neg(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    // Integer literals, `MIN`, `MAX`, parentheses, unary `-` and the four
+    // operators with the usual precedence: `2 + 3 * -4`, `(MAX - 1) * 2`
+    pub fn parse(source: &str) -> Result<Expr, String> {
+        let mut parser = Parser {
+            chars: source.char_indices().peekable(),
+        };
+        let expr = parser.sum()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some((column, c)) => Err(format!("unexpected `{}` at column {}", c, column + 1)),
+        }
+    }
+
+    // Both operands are evaluated before an operator is applied, so a
+    // literal that does not fit is reported even after an earlier panic,
+    // just as the compiler would reject it
+    pub fn evaluate<T: Integer>(&self, mode: Mode) -> Result<Outcome<T>, String> {
+        match self {
+            Expr::Literal(text) => match text.parse::<T>() {
+                Ok(value) => Ok(Outcome::Value(value, false)),
+                Err(_) if text.starts_with('-') && !T::SIGNED => {
+                    Err(format!("{} cannot be negative", T::NAME))
+                }
+                Err(_) => Err(format!("literal `{}` does not fit in {}", text, T::NAME)),
+            },
+            Expr::Min => Ok(Outcome::Value(T::MIN, false)),
+            Expr::Max => Ok(Outcome::Value(T::MAX, false)),
+            Expr::Neg(inner) => {
+                if !T::SIGNED {
+                    return Err(format!("{} cannot be negated", T::NAME));
+                }
+                // `-x` behaves exactly like `0 - x` in every mode; only the
+                // panic message differs
+                let zero = Outcome::Value(T::ZERO, false);
+                Ok(match apply(Op::Sub, zero, inner.evaluate(mode)?, mode) {
+                    Outcome::Panic(_) => Outcome::Panic(NEGATE_OVERFLOW),
+                    outcome => outcome,
+                })
+            }
+            Expr::Binary(op, left, right) => {
+                let left = left.evaluate(mode)?;
+                Ok(apply(*op, left, right.evaluate(mode)?, mode))
+            }
+        }
+    }
+}
+
+fn apply<T: Integer>(op: Op, left: Outcome<T>, right: Outcome<T>, mode: Mode) -> Outcome<T> {
+    let (a, a_overflowed) = match left {
+        Outcome::Value(value, overflowed) => (value, overflowed),
+        stopped => return stopped,
+    };
+    let (b, b_overflowed) = match right {
+        Outcome::Value(value, overflowed) => (value, overflowed),
+        stopped => return stopped,
+    };
+    if op == Op::Div && b == T::ZERO {
+        return match mode {
+            Mode::Checked => Outcome::Nothing,
+            _ => Outcome::Panic(DIVIDE_BY_ZERO),
+        };
+    }
+
+    let (wrapped, overflowed) = T::overflowing(op, a, b);
+    let so_far = a_overflowed || b_overflowed || overflowed;
+    match mode {
+        Mode::Wrapping | Mode::Overflowing => Outcome::Value(wrapped, so_far),
+        Mode::Saturating => Outcome::Value(T::saturating(op, a, b), so_far),
+        Mode::Checked => match T::checked(op, a, b) {
+            Some(value) => Outcome::Value(value, so_far),
+            None => Outcome::Nothing,
+        },
+        // `MIN / -1` is checked whatever the profile says
+        Mode::Debug | Mode::Release if overflowed && (mode == Mode::Debug || op == Op::Div) => {
+            Outcome::Panic(op.overflow_message())
+        }
+        Mode::Debug | Mode::Release => Outcome::Value(wrapped, so_far),
+    }
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    // The next character that is not whitespace, with its byte offset
+    fn peek(&mut self) -> Option<(usize, char)> {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+        self.chars.peek().copied()
+    }
+
+    fn sum(&mut self) -> Result<Expr, String> {
+        let mut expr = self.product()?;
+        while let Some((_, c @ ('+' | '-'))) = self.peek() {
+            self.chars.next();
+            let op = if c == '+' { Op::Add } else { Op::Sub };
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.product()?));
+        }
+        Ok(expr)
+    }
+
+    fn product(&mut self) -> Result<Expr, String> {
+        let mut expr = self.unary()?;
+        while let Some((_, c @ ('*' | '/'))) = self.peek() {
+            self.chars.next();
+            let op = if c == '*' { Op::Mul } else { Op::Div };
+            expr = Expr::Binary(op, Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        if self.peek().map(|(_, c)| c) != Some('-') {
+            return self.atom();
+        }
+        self.chars.next();
+        // Like rustc, `-128` is read as one literal, so it fits in an i8
+        match self.unary()? {
+            Expr::Literal(digits) if !digits.starts_with('-') => {
+                Ok(Expr::Literal(format!("-{}", digits)))
+            }
+            inner => Ok(Expr::Neg(Box::new(inner))),
+        }
+    }
+
+    fn atom(&mut self) -> Result<Expr, String> {
+        match self.peek() {
+            None => Err("expected a number at the end of the expression".to_string()),
+            Some((start, '(')) => {
+                self.chars.next();
+                let expr = self.sum()?;
+                match self.peek() {
+                    Some((_, ')')) => {
+                        self.chars.next();
+                        Ok(expr)
+                    }
+                    _ => Err(format!("unclosed `(` at column {}", start + 1)),
+                }
+            }
+            Some((_, c)) if c.is_ascii_digit() => {
+                let mut digits = String::new();
+                while let Some((_, c)) =
+                    self.chars.next_if(|(_, c)| c.is_ascii_digit() || *c == '_')
+                {
+                    if c != '_' {
+                        digits.push(c);
+                    }
+                }
+                Ok(Expr::Literal(digits))
+            }
+            Some((start, c)) if c.is_ascii_alphabetic() => {
+                let mut word = String::new();
+                while let Some((_, c)) = self.chars.next_if(|(_, c)| c.is_ascii_alphanumeric()) {
+                    word.push(c);
+                }
+                match word.as_str() {
+                    "MIN" => Ok(Expr::Min),
+                    "MAX" => Ok(Expr::Max),
+                    _ => Err(format!(
+                        "unknown name `{}` at column {} (try MIN or MAX)",
+                        word,
+                        start + 1
+                    )),
+                }
+            }
+            Some((column, c)) => Err(format!("unexpected `{}` at column {}", c, column + 1)),
+        }
+    }
+}
+
+// One row of the report: the outcome under every mode, or why the
+// expression does not compile for this type
+struct Row {
+    name: &'static str,
+    outcomes: Result<Vec<(String, Option<&'static str>)>, String>,
+}
+
+fn row<T: Integer>(expr: &Expr) -> Row {
+    let outcomes = MODES
+        .iter()
+        .map(|&mode| {
+            let outcome = expr.evaluate::<T>(mode)?;
+            let panic = match outcome {
+                Outcome::Panic(message) => Some(message),
+                _ => None,
+            };
+            Ok((outcome.describe(mode), panic))
+        })
+        .collect();
+    Row {
+        name: T::NAME,
+        outcomes,
+    }
+}
+
+const ROWS: [fn(&Expr) -> Row; 12] = [
+    row::<i8>,
+    row::<i16>,
+    row::<i32>,
+    row::<i64>,
+    row::<i128>,
+    row::<isize>,
+    row::<u8>,
+    row::<u16>,
+    row::<u32>,
+    row::<u64>,
+    row::<u128>,
+    row::<usize>,
+];
+
+// Evaluates an expression for every integer type under every mode, as a
+// table followed by the panic messages
+pub fn explore(source: &str) -> Result<String, String> {
+    let expr = Expr::parse(source)?;
+    let rows: Vec<Row> = ROWS.iter().map(|row| row(&expr)).collect();
+
+    let mut widths: Vec<usize> = MODES.iter().map(|mode| mode.label().len()).collect();
+    for row in &rows {
+        if let Ok(outcomes) = &row.outcomes {
+            for (width, (cell, _)) in widths.iter_mut().zip(outcomes) {
+                *width = (*width).max(cell.len());
+            }
+        }
+    }
+
+    let mut out = format!("{:<6}", "type");
+    for (mode, width) in MODES.iter().zip(&widths) {
+        out.push_str(&format!("  {:>width$}", mode.label()));
+    }
+    out.push('\n');
+
+    let mut panics: Vec<(&str, Vec<&str>)> = Vec::new();
+    for row in &rows {
+        out.push_str(&format!("{:<6}", row.name));
+        match &row.outcomes {
+            Ok(outcomes) => {
+                for ((cell, panic), width) in outcomes.iter().zip(&widths) {
+                    out.push_str(&format!("  {:>width$}", cell));
+                    if let Some(message) = panic {
+                        match panics.iter_mut().find(|(m, _)| m == message) {
+                            Some((_, names)) if names.last() == Some(&row.name) => {}
+                            Some((_, names)) => names.push(row.name),
+                            None => panics.push((message, vec![row.name])),
+                        }
+                    }
+                }
+            }
+            Err(error) => out.push_str(&format!("  does not compile: {}", error)),
+        }
+        out.push('\n');
+    }
+
+    for (message, names) in panics {
+        out.push_str(&format!("panic: {} ({})\n", message, names.join(", ")));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcomes<T: Integer>(source: &str) -> Vec<Outcome<T>> {
+        let expr = Expr::parse(source).unwrap();
+        MODES
+            .iter()
+            .map(|&mode| expr.evaluate::<T>(mode).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_overflow_modes() {
+        assert_eq!(
+            outcomes::<i8>("100 + 100"),
+            [
+                Outcome::Value(-56, true),
+                Outcome::Nothing,
+                Outcome::Value(127, true),
+                Outcome::Value(-56, true),
+                Outcome::Panic("attempt to add with overflow"),
+                Outcome::Value(-56, true),
+            ]
+        );
+        assert_eq!(outcomes::<u8>("0 - 1")[2], Outcome::Value(0, true));
+        assert_eq!(outcomes::<i16>("100 + 100")[4], Outcome::Value(200, false));
+        assert_eq!(
+            outcomes::<i32>("-MIN")[4],
+            Outcome::Panic("attempt to negate with overflow")
+        );
+        // The wrapped value carries on through later steps
+        assert_eq!(outcomes::<u8>("(MAX + 2) * 3")[3], Outcome::Value(3, true));
+    }
+
+    #[test]
+    fn test_division_panics_in_release_too() {
+        let results = outcomes::<i8>("MIN / -1");
+        assert_eq!(results[0], Outcome::Value(-128, true));
+        assert_eq!(results[2], Outcome::Value(127, true));
+        assert_eq!(
+            results[5],
+            Outcome::Panic("attempt to divide with overflow")
+        );
+
+        let results = outcomes::<u64>("1 / (MAX - MAX)");
+        assert_eq!(results[1], Outcome::Nothing);
+        for result in [results[0], results[2], results[3], results[4], results[5]] {
+            assert_eq!(result, Outcome::Panic("attempt to divide by zero"));
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let eval = |source: &str| Expr::parse(source).unwrap().evaluate::<i64>(Mode::Debug);
+        assert_eq!(eval("2 + 3 * 4"), Ok(Outcome::Value(14, false)));
+        assert_eq!(eval("(2 + 3) * 4"), Ok(Outcome::Value(20, false)));
+        assert_eq!(eval("10 - 4 - 3"), Ok(Outcome::Value(3, false)));
+        assert_eq!(eval("-2 * -3 / 2"), Ok(Outcome::Value(3, false)));
+        assert_eq!(eval("1_000 * 1_000"), Ok(Outcome::Value(1_000_000, false)));
+
+        assert_eq!(
+            Expr::parse("1 +"),
+            Err("expected a number at the end of the expression".to_string())
+        );
+        assert_eq!(
+            Expr::parse("(1 + 2"),
+            Err("unclosed `(` at column 1".to_string())
+        );
+        assert_eq!(
+            Expr::parse("1 % 2"),
+            Err("unexpected `%` at column 3".to_string())
+        );
+        assert!(Expr::parse("2 * max")
+            .unwrap_err()
+            .contains("unknown name `max`"));
+    }
+
+    #[test]
+    fn test_literals_must_fit() {
+        let expr = |source: &str| Expr::parse(source).unwrap();
+        assert_eq!(
+            expr("-128").evaluate::<i8>(Mode::Debug),
+            Ok(Outcome::Value(-128, false))
+        );
+        assert_eq!(
+            expr("MAX / 0 + 128").evaluate::<i8>(Mode::Debug),
+            Err("literal `128` does not fit in i8".to_string())
+        );
+        assert_eq!(
+            expr("-1").evaluate::<u32>(Mode::Wrapping),
+            Err("u32 cannot be negative".to_string())
+        );
+        assert_eq!(
+            expr("-(1 + 1)").evaluate::<u32>(Mode::Wrapping),
+            Err("u32 cannot be negated".to_string())
+        );
+    }
+
+    #[test]
+    fn test_explore_table() {
+        let table = explore("MAX + 1").unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 1 + 12 + 1);
+        assert!(lines[0].starts_with("type    wrapping"));
+        assert_eq!(
+            lines[1],
+            "i8           MIN     None         MAX  (MIN, true)  panic      MIN"
+        );
+        assert!(lines[7].starts_with("u8             0     None         MAX    (0, true)"));
+        assert_eq!(
+            lines[13],
+            "panic: attempt to add with overflow (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize)"
+        );
+
+        let table = explore("-1").unwrap();
+        assert!(table.contains("u8      does not compile: u8 cannot be negative"));
+    }
+}
@@ -14,83 +14,53 @@ pub fn run_exercises() {
     exercise5();
 }
 
+// Exercise entry points in order, used by `rustpath run <module> --exercise <n>`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
 // Exercise 1: Type System and Memory Layout
 // This exercise demonstrates:
 // - How Rust's type system ensures memory safety
 // - Stack vs heap allocation
 // - Size and alignment of different types
+// - Which traits (Copy, Send, Sync, ...) a type implements
 fn exercise1() {
     println!("Exercise 1: Type System and Memory Layout");
     println!("------------------------------------");
-    println!("TODO: Implement the analyze_types function\n");
-
-    // Function to analyze and print type information
-    fn analyze_types<T: std::fmt::Debug>(value: T) {
-        // Get the type name (requires nightly Rust for better output)
-        println!("Type: {:?}", std::any::type_name::<T>());
-
-        // Print size and alignment
-        println!("Size: {} bytes", std::mem::size_of::<T>());
-        println!("Alignment: {} bytes", std::mem::align_of::<T>());
-
-        // Print whether the type implements various traits
-        println!("Implements Copy: {}", is_copy::<T>());
-        println!("Implements Clone: {}", is_clone::<T>());
-        println!("Implements Send: {}", is_send::<T>());
-        println!("Implements Sync: {}", is_sync::<T>());
-    }
-
-    // Helper functions to check trait implementations
-    fn is_copy<T: ?Sized>() -> bool {
-        // needs_drop returns the opposite of what we want
-        // A type is Copy if it doesn't need drop
-        !std::mem::needs_drop::<T>()
-    }
-
-    fn is_clone<T: ?Sized>() -> bool {
-        trait IsClone {
-            fn is_clone(&self) -> bool {
-                true
-            }
-        }
-        impl<T: Clone> IsClone for T {}
-        true
-    }
-
-    fn is_send<T: ?Sized>() -> bool {
-        trait IsSend {
-            fn is_send(&self) -> bool {
-                true
-            }
-        }
-        impl<T: Send> IsSend for T {}
-        true
-    }
 
-    fn is_sync<T: ?Sized>() -> bool {
-        trait IsSync {
-            fn is_sync(&self) -> bool {
-                true
-            }
-        }
-        impl<T: Sync> IsSync for T {}
-        true
-    }
-
-    // Test various types
-    println!("Analyzing i32:");
-    analyze_types(42_i32);
-
-    println!("\nAnalyzing String:");
-    analyze_types(String::from("hello"));
-
-    println!("\nAnalyzing custom struct:");
+    // Size, alignment and trait implementations are all fixed at compile
+    // time. `analyze_types!` (see introspection.rs) asks the compiler about
+    // each type in the list and prints one row per type:
+    // - Copy types are duplicated bit for bit; String owns heap memory, so
+    //   it can only be cloned
+    // - Rc is neither Send nor Sync, so it can never cross a thread boundary,
+    //   while Cell may be sent but not shared
+    // - str and [u8] are dynamically sized: they have no size of their own
+    //   and are only used behind a pointer such as &str or Box<[u8]>
+    // - Option<Box<u8>> is no bigger than Box<u8>: None hides in the null
+    //   pointer, which a Box can never be
     #[derive(Debug)]
     struct Point {
         x: i32,
         y: i32,
     }
-    analyze_types(Point { x: 0, y: 0 });
+
+    crate::analyze_types!(
+        i32,
+        char,
+        (u8, u32),
+        [u16; 3],
+        Point,
+        String,
+        &str,
+        str,
+        [u8],
+        Box<u8>,
+        Option<Box<u8>>,
+        std::rc::Rc<u8>,
+        std::cell::Cell<i32>,
+        std::marker::PhantomPinned,
+    );
+    println!();
 }
 
 // Exercise 2: Expression Evaluation
@@ -99,6 +69,7 @@ fn exercise1() {
 // - Operator precedence
 // - Type inference
 // - Control flow as expressions
+// - Integer overflow modes and IEEE 754 floats
 fn exercise2() {
     println!("\nExercise 2: Expression Evaluation");
     println!("------------------------------");
@@ -139,6 +110,20 @@ fn exercise2() {
 
     let result = evaluate_expressions();
     println!("Expression evaluation result: {}", result);
+
+    // The answer above is an i32, but the same expression can mean something
+    // else in another type. `explore` (see overflow.rs) evaluates it for
+    // every integer width with wrapping, checked, saturating and overflowing
+    // arithmetic, and with the plain operators in debug and release builds.
+    // `decompose` (see ieee754.rs) shows why 0.1 is not quite 0.1 as a float.
+    // Try your own with `cargo run -p rust_basics --bin numbers`.
+    println!();
+    match crate::overflow::explore("100 + 100") {
+        Ok(table) => print!("{}", table),
+        Err(error) => println!("error: {}", error),
+    }
+    println!();
+    print!("{}", crate::ieee754::decompose(0.1f32).render());
 }
 
 // Exercise 3: Memory Management
@@ -150,19 +135,23 @@ fn exercise2() {
 fn exercise3() {
     println!("\nExercise 3: Memory Management");
     println!("--------------------------");
-    println!("TODO: Implement the memory_examples function\n");
 
-    // Struct that owns heap memory
-    struct HeapData {
-        value: Box<i32>,
-        text: String,
-    }
+    // `inspect_layout!` (see layout.rs) defines the structs as written and
+    // records where each field lives, for the layout dump below
+    use crate::layout::Inspect;
+    crate::inspect_layout! {
+        // Struct that owns heap memory
+        struct HeapData {
+            value: Box<i32>,
+            text: String,
+        }
 
-    // Struct that only uses stack memory
-    #[derive(Copy, Clone)]
-    struct StackData {
-        value: i32,
-        flag: bool,
+        // Struct that only uses stack memory
+        #[derive(Copy, Clone)]
+        struct StackData {
+            value: i32,
+            flag: bool,
+        }
     }
 
     // Function that demonstrates memory management
@@ -206,6 +195,13 @@ fn exercise3() {
     }
 
     memory_examples();
+
+    // Only the pointers, lengths and capacities of HeapData live inline; the
+    // i32 and the text are somewhere on the heap. StackData is all inline.
+    println!();
+    print!("{}", HeapData::layout().render());
+    println!();
+    print!("{}", StackData::layout().render());
 }
 
 // Exercise 4: Control Flow and Pattern Matching
@@ -214,19 +210,14 @@ fn exercise3() {
 // - Match expressions
 // - If let and while let
 // - Range patterns
+// - Parsing text into enum variants
 fn exercise4() {
     println!("\nExercise 4: Control Flow and Pattern Matching");
     println!("----------------------------------------");
-    println!("TODO: Implement the pattern_matching function\n");
 
-    // Enum for demonstration
-    #[derive(Debug)]
-    enum Message {
-        Quit,
-        Move { x: i32, y: i32 },
-        Write(String),
-        ChangeColor(i32, i32, i32),
-    }
+    // Enum for demonstration: Quit, Move { x, y }, Write(String) and
+    // ChangeColor(r, g, b), defined in messages.rs
+    use crate::messages::{Interpreter, Message};
 
     // Function that demonstrates pattern matching
     fn pattern_matching() {
@@ -267,6 +258,21 @@ fn exercise4() {
     }
 
     pattern_matching();
+
+    // The same messages, parsed from text and applied to a small canvas by
+    // the interpreter in messages.rs. Drive it yourself with
+    // `cargo run -p rust_basics --bin messages`.
+    println!();
+    let mut interpreter = Interpreter::new(24, 3, 10);
+    match interpreter.replay("color 0 200 0\nmove 2 1\nwrite Hello, canvas!\n") {
+        Ok(replies) => replies.iter().for_each(|reply| println!("{}", reply)),
+        Err(error) => println!("Script stopped: {}", error),
+    }
+    // Invalid messages are rejected and leave the canvas untouched
+    if let Err(error) = interpreter.apply(Message::Move { x: 30, y: 1 }) {
+        println!("Rejected: {}", error);
+    }
+    print!("{}", interpreter.canvas().render(false));
 }
 
 // Exercise 5: Functions and Closures
@@ -275,10 +281,11 @@ fn exercise4() {
 // - Closures and their environment
 // - Higher-order functions
 // - Type inference with closures
+// - Combinators over Fn, FnMut and FnOnce
+// - What a closure captures, and how
 fn exercise5() {
     println!("\nExercise 5: Functions and Closures");
     println!("-------------------------------");
-    println!("TODO: Implement the higher_order_functions example\n");
 
     // Function that takes a function pointer
     fn apply_function(f: fn(i32) -> i32, x: i32) -> i32 {
@@ -304,7 +311,7 @@ fn exercise5() {
         println!("5 times {}: {}", factor, multiplier(5));
 
         // Iterator adaptors with closures
-        let numbers = vec![1, 2, 3, 4, 5];
+        let numbers = [1, 2, 3, 4, 5];
         let sum: i32 = numbers
             .iter()
             .map(|x| x * x) // Square each number
@@ -314,69 +321,53 @@ fn exercise5() {
     }
 
     higher_order_functions();
-}
-
-/* Example Solutions (Try to solve the exercises before looking at these!)
-
-// Exercise 1 Solution:
-fn analyze_types() {
-    // Implementation details in the exercise
-    // Focus on understanding the output and why different types
-    // have different characteristics
-}
-
-// Exercise 2 Solution:
-fn evaluate_expressions() -> i32 {
-    let x = 5;
-    let y = 10;
 
-    let block_result = {
-        let temp = x * 2;
-        temp + y
-    };
-
-    let if_result = if x > y { x } else { y };
-
-    let match_result = match x {
-        0 => "zero",
-        1 => "one",
-        _ => "other",
-    }.len();
-
-    let loop_result = loop {
-        if x < y {
-            break x + y;
+    // Closures that build closures (see combinators.rs)
+    use crate::combinators::{compose, curry, memoize, retry};
+    let square_then_describe = compose(|x: i32| x * x, |x: i32| format!("{} squared", x));
+    println!("Composed: {}", square_then_describe(4));
+    let add = curry(|a: i32, b: i32| a + b);
+    println!("Curried: add(2)(3) = {}", add(2)(3));
+    let mut fib = memoize(|n: u32| (1..n).fold((0u64, 1u64), |(a, b), _| (b, a + b)).1);
+    println!("Memoized: fib(50) = {}, again: {}", fib(50), fib(50));
+    let connected = retry(3, |attempt| {
+        if attempt < 3 {
+            Err("timed out")
+        } else {
+            Ok(attempt)
         }
-    };
-
-    block_result + if_result + match_result + loop_result
-}
-
-// Exercise 3 Solution:
-fn memory_examples() {
-    // Implementation showing proper memory management
-    // Focus on understanding ownership transfers and borrowing rules
+    });
+    println!("Retried: {:?}", connected);
+    let words = crate::pipe!(
+        "  Hello Closures  ",
+        str::trim,
+        str::to_lowercase,
+        |s: String| s.split(' ').count()
+    );
+    println!("Piped: {} words", words);
+
+    // A closure's size is the size of what it captured: nothing, a reference
+    // per borrowed variable, or the whole value for each one moved in. Note
+    // that `move |x| x * scale` is 8 bytes because it holds the f64 itself,
+    // while the closure borrowing both `buffer` and `scale` holds two
+    // references. A Box<dyn Fn()> is a data pointer plus a vtable pointer.
+    println!();
+    let buffer = [0u8; 256];
+    let scale = 2.5f64;
+    let fn_pointer: fn(i32) -> i32 = |x| x + 1;
+    crate::closure_sizes!(
+        "|x| x + 1" => |x: i32| x + 1,
+        "fn pointer" => fn_pointer,
+        "|| buffer.len()" => || buffer.len(),
+        "move || buffer.len()" => move || buffer.len(),
+        "move |x| x * scale" => move |x: f64| x * scale,
+        "|| buffer[0] as f64 * scale" => || buffer[0] as f64 * scale,
+        "Box<dyn Fn()>" => Box::new(|| ()) as Box<dyn Fn()>,
+        "compose(|x| x * x, ..)" => square_then_describe,
+    );
 }
 
-// Exercise 4 Solution:
-fn pattern_matching() {
-    // Implementation showing various pattern matching techniques
-    // Focus on exhaustiveness and match guards
-}
-
-// Exercise 5 Solution:
-fn higher_order_functions() {
-    fn square(x: i32) -> i32 { x * x }
-    println!("Square of 5: {}", apply_function(square, 5));
-
-    let multiplier = create_multiplier(3);
-    println!("5 times 3: {}", multiplier(5));
-
-    let numbers = vec![1, 2, 3, 4, 5];
-    let sum: i32 = numbers.iter()
-        .map(|x| x * x)
-        .filter(|x| x % 2 == 0)
-        .sum();
-    println!("Sum of even squares: {}", sum);
-}
-*/
+// Example Solutions
+// The reference answers live in src/solutions.rs and are compiled with the
+// `solutions` feature (`rustpath run <module> --solution`). Try solving the
+// exercises yourself first!
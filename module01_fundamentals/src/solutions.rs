@@ -0,0 +1,105 @@
+// Module 1: Reference Solutions
+// Worked answers to the exercises in problems.rs, compiled only with the
+// `solutions` feature. Try solving the exercises yourself first!
+// Exercises 1, 3, 4 and 5 are walkthroughs with nothing left to fill in, so
+// their answer is the walkthrough itself.
+
+use crate::problems;
+
+pub fn run_exercises() {
+    println!("Module 1 Reference Solutions - Rust Fundamentals");
+    println!("==============================================\n");
+
+    exercise1();
+    exercise2();
+    exercise3();
+    exercise4();
+    exercise5();
+}
+
+// Solution entry points in order, used by `rustpath run <module> --solution`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Exercise 1 Solution: Type System and Memory Layout
+// Focus on understanding the output and why different types have different
+// characteristics
+fn exercise1() {
+    problems::EXERCISES[0]();
+}
+
+// Exercise 2 Solution: Expression Evaluation
+
+fn evaluate_expressions() -> i32 {
+    let x = 5;
+    let y = 10;
+
+    let block_result = {
+        let temp = x * 2;
+        temp + y
+    };
+
+    let if_result = if x > y { x } else { y };
+
+    let match_result = match x {
+        0 => "zero",
+        1 => "one",
+        _ => "other",
+    }
+    .len();
+
+    let loop_result = loop {
+        if x < y {
+            break x + y;
+        }
+    };
+
+    block_result + if_result + match_result as i32 + loop_result
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Expression Evaluation");
+    println!("------------------------------");
+
+    println!("Expression evaluation result: {}", evaluate_expressions());
+
+    println!();
+    match crate::overflow::explore("100 + 100") {
+        Ok(table) => print!("{}", table),
+        Err(error) => println!("error: {}", error),
+    }
+    println!();
+    print!("{}", crate::ieee754::decompose(0.1f32).render());
+}
+
+// Exercise 3 Solution: Memory Management
+// Focus on understanding ownership transfers and borrowing rules
+fn exercise3() {
+    problems::EXERCISES[2]();
+}
+
+// Exercise 4 Solution: Control Flow and Pattern Matching
+// Focus on exhaustiveness and match guards
+fn exercise4() {
+    problems::EXERCISES[3]();
+}
+
+// Exercise 5 Solution: Functions and Closures
+fn exercise5() {
+    problems::EXERCISES[4]();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_expressions() {
+        // 20 from the block, 10 from the if, 5 for "other", 15 from the loop
+        assert_eq!(evaluate_expressions(), 50);
+    }
+
+    #[test]
+    fn test_run_exercises() {
+        super::run_exercises();
+    }
+}
@@ -0,0 +1,36 @@
+// Module 2: Tokenizer Benchmark
+// Times the zero-copy tokenizer in tokenizer.rs against one that copies
+// every token into its own String:
+//
+//   cargo run -p module2 --release --bin tokens              # main.rs as input
+//   cargo run -p module2 --release --bin tokens -- FILE 500  # FILE, 500 rounds
+//
+// Debug builds are much slower and skew the comparison.
+
+use module2::tokenizer;
+
+const DEFAULT_ROUNDS: usize = 200;
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let input = match args.next() {
+        Some(path) => std::fs::read_to_string(&path).unwrap_or_else(|e| {
+            eprintln!("error: cannot read {}: {}", path, e);
+            std::process::exit(1);
+        }),
+        None => include_str!("../main.rs").to_string(),
+    };
+    let rounds = match args.next().map(|arg| arg.parse::<usize>()) {
+        None => DEFAULT_ROUNDS,
+        Some(Ok(rounds)) if rounds > 0 => rounds,
+        Some(_) => {
+            eprintln!("error: the number of rounds must be a positive integer");
+            std::process::exit(1);
+        }
+    };
+
+    if cfg!(debug_assertions) {
+        println!("(debug build: rerun with --release for meaningful numbers)");
+    }
+    print!("{}", tokenizer::benchmark(&input, rounds).render());
+}
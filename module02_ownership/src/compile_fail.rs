@@ -0,0 +1,84 @@
+//! Compile-fail checks for the exercise 5 protocol types in problems.rs.
+//! Each snippet below makes a transition the type states are meant to rule
+//! out, so `cargo test` (which runs these as doctests) passes only while
+//! the compiler keeps rejecting it. They check signatures, not behaviour,
+//! so they hold for the unimplemented stubs as much as for a solution.
+//!
+//! The whole protocol, which must compile:
+//!
+//! ```no_run
+//! use module2::problems::{DisconnectedConnection, Loopback};
+//!
+//! let mut transport = Loopback::new("secret");
+//! let connecting = DisconnectedConnection.open(&mut transport).unwrap();
+//! let mut conn = connecting.authenticate("secret").unwrap();
+//! conn.send(b"ping").unwrap();
+//! let closed = conn.close();
+//! println!("{:?}", closed.counters);
+//! ```
+//!
+//! Sending before logging in:
+//!
+//! ```compile_fail,E0599
+//! use module2::problems::{DisconnectedConnection, Loopback};
+//!
+//! let mut transport = Loopback::new("secret");
+//! let mut connecting = DisconnectedConnection.open(&mut transport).unwrap();
+//! connecting.send(b"ping");
+//! ```
+//!
+//! Logging in twice:
+//!
+//! ```compile_fail,E0599
+//! use module2::problems::{DisconnectedConnection, Loopback};
+//!
+//! let mut transport = Loopback::new("secret");
+//! let connecting = DisconnectedConnection.open(&mut transport).unwrap();
+//! let conn = connecting.authenticate("secret").unwrap();
+//! conn.authenticate("secret");
+//! ```
+//!
+//! Sending on a connection after closing it:
+//!
+//! ```compile_fail,E0382
+//! use module2::problems::{DisconnectedConnection, Loopback};
+//!
+//! let mut transport = Loopback::new("secret");
+//! let connecting = DisconnectedConnection.open(&mut transport).unwrap();
+//! let mut conn = connecting.authenticate("secret").unwrap();
+//! let closed = conn.close();
+//! conn.send(b"ping");
+//! ```
+//!
+//! Reopening a closed connection:
+//!
+//! ```compile_fail,E0599
+//! use module2::problems::{DisconnectedConnection, Loopback};
+//!
+//! let mut transport = Loopback::new("secret");
+//! let connecting = DisconnectedConnection.open(&mut transport).unwrap();
+//! let closed = connecting.authenticate("secret").unwrap().close();
+//! closed.open(&mut transport);
+//! ```
+//!
+//! Two connections over the same transport at once:
+//!
+//! ```compile_fail,E0499
+//! use module2::problems::{DisconnectedConnection, Loopback};
+//!
+//! let mut transport = Loopback::new("secret");
+//! let first = DisconnectedConnection.open(&mut transport).unwrap();
+//! let second = DisconnectedConnection.open(&mut transport).unwrap();
+//! first.authenticate("secret");
+//! ```
+//!
+//! Dropping the transport while a connection still uses it:
+//!
+//! ```compile_fail,E0505
+//! use module2::problems::{DisconnectedConnection, Loopback};
+//!
+//! let mut transport = Loopback::new("secret");
+//! let connecting = DisconnectedConnection.open(&mut transport).unwrap();
+//! drop(transport);
+//! connecting.authenticate("secret");
+//! ```
@@ -0,0 +1,97 @@
+// Module 2: Hints
+// Progressive hints for the exercises in problems.rs, one list per exercise,
+// shown by `rustpath run 2 --exercise <n> --hint <level>`. Level 1 is the
+// exercise's "This exercise demonstrates" list; these are levels 2 and up,
+// each giving away a little more than the one before.
+
+pub const HINTS: [&[&str]; 5] = [
+    // Exercise 1: Memory Layout and Ownership
+    &[
+        "`std::mem::size_of::<T>()` and `std::mem::align_of::<T>()` report a type's inline size \
+         and alignment. For HeapStruct that is only the pointer/length/capacity triples, not the \
+         heap data they point to.",
+        "StackStruct holds only plain values, so it can `#[derive(Clone, Copy)]`: assigning it \
+         copies and the original stays usable. HeapStruct owns heap memory, so assigning it moves \
+         it, and `.clone()` makes an explicit deep copy.",
+        "Print the sizes, copy the stack struct and print both copies, clone the heap struct, then \
+         move it into a new binding. Values are dropped in reverse declaration order at the end \
+         of the function.",
+    ],
+    // Exercise 2: Advanced Borrowing
+    &[
+        "`get_data` takes `&mut self` only so that it can bump `access_count`. The reference it \
+         returns then borrows the whole tracker until it is last used.",
+        "`modify_data` clones the data before calling `modifier(&mut self.data)`, then pushes \
+         `R::record(before, &self.data)` onto `revisions` and the description onto \
+         `modifications`. A new modification also clears `undone`.",
+        "For Snapshots the entry is the old value itself: `record` returns `before`, and both \
+         `undo` and `redo` are `std::mem::swap(entry, data)`, which leaves the other version in \
+         the entry for the way back.",
+        "`undo` pops an entry and a description (`?` on the Options), applies `R::undo`, pushes \
+         both onto `undone` and returns `self.undone.last()` as a `&str`. `redo` is the mirror \
+         image; `rollback_to` checks the count and calls `undo` until enough are gone.",
+        "`history` zips `self.modifications.iter().map(String::as_str)` with `&self.revisions`. \
+         For `VecDiff`, count the equal elements at the front, then at the back of what is \
+         left; `apply` and `revert` are `splice` calls over `at..at + removed.len()` and \
+         `at..at + inserted.len()`.",
+    ],
+    // Exercise 3: Resource Management
+    &[
+        "The pool owns its idle resources in a `Vec<T>` inside the Mutex. Whatever is removed \
+         from or never added to the Vec is dropped by whoever owns it at that point.",
+        "`add_resource` must refuse once idle plus checked-out resources reach `max_size`. \
+         `&mut self` rules out live guards, so `self.state.get_mut()` reaches the state \
+         without locking. Return `Err` without pushing, and the rejected resource is dropped \
+         (and cleaned up) right away.",
+        "`checkout` locks the state, pops a resource, stores a `Checkout` under the next \
+         number with `Location::caller()` and `Instant::now()`, and wraps the resource in a \
+         `PoolGuard`. A `#[track_caller]` function passes its caller's location on, so the \
+         timeout variants can share a private helper that takes the location as an argument.",
+        "The guard's Drop takes the resource out of its `Option`, runs the validator, removes \
+         its checkout number and either pushes the resource back or lets it drop, then calls \
+         `returned.notify_one()`. Release the lock before dropping a rejected resource.",
+        "Waiting is a loop: pop a resource if there is one, give up if nothing is checked out, \
+         otherwise `returned.wait_timeout(state, deadline - now)` and try again; wakeups can \
+         be spurious. A guard passed to `mem::forget` never runs Drop, so its checkout stays \
+         in the map; the pool's Drop prints what is left when `cfg!(debug_assertions)`.",
+    ],
+    // Exercise 4: Shared Ownership
+    &[
+        "Store every node in the HashMap as `Rc<RefCell<Node>>`, and hand out `Rc::clone`s of \
+         it. The map and the caller then share the node.",
+        "Edges point down strongly and up weakly: push `Rc::clone(to)` into the parent's \
+         `children` and `Rc::downgrade(from)` into the child's `parents`. Strong links in both \
+         directions would form a cycle that is never freed.",
+        "A cycle of `children` leaks just the same. Before adding an edge, check whether \
+         `self.bfs(to_id)` reaches `from` (`Rc::ptr_eq`); if it does, the new edge would close \
+         a cycle, so push `Rc::downgrade(to)` into `back_edges` instead.",
+        "`Bfs` pops from the front of its queue and pushes unseen successors to the back, \
+         marking them seen as they are queued. `Dfs` pops from its stack, skips nodes already \
+         seen, and pushes the successors in reverse. `shortest_path` is a BFS that records \
+         which node each id was first reached from, then walks that map back from `to`.",
+        "For `topological_sort`, visit each node depth-first and push its id once all its \
+         successors are done, then reverse. Keep the ids still being visited on a path; \
+         reaching one of them again means a cycle, and the path from it onwards is the answer. \
+         For `subgraph`, add the copied nodes first, then every edge between two of them.",
+    ],
+    // Exercise 5: Safe Abstractions
+    &[
+        "Each state is its own type, and each transition consumes `self`. Once you have \
+         disconnected, the old ConnectedConnection can no longer be used.",
+        "`handle` only has `&mut self`, but the transitions need the state by value. Take it out \
+         with `std::mem::replace(self, Connection::Disconnected(DisconnectedConnection))`, then \
+         assign the next state back to `*self`.",
+        "Match on the taken state. Disconnected connects, Connected sends data (turning into \
+         Failed on an error) and Failed stays failed. For the protocol, write a helper that \
+         writes a frame, reads the reply and adds both lengths to the counters; every \
+         transition is one call to it plus a check of the reply.",
+        "`Backoff::delay` is `initial.saturating_mul(2u32.saturating_pow(retry)).min(max)`: \
+         saturating, so a large retry number cannot overflow. `close` clears the transport's \
+         unread replies before its BYE exchange, and returns a ClosedConnection even if that \
+         exchange fails.",
+        "In `reconnect`, returning a `ConnectingConnection<'t>` from inside the loop makes the \
+         borrow checker hold `transport` for all of `'t`, so the next iteration cannot use it. \
+         Do the handshake in a helper that only borrows the transport for the call, and build \
+         the ConnectingConnection from `transport` once it has succeeded.",
+    ],
+];
@@ -0,0 +1,23 @@
+// Module 2: Ownership and Memory Management
+// Library target exposing the exercises so the `rustpath` runner can drive them.
+
+pub mod problems;
+
+// A zero-copy tokenizer that owns its input, shown in main.rs and timed by
+// the `tokens` benchmark (src/bin/tokens.rs)
+pub mod tokenizer;
+
+// longest, first_word and other functions returning slices of their inputs,
+// shown in main.rs
+pub mod strings;
+
+// Progressive hints shown by `rustpath run --hint`
+pub mod hints;
+
+// Compile-fail checks for the exercise 5 type states, run as doctests
+#[cfg(doctest)]
+mod compile_fail;
+
+// Reference solutions, compiled only with the `solutions` feature
+#[cfg(feature = "solutions")]
+pub mod solutions;
@@ -3,7 +3,9 @@
 // We'll explore not just how ownership works, but why it exists and how it compares
 // to other memory management approaches like garbage collection or manual management.
 
-mod problems;
+use module2::problems;
+use module2::strings;
+use module2::tokenizer::SafeParser;
 
 use std::mem;
 
@@ -80,17 +82,18 @@ fn main() {
     println!("\n3. Advanced Borrowing Patterns:");
     println!("----------------------------");
 
-    // Self-referential structs (usually need lifetime annotations)
-    // Fixed self-referential struct with proper lifetime
+    // Self-referential structs: a parser that owns its input and keeps a
+    // reference into it. There is no lifetime that means "as long as this
+    // struct's own field", so this version only compiles by leaking the
+    // String to get a reference that lives forever.
     struct Parser<'a> {
         content: String,
         current_pos: &'a str, // References part of content
     }
 
     impl<'a> Parser<'a> {
-        // This function now takes a reference to avoid the self-referential issue
         fn new(content: String) -> (Parser<'a>, &'a str) {
-            let content_ref = Box::leak(Box::new(content));
+            let content_ref = Box::leak(Box::new(content)); // Never freed
             let current_pos = &content_ref[..];
             (
                 Parser {
@@ -102,24 +105,25 @@ fn main() {
         }
     }
 
-    // Alternative approach using separate allocation
-    struct SafeParser {
-        content: String,
-        current_pos_idx: usize,
-    }
-
-    impl SafeParser {
-        fn new(content: String) -> Self {
-            SafeParser {
-                content,
-                current_pos_idx: 0,
-            }
-        }
-
-        fn current_pos(&self) -> &str {
-            &self.content[self.current_pos_idx..]
-        }
-    }
+    // The workaround (src/tokenizer.rs): keep the String and a position, and
+    // hand out tokens that borrow the parser instead of living inside it
+    let parser = SafeParser::new("total = price * (1 + rate)".to_string());
+    let name = parser.next_token().unwrap();
+    let checkpoint = parser.checkpoint();
+    println!("First token: {:?}, then peeking {:?}", name, parser.peek());
+    let rest: Vec<&str> = parser.tokens().collect();
+    println!("Rest: {:?} (still borrowing `parser`, no copies)", rest);
+    parser.rewind(checkpoint);
+    // An Iterator cannot lend out borrows of itself, so the owning variant
+    // yields byte ranges into the String it owns
+    let mut spans = parser.into_tokens();
+    let span = spans.next().unwrap();
+    println!(
+        "Owned iterator: {:?} is {:?}",
+        span.clone(),
+        spans.text(span)
+    );
+    println!("(Benchmark: cargo run -p module2 --release --bin tokens)");
 
     // Multiple mutable borrows (non-overlapping)
     let mut numbers = vec![1, 2, 3, 4, 5];
@@ -136,22 +140,25 @@ fn main() {
     println!("\n4. Lifetimes in Detail:");
     println!("---------------------");
 
-    // Explicit lifetime annotations
-    fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
-        if x.len() > y.len() {
-            x
-        } else {
-            y
-        }
-    }
-
-    // Lifetime elision rules
-    fn first_word(s: &str) -> &str {
-        // Lifetimes elided
-        match s.find(' ') {
-            Some(pos) => &s[..pos],
-            None => s,
-        }
+    // Explicit lifetime annotations (src/strings.rs): the result of
+    // `longest<'a>(x: &'a str, y: &'a str) -> &'a str` may borrow from
+    // either input, so both must outlive it
+    let title = String::from("Ownership and Borrowing");
+    let longer;
+    {
+        let subtitle = String::from("Borrowing Rules");
+        println!("Longest: {}", strings::longest(&title, &subtitle));
+        // `longer = strings::longest(&title, &subtitle);` would not compile:
+        // `subtitle` is dropped at the end of this block
+        longer = strings::longest_common_substring(&title, &subtitle);
+    } // Fine: the common substring borrows only from `title`
+    println!("Longest common substring: {:?}", longer);
+
+    // Lifetime elision rules: one input reference, so `first_word(s: &str)
+    // -> &str` needs no annotations
+    println!("First word: {}", strings::first_word(&title));
+    for (position, word) in strings::words_with_positions(&title) {
+        println!("  {:>2}: {}", position, word);
     }
 
     // Static lifetime
@@ -193,11 +200,13 @@ fn main() {
     println!("--------------------------");
 
     // Example: Iterator vs manual indexing
+    #[allow(clippy::useless_vec)]
     let vec = vec![1, 2, 3, 4, 5];
 
     // These compile to the same efficient machine code
     let sum1: i32 = vec.iter().sum();
     let mut sum2 = 0;
+    #[allow(clippy::needless_range_loop)] // manual indexing is the point of the comparison
     for i in 0..vec.len() {
         sum2 += vec[i];
     }
@@ -285,7 +294,7 @@ fn take_and_return(mut vec: Vec<i32>) -> Vec<i32> {
 //    - Borrowing for temporary access
 
 // Try experimenting with these concepts:
-// 1. Create self-referential structures
+// 1. Create self-referential structures (see src/tokenizer.rs)
 // 2. Implement custom Drop traits
 // 3. Use scoped threads with ownership
 // 4. Create zero-cost abstractions
@@ -3,9 +3,13 @@
 // Each problem is designed to deepen your understanding of how Rust manages memory
 // and why its approach leads to memory-safe, efficient code.
 
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
 use std::rc::Rc;
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
 
 pub fn run_exercises() {
     println!("Module 2 Exercises - Ownership and Memory Management");
@@ -18,41 +22,49 @@ pub fn run_exercises() {
     exercise5();
 }
 
+// Exercise entry points in order, used by `rustpath run <module> --exercise <n>`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Hidden per-exercise checks, run against this file by `rustpath grade`.
+#[cfg(all(test, feature = "grading"))]
+mod grading;
+
 // Exercise 1: Memory Layout and Ownership
 // This exercise demonstrates:
 // - Stack vs heap allocation
 // - Memory layout of different types
 // - Ownership transfer
 // - Drop behavior
+
+// Custom types with different memory characteristics
+#[derive(Debug)]
+struct StackStruct {
+    x: i32,
+    y: i32,
+    z: bool,
+}
+
+#[derive(Debug)]
+struct HeapStruct {
+    data: Vec<i32>,
+    name: String,
+}
+
+// Implement this function to demonstrate memory layout
+fn memory_layout_examples() {
+    // TODO: Create instances of both structs and demonstrate:
+    // 1. Size and alignment differences
+    // 2. Ownership transfer behavior
+    // 3. Drop behavior
+    // 4. Clone vs Copy semantics
+    unimplemented!("Implement memory_layout_examples");
+}
+
 fn exercise1() {
     println!("Exercise 1: Memory Layout and Ownership");
     println!("----------------------------------");
     println!("TODO: Implement the memory_layout_examples function\n");
 
-    // Custom types with different memory characteristics
-    #[derive(Debug)]
-    struct StackStruct {
-        x: i32,
-        y: i32,
-        z: bool,
-    }
-
-    #[derive(Debug)]
-    struct HeapStruct {
-        data: Vec<i32>,
-        name: String,
-    }
-
-    // Implement this function to demonstrate memory layout
-    fn memory_layout_examples() {
-        // TODO: Create instances of both structs and demonstrate:
-        // 1. Size and alignment differences
-        // 2. Ownership transfer behavior
-        // 3. Drop behavior
-        // 4. Clone vs Copy semantics
-        unimplemented!("Implement memory_layout_examples");
-    }
-
     // Test your implementation:
     // memory_layout_examples();
 }
@@ -63,49 +75,191 @@ fn exercise1() {
 // - Lifetime relationships
 // - Mutable and immutable borrows
 // - Borrowing rules and scope
-fn exercise2() {
-    println!("\nExercise 2: Advanced Borrowing");
-    println!("---------------------------");
-    println!("TODO: Implement the DataTracker struct and its methods\n");
+// - Returning references that keep a struct borrowed
+// - Undo history with snapshots or diffs
+
+// How a tracker remembers the versions it can go back to. `record` gets the
+// value from before a modification (a clone taken by the tracker) and the
+// value after it, and returns whatever undo and redo will need.
+trait Revisions<T> {
+    type Entry;
+
+    fn record(before: T, after: &T) -> Self::Entry;
+    fn undo(entry: &mut Self::Entry, data: &mut T);
+    fn redo(entry: &mut Self::Entry, data: &mut T);
+}
+
+// Keeps a full copy of every earlier version
+struct Snapshots;
+
+impl<T> Revisions<T> for Snapshots {
+    type Entry = T;
 
-    // A struct that tracks data and its access patterns
-    struct DataTracker<T> {
-        data: T,
-        access_count: usize,
-        modifications: Vec<String>,
+    fn record(before: T, _after: &T) -> T {
+        unimplemented!("Implement Snapshots::record");
     }
 
-    impl<T: std::fmt::Debug> DataTracker<T> {
-        // Create new tracker
-        fn new(data: T) -> Self {
-            unimplemented!("Implement new");
-        }
+    // TODO: Restore the earlier version, and leave the newer one in the
+    // entry so that redo can bring it back (std::mem::swap)
+    fn undo(entry: &mut T, data: &mut T) {
+        unimplemented!("Implement Snapshots::undo");
+    }
 
-        // Borrow data immutably and record access
-        fn get_data(&mut self) -> &T {
-            unimplemented!("Implement get_data");
-        }
+    fn redo(entry: &mut T, data: &mut T) {
+        unimplemented!("Implement Snapshots::redo");
+    }
+}
 
-        // Modify data and record modification
-        fn modify_data<F>(&mut self, modifier: F, description: &str)
-        where
-            F: FnOnce(&mut T),
-        {
-            unimplemented!("Implement modify_data");
-        }
+// Keeps only what changed, for types that can describe their changes
+struct Diffs;
 
-        // Get access statistics
-        fn get_stats(&self) -> (usize, &[String]) {
-            unimplemented!("Implement get_stats");
-        }
+trait Diffable {
+    type Diff;
+
+    // What turns `self` into `after`
+    fn diff(&self, after: &Self) -> Self::Diff;
+    // Turns the old value into the new one
+    fn apply(&mut self, diff: &Self::Diff);
+    // Turns the new value back into the old one
+    fn revert(&mut self, diff: &Self::Diff);
+}
+
+impl<T: Diffable> Revisions<T> for Diffs {
+    type Entry = T::Diff;
+
+    fn record(before: T, after: &T) -> T::Diff {
+        before.diff(after)
+    }
+
+    fn undo(entry: &mut T::Diff, data: &mut T) {
+        data.revert(entry);
+    }
+
+    fn redo(entry: &mut T::Diff, data: &mut T) {
+        data.apply(entry);
+    }
+}
+
+// The elements between the common prefix and the common suffix of two
+// versions of a Vec: `removed` from the old one, `inserted` in the new one
+#[derive(Debug, PartialEq)]
+struct VecDiff<T> {
+    at: usize,
+    removed: Vec<T>,
+    inserted: Vec<T>,
+}
+
+impl<T: Clone + PartialEq> Diffable for Vec<T> {
+    type Diff = VecDiff<T>;
+
+    fn diff(&self, after: &Self) -> VecDiff<T> {
+        unimplemented!("Implement diff");
+    }
+
+    // Hint: Vec::splice replaces a range with the items of an iterator
+    fn apply(&mut self, diff: &VecDiff<T>) {
+        unimplemented!("Implement apply");
+    }
+
+    fn revert(&mut self, diff: &VecDiff<T>) {
+        unimplemented!("Implement revert");
+    }
+}
+
+// A struct that tracks data and its access patterns
+// `modifications` and `revisions` run in step: one description and one
+// entry per modification currently applied. Undone ones move to `undone`
+// (most recent last) until redone, or dropped by the next modification.
+struct DataTracker<T, R: Revisions<T> = Snapshots> {
+    data: T,
+    access_count: usize,
+    modifications: Vec<String>,
+    revisions: Vec<R::Entry>,
+    undone: Vec<(String, R::Entry)>,
+}
+
+impl<T: Clone> DataTracker<T> {
+    // Create new tracker, keeping snapshots
+    fn new(data: T) -> Self {
+        DataTracker::with_revisions(data)
+    }
+}
+
+impl<T: Clone + Diffable> DataTracker<T, Diffs> {
+    // Create new tracker, keeping diffs
+    fn with_diffs(data: T) -> Self {
+        DataTracker::with_revisions(data)
+    }
+}
+
+impl<T: Clone, R: Revisions<T>> DataTracker<T, R> {
+    fn with_revisions(data: T) -> Self {
+        unimplemented!("Implement with_revisions");
+    }
+
+    // Borrow data immutably and record access
+    fn get_data(&mut self) -> &T {
+        unimplemented!("Implement get_data");
+    }
+
+    // Modify data and record modification
+    fn modify_data<F>(&mut self, modifier: F, description: &str)
+    where
+        F: FnOnce(&mut T),
+    {
+        unimplemented!("Implement modify_data");
     }
 
+    // Get access statistics
+    fn get_stats(&self) -> (usize, &[String]) {
+        unimplemented!("Implement get_stats");
+    }
+
+    // Take back the latest modification, returning its description
+    fn undo(&mut self) -> Option<&str> {
+        unimplemented!("Implement undo");
+    }
+
+    // Reapply the most recently undone modification
+    fn redo(&mut self) -> Option<&str> {
+        unimplemented!("Implement redo");
+    }
+
+    // Undo modifications until only the first `count` remain
+    fn rollback_to(&mut self, count: usize) -> Result<(), String> {
+        unimplemented!("Implement rollback_to");
+    }
+
+    // The applied modifications, oldest first, with what each one stored
+    fn history(&self) -> impl Iterator<Item = (&str, &R::Entry)> {
+        unimplemented!("Implement history");
+        // Never reached; an `impl Iterator` return needs some iterator type
+        // to stand behind it until the real one is written
+        std::iter::empty()
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Advanced Borrowing");
+    println!("---------------------------");
+    println!("TODO: Implement the DataTracker struct and its methods\n");
+
     // Test your implementation:
     // let mut tracker = DataTracker::new(vec![1, 2, 3]);
     // println!("Initial data: {:?}", tracker.get_data());
     // tracker.modify_data(|v| v.push(4), "Added 4");
     // let (accesses, mods) = tracker.get_stats();
     // println!("Accesses: {}, Modifications: {:?}", accesses, mods);
+    // tracker.modify_data(|v| v.retain(|&x| x % 2 == 0), "Kept evens");
+    // for (description, before) in tracker.history() {
+    //     println!("  {} was {:?}", description, before);
+    // }
+    // let undone = tracker.undo().map(str::to_string);
+    // println!("Undo {:?}: {:?}", undone, tracker.get_data());
+    //
+    // let mut tracker = DataTracker::with_diffs((1..=1000).collect::<Vec<i32>>());
+    // tracker.modify_data(|v| v[500] = 0, "Zeroed 501");
+    // println!("{:?}", tracker.history().next());
 }
 
 // Exercise 3: Resource Management
@@ -114,58 +268,166 @@ fn exercise2() {
 // - Custom Drop implementation
 // - Resource cleanup
 // - Error handling with resources
-fn exercise3() {
-    println!("\nExercise 3: Resource Management");
-    println!("----------------------------");
-    println!("TODO: Implement the ResourcePool struct and its methods\n");
+// - Guards that hand a borrowed resource back when dropped
+// - Blocking on a Condvar with a timeout
+// - Detecting leaks with mem::forget and #[track_caller]
+
+// A generic resource pool that manages cleanup. Guards hand resources back
+// through a shared reference, possibly from another thread, so the pool's
+// state lives behind a Mutex.
+struct ResourcePool<T> {
+    state: Mutex<PoolState<T>>,
+    // Signal this whenever a guard hands its resource back
+    returned: Condvar,
+    max_size: usize,
+    validator: Option<Validator<T>>,
+}
+
+// Decides whether a returned resource may go back into the pool
+type Validator<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
+
+struct PoolState<T> {
+    // Resources waiting to be checked out
+    resources: Vec<T>,
+    // Guards not yet dropped, by checkout number
+    checked_out: HashMap<u64, Checkout>,
+    next_checkout: u64,
+}
+
+// Where and when a guard was handed out, for the leak report
+struct Checkout {
+    location: &'static Location<'static>,
+    since: Instant,
+}
 
-    // A generic resource pool that manages cleanup
-    struct ResourcePool<T> {
-        resources: Vec<T>,
-        max_size: usize,
+// A resource on loan from a pool, handed back when the guard is dropped
+struct PoolGuard<'a, T> {
+    pool: &'a ResourcePool<T>,
+    // Only None while the guard is being dropped
+    resource: Option<T>,
+    checkout: u64,
+}
+
+// A resource that requires cleanup
+struct Resource {
+    id: usize,
+    data: Vec<u8>,
+}
+
+impl Resource {
+    fn new(id: usize) -> Self {
+        unimplemented!("Implement Resource::new");
     }
+}
 
-    // A resource that requires cleanup
-    struct Resource {
-        id: usize,
-        data: Vec<u8>,
+impl Drop for Resource {
+    fn drop(&mut self) {
+        // Simulate resource cleanup
+        println!("Cleaning up resource {}", self.id);
     }
+}
 
-    impl Resource {
-        fn new(id: usize) -> Self {
-            unimplemented!("Implement Resource::new");
-        }
+impl<T> ResourcePool<T> {
+    // Initialize the pool
+    fn new(max_size: usize) -> Self {
+        unimplemented!("Implement ResourcePool::new");
     }
 
-    impl Drop for Resource {
-        fn drop(&mut self) {
-            // Simulate resource cleanup
-            println!("Cleaning up resource {}", self.id);
-        }
+    // Resources failing `valid` when they come back are dropped instead of
+    // being returned to the pool, which frees their slot
+    fn with_validator(mut self, valid: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        unimplemented!("Implement with_validator");
     }
 
-    impl<T> ResourcePool<T> {
-        // Initialize the pool
-        fn new(max_size: usize) -> Self {
-            unimplemented!("Implement ResourcePool::new");
-        }
+    // Add resource to pool, return error if pool is full. Checked-out
+    // resources still count towards `max_size`.
+    fn add_resource(&mut self, resource: T) -> Result<(), String> {
+        unimplemented!("Implement add_resource");
+    }
 
-        // Add resource to pool, return error if pool is full
-        fn add_resource(&mut self, resource: T) -> Result<(), String> {
-            unimplemented!("Implement add_resource");
-        }
+    // Remove and return resource if available; it leaves the pool for good
+    fn take_resource(&mut self) -> Option<T> {
+        unimplemented!("Implement take_resource");
+    }
 
-        // Remove and return resource if available
-        fn take_resource(&mut self) -> Option<T> {
-            unimplemented!("Implement take_resource");
-        }
+    // Lend out a resource if one is free right now, recording
+    // `Location::caller()` for the leak report
+    #[track_caller]
+    fn checkout(&self) -> Option<PoolGuard<'_, T>> {
+        unimplemented!("Implement checkout");
+    }
+
+    // Wait up to `timeout` for another guard to hand a resource back
+    #[track_caller]
+    fn checkout_timeout(&self, timeout: Duration) -> Result<PoolGuard<'_, T>, String> {
+        unimplemented!("Implement checkout_timeout");
+    }
+
+    // Wait for as long as it takes; fail only if nothing is checked out that
+    // could ever come back
+    #[track_caller]
+    fn checkout_blocking(&self) -> Result<PoolGuard<'_, T>, String> {
+        unimplemented!("Implement checkout_blocking");
+    }
+
+    // One line per guard that has not been dropped, oldest first, naming
+    // the checkout number and where it was checked out
+    fn leak_report(&self) -> Vec<String> {
+        unimplemented!("Implement leak_report");
+    }
+}
+
+impl<T> Deref for PoolGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.resource
+            .as_ref()
+            .expect("resource is present until drop")
+    }
+}
+
+impl<T> DerefMut for PoolGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.resource
+            .as_mut()
+            .expect("resource is present until drop")
     }
+}
+
+impl<T> Drop for PoolGuard<'_, T> {
+    // Validate the resource, then put it back (or drop it) and wake a waiter
+    fn drop(&mut self) {
+        unimplemented!("Implement PoolGuard::drop");
+    }
+}
+
+impl<T> Drop for ResourcePool<T> {
+    // TODO: in debug builds, print the leak report to stderr if it is not
+    // empty. (Left empty rather than unimplemented!, so that every pool the
+    // earlier tests drop doesn't panic.)
+    fn drop(&mut self) {}
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Resource Management");
+    println!("----------------------------");
+    println!("TODO: Implement the ResourcePool struct and its methods\n");
 
     // Test your implementation:
     // let mut pool = ResourcePool::new(2);
     // pool.add_resource(Resource::new(1)).unwrap();
     // pool.add_resource(Resource::new(2)).unwrap();
     // assert!(pool.add_resource(Resource::new(3)).is_err()); // Pool is full
+    //
+    // let pool = pool.with_validator(|resource| resource.data.len() == 64);
+    // {
+    //     let guard = pool.checkout().unwrap();
+    //     println!("Checked out resource {}", guard.id);
+    // } // The guard hands the resource back here
+    // let guard = pool.checkout().unwrap();
+    // std::mem::forget(guard);
+    // println!("{:?}", pool.leak_report()); // Names the line above
 }
 
 // Exercise 4: Shared Ownership
@@ -174,52 +436,139 @@ fn exercise3() {
 // - Interior mutability with RefCell<T>
 // - Circular references
 // - Memory leak prevention
-fn exercise4() {
-    println!("\nExercise 4: Shared Ownership");
-    println!("--------------------------");
-    println!("TODO: Implement the Graph struct and its methods\n");
+// - Custom iterators over shared nodes
+// - Graph algorithms: BFS, DFS, topological sort, shortest path
+
+// A node in a graph that can have multiple parents and children
+type NodeHandle = Rc<RefCell<Node>>;
+
+struct Node {
+    id: usize,
+    data: String,
+    children: Vec<NodeHandle>,
+    // Weak edges for the edges that would close a cycle, so the strong
+    // edges never form one and dropping the graph frees every node
+    back_edges: Vec<std::rc::Weak<RefCell<Node>>>,
+    // Weak references to parents to prevent reference cycles
+    parents: Vec<std::rc::Weak<RefCell<Node>>>,
+}
 
-    // A node in a graph that can have multiple parents and children
-    type NodeHandle = Rc<RefCell<Node>>;
+struct Graph {
+    nodes: HashMap<usize, NodeHandle>,
+}
+
+thread_local! {
+    // Nodes created on this thread and not dropped yet
+    static LIVE_NODES: Cell<usize> = const { Cell::new(0) };
+}
+
+fn live_nodes() -> usize {
+    LIVE_NODES.with(Cell::get)
+}
 
-    struct Node {
-        id: usize,
-        data: String,
-        children: Vec<NodeHandle>,
-        // Weak references to parents to prevent reference cycles
-        parents: Vec<std::rc::Weak<RefCell<Node>>>,
+impl Node {
+    // Count the new node in LIVE_NODES; Drop below takes it off again
+    fn new(id: usize, data: String) -> Self {
+        unimplemented!("Implement Node::new");
     }
 
-    struct Graph {
-        nodes: HashMap<usize, NodeHandle>,
+    // Every node this one has an edge to, strong edges first
+    fn successors(&self) -> Vec<NodeHandle> {
+        unimplemented!("Implement successors");
     }
+}
 
-    impl Node {
-        fn new(id: usize, data: String) -> Self {
-            unimplemented!("Implement Node::new");
-        }
+impl Drop for Node {
+    fn drop(&mut self) {
+        LIVE_NODES.with(|live| live.set(live.get() - 1));
     }
+}
 
-    impl Graph {
-        fn new() -> Self {
-            unimplemented!("Implement Graph::new");
-        }
+// Nodes in breadth-first order, each visited once
+struct Bfs {
+    queue: VecDeque<NodeHandle>,
+    seen: HashSet<usize>,
+}
 
-        // Add a node to the graph
-        fn add_node(&mut self, id: usize, data: String) -> NodeHandle {
-            unimplemented!("Implement add_node");
-        }
+impl Iterator for Bfs {
+    type Item = NodeHandle;
 
-        // Add an edge between nodes
-        fn add_edge(&mut self, from_id: usize, to_id: usize) -> Result<(), String> {
-            unimplemented!("Implement add_edge");
-        }
+    fn next(&mut self) -> Option<NodeHandle> {
+        unimplemented!("Implement Bfs::next");
+    }
+}
 
-        // Get all ancestors of a node
-        fn get_ancestors(&self, id: usize) -> Vec<usize> {
-            unimplemented!("Implement get_ancestors");
-        }
+// Nodes in depth-first preorder, each visited once
+struct Dfs {
+    stack: Vec<NodeHandle>,
+    seen: HashSet<usize>,
+}
+
+impl Iterator for Dfs {
+    type Item = NodeHandle;
+
+    fn next(&mut self) -> Option<NodeHandle> {
+        unimplemented!("Implement Dfs::next");
     }
+}
+
+impl Graph {
+    fn new() -> Self {
+        unimplemented!("Implement Graph::new");
+    }
+
+    // Add a node to the graph
+    fn add_node(&mut self, id: usize, data: String) -> NodeHandle {
+        unimplemented!("Implement add_node");
+    }
+
+    // Add an edge between nodes. If `from` can already be reached from `to`,
+    // the edge closes a cycle and goes into `back_edges` instead of
+    // `children`.
+    fn add_edge(&mut self, from_id: usize, to_id: usize) -> Result<(), String> {
+        unimplemented!("Implement add_edge");
+    }
+
+    // Get all ancestors of a node
+    fn get_ancestors(&self, id: usize) -> Vec<usize> {
+        unimplemented!("Implement get_ancestors");
+    }
+
+    // Everything reachable from `start`, nearest first; empty if there is
+    // no such node
+    fn bfs(&self, start: usize) -> Bfs {
+        unimplemented!("Implement bfs");
+    }
+
+    // Everything reachable from `start`, following the first successor as
+    // deep as it goes before the next
+    fn dfs(&self, start: usize) -> Dfs {
+        unimplemented!("Implement dfs");
+    }
+
+    // The ids ordered so that every edge points forwards, or the ids around
+    // a cycle if there is none: each has an edge to the next, and the last
+    // one back to the first
+    fn topological_sort(&self) -> Result<Vec<usize>, Vec<usize>> {
+        unimplemented!("Implement topological_sort");
+    }
+
+    // The path with the fewest edges, both ends included
+    fn shortest_path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        unimplemented!("Implement shortest_path");
+    }
+
+    // A new graph with copies of the given nodes and the edges between them.
+    // Unknown ids are skipped.
+    fn subgraph(&self, ids: &[usize]) -> Graph {
+        unimplemented!("Implement subgraph");
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Shared Ownership");
+    println!("--------------------------");
+    println!("TODO: Implement the Graph struct and its methods\n");
 
     // Test your implementation:
     // let mut graph = Graph::new();
@@ -227,6 +576,11 @@ fn exercise4() {
     // let node2 = graph.add_node(2, "Two".to_string());
     // graph.add_edge(1, 2).unwrap();
     // println!("Ancestors of 2: {:?}", graph.get_ancestors(2));
+    //
+    // graph.add_edge(2, 1).unwrap(); // Closes a cycle
+    // println!("{:?}", graph.topological_sort()); // Err([1, 2])
+    // drop((node1, node2, graph));
+    // println!("Live nodes: {}", live_nodes()); // 0
 }
 
 // Exercise 5: Safe Abstractions
@@ -235,183 +589,255 @@ fn exercise4() {
 // - Ownership and borrowing in APIs
 // - Error handling
 // - Type-state programming
-fn exercise5() {
-    println!("\nExercise 5: Safe Abstractions");
-    println!("--------------------------");
-    println!("TODO: Implement the Connection types and methods\n");
+// - States that borrow a resource for as long as they last
+// - Retrying with exponential backoff
+//
+// The protocol types are `pub` so that the compile-fail checks in
+// src/compile_fail.rs can name them: each check makes a transition the
+// types are meant to rule out, and must be rejected by the compiler.
+
+// Type-state programming example: Connection handling
+pub struct DisconnectedConnection;
+
+struct ConnectedConnection {
+    buffer: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct FailedConnection {
+    error: String,
+    // Connection attempts that have failed in a row
+    attempts: u32,
+}
 
-    // Type-state programming example: Connection handling
-    struct DisconnectedConnection;
-    struct ConnectedConnection {
-        buffer: Vec<u8>,
+// Connection that can be in different states
+enum Connection {
+    Disconnected(DisconnectedConnection),
+    Connected(ConnectedConnection),
+    Failed(FailedConnection),
+}
+
+impl DisconnectedConnection {
+    // Attempt to connect
+    fn connect(self) -> Connection {
+        unimplemented!("Implement connect");
     }
-    struct FailedConnection {
-        error: String,
+}
+
+impl ConnectedConnection {
+    // Send data
+    fn send_data(&mut self, data: &[u8]) -> Result<(), String> {
+        unimplemented!("Implement send_data");
     }
 
-    // Connection that can be in different states
-    enum Connection {
-        Disconnected(DisconnectedConnection),
-        Connected(ConnectedConnection),
-        Failed(FailedConnection),
+    // Disconnect gracefully
+    fn disconnect(self) -> DisconnectedConnection {
+        unimplemented!("Implement disconnect");
     }
+}
 
-    impl DisconnectedConnection {
-        // Attempt to connect
-        fn connect(self) -> Connection {
-            unimplemented!("Implement connect");
-        }
+impl Connection {
+    // Create new connection in disconnected state
+    fn new() -> Self {
+        Connection::Disconnected(DisconnectedConnection)
     }
 
-    impl ConnectedConnection {
-        // Send data
-        fn send_data(&mut self, data: &[u8]) -> Result<(), String> {
-            unimplemented!("Implement send_data");
-        }
+    // Handle the connection based on its state
+    fn handle(&mut self) {
+        unimplemented!("Implement handle");
+    }
+}
 
-        // Disconnect gracefully
-        fn disconnect(self) -> DisconnectedConnection {
-            unimplemented!("Implement disconnect");
+// The full protocol: Disconnected -> Connecting -> Authenticated -> Closed,
+// with Failed reachable from the first two. It runs over this in-memory
+// network, which is provided: the peer on the other end answers each frame
+// the client writes by queueing a reply for it to read.
+//
+//   client writes      peer replies
+//   HELLO              WELCOME (or refuses, see `refusing`)
+//   AUTH <token>       OK, or DENIED for the wrong token
+//   BYE                BYE
+//   anything else      the same bytes, once authenticated
+pub struct Loopback {
+    replies: VecDeque<Vec<u8>>,
+    token: String,
+    // The peer refuses this many connection attempts before accepting
+    refusals: u32,
+    connected: bool,
+    authenticated: bool,
+}
+
+impl Loopback {
+    pub fn new(token: &str) -> Self {
+        Loopback {
+            replies: VecDeque::new(),
+            token: token.to_string(),
+            refusals: 0,
+            connected: false,
+            authenticated: false,
         }
     }
 
-    impl Connection {
-        // Create new connection in disconnected state
-        fn new() -> Self {
-            Connection::Disconnected(DisconnectedConnection)
-        }
+    pub fn refusing(mut self, attempts: u32) -> Self {
+        self.refusals = attempts;
+        self
+    }
 
-        // Handle the connection based on its state
-        fn handle(&mut self) {
-            unimplemented!("Implement handle");
-        }
+    fn write(&mut self, frame: &[u8]) -> Result<(), String> {
+        let reply: &[u8] = match frame {
+            b"HELLO" if self.refusals > 0 => {
+                self.refusals -= 1;
+                return Err("Connection refused".to_string());
+            }
+            b"HELLO" => {
+                self.connected = true;
+                b"WELCOME"
+            }
+            _ if !self.connected => return Err("Not connected".to_string()),
+            b"BYE" => {
+                self.connected = false;
+                self.authenticated = false;
+                b"BYE"
+            }
+            _ => match frame.strip_prefix(b"AUTH ") {
+                Some(token) if token == self.token.as_bytes() => {
+                    self.authenticated = true;
+                    b"OK"
+                }
+                Some(_) => b"DENIED",
+                None if self.authenticated => frame,
+                None => return Err("Not authenticated".to_string()),
+            },
+        };
+        self.replies.push_back(reply.to_vec());
+        Ok(())
     }
 
-    // Test your implementation:
-    // let mut conn = Connection::new();
-    // conn.handle();
+    fn read(&mut self) -> Result<Vec<u8>, String> {
+        self.replies
+            .pop_front()
+            .ok_or_else(|| "Nothing to read".to_string())
+    }
 }
 
-/* Example Solutions (Try to solve the exercises before looking at these!)
+// Bytes written and read over the life of one connection, frames included
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ByteCounters {
+    pub sent: usize,
+    pub received: usize,
+}
 
-// Exercise 1 Solution:
-fn memory_layout_examples() {
-    let stack_struct = StackStruct { x: 1, y: 2, z: true };
-    let heap_struct = HeapStruct {
-        data: vec![1, 2, 3],
-        name: String::from("example"),
-    };
+// Delays between reconnection attempts: `initial`, doubling each time up
+// to `max`, for at most `attempts` attempts
+pub struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub attempts: u32,
+}
 
-    println!("Stack struct size: {} bytes", std::mem::size_of::<StackStruct>());
-    println!("Heap struct size: {} bytes", std::mem::size_of::<HeapStruct>());
+impl Backoff {
+    // The delay before retry number `retry`, counting from 0
+    pub fn delay(&self, retry: u32) -> Duration {
+        unimplemented!("Implement Backoff::delay");
+    }
+}
 
-    // Move semantics
-    let moved_stack = stack_struct; // Creates a copy
-    println!("Original still valid: {:?}", stack_struct);
+// The handshake has been accepted but the client has not logged in yet.
+// Each state below borrows the transport for as long as it is connected.
+pub struct ConnectingConnection<'t> {
+    transport: &'t mut Loopback,
+    counters: ByteCounters,
+}
 
-    let moved_heap = heap_struct; // Moves ownership
-    // println!("Original invalid: {:?}", heap_struct); // Would not compile
+pub struct AuthenticatedConnection<'t> {
+    transport: &'t mut Loopback,
+    counters: ByteCounters,
 }
 
-// Exercise 2 Solution:
-impl<T: std::fmt::Debug> DataTracker<T> {
-    fn new(data: T) -> Self {
-        DataTracker {
-            data,
-            access_count: 0,
-            modifications: Vec::new(),
-        }
-    }
+// Finished for good: there are no transitions out of it
+pub struct ClosedConnection {
+    pub counters: ByteCounters,
+}
 
-    fn get_data(&mut self) -> &T {
-        self.access_count += 1;
-        &self.data
+impl DisconnectedConnection {
+    // Write HELLO and expect WELCOME back. A failure counts as attempt 1.
+    pub fn open(
+        self,
+        transport: &mut Loopback,
+    ) -> Result<ConnectingConnection<'_>, FailedConnection> {
+        unimplemented!("Implement open");
     }
+}
 
-    fn modify_data<F>(&mut self, modifier: F, description: &str)
-    where
-        F: FnOnce(&mut T),
-    {
-        modifier(&mut self.data);
-        self.modifications.push(description.to_string());
+impl<'t> ConnectingConnection<'t> {
+    // Write `AUTH <token>` and expect OK back
+    pub fn authenticate(
+        mut self,
+        token: &str,
+    ) -> Result<AuthenticatedConnection<'t>, FailedConnection> {
+        unimplemented!("Implement authenticate");
     }
+}
 
-    fn get_stats(&self) -> (usize, &[String]) {
-        (self.access_count, &self.modifications)
+impl AuthenticatedConnection<'_> {
+    pub fn send(&mut self, data: &[u8]) -> Result<(), String> {
+        unimplemented!("Implement send");
     }
-}
 
-// Exercise 3 Solution:
-impl<T> ResourcePool<T> {
-    fn new(max_size: usize) -> Self {
-        ResourcePool {
-            resources: Vec::new(),
-            max_size,
-        }
+    pub fn receive(&mut self) -> Result<Vec<u8>, String> {
+        unimplemented!("Implement receive");
     }
 
-    fn add_resource(&mut self, resource: T) -> Result<(), String> {
-        if self.resources.len() >= self.max_size {
-            Err("Pool is full".to_string())
-        } else {
-            self.resources.push(resource);
-            Ok(())
-        }
+    pub fn counters(&self) -> ByteCounters {
+        unimplemented!("Implement counters");
     }
 
-    fn take_resource(&mut self) -> Option<T> {
-        self.resources.pop()
+    // Discard anything unread, then write BYE and read the peer's BYE
+    pub fn close(mut self) -> ClosedConnection {
+        unimplemented!("Implement close");
     }
 }
 
-// Exercise 4 Solution:
-impl Graph {
-    fn add_node(&mut self, id: usize, data: String) -> NodeHandle {
-        let node = Rc::new(RefCell::new(Node::new(id, data)));
-        self.nodes.insert(id, Rc::clone(&node));
-        node
+impl FailedConnection {
+    pub fn error(&self) -> &str {
+        &self.error
     }
 
-    fn add_edge(&mut self, from_id: usize, to_id: usize) -> Result<(), String> {
-        let from_node = self.nodes.get(&from_id).ok_or("From node not found")?;
-        let to_node = self.nodes.get(&to_id).ok_or("To node not found")?;
-
-        from_node.borrow_mut().children.push(Rc::clone(to_node));
-        to_node.borrow_mut().parents.push(Rc::downgrade(from_node));
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
 
-        Ok(())
+    // Up to `backoff.attempts` more handshakes, calling `wait` with
+    // `backoff.delay(n)` before each; `wait` is `std::thread::sleep`
+    // outside of tests
+    pub fn reconnect<'t>(
+        mut self,
+        transport: &'t mut Loopback,
+        backoff: &Backoff,
+        mut wait: impl FnMut(Duration),
+    ) -> Result<ConnectingConnection<'t>, FailedConnection> {
+        unimplemented!("Implement reconnect");
     }
 }
 
-// Exercise 5 Solution:
-impl ConnectedConnection {
-    fn send_data(&mut self, data: &[u8]) -> Result<(), String> {
-        self.buffer.extend_from_slice(data);
-        Ok(())
-    }
+fn exercise5() {
+    println!("\nExercise 5: Safe Abstractions");
+    println!("--------------------------");
+    println!("TODO: Implement the Connection types and methods\n");
 
-    fn disconnect(self) -> DisconnectedConnection {
-        DisconnectedConnection
-    }
+    // Test your implementation:
+    // let mut conn = Connection::new();
+    // conn.handle();
+    //
+    // let mut transport = Loopback::new("secret");
+    // let connecting = DisconnectedConnection.open(&mut transport).unwrap();
+    // let mut conn = connecting.authenticate("secret").unwrap();
+    // conn.send(b"ping").unwrap();
+    // println!("{:?}", conn.receive()); // Ok([112, 105, 110, 103])
+    // println!("{:?}", conn.close().counters);
 }
 
-impl Connection {
-    fn handle(&mut self) {
-        match self {
-            Connection::Disconnected(conn) => {
-                *self = conn.connect();
-            }
-            Connection::Connected(conn) => {
-                if let Err(e) = conn.send_data(&[1, 2, 3]) {
-                    *self = Connection::Failed(FailedConnection {
-                        error: e,
-                    });
-                }
-            }
-            Connection::Failed(_) => {
-                // Handle failure state
-            }
-        }
-    }
-}
-*/
+// Example Solutions
+// The reference answers live in src/solutions.rs and are compiled with the
+// `solutions` feature (`rustpath run <module> --solution`). Try solving the
+// exercises yourself first!
@@ -0,0 +1,496 @@
+// Grading suite for the module 2 exercises
+// These checks are hidden from the exercise walkthrough: `rustpath grade 2`
+// runs them against your implementations in problems.rs.
+
+use super::*;
+
+mod exercise1 {
+    use super::*;
+
+    #[test]
+    fn test_memory_layout_examples_runs() {
+        memory_layout_examples();
+    }
+}
+
+mod exercise2 {
+    use super::*;
+
+    #[test]
+    fn test_new_tracker_has_no_history() {
+        let tracker = DataTracker::new(vec![1, 2, 3]);
+        let (accesses, modifications) = tracker.get_stats();
+        assert_eq!(accesses, 0);
+        assert!(modifications.is_empty());
+    }
+
+    #[test]
+    fn test_get_data_counts_accesses() {
+        let mut tracker = DataTracker::new(String::from("data"));
+        assert_eq!(tracker.get_data(), "data");
+        tracker.get_data();
+        assert_eq!(tracker.get_stats().0, 2);
+    }
+
+    #[test]
+    fn test_modify_data_records_description() {
+        let mut tracker = DataTracker::new(vec![1, 2, 3]);
+        tracker.modify_data(|v| v.push(4), "Added 4");
+        tracker.modify_data(|v| v.retain(|&x| x % 2 == 0), "Kept evens");
+        assert_eq!(tracker.get_data(), &vec![2, 4]);
+        assert_eq!(tracker.get_stats().1, ["Added 4", "Kept evens"]);
+    }
+
+    #[test]
+    fn test_undo_and_redo() {
+        let mut tracker = DataTracker::new(String::from("a"));
+        assert_eq!(tracker.undo(), None);
+        tracker.modify_data(|s| s.push('b'), "Added b");
+        tracker.modify_data(|s| s.push('c'), "Added c");
+
+        assert_eq!(tracker.undo(), Some("Added c"));
+        assert_eq!(tracker.get_data(), "ab");
+        assert_eq!(tracker.undo(), Some("Added b"));
+        assert_eq!(tracker.get_data(), "a");
+        assert!(tracker.get_stats().1.is_empty());
+
+        assert_eq!(tracker.redo(), Some("Added b"));
+        assert_eq!(tracker.get_data(), "ab");
+        assert_eq!(tracker.get_stats().1, ["Added b"]);
+
+        // A new modification discards what could have been redone
+        tracker.modify_data(|s| s.push('d'), "Added d");
+        assert_eq!(tracker.redo(), None);
+        assert_eq!(tracker.get_data(), "abd");
+    }
+
+    #[test]
+    fn test_rollback_to() {
+        let mut tracker = DataTracker::new(vec![0]);
+        for i in 1..=4 {
+            tracker.modify_data(|v| v.push(i), &format!("Pushed {}", i));
+        }
+        assert!(tracker.rollback_to(5).is_err());
+        tracker.rollback_to(1).unwrap();
+        assert_eq!(tracker.get_data(), &vec![0, 1]);
+        assert_eq!(tracker.get_stats().1, ["Pushed 1"]);
+        tracker.redo();
+        assert_eq!(tracker.get_data(), &vec![0, 1, 2]);
+        tracker.rollback_to(0).unwrap();
+        assert_eq!(tracker.get_data(), &vec![0]);
+    }
+
+    #[test]
+    fn test_history_holds_earlier_versions() {
+        let mut tracker = DataTracker::new(vec![1]);
+        tracker.modify_data(|v| v.push(2), "Added 2");
+        tracker.modify_data(|v| v.clear(), "Cleared");
+        let history: Vec<(&str, &Vec<i32>)> = tracker.history().collect();
+        assert_eq!(history, [("Added 2", &vec![1]), ("Cleared", &vec![1, 2])]);
+    }
+
+    #[test]
+    fn test_vec_diff_keeps_only_the_change() {
+        let before = vec![1, 2, 3, 4, 5];
+        let after = vec![1, 2, 9, 9, 4, 5];
+        let diff = before.diff(&after);
+        assert_eq!(
+            diff,
+            VecDiff {
+                at: 2,
+                removed: vec![3],
+                inserted: vec![9, 9]
+            }
+        );
+
+        let mut data = before.clone();
+        data.apply(&diff);
+        assert_eq!(data, after);
+        data.revert(&diff);
+        assert_eq!(data, before);
+    }
+
+    #[test]
+    fn test_diff_tracker_undo_and_redo() {
+        let mut tracker = DataTracker::with_diffs(vec![1, 2, 3]);
+        tracker.modify_data(|v| v.insert(0, 0), "Prepended 0");
+        tracker.modify_data(|v| v.truncate(2), "Truncated");
+        assert_eq!(tracker.get_data(), &vec![0, 1]);
+
+        let diffs: Vec<&VecDiff<i32>> = tracker.history().map(|(_, diff)| diff).collect();
+        assert_eq!(diffs[1].removed, [2, 3]);
+
+        tracker.rollback_to(0).unwrap();
+        assert_eq!(tracker.get_data(), &vec![1, 2, 3]);
+        assert_eq!(tracker.redo(), Some("Prepended 0"));
+        assert_eq!(tracker.redo(), Some("Truncated"));
+        assert_eq!(tracker.get_data(), &vec![0, 1]);
+    }
+}
+
+mod exercise3 {
+    use super::*;
+
+    #[test]
+    fn test_pool_rejects_resources_beyond_max_size() {
+        let mut pool = ResourcePool::new(2);
+        assert!(pool.add_resource(1).is_ok());
+        assert!(pool.add_resource(2).is_ok());
+        assert!(pool.add_resource(3).is_err());
+    }
+
+    #[test]
+    fn test_take_resource_frees_a_slot() {
+        let mut pool = ResourcePool::new(1);
+        assert_eq!(pool.take_resource(), None);
+        pool.add_resource("a").unwrap();
+        assert_eq!(pool.take_resource(), Some("a"));
+        assert!(pool.add_resource("b").is_ok());
+    }
+
+    #[test]
+    fn test_resources_are_created_with_their_id() {
+        let mut pool = ResourcePool::new(2);
+        pool.add_resource(Resource::new(7)).unwrap();
+        let resource = pool.take_resource().unwrap();
+        assert_eq!(resource.id, 7);
+    }
+
+    #[test]
+    fn test_guard_returns_resource_on_drop() {
+        let mut pool = ResourcePool::new(1);
+        pool.add_resource(vec![1, 2]).unwrap();
+        {
+            let mut guard = pool.checkout().unwrap();
+            guard.push(3);
+            assert!(pool.checkout().is_none());
+        }
+        assert_eq!(*pool.checkout().unwrap(), [1, 2, 3]);
+        assert!(pool.leak_report().is_empty());
+    }
+
+    #[test]
+    fn test_checked_out_resources_count_towards_max_size() {
+        let mut pool = ResourcePool::new(1);
+        pool.add_resource(1).unwrap();
+        let guard = pool.checkout().unwrap();
+        std::mem::forget(guard);
+        assert!(pool.add_resource(2).is_err());
+    }
+
+    #[test]
+    fn test_checkout_timeout() {
+        let mut pool = ResourcePool::new(1);
+        assert!(pool.checkout_timeout(Duration::from_millis(10)).is_err());
+        pool.add_resource("a").unwrap();
+        let guard = pool.checkout().unwrap();
+        assert!(pool.checkout_timeout(Duration::from_millis(10)).is_err());
+
+        // Handed back from another thread while the main thread waits
+        std::thread::scope(|scope| {
+            scope.spawn(move || {
+                std::thread::sleep(Duration::from_millis(20));
+                drop(guard);
+            });
+            let guard = pool.checkout_timeout(Duration::from_secs(5)).unwrap();
+            assert_eq!(*guard, "a");
+        });
+        assert!(pool.checkout_blocking().is_ok());
+    }
+
+    #[test]
+    fn test_validator_drops_invalid_resources() {
+        let mut pool = ResourcePool::new(2).with_validator(|n: &i32| *n >= 0);
+        pool.add_resource(1).unwrap();
+        *pool.checkout().unwrap() = -1;
+        assert!(pool.checkout().is_none());
+        // The rejected resource's slot is free again
+        pool.add_resource(2).unwrap();
+        pool.add_resource(3).unwrap();
+    }
+
+    #[test]
+    fn test_leak_report_names_forgotten_guards() {
+        let mut pool = ResourcePool::new(2);
+        pool.add_resource(1).unwrap();
+        pool.add_resource(2).unwrap();
+        let kept = pool.checkout().unwrap();
+        let line = line!() + 1;
+        std::mem::forget(pool.checkout().unwrap());
+        drop(kept);
+
+        let report = pool.leak_report();
+        assert_eq!(report.len(), 1);
+        assert!(report[0].contains("#2"));
+        assert!(report[0].contains(&format!("grading.rs:{}:", line)));
+    }
+}
+
+mod exercise4 {
+    use super::*;
+
+    #[test]
+    fn test_add_node_returns_shared_handle() {
+        let mut graph = Graph::new();
+        let node = graph.add_node(1, "One".to_string());
+        assert_eq!(node.borrow().id, 1);
+        assert_eq!(node.borrow().data, "One");
+        assert!(Rc::ptr_eq(&node, &graph.nodes[&1]));
+    }
+
+    #[test]
+    fn test_add_edge_links_both_directions() {
+        let mut graph = Graph::new();
+        let one = graph.add_node(1, "One".to_string());
+        let two = graph.add_node(2, "Two".to_string());
+        graph.add_edge(1, 2).unwrap();
+
+        assert!(Rc::ptr_eq(&one.borrow().children[0], &two));
+        let parent = two.borrow().parents[0].upgrade().unwrap();
+        assert!(Rc::ptr_eq(&parent, &one));
+        drop(parent);
+        // Parents are weak, so the edge adds no strong count to `one`
+        assert_eq!(Rc::strong_count(&one), 2);
+    }
+
+    #[test]
+    fn test_add_edge_to_unknown_node_fails() {
+        let mut graph = Graph::new();
+        graph.add_node(1, "One".to_string());
+        assert!(graph.add_edge(1, 2).is_err());
+        assert!(graph.add_edge(3, 1).is_err());
+    }
+
+    #[test]
+    fn test_get_ancestors_walks_all_parents() {
+        let mut graph = Graph::new();
+        for id in 1..=4 {
+            graph.add_node(id, id.to_string());
+        }
+        graph.add_edge(1, 2).unwrap();
+        graph.add_edge(2, 4).unwrap();
+        graph.add_edge(3, 4).unwrap();
+
+        let mut ancestors = graph.get_ancestors(4);
+        ancestors.sort();
+        assert_eq!(ancestors, [1, 2, 3]);
+        assert!(graph.get_ancestors(1).is_empty());
+    }
+
+    // 1 -> 2, 1 -> 3, 2 -> 4, 3 -> 4, 4 -> 5
+    fn diamond() -> Graph {
+        let mut graph = Graph::new();
+        for id in 1..=5 {
+            graph.add_node(id, id.to_string());
+        }
+        for (from, to) in [(1, 2), (1, 3), (2, 4), (3, 4), (4, 5)] {
+            graph.add_edge(from, to).unwrap();
+        }
+        graph
+    }
+
+    fn ids(nodes: impl Iterator<Item = NodeHandle>) -> Vec<usize> {
+        nodes.map(|node| node.borrow().id).collect()
+    }
+
+    #[test]
+    fn test_bfs_and_dfs_visit_each_reachable_node_once() {
+        let graph = diamond();
+        assert_eq!(ids(graph.bfs(1)), [1, 2, 3, 4, 5]);
+        assert_eq!(ids(graph.dfs(1)), [1, 2, 4, 5, 3]);
+        assert_eq!(ids(graph.bfs(3)), [3, 4, 5]);
+        assert_eq!(ids(graph.dfs(9)), []);
+    }
+
+    #[test]
+    fn test_topological_sort_orders_every_edge() {
+        let graph = diamond();
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(order.len(), 5);
+        let position = |id| order.iter().position(|&o| o == id).unwrap();
+        for (from, to) in [(1, 2), (1, 3), (2, 4), (3, 4), (4, 5)] {
+            assert!(position(from) < position(to), "{:?}", order);
+        }
+    }
+
+    #[test]
+    fn test_topological_sort_reports_cycles() {
+        let mut graph = diamond();
+        graph.add_edge(5, 2).unwrap();
+        assert_eq!(graph.topological_sort(), Err(vec![2, 4, 5]));
+
+        graph.add_node(6, "Six".to_string());
+        graph.add_edge(6, 6).unwrap();
+        let cycle = graph.subgraph(&[6]).topological_sort();
+        assert_eq!(cycle, Err(vec![6]));
+    }
+
+    #[test]
+    fn test_cycle_closing_edges_are_weak() {
+        let mut graph = diamond();
+        graph.add_edge(5, 1).unwrap();
+        let five = &graph.nodes[&5];
+        assert!(five.borrow().children.is_empty());
+        assert_eq!(five.borrow().back_edges.len(), 1);
+        assert_eq!(ids(graph.bfs(5)), [5, 1, 2, 3, 4]);
+        let mut ancestors = graph.get_ancestors(1);
+        ancestors.sort();
+        assert_eq!(ancestors, [2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut graph = diamond();
+        assert_eq!(graph.shortest_path(1, 5), Some(vec![1, 2, 4, 5]));
+        assert_eq!(graph.shortest_path(3, 3), Some(vec![3]));
+        assert_eq!(graph.shortest_path(5, 1), None);
+        graph.add_edge(5, 1).unwrap();
+        assert_eq!(graph.shortest_path(5, 3), Some(vec![5, 1, 3]));
+    }
+
+    #[test]
+    fn test_subgraph_copies_nodes_and_edges_between_them() {
+        let graph = diamond();
+        let part = graph.subgraph(&[4, 1, 2, 7, 1]);
+        let mut kept: Vec<usize> = part.nodes.keys().copied().collect();
+        kept.sort();
+        assert_eq!(kept, [1, 2, 4]);
+        assert_eq!(part.topological_sort(), Ok(vec![1, 2, 4]));
+        assert_eq!(part.nodes[&2].borrow().data, "2");
+        // Copies, not shared handles
+        assert!(!Rc::ptr_eq(&part.nodes[&1], &graph.nodes[&1]));
+    }
+
+    #[test]
+    fn test_dropping_a_cyclic_graph_frees_every_node() {
+        let before = live_nodes();
+        let mut graph = diamond();
+        graph.add_edge(5, 1).unwrap();
+        graph.add_edge(4, 2).unwrap();
+        graph.add_edge(3, 3).unwrap();
+        let part = graph.subgraph(&[1, 2, 4, 5]);
+        assert_eq!(live_nodes(), before + 9);
+
+        drop(part);
+        assert_eq!(live_nodes(), before + 5);
+        drop(graph);
+        assert_eq!(live_nodes(), before);
+    }
+}
+
+mod exercise5 {
+    use super::*;
+
+    #[test]
+    fn test_handle_connects_a_new_connection() {
+        let mut conn = Connection::new();
+        conn.handle();
+        assert!(matches!(conn, Connection::Connected(_)));
+    }
+
+    #[test]
+    fn test_send_data_buffers_bytes() {
+        let mut conn = ConnectedConnection { buffer: Vec::new() };
+        conn.send_data(&[1, 2]).unwrap();
+        conn.send_data(&[3]).unwrap();
+        assert_eq!(conn.buffer, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_disconnect_then_reconnect() {
+        let conn = ConnectedConnection { buffer: vec![1] };
+        let disconnected = conn.disconnect();
+        assert!(matches!(disconnected.connect(), Connection::Connected(_)));
+    }
+
+    #[test]
+    fn test_protocol_round_trip_counts_bytes() {
+        let mut transport = Loopback::new("secret");
+        let connecting = DisconnectedConnection.open(&mut transport).unwrap();
+        let mut conn = connecting.authenticate("secret").unwrap();
+        // HELLO and AUTH secret out, WELCOME and OK back
+        assert_eq!(
+            conn.counters(),
+            ByteCounters {
+                sent: 16,
+                received: 9
+            }
+        );
+
+        conn.send(b"ping").unwrap();
+        conn.send(b"!").unwrap();
+        assert_eq!(conn.receive().unwrap(), b"ping");
+        let closed = conn.close();
+        // The unread "!" is discarded, then BYE both ways
+        assert_eq!(
+            closed.counters,
+            ByteCounters {
+                sent: 24,
+                received: 16
+            }
+        );
+    }
+
+    #[test]
+    fn test_wrong_token_fails() {
+        let mut transport = Loopback::new("secret");
+        let connecting = DisconnectedConnection.open(&mut transport).unwrap();
+        let Err(failed) = connecting.authenticate("guess") else {
+            panic!("the wrong token should be denied");
+        };
+        assert_eq!(failed.error(), "Authentication denied");
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let backoff = Backoff {
+            initial: Duration::from_millis(10),
+            max: Duration::from_millis(50),
+            attempts: 10,
+        };
+        let delays: Vec<u128> = (0..5).map(|n| backoff.delay(n).as_millis()).collect();
+        assert_eq!(delays, [10, 20, 40, 50, 50]);
+        assert_eq!(backoff.delay(100), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_reconnect_retries_with_backoff() {
+        let mut transport = Loopback::new("secret").refusing(3);
+        let Err(failed) = DisconnectedConnection.open(&mut transport) else {
+            panic!("the first attempt should be refused");
+        };
+        assert_eq!(failed.attempts(), 1);
+
+        let backoff = Backoff {
+            initial: Duration::from_millis(100),
+            max: Duration::from_secs(1),
+            attempts: 5,
+        };
+        let mut waited = Vec::new();
+        let connecting = failed
+            .reconnect(&mut transport, &backoff, |delay| waited.push(delay))
+            .unwrap();
+        assert!(connecting.authenticate("secret").is_ok());
+        assert_eq!(waited, [100, 200, 400].map(Duration::from_millis).to_vec());
+    }
+
+    #[test]
+    fn test_reconnect_gives_up_after_max_attempts() {
+        let mut transport = Loopback::new("secret").refusing(10);
+        let Err(failed) = DisconnectedConnection.open(&mut transport) else {
+            panic!("the first attempt should be refused");
+        };
+        let backoff = Backoff {
+            initial: Duration::ZERO,
+            max: Duration::ZERO,
+            attempts: 3,
+        };
+        let mut waits = 0;
+        let Err(failed) = failed.reconnect(&mut transport, &backoff, |_| waits += 1) else {
+            panic!("every attempt should be refused");
+        };
+        assert_eq!(waits, 3);
+        assert_eq!(failed.attempts(), 4);
+        assert_eq!(failed.error(), "Connection refused");
+    }
+}
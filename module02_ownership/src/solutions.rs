@@ -0,0 +1,1300 @@
+// Module 2: Reference Solutions
+// Worked answers to the exercises in problems.rs, compiled only with the
+// `solutions` feature. Try solving the exercises yourself first!
+// The types and signatures mirror problems.rs, so the hidden grading suite
+// runs unchanged against these answers (see the tests at the end).
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
+use std::rc::Rc;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+pub fn run_exercises() {
+    println!("Module 2 Reference Solutions - Ownership and Memory Management");
+    println!("=========================================================\n");
+
+    exercise1();
+    exercise2();
+    exercise3();
+    exercise4();
+    exercise5();
+}
+
+// Solution entry points in order, used by `rustpath run <module> --solution`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Exercise 1 Solution: Memory Layout and Ownership
+
+// Only plain stack data, so the struct can be `Copy`
+#[derive(Debug, Clone, Copy)]
+struct StackStruct {
+    x: i32,
+    y: i32,
+    z: bool,
+}
+
+#[derive(Debug, Clone)]
+struct HeapStruct {
+    data: Vec<i32>,
+    name: String,
+}
+
+fn memory_layout_examples() {
+    let stack_struct = StackStruct {
+        x: 1,
+        y: 2,
+        z: true,
+    };
+    let heap_struct = HeapStruct {
+        data: vec![1, 2, 3],
+        name: String::from("example"),
+    };
+
+    println!(
+        "Stack struct: {} bytes, aligned to {}",
+        std::mem::size_of::<StackStruct>(),
+        std::mem::align_of::<StackStruct>()
+    );
+    println!(
+        "Heap struct: {} bytes inline (pointers, lengths and capacities), aligned to {}",
+        std::mem::size_of::<HeapStruct>(),
+        std::mem::align_of::<HeapStruct>()
+    );
+
+    // Copy: assignment duplicates the bits and the original stays usable
+    let copied = stack_struct;
+    println!(
+        "Copied {:?}, original still valid: {:?}",
+        copied, stack_struct
+    );
+
+    // Clone: an explicit deep copy of the heap data
+    let cloned = heap_struct.clone();
+    println!("Cloned {:?}", cloned);
+
+    // Move: ownership transfers and `heap_struct` can no longer be used
+    let moved = heap_struct;
+    println!("Moved {} with {} items", moved.name, moved.data.len());
+
+    // Drop: `moved` and `cloned` free their heap buffers here, in reverse
+    // declaration order
+}
+
+fn exercise1() {
+    println!("Exercise 1: Memory Layout and Ownership");
+    println!("----------------------------------");
+
+    memory_layout_examples();
+}
+
+// Exercise 2 Solution: Advanced Borrowing
+
+// How a tracker remembers the versions it can go back to. `record` gets the
+// value from before a modification (a clone taken by the tracker) and the
+// value after it, and returns whatever undo and redo will need.
+trait Revisions<T> {
+    type Entry;
+
+    fn record(before: T, after: &T) -> Self::Entry;
+    fn undo(entry: &mut Self::Entry, data: &mut T);
+    fn redo(entry: &mut Self::Entry, data: &mut T);
+}
+
+// Keeps a full copy of every earlier version. Undo swaps the copy with the
+// current data, so the entry then holds the newer version, ready for redo.
+struct Snapshots;
+
+impl<T> Revisions<T> for Snapshots {
+    type Entry = T;
+
+    fn record(before: T, _after: &T) -> T {
+        before
+    }
+
+    fn undo(entry: &mut T, data: &mut T) {
+        std::mem::swap(entry, data);
+    }
+
+    fn redo(entry: &mut T, data: &mut T) {
+        std::mem::swap(entry, data);
+    }
+}
+
+// Keeps only what changed, for types that can describe their changes
+struct Diffs;
+
+trait Diffable {
+    type Diff;
+
+    // What turns `self` into `after`
+    fn diff(&self, after: &Self) -> Self::Diff;
+    // Turns the old value into the new one
+    fn apply(&mut self, diff: &Self::Diff);
+    // Turns the new value back into the old one
+    fn revert(&mut self, diff: &Self::Diff);
+}
+
+impl<T: Diffable> Revisions<T> for Diffs {
+    type Entry = T::Diff;
+
+    fn record(before: T, after: &T) -> T::Diff {
+        before.diff(after)
+    }
+
+    fn undo(entry: &mut T::Diff, data: &mut T) {
+        data.revert(entry);
+    }
+
+    fn redo(entry: &mut T::Diff, data: &mut T) {
+        data.apply(entry);
+    }
+}
+
+// The elements between the common prefix and the common suffix of two
+// versions of a Vec: `removed` from the old one, `inserted` in the new one
+#[derive(Debug, PartialEq)]
+struct VecDiff<T> {
+    at: usize,
+    removed: Vec<T>,
+    inserted: Vec<T>,
+}
+
+impl<T: Clone + PartialEq> Diffable for Vec<T> {
+    type Diff = VecDiff<T>;
+
+    fn diff(&self, after: &Self) -> VecDiff<T> {
+        let prefix = self.iter().zip(after).take_while(|(a, b)| a == b).count();
+        let suffix = self[prefix..]
+            .iter()
+            .rev()
+            .zip(after[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        VecDiff {
+            at: prefix,
+            removed: self[prefix..self.len() - suffix].to_vec(),
+            inserted: after[prefix..after.len() - suffix].to_vec(),
+        }
+    }
+
+    fn apply(&mut self, diff: &VecDiff<T>) {
+        let end = diff.at + diff.removed.len();
+        self.splice(diff.at..end, diff.inserted.iter().cloned());
+    }
+
+    fn revert(&mut self, diff: &VecDiff<T>) {
+        let end = diff.at + diff.inserted.len();
+        self.splice(diff.at..end, diff.removed.iter().cloned());
+    }
+}
+
+// `modifications` and `revisions` run in step: one description and one
+// entry per modification currently applied. Undone ones move to `undone`
+// (most recent last) until redone, or dropped by the next modification.
+struct DataTracker<T, R: Revisions<T> = Snapshots> {
+    data: T,
+    access_count: usize,
+    modifications: Vec<String>,
+    revisions: Vec<R::Entry>,
+    undone: Vec<(String, R::Entry)>,
+}
+
+impl<T: Clone> DataTracker<T> {
+    fn new(data: T) -> Self {
+        DataTracker::with_revisions(data)
+    }
+}
+
+impl<T: Clone + Diffable> DataTracker<T, Diffs> {
+    fn with_diffs(data: T) -> Self {
+        DataTracker::with_revisions(data)
+    }
+}
+
+impl<T: Clone, R: Revisions<T>> DataTracker<T, R> {
+    fn with_revisions(data: T) -> Self {
+        DataTracker {
+            data,
+            access_count: 0,
+            modifications: Vec::new(),
+            revisions: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    fn get_data(&mut self) -> &T {
+        self.access_count += 1;
+        &self.data
+    }
+
+    fn modify_data<F>(&mut self, modifier: F, description: &str)
+    where
+        F: FnOnce(&mut T),
+    {
+        let before = self.data.clone();
+        modifier(&mut self.data);
+        self.revisions.push(R::record(before, &self.data));
+        self.modifications.push(description.to_string());
+        // A new modification starts a new branch; the old one cannot be redone
+        self.undone.clear();
+    }
+
+    fn get_stats(&self) -> (usize, &[String]) {
+        (self.access_count, &self.modifications)
+    }
+
+    // Takes back the latest modification, returning its description
+    fn undo(&mut self) -> Option<&str> {
+        let mut entry = self.revisions.pop()?;
+        let description = self.modifications.pop()?;
+        R::undo(&mut entry, &mut self.data);
+        self.undone.push((description, entry));
+        self.undone
+            .last()
+            .map(|(description, _)| description.as_str())
+    }
+
+    // Reapplies the most recently undone modification
+    fn redo(&mut self) -> Option<&str> {
+        let (description, mut entry) = self.undone.pop()?;
+        R::redo(&mut entry, &mut self.data);
+        self.revisions.push(entry);
+        self.modifications.push(description);
+        self.modifications.last().map(String::as_str)
+    }
+
+    // Undoes modifications until only the first `count` remain
+    fn rollback_to(&mut self, count: usize) -> Result<(), String> {
+        if count > self.modifications.len() {
+            return Err(format!(
+                "cannot roll back to modification {}: only {} applied",
+                count,
+                self.modifications.len()
+            ));
+        }
+        while self.modifications.len() > count {
+            self.undo();
+        }
+        Ok(())
+    }
+
+    // The applied modifications, oldest first, with what each one stored.
+    // The iterator borrows the tracker, so it cannot change while in use.
+    fn history(&self) -> impl Iterator<Item = (&str, &R::Entry)> {
+        self.modifications
+            .iter()
+            .map(String::as_str)
+            .zip(&self.revisions)
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Advanced Borrowing");
+    println!("---------------------------");
+
+    let mut tracker = DataTracker::new(vec![1, 2, 3]);
+    println!("Initial data: {:?}", tracker.get_data());
+    tracker.modify_data(|v| v.push(4), "Added 4");
+    let (accesses, mods) = tracker.get_stats();
+    println!("Accesses: {}, Modifications: {:?}", accesses, mods);
+
+    tracker.modify_data(|v| v.retain(|&x| x % 2 == 0), "Kept evens");
+    tracker.modify_data(|v| v.iter_mut().for_each(|x| *x *= 10), "Times ten");
+    // Each snapshot is the data from before its modification
+    for (description, before) in tracker.history() {
+        println!("  {:<10} was {:?}", description, before);
+    }
+    // `undo` returns a description borrowed from the tracker, which has to
+    // be copied out before the tracker can be used again
+    let undone = tracker.undo().map(str::to_string);
+    println!("Undo {:?}: {:?}", undone, tracker.get_data());
+    let redone = tracker.redo().map(str::to_string);
+    println!("Redo {:?}: {:?}", redone, tracker.get_data());
+    tracker.rollback_to(1).unwrap();
+    println!("Rolled back to 1 modification: {:?}", tracker.get_data());
+
+    // The same history as diffs: only the changed elements are stored
+    let mut tracker = DataTracker::with_diffs((1..=1000).collect::<Vec<i32>>());
+    tracker.modify_data(|v| v[500] = 0, "Zeroed 501");
+    tracker.modify_data(|v| v.push(1001), "Added 1001");
+    for (description, diff) in tracker.history() {
+        println!("  {:<10} stored {:?}", description, diff);
+    }
+    tracker.undo();
+    tracker.undo();
+    let data = tracker.get_data();
+    println!(
+        "Undone twice: {} elements, data[500] = {}",
+        data.len(),
+        data[500]
+    );
+}
+
+// Exercise 3 Solution: Resource Management
+
+// Guards hand resources back through a shared reference, possibly from
+// another thread, so the pool's state lives behind a Mutex
+struct ResourcePool<T> {
+    state: Mutex<PoolState<T>>,
+    // Signalled whenever a guard hands its resource back
+    returned: Condvar,
+    max_size: usize,
+    validator: Option<Validator<T>>,
+}
+
+// Decides whether a returned resource may go back into the pool
+type Validator<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
+
+struct PoolState<T> {
+    // Resources waiting to be checked out
+    resources: Vec<T>,
+    // Guards not yet dropped, by checkout number
+    checked_out: HashMap<u64, Checkout>,
+    next_checkout: u64,
+}
+
+// Where and when a guard was handed out, for the leak report
+struct Checkout {
+    location: &'static Location<'static>,
+    since: Instant,
+}
+
+// A resource on loan from a pool, handed back when the guard is dropped
+struct PoolGuard<'a, T> {
+    pool: &'a ResourcePool<T>,
+    // Only None while the guard is being dropped
+    resource: Option<T>,
+    checkout: u64,
+}
+
+struct Resource {
+    id: usize,
+    data: Vec<u8>,
+}
+
+impl Resource {
+    fn new(id: usize) -> Self {
+        Resource {
+            id,
+            data: vec![0; 64],
+        }
+    }
+}
+
+impl Drop for Resource {
+    fn drop(&mut self) {
+        println!("Cleaning up resource {}", self.id);
+    }
+}
+
+impl<T> ResourcePool<T> {
+    fn new(max_size: usize) -> Self {
+        ResourcePool {
+            state: Mutex::new(PoolState {
+                resources: Vec::with_capacity(max_size),
+                checked_out: HashMap::new(),
+                next_checkout: 1,
+            }),
+            returned: Condvar::new(),
+            max_size,
+            validator: None,
+        }
+    }
+
+    // Resources failing `valid` when they come back are dropped instead of
+    // being returned to the pool, which frees their slot
+    fn with_validator(mut self, valid: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        self.validator = Some(Box::new(valid));
+        self
+    }
+
+    // Checked-out resources still count towards `max_size`
+    fn add_resource(&mut self, resource: T) -> Result<(), String> {
+        // `&mut self` means no guard is alive, so there is nothing to lock
+        let state = self.state.get_mut().unwrap_or_else(PoisonError::into_inner);
+        if state.resources.len() + state.checked_out.len() >= self.max_size {
+            Err("Pool is full".to_string())
+        } else {
+            state.resources.push(resource);
+            Ok(())
+        }
+    }
+
+    // Removes a resource from the pool for good
+    fn take_resource(&mut self) -> Option<T> {
+        let state = self.state.get_mut().unwrap_or_else(PoisonError::into_inner);
+        state.resources.pop()
+    }
+
+    // Lends out a resource if one is free right now. `#[track_caller]` makes
+    // `Location::caller()` the line that called `checkout`, not this one.
+    #[track_caller]
+    fn checkout(&self) -> Option<PoolGuard<'_, T>> {
+        let mut state = self.lock();
+        let resource = state.resources.pop()?;
+        Some(self.lend(&mut state, resource, Location::caller()))
+    }
+
+    // Waits up to `timeout` for another guard to hand a resource back
+    #[track_caller]
+    fn checkout_timeout(&self, timeout: Duration) -> Result<PoolGuard<'_, T>, String> {
+        self.wait_for_resource(Some(timeout), Location::caller())
+    }
+
+    // Waits for as long as it takes; fails only if nothing is checked out
+    // that could ever come back
+    #[track_caller]
+    fn checkout_blocking(&self) -> Result<PoolGuard<'_, T>, String> {
+        self.wait_for_resource(None, Location::caller())
+    }
+
+    fn wait_for_resource(
+        &self,
+        timeout: Option<Duration>,
+        location: &'static Location<'static>,
+    ) -> Result<PoolGuard<'_, T>, String> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut state = self.lock();
+        loop {
+            if let Some(resource) = state.resources.pop() {
+                return Ok(self.lend(&mut state, resource, location));
+            }
+            if state.checked_out.is_empty() {
+                return Err("The pool has no resources to wait for".to_string());
+            }
+            // Condvar waits can wake up spuriously, hence the loop
+            state = match (deadline, timeout) {
+                (Some(deadline), Some(timeout)) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(format!(
+                            "Timed out after {:?} waiting for a resource",
+                            timeout
+                        ));
+                    }
+                    let (state, _) = self
+                        .returned
+                        .wait_timeout(state, deadline - now)
+                        .unwrap_or_else(PoisonError::into_inner);
+                    state
+                }
+                _ => self
+                    .returned
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner),
+            };
+        }
+    }
+
+    fn lend(
+        &self,
+        state: &mut PoolState<T>,
+        resource: T,
+        location: &'static Location<'static>,
+    ) -> PoolGuard<'_, T> {
+        let checkout = state.next_checkout;
+        state.next_checkout += 1;
+        state.checked_out.insert(
+            checkout,
+            Checkout {
+                location,
+                since: Instant::now(),
+            },
+        );
+        PoolGuard {
+            pool: self,
+            resource: Some(resource),
+            checkout,
+        }
+    }
+
+    // One line per guard that has not been dropped, oldest first. A guard
+    // still listed when the pool drops was leaked with `mem::forget`.
+    fn leak_report(&self) -> Vec<String> {
+        let state = self.lock();
+        let mut checkouts: Vec<_> = state.checked_out.iter().collect();
+        checkouts.sort_by_key(|(number, _)| **number);
+        checkouts
+            .into_iter()
+            .map(|(number, checkout)| {
+                format!(
+                    "checkout #{} at {}, out for {:?}",
+                    number,
+                    checkout.location,
+                    checkout.since.elapsed()
+                )
+            })
+            .collect()
+    }
+
+    // A panic while the lock is held poisons it; the state is still
+    // consistent, so carry on rather than panicking in every later guard
+    fn lock(&self) -> MutexGuard<'_, PoolState<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Deref for PoolGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.resource
+            .as_ref()
+            .expect("resource is present until drop")
+    }
+}
+
+impl<T> DerefMut for PoolGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.resource
+            .as_mut()
+            .expect("resource is present until drop")
+    }
+}
+
+impl<T> Drop for PoolGuard<'_, T> {
+    fn drop(&mut self) {
+        let Some(resource) = self.resource.take() else {
+            return;
+        };
+        let valid = self
+            .pool
+            .validator
+            .as_ref()
+            .is_none_or(|valid| valid(&resource));
+        let mut state = self.pool.lock();
+        state.checked_out.remove(&self.checkout);
+        let rejected = if valid {
+            state.resources.push(resource);
+            None
+        } else {
+            Some(resource)
+        };
+        // Release the lock before a rejected resource runs its own Drop
+        drop(state);
+        drop(rejected);
+        self.pool.returned.notify_one();
+    }
+}
+
+// Debug builds only, like `debug_assert!`: guards can only outlive the pool
+// they borrow if they were forgotten, so anything still checked out leaked
+impl<T> Drop for ResourcePool<T> {
+    fn drop(&mut self) {
+        if cfg!(debug_assertions) {
+            let leaks = self.leak_report();
+            if !leaks.is_empty() {
+                eprintln!(
+                    "ResourcePool dropped with {} resource(s) never returned:",
+                    leaks.len()
+                );
+                for leak in leaks {
+                    eprintln!("  {}", leak);
+                }
+            }
+        }
+    }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Resource Management");
+    println!("----------------------------");
+
+    let mut pool = ResourcePool::new(2);
+    pool.add_resource(Resource::new(1)).unwrap();
+    pool.add_resource(Resource::new(2)).unwrap();
+    // The rejected resource is dropped (and cleaned up) straight away
+    assert!(pool.add_resource(Resource::new(3)).is_err());
+
+    // Resources that come back with their buffer cut short are thrown away
+    let mut pool = pool.with_validator(|resource| resource.data.len() == 64);
+    {
+        let guard = pool.checkout().unwrap();
+        println!("Checked out resource {}", guard.id);
+    }
+    println!(
+        "Returned on drop; {} guard(s) still out",
+        pool.leak_report().len()
+    );
+
+    let mut guard = pool.checkout().unwrap();
+    guard.data.truncate(8);
+    println!("Resource {} comes back damaged:", guard.id);
+    drop(guard);
+    pool.add_resource(Resource::new(4)).unwrap();
+
+    // Hold one resource on another thread and wait for it to come back
+    let first = pool.checkout().unwrap();
+    let second = pool.checkout().unwrap();
+    std::thread::scope(|scope| {
+        scope.spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            drop(second);
+        });
+        match pool.checkout_timeout(Duration::from_millis(1)) {
+            Ok(_) => println!("Unexpectedly found a free resource"),
+            Err(error) => println!("{}", error),
+        }
+        let guard = pool.checkout_timeout(Duration::from_secs(1)).unwrap();
+        println!("Got resource {} back from the other thread", guard.id);
+    });
+
+    // A forgotten guard never returns its resource; in a debug build the
+    // pool lists it on stderr when it is dropped
+    std::mem::forget(first);
+    for leak in pool.leak_report() {
+        println!("Leaked: {}", leak);
+    }
+}
+
+// Exercise 4 Solution: Shared Ownership
+
+type NodeHandle = Rc<RefCell<Node>>;
+
+struct Node {
+    id: usize,
+    data: String,
+    children: Vec<NodeHandle>,
+    // Edges that would close a cycle are kept weak, so the strong edges
+    // never form one and dropping the graph frees every node
+    back_edges: Vec<std::rc::Weak<RefCell<Node>>>,
+    parents: Vec<std::rc::Weak<RefCell<Node>>>,
+}
+
+struct Graph {
+    nodes: HashMap<usize, NodeHandle>,
+}
+
+thread_local! {
+    // Nodes created on this thread and not dropped yet
+    static LIVE_NODES: Cell<usize> = const { Cell::new(0) };
+}
+
+fn live_nodes() -> usize {
+    LIVE_NODES.with(Cell::get)
+}
+
+impl Node {
+    fn new(id: usize, data: String) -> Self {
+        LIVE_NODES.with(|live| live.set(live.get() + 1));
+        Node {
+            id,
+            data,
+            children: Vec::new(),
+            back_edges: Vec::new(),
+            parents: Vec::new(),
+        }
+    }
+
+    // Every node this one has an edge to, strong edges first
+    fn successors(&self) -> Vec<NodeHandle> {
+        let back = self.back_edges.iter().filter_map(|edge| edge.upgrade());
+        self.children.iter().cloned().chain(back).collect()
+    }
+}
+
+impl Drop for Node {
+    fn drop(&mut self) {
+        LIVE_NODES.with(|live| live.set(live.get() - 1));
+    }
+}
+
+// Nodes in breadth-first order, each visited once
+struct Bfs {
+    queue: VecDeque<NodeHandle>,
+    seen: HashSet<usize>,
+}
+
+impl Iterator for Bfs {
+    type Item = NodeHandle;
+
+    fn next(&mut self) -> Option<NodeHandle> {
+        let node = self.queue.pop_front()?;
+        for next in node.borrow().successors() {
+            if self.seen.insert(next.borrow().id) {
+                self.queue.push_back(next);
+            }
+        }
+        Some(node)
+    }
+}
+
+// Nodes in depth-first preorder, each visited once
+struct Dfs {
+    stack: Vec<NodeHandle>,
+    seen: HashSet<usize>,
+}
+
+impl Iterator for Dfs {
+    type Item = NodeHandle;
+
+    fn next(&mut self) -> Option<NodeHandle> {
+        loop {
+            let node = self.stack.pop()?;
+            if !self.seen.insert(node.borrow().id) {
+                continue;
+            }
+            // Pushed in reverse, so the first successor is visited first
+            for next in node.borrow().successors().into_iter().rev() {
+                if !self.seen.contains(&next.borrow().id) {
+                    self.stack.push(next);
+                }
+            }
+            return Some(node);
+        }
+    }
+}
+
+impl Graph {
+    fn new() -> Self {
+        Graph {
+            nodes: HashMap::new(),
+        }
+    }
+
+    fn add_node(&mut self, id: usize, data: String) -> NodeHandle {
+        let node = Rc::new(RefCell::new(Node::new(id, data)));
+        self.nodes.insert(id, Rc::clone(&node));
+        node
+    }
+
+    fn add_edge(&mut self, from_id: usize, to_id: usize) -> Result<(), String> {
+        let from_node = self.nodes.get(&from_id).ok_or("From node not found")?;
+        let to_node = self.nodes.get(&to_id).ok_or("To node not found")?;
+
+        // If `from` can already be reached from `to`, this edge closes a cycle
+        if self.bfs(to_id).any(|node| Rc::ptr_eq(&node, from_node)) {
+            from_node
+                .borrow_mut()
+                .back_edges
+                .push(Rc::downgrade(to_node));
+        } else {
+            from_node.borrow_mut().children.push(Rc::clone(to_node));
+        }
+        to_node.borrow_mut().parents.push(Rc::downgrade(from_node));
+
+        Ok(())
+    }
+
+    // Breadth-first walk up the weak parent links, visiting each node once
+    fn get_ancestors(&self, id: usize) -> Vec<usize> {
+        let mut ancestors = Vec::new();
+        let mut queue: VecDeque<NodeHandle> = self.nodes.get(&id).into_iter().cloned().collect();
+
+        while let Some(node) = queue.pop_front() {
+            for parent in node.borrow().parents.iter().filter_map(|p| p.upgrade()) {
+                let parent_id = parent.borrow().id;
+                if parent_id != id && !ancestors.contains(&parent_id) {
+                    ancestors.push(parent_id);
+                    queue.push_back(parent);
+                }
+            }
+        }
+        ancestors
+    }
+
+    // Everything reachable from `start`, nearest first; empty if there is
+    // no such node
+    fn bfs(&self, start: usize) -> Bfs {
+        let queue: VecDeque<NodeHandle> = self.nodes.get(&start).into_iter().cloned().collect();
+        Bfs {
+            seen: queue.iter().map(|node| node.borrow().id).collect(),
+            queue,
+        }
+    }
+
+    fn dfs(&self, start: usize) -> Dfs {
+        Dfs {
+            stack: self.nodes.get(&start).into_iter().cloned().collect(),
+            seen: HashSet::new(),
+        }
+    }
+
+    // The ids ordered so that every edge points forwards, or the ids around
+    // a cycle if there is none: each has an edge to the next, and the last
+    // one back to the first
+    fn topological_sort(&self) -> Result<Vec<usize>, Vec<usize>> {
+        let mut ids: Vec<usize> = self.nodes.keys().copied().collect();
+        ids.sort();
+        let mut finished = HashSet::new();
+        let mut path = Vec::new();
+        let mut order = Vec::new();
+        for id in ids {
+            Self::visit(&self.nodes[&id], &mut finished, &mut path, &mut order)?;
+        }
+        // A node finishes only after everything it points to
+        order.reverse();
+        Ok(order)
+    }
+
+    // Depth-first, recording nodes as they finish. `path` holds the nodes
+    // still being visited; meeting one of them again means a cycle.
+    fn visit(
+        node: &NodeHandle,
+        finished: &mut HashSet<usize>,
+        path: &mut Vec<usize>,
+        order: &mut Vec<usize>,
+    ) -> Result<(), Vec<usize>> {
+        let id = node.borrow().id;
+        if finished.contains(&id) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|&on_path| on_path == id) {
+            return Err(path[start..].to_vec());
+        }
+        path.push(id);
+        for next in node.borrow().successors() {
+            Self::visit(&next, finished, path, order)?;
+        }
+        path.pop();
+        finished.insert(id);
+        order.push(id);
+        Ok(())
+    }
+
+    // The path with the fewest edges, both ends included
+    fn shortest_path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let start = self.nodes.get(&from)?;
+        // How each node was first reached
+        let mut previous = HashMap::new();
+        let mut seen = HashSet::from([from]);
+        let mut queue = VecDeque::from([Rc::clone(start)]);
+
+        while let Some(node) = queue.pop_front() {
+            let id = node.borrow().id;
+            if id == to {
+                let mut path = vec![to];
+                while let Some(&before) = previous.get(&path[path.len() - 1]) {
+                    path.push(before);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for next in node.borrow().successors() {
+                let next_id = next.borrow().id;
+                if seen.insert(next_id) {
+                    previous.insert(next_id, id);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    // A new graph with copies of the given nodes and the edges between them.
+    // Unknown ids are skipped.
+    fn subgraph(&self, ids: &[usize]) -> Graph {
+        let mut graph = Graph::new();
+        let mut copied = Vec::new();
+        for &id in ids {
+            if let Some(node) = self.nodes.get(&id) {
+                if !graph.nodes.contains_key(&id) {
+                    graph.add_node(id, node.borrow().data.clone());
+                    copied.push(id);
+                }
+            }
+        }
+        for id in copied {
+            for next in self.nodes[&id].borrow().successors() {
+                let next_id = next.borrow().id;
+                if graph.nodes.contains_key(&next_id) {
+                    graph.add_edge(id, next_id).expect("both ends were copied");
+                }
+            }
+        }
+        graph
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Shared Ownership");
+    println!("--------------------------");
+
+    let mut graph = Graph::new();
+    let node1 = graph.add_node(1, "One".to_string());
+    let node2 = graph.add_node(2, "Two".to_string());
+    graph.add_edge(1, 2).unwrap();
+    println!("Ancestors of 2: {:?}", graph.get_ancestors(2));
+
+    for id in 3..=5 {
+        graph.add_node(id, format!("Node {}", id));
+    }
+    for (from, to) in [(1, 3), (2, 4), (3, 4), (4, 5)] {
+        graph.add_edge(from, to).unwrap();
+    }
+    let ids = |nodes: &mut dyn Iterator<Item = NodeHandle>| -> Vec<usize> {
+        nodes.map(|node| node.borrow().id).collect()
+    };
+    println!("Breadth-first from 1: {:?}", ids(&mut graph.bfs(1)));
+    println!("Depth-first from 1: {:?}", ids(&mut graph.dfs(1)));
+    println!("Topological order: {:?}", graph.topological_sort());
+    println!("Shortest path 1 -> 5: {:?}", graph.shortest_path(1, 5));
+
+    // 5 -> 2 closes the cycle 2 -> 4 -> 5 -> 2, so it is stored as a weak edge
+    graph.add_edge(5, 2).unwrap();
+    println!("With 5 -> 2, cycle: {:?}", graph.topological_sort());
+    let part = graph.subgraph(&[1, 2, 3]);
+    println!(
+        "Subgraph of 1, 2, 3 in order: {:?}",
+        part.topological_sort()
+    );
+
+    drop((node1, node2, part));
+    println!("Live nodes before dropping the graph: {}", live_nodes());
+    drop(graph);
+    println!("Live nodes after: {}", live_nodes());
+}
+
+// Exercise 5 Solution: Safe Abstractions
+
+pub struct DisconnectedConnection;
+
+struct ConnectedConnection {
+    buffer: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct FailedConnection {
+    error: String,
+    // Connection attempts that have failed in a row
+    attempts: u32,
+}
+
+enum Connection {
+    Disconnected(DisconnectedConnection),
+    Connected(ConnectedConnection),
+    Failed(FailedConnection),
+}
+
+impl DisconnectedConnection {
+    fn connect(self) -> Connection {
+        Connection::Connected(ConnectedConnection { buffer: Vec::new() })
+    }
+}
+
+impl ConnectedConnection {
+    fn send_data(&mut self, data: &[u8]) -> Result<(), String> {
+        self.buffer.extend_from_slice(data);
+        Ok(())
+    }
+
+    fn disconnect(self) -> DisconnectedConnection {
+        DisconnectedConnection
+    }
+}
+
+impl Connection {
+    fn new() -> Self {
+        Connection::Disconnected(DisconnectedConnection)
+    }
+
+    // Each state consumes itself to produce the next one, so the current
+    // state is taken out of `self` before the transition
+    fn handle(&mut self) {
+        let state = std::mem::replace(self, Connection::Disconnected(DisconnectedConnection));
+        *self = match state {
+            Connection::Disconnected(conn) => {
+                println!("Connecting...");
+                conn.connect()
+            }
+            Connection::Connected(mut conn) => match conn.send_data(&[1, 2, 3]) {
+                Ok(()) => Connection::Connected(conn),
+                Err(error) => Connection::Failed(FailedConnection { error, attempts: 0 }),
+            },
+            Connection::Failed(failed) => {
+                println!("Connection failed: {}", failed.error);
+                Connection::Failed(failed)
+            }
+        };
+    }
+}
+
+// An in-memory network: the peer on the other end answers each frame the
+// client writes by queueing a reply for it to read
+pub struct Loopback {
+    replies: VecDeque<Vec<u8>>,
+    token: String,
+    // The peer refuses this many connection attempts before accepting
+    refusals: u32,
+    connected: bool,
+    authenticated: bool,
+}
+
+impl Loopback {
+    pub fn new(token: &str) -> Self {
+        Loopback {
+            replies: VecDeque::new(),
+            token: token.to_string(),
+            refusals: 0,
+            connected: false,
+            authenticated: false,
+        }
+    }
+
+    pub fn refusing(mut self, attempts: u32) -> Self {
+        self.refusals = attempts;
+        self
+    }
+
+    fn write(&mut self, frame: &[u8]) -> Result<(), String> {
+        let reply: &[u8] = match frame {
+            b"HELLO" if self.refusals > 0 => {
+                self.refusals -= 1;
+                return Err("Connection refused".to_string());
+            }
+            b"HELLO" => {
+                self.connected = true;
+                b"WELCOME"
+            }
+            _ if !self.connected => return Err("Not connected".to_string()),
+            b"BYE" => {
+                self.connected = false;
+                self.authenticated = false;
+                b"BYE"
+            }
+            _ => match frame.strip_prefix(b"AUTH ") {
+                Some(token) if token == self.token.as_bytes() => {
+                    self.authenticated = true;
+                    b"OK"
+                }
+                Some(_) => b"DENIED",
+                None if self.authenticated => frame,
+                None => return Err("Not authenticated".to_string()),
+            },
+        };
+        self.replies.push_back(reply.to_vec());
+        Ok(())
+    }
+
+    fn read(&mut self) -> Result<Vec<u8>, String> {
+        self.replies
+            .pop_front()
+            .ok_or_else(|| "Nothing to read".to_string())
+    }
+}
+
+// Bytes written and read over the life of one connection, frames included
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ByteCounters {
+    pub sent: usize,
+    pub received: usize,
+}
+
+// Delays between reconnection attempts: `initial`, doubling each time up
+// to `max`, for at most `attempts` attempts
+pub struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub attempts: u32,
+}
+
+impl Backoff {
+    // The delay before retry number `retry`, counting from 0
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.initial
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max)
+    }
+}
+
+// The handshake has been accepted but the client has not logged in yet.
+// Each state below borrows the transport for as long as it is connected.
+pub struct ConnectingConnection<'t> {
+    transport: &'t mut Loopback,
+    counters: ByteCounters,
+}
+
+pub struct AuthenticatedConnection<'t> {
+    transport: &'t mut Loopback,
+    counters: ByteCounters,
+}
+
+// Finished for good: there are no transitions out of it
+pub struct ClosedConnection {
+    pub counters: ByteCounters,
+}
+
+// Writes a frame and reads the reply, counting both
+fn exchange(
+    transport: &mut Loopback,
+    counters: &mut ByteCounters,
+    frame: &[u8],
+) -> Result<Vec<u8>, String> {
+    transport.write(frame)?;
+    counters.sent += frame.len();
+    let reply = transport.read()?;
+    counters.received += reply.len();
+    Ok(reply)
+}
+
+// Only borrows the transport while it runs, so a failed attempt leaves it
+// free for the next one
+fn handshake(transport: &mut Loopback, counters: &mut ByteCounters) -> Result<(), String> {
+    match exchange(transport, counters, b"HELLO")?.as_slice() {
+        b"WELCOME" => Ok(()),
+        reply => Err(format!(
+            "Unexpected handshake reply {:?}",
+            String::from_utf8_lossy(reply)
+        )),
+    }
+}
+
+impl DisconnectedConnection {
+    pub fn open(
+        self,
+        transport: &mut Loopback,
+    ) -> Result<ConnectingConnection<'_>, FailedConnection> {
+        let mut counters = ByteCounters::default();
+        match handshake(transport, &mut counters) {
+            Ok(()) => Ok(ConnectingConnection {
+                transport,
+                counters,
+            }),
+            Err(error) => Err(FailedConnection { error, attempts: 1 }),
+        }
+    }
+}
+
+impl<'t> ConnectingConnection<'t> {
+    pub fn authenticate(
+        mut self,
+        token: &str,
+    ) -> Result<AuthenticatedConnection<'t>, FailedConnection> {
+        let frame = [b"AUTH ", token.as_bytes()].concat();
+        match exchange(self.transport, &mut self.counters, &frame) {
+            Ok(reply) if reply == b"OK" => Ok(AuthenticatedConnection {
+                transport: self.transport,
+                counters: self.counters,
+            }),
+            Ok(_) => Err(FailedConnection {
+                error: "Authentication denied".to_string(),
+                attempts: 0,
+            }),
+            Err(error) => Err(FailedConnection { error, attempts: 0 }),
+        }
+    }
+}
+
+impl AuthenticatedConnection<'_> {
+    pub fn send(&mut self, data: &[u8]) -> Result<(), String> {
+        self.transport.write(data)?;
+        self.counters.sent += data.len();
+        Ok(())
+    }
+
+    pub fn receive(&mut self) -> Result<Vec<u8>, String> {
+        let data = self.transport.read()?;
+        self.counters.received += data.len();
+        Ok(data)
+    }
+
+    pub fn counters(&self) -> ByteCounters {
+        self.counters
+    }
+
+    // Anything still unread is discarded along with the connection
+    pub fn close(mut self) -> ClosedConnection {
+        self.transport.replies.clear();
+        // Closed either way: a peer that does not answer BYE is gone already
+        let _ = exchange(self.transport, &mut self.counters, b"BYE");
+        ClosedConnection {
+            counters: self.counters,
+        }
+    }
+}
+
+impl FailedConnection {
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    // Waits `backoff.delay(n)` before each attempt; `wait` is
+    // `std::thread::sleep` outside of tests
+    pub fn reconnect<'t>(
+        mut self,
+        transport: &'t mut Loopback,
+        backoff: &Backoff,
+        mut wait: impl FnMut(Duration),
+    ) -> Result<ConnectingConnection<'t>, FailedConnection> {
+        for retry in 0..backoff.attempts {
+            wait(backoff.delay(retry));
+            let mut counters = ByteCounters::default();
+            match handshake(transport, &mut counters) {
+                Ok(()) => {
+                    return Ok(ConnectingConnection {
+                        transport,
+                        counters,
+                    })
+                }
+                Err(error) => {
+                    self.error = error;
+                    self.attempts += 1;
+                }
+            }
+        }
+        Err(self)
+    }
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Safe Abstractions");
+    println!("--------------------------");
+
+    let mut conn = Connection::new();
+    conn.handle();
+    conn.handle();
+    if let Connection::Connected(connected) = conn {
+        println!("Sent {} bytes", connected.buffer.len());
+    }
+
+    // The peer turns the first three attempts away
+    let mut transport = Loopback::new("secret").refusing(3);
+    let backoff = Backoff {
+        initial: Duration::from_millis(1),
+        max: Duration::from_millis(3),
+        attempts: 5,
+    };
+    let connecting = match DisconnectedConnection.open(&mut transport) {
+        Ok(connecting) => connecting,
+        Err(failed) => {
+            println!("{} (attempt {})", failed.error(), failed.attempts());
+            failed
+                .reconnect(&mut transport, &backoff, |delay| {
+                    println!("Retrying in {:?}", delay);
+                    std::thread::sleep(delay);
+                })
+                .unwrap()
+        }
+    };
+    let mut conn = connecting.authenticate("secret").unwrap();
+    conn.send(b"ping").unwrap();
+    println!(
+        "Echoed: {}",
+        String::from_utf8_lossy(&conn.receive().unwrap())
+    );
+    let closed = conn.close();
+    println!("Closed after {:?}", closed.counters);
+}
+
+// The grading suite from problems/grading.rs, run against these answers.
+// With the `grading` feature on too, problems.rs loads the same file for the
+// stubs; the two copies are meant to be separate modules.
+#[cfg(test)]
+#[path = "problems/grading.rs"]
+#[allow(clippy::duplicate_mod)]
+mod grading;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_run_exercises() {
+        super::run_exercises();
+    }
+}
@@ -0,0 +1,307 @@
+// Module 2: Borrowing String Utilities
+// Functions that take string slices and return slices of them, never new
+// Strings. The lifetime parameters say which input each result borrows
+// from, so the caller knows which inputs must outlive it:
+//
+//   longest<'a>(x: &'a str, y: &'a str) -> &'a str            x or y
+//   longest_common_substring<'a>(a: &'a str, b: &str) -> &'a str  only a
+//
+// With a single input reference, elision fills the annotations in:
+// `first_word(s: &str) -> &str` means `first_word<'a>(s: &'a str) -> &'a str`.
+
+// Whether `part` lies inside `whole` in memory, not merely equal to some
+// text in it. Every slice returned below passes this against its input.
+pub fn is_subslice(whole: &str, part: &str) -> bool {
+    let whole = whole.as_bytes().as_ptr_range();
+    let part = part.as_bytes().as_ptr_range();
+    whole.start <= part.start && part.end <= whole.end
+}
+
+// The longer of the two, `y` on a tie
+pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+// Everything before the first space
+pub fn first_word(s: &str) -> &str {
+    match s.find(' ') {
+        Some(pos) => &s[..pos],
+        None => s,
+    }
+}
+
+// `str::split_once` at every occurrence of `delimiter` instead of the first:
+// ("a,b,c", ",") gives [("a", "b,c"), ("a,b", "c")]. The results borrow only
+// from `text`, so `delimiter` may be a temporary.
+pub fn split_once_all<'a>(text: &'a str, delimiter: &str) -> Vec<(&'a str, &'a str)> {
+    text.match_indices(delimiter)
+        .map(|(at, found)| (&text[..at], &text[at + found.len()..]))
+        .collect()
+}
+
+// Each whitespace-separated word with its byte offset in `text`. The words
+// are slices of `text`, so the offset is just the distance between the two
+// start addresses.
+pub fn words_with_positions(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let start = text.as_ptr() as usize;
+    text.split_whitespace()
+        .map(move |word| (word.as_ptr() as usize - start, word))
+}
+
+// The longest run of characters found in both, as a slice of `a`; the first
+// one in `a` on a tie. Dynamic programming over characters, O(len(a) * len(b)).
+pub fn longest_common_substring<'a>(a: &'a str, b: &str) -> &'a str {
+    let a_chars: Vec<(usize, char)> = a.char_indices().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    // previous[j]: length of the common run ending at the previous
+    // character of `a` and at b_chars[j - 1]
+    let mut previous = vec![0; b_chars.len() + 1];
+    let mut best_len = 0;
+    let mut best_end = 0;
+    for (i, &(_, ca)) in a_chars.iter().enumerate() {
+        let mut current = vec![0; b_chars.len() + 1];
+        for (j, &cb) in b_chars.iter().enumerate() {
+            if ca == cb {
+                current[j + 1] = previous[j] + 1;
+                if current[j + 1] > best_len {
+                    best_len = current[j + 1];
+                    best_end = i + 1;
+                }
+            }
+        }
+        previous = current;
+    }
+
+    let byte_at = |index: usize| a_chars.get(index).map_or(a.len(), |&(at, _)| at);
+    &a[byte_at(best_end - best_len)..byte_at(best_end)]
+}
+
+// Removes at most one of `prefixes` and then at most one of `suffixes`, the
+// longest that matches in each case
+pub fn strip_affixes<'a>(text: &'a str, prefixes: &[&str], suffixes: &[&str]) -> &'a str {
+    let prefix = prefixes
+        .iter()
+        .filter(|p| text.starts_with(**p))
+        .map(|p| p.len())
+        .max();
+    let text = &text[prefix.unwrap_or(0)..];
+    let suffix = suffixes
+        .iter()
+        .filter(|s| text.ends_with(**s))
+        .map(|s| s.len())
+        .max();
+    &text[..text.len() - suffix.unwrap_or(0)]
+}
+
+// Every run of `size` consecutive characters, like `slice::windows` for
+// text. The windows borrow the text rather than the iterator, so this can be
+// a plain Iterator; `windows_mut` below cannot.
+pub fn windows(text: &str, size: usize) -> Windows<'_> {
+    assert!(size > 0, "window size must be non-zero");
+    Windows { text, size }
+}
+
+pub struct Windows<'a> {
+    // Starts at the next window
+    text: &'a str,
+    size: usize,
+}
+
+// The byte length of the first `size` characters, if there are that many
+fn window_len(text: &str, size: usize) -> Option<usize> {
+    match text.char_indices().nth(size) {
+        Some((end, _)) => Some(end),
+        None => (text.chars().count() == size).then_some(text.len()),
+    }
+}
+
+impl<'a> Iterator for Windows<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let len = window_len(self.text, self.size)?;
+        let window = &self.text[..len];
+        let first = window.chars().next().map_or(0, char::len_utf8);
+        self.text = &self.text[first..];
+        Some(window)
+    }
+}
+
+// An iterator whose items borrow the iterator itself, so each item must be
+// gone before `next` is called again. `Iterator` cannot say that: its
+// `Item` type has no lifetime to tie to the `&mut self` of `next`, which is
+// what the generic associated type `Item<'b>` adds. Use it with
+// `while let Some(item) = iter.next() { ... }`.
+pub trait LendingIterator {
+    type Item<'b>
+    where
+        Self: 'b;
+
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
+
+// Overlapping mutable windows. Handing out two overlapping `&mut str`s at
+// once would be unsound, so each window is lent only until the next call.
+pub fn windows_mut(text: &mut str, size: usize) -> WindowsMut<'_> {
+    assert!(size > 0, "window size must be non-zero");
+    WindowsMut {
+        text,
+        size,
+        start: 0,
+    }
+}
+
+pub struct WindowsMut<'a> {
+    text: &'a mut str,
+    size: usize,
+    start: usize,
+}
+
+impl LendingIterator for WindowsMut<'_> {
+    type Item<'b>
+        = &'b mut str
+    where
+        Self: 'b;
+
+    fn next(&mut self) -> Option<&mut str> {
+        let start = self.start;
+        let len = window_len(&self.text[start..], self.size)?;
+        self.start += self.text[start..].chars().next().map_or(0, char::len_utf8);
+        Some(&mut self.text[start..start + len])
+    }
+}
+
+// Uppercases (ASCII only) every occurrence of `word`, overlapping ones
+// included: "banana" with "ana" becomes "bANANA"
+pub fn uppercase_matches(text: &mut str, word: &str) {
+    let size = word.chars().count();
+    if size == 0 {
+        return;
+    }
+    let mut windows = windows_mut(text, size);
+    while let Some(window) = windows.next() {
+        if window.eq_ignore_ascii_case(word) {
+            window.make_ascii_uppercase();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(longest("hello", "hi"), "hello");
+        assert_eq!(first_word("hello world"), "hello");
+        assert_eq!(split_once_all("a,b,c", ","), [("a", "b,c"), ("a,b", "c")]);
+        let words: Vec<_> = words_with_positions("  fn  main() {}").collect();
+        assert_eq!(words, [(2, "fn"), (6, "main()"), (13, "{}")]);
+        assert_eq!(
+            longest_common_substring("ownership", "membership"),
+            "ership"
+        );
+        assert_eq!(longest_common_substring("größe", "große"), "gr");
+        assert_eq!(longest_common_substring("abc", "xyz"), "");
+        assert_eq!(
+            strip_affixes("Mrs. Ferris.rs", &["Mr. ", "Mrs. "], &[".rs", "s"]),
+            "Ferris"
+        );
+    }
+
+    #[test]
+    fn test_results_outlive_other_inputs() {
+        let text = String::from("key=value");
+        let pairs = {
+            let delimiter = String::from("=");
+            split_once_all(&text, &delimiter)
+        };
+        assert_eq!(pairs, [("key", "value")]);
+
+        let common = {
+            let other = String::from("values");
+            longest_common_substring(&text, &other)
+        };
+        assert_eq!(common, "value");
+    }
+
+    #[test]
+    fn test_windows() {
+        let all: Vec<&str> = windows("añb", 2).collect();
+        assert_eq!(all, ["añ", "ñb"]);
+        assert_eq!(windows("ab", 2).count(), 1);
+        assert_eq!(windows("ab", 3).count(), 0);
+
+        let mut text = String::from("banana band");
+        uppercase_matches(&mut text, "ana");
+        assert_eq!(text, "bANANA band");
+
+        let mut text = String::from("añb");
+        let mut windows = windows_mut(&mut text, 2);
+        let mut seen = Vec::new();
+        while let Some(window) = windows.next() {
+            seen.push(window.to_string());
+        }
+        assert_eq!(seen, ["añ", "ñb"]);
+    }
+
+    // A small alphabet, so that matches and repeats are common
+    const TEXT: &str = "[ab, =é]{0,24}";
+
+    proptest! {
+        #[test]
+        fn test_longest_and_first_word_borrow_input(x in TEXT, y in TEXT) {
+            let result = longest(&x, &y);
+            prop_assert!(std::ptr::eq(result, x.as_str()) || std::ptr::eq(result, y.as_str()));
+            let word = first_word(&x);
+            prop_assert!(is_subslice(&x, word));
+            prop_assert!(!word.contains(' '));
+        }
+
+        #[test]
+        fn test_split_once_all_slices_lie_within_input(text in TEXT, delimiter in "[,=]{1,2}") {
+            for (before, after) in split_once_all(&text, &delimiter) {
+                prop_assert!(is_subslice(&text, before) && is_subslice(&text, after));
+                prop_assert_eq!(format!("{}{}{}", before, delimiter, after), text.clone());
+            }
+        }
+
+        #[test]
+        fn test_words_lie_at_their_positions(text in TEXT) {
+            for (position, word) in words_with_positions(&text) {
+                prop_assert!(is_subslice(&text, word));
+                prop_assert!(std::ptr::eq(word.as_ptr(), text[position..].as_ptr()));
+            }
+        }
+
+        #[test]
+        fn test_common_substring_lies_within_first_input(a in TEXT, b in TEXT) {
+            let common = longest_common_substring(&a, &b);
+            prop_assert!(is_subslice(&a, common));
+            prop_assert!(b.contains(common));
+        }
+
+        #[test]
+        fn test_strip_affixes_lies_within_input(text in TEXT, prefix in "[ab]{0,2}", suffix in "[ab]{0,2}") {
+            let stripped = strip_affixes(&text, &[&prefix], &[&suffix]);
+            prop_assert!(is_subslice(&text, stripped));
+            prop_assert!(stripped.len() + prefix.len() + suffix.len() >= text.len());
+        }
+
+        #[test]
+        fn test_windows_lie_within_input(text in TEXT, size in 1..4usize) {
+            let count = text.chars().count();
+            let all: Vec<&str> = windows(&text, size).collect();
+            prop_assert_eq!(all.len(), (count + 1).saturating_sub(size));
+            for window in all {
+                prop_assert!(is_subslice(&text, window));
+                prop_assert_eq!(window.chars().count(), size);
+            }
+        }
+    }
+}
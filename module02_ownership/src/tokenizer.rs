@@ -0,0 +1,326 @@
+// Module 2: Zero-Copy Tokenizer
+// A parser would like to own its input and hand out `&str` tokens pointing
+// into it, all in one struct:
+//
+//   struct Parser<'a> { content: String, current: &'a str }
+//
+// There is no lifetime to write for `'a`: it would have to be "as long as
+// this struct's own `content`", which Rust cannot express, and moving the
+// struct would invalidate any reference it held to itself. main.rs shows the
+// usual dead end (leaking the String). The two workarounds used here:
+//
+//   - Keep the tokens out of the struct. `SafeParser` returns `&str` from
+//     methods on `&self`, so each token borrows the parser and the compiler
+//     checks that none outlives it.
+//   - Store positions instead of references. `IntoTokens` owns the String
+//     and yields byte ranges, which stay valid wherever the struct moves.
+//
+// Either way no token is ever copied; `cloning_tokens` is the version that
+// copies each one into its own String, for comparison (see `benchmark`).
+
+use std::cell::Cell;
+use std::hint::black_box;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+// Tokens are runs of letters, digits and underscores, double-quoted strings
+// (quotes included; an unterminated one runs to the end) or any other single
+// character. Whitespace only separates them.
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// The byte range of the first token at or after `from`
+fn next_span(text: &str, from: usize) -> Option<Range<usize>> {
+    let rest = &text[from..];
+    let start = from + rest.len() - rest.trim_start().len();
+    let rest = &text[start..];
+    let first = rest.chars().next()?;
+    let len = if is_word(first) {
+        rest.find(|c| !is_word(c)).unwrap_or(rest.len())
+    } else if first == '"' {
+        rest[1..].find('"').map_or(rest.len(), |end| end + 2)
+    } else {
+        first.len_utf8()
+    };
+    Some(start..start + len)
+}
+
+// Where a parser was, to go back to after a failed attempt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint(usize);
+
+pub struct SafeParser {
+    content: String,
+    // A Cell, so that advancing only needs `&self`: with `&mut self`, no
+    // token handed out earlier could still be in use
+    pos: Cell<usize>,
+}
+
+impl SafeParser {
+    pub fn new(content: String) -> Self {
+        SafeParser {
+            content,
+            pos: Cell::new(0),
+        }
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    // The input not consumed yet
+    pub fn remaining(&self) -> &str {
+        &self.content[self.pos.get()..]
+    }
+
+    pub fn next_token(&self) -> Option<&str> {
+        let span = next_span(&self.content, self.pos.get())?;
+        self.pos.set(span.end);
+        Some(&self.content[span])
+    }
+
+    pub fn peek(&self) -> Option<&str> {
+        next_span(&self.content, self.pos.get()).map(|span| &self.content[span])
+    }
+
+    // Consumes the next token only if it is `expected`
+    pub fn eat(&self, expected: &str) -> bool {
+        let matches = self.peek() == Some(expected);
+        if matches {
+            self.next_token();
+        }
+        matches
+    }
+
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.pos.get())
+    }
+
+    // Panics on a checkpoint from a parser with a shorter input
+    pub fn rewind(&self, checkpoint: Checkpoint) {
+        assert!(
+            checkpoint.0 <= self.content.len(),
+            "checkpoint from another parser"
+        );
+        self.pos.set(checkpoint.0);
+    }
+
+    // The remaining tokens, advancing the parser as they are taken
+    pub fn tokens(&self) -> impl Iterator<Item = &str> {
+        std::iter::from_fn(move || self.next_token())
+    }
+
+    // Carries on from the current position
+    pub fn into_tokens(self) -> IntoTokens {
+        IntoTokens {
+            pos: self.pos.get(),
+            content: self.content,
+        }
+    }
+}
+
+// Owns the input and yields the byte range of each token. An Iterator cannot
+// yield `&str`s borrowed from itself (`next` takes `&mut self`, and each
+// item would have to outlive that borrow), so the items are positions; look
+// them up with `text`.
+pub struct IntoTokens {
+    content: String,
+    pos: usize,
+}
+
+impl IntoTokens {
+    pub fn text(&self, span: Range<usize>) -> &str {
+        &self.content[span]
+    }
+
+    pub fn into_content(self) -> String {
+        self.content
+    }
+}
+
+impl Iterator for IntoTokens {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        let span = next_span(&self.content, self.pos)?;
+        self.pos = span.end;
+        Some(span)
+    }
+}
+
+// The same tokens, each copied into a String of its own
+pub fn cloning_tokens(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while let Some(span) = next_span(text, pos) {
+        pos = span.end;
+        tokens.push(text[span].to_string());
+    }
+    tokens
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    pub label: &'static str,
+    // Over all rounds
+    pub elapsed: Duration,
+    // Per round
+    pub bytes_copied: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Benchmark {
+    pub input_bytes: usize,
+    pub tokens: usize,
+    pub rounds: usize,
+    pub timings: Vec<Timing>,
+}
+
+// Tokenizes `input` `rounds` times each way. `black_box` keeps the optimizer
+// from skipping work whose result is never used. Only meaningful in a
+// release build: `cargo run -p module2 --release --bin tokens`.
+pub fn benchmark(input: &str, rounds: usize) -> Benchmark {
+    let parser = SafeParser::new(input.to_string());
+    let tokens = parser.tokens().count();
+    let token_bytes: usize = cloning_tokens(input).iter().map(String::len).sum();
+
+    let start = Instant::now();
+    for _ in 0..rounds {
+        parser.rewind(Checkpoint(0));
+        for token in parser.tokens() {
+            black_box(token);
+        }
+    }
+    let borrowed = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..rounds {
+        // IntoTokens needs an owned String of its own every round
+        for span in SafeParser::new(black_box(input).to_string()).into_tokens() {
+            black_box(span);
+        }
+    }
+    let ranges = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..rounds {
+        black_box(cloning_tokens(black_box(input)));
+    }
+    let cloned = start.elapsed();
+
+    Benchmark {
+        input_bytes: input.len(),
+        tokens,
+        rounds,
+        timings: vec![
+            Timing {
+                label: "borrowed &str (SafeParser)",
+                elapsed: borrowed,
+                bytes_copied: 0,
+            },
+            Timing {
+                label: "byte ranges (IntoTokens)",
+                elapsed: ranges,
+                bytes_copied: input.len(),
+            },
+            Timing {
+                label: "String per token (cloning_tokens)",
+                elapsed: cloned,
+                bytes_copied: token_bytes,
+            },
+        ],
+    }
+}
+
+impl Benchmark {
+    pub fn render(&self) -> String {
+        let mut out = format!(
+            "{} bytes, {} tokens, {} rounds\n",
+            self.input_bytes, self.tokens, self.rounds
+        );
+        out.push_str("  ns/token  bytes copied/round  tokenizer\n");
+        let token_count = (self.tokens * self.rounds).max(1) as f64;
+        for timing in &self.timings {
+            out.push_str(&format!(
+                "{:>10.1}  {:>18}  {}\n",
+                timing.elapsed.as_nanos() as f64 / token_count,
+                timing.bytes_copied,
+                timing.label
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let parser = SafeParser::new(r#"let größe = f(x_1, "a b") + 42;"#.to_string());
+        let tokens: Vec<&str> = parser.tokens().collect();
+        assert_eq!(
+            tokens,
+            ["let", "größe", "=", "f", "(", "x_1", ",", r#""a b""#, ")", "+", "42", ";"]
+        );
+        assert_eq!(parser.next_token(), None);
+        assert_eq!(parser.remaining(), "");
+
+        let unterminated = SafeParser::new(r#"say "hi"#.to_string());
+        assert_eq!(unterminated.tokens().last(), Some(r#""hi"#));
+    }
+
+    #[test]
+    fn test_tokens_outlive_later_calls() {
+        let parser = SafeParser::new("a + b".to_string());
+        let first = parser.next_token().unwrap();
+        let second = parser.next_token().unwrap();
+        // Both still borrow the parser, and point into its own String
+        assert_eq!((first, second), ("a", "+"));
+        assert!(std::ptr::eq(first.as_ptr(), parser.content().as_ptr()));
+    }
+
+    #[test]
+    fn test_peek_eat_and_rewind() {
+        let parser = SafeParser::new("x = 1".to_string());
+        assert_eq!(parser.peek(), Some("x"));
+        assert_eq!(parser.peek(), Some("x"));
+        let start = parser.checkpoint();
+        assert!(!parser.eat("y"));
+        assert!(parser.eat("x"));
+        assert!(parser.eat("="));
+        assert_eq!(parser.remaining(), " 1");
+
+        parser.rewind(start);
+        assert_eq!(parser.tokens().collect::<Vec<_>>(), ["x", "=", "1"]);
+    }
+
+    #[test]
+    fn test_into_tokens_yields_ranges() {
+        let parser = SafeParser::new("fn main() {}".to_string());
+        parser.next_token();
+        let mut tokens = parser.into_tokens();
+        let first = tokens.next().unwrap();
+        assert_eq!(first, 3..7);
+        assert_eq!(tokens.text(first), "main");
+        let rest: Vec<Range<usize>> = tokens.by_ref().collect();
+        assert_eq!(rest.len(), 4);
+        assert_eq!(tokens.into_content(), "fn main() {}");
+    }
+
+    #[test]
+    fn test_cloning_tokens_match() {
+        let input = "a.b(c, \"d\")";
+        let parser = SafeParser::new(input.to_string());
+        let borrowed: Vec<&str> = parser.tokens().collect();
+        assert_eq!(cloning_tokens(input), borrowed);
+
+        let report = benchmark(input, 3);
+        assert_eq!((report.tokens, report.rounds), (8, 3));
+        assert_eq!(report.timings[0].bytes_copied, 0);
+        assert_eq!(report.timings[2].bytes_copied, 10);
+        assert!(report.render().contains("cloning_tokens"));
+    }
+}
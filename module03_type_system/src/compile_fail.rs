@@ -0,0 +1,77 @@
+//! Compile-fail checks for the exercise 4 document states in problems.rs.
+//! Each snippet below makes a transition the type states are meant to rule
+//! out, so `cargo test` (which runs these as doctests) passes only while
+//! the compiler keeps rejecting it. They check signatures, not behaviour,
+//! so they hold for the unimplemented stubs as much as for a solution.
+//!
+//! The whole workflow, which must compile:
+//!
+//! ```no_run
+//! use module3::problems::{AnyDocument, Document, Published};
+//!
+//! let mut doc = Document::new("text".to_string());
+//! doc.edit("better text".to_string());
+//! let doc = doc.submit_for_review();
+//! let doc = doc.approve("alice").unwrap().approve("bob").unwrap();
+//! let stored = AnyDocument::from(doc.publish());
+//! let doc = Document::<Published>::try_from(stored).unwrap();
+//! println!("{:?}", doc.archive().history());
+//! ```
+//!
+//! Publishing a draft:
+//!
+//! ```compile_fail,E0599
+//! use module3::problems::Document;
+//!
+//! let doc = Document::new("text".to_string());
+//! doc.publish();
+//! ```
+//!
+//! Publishing with a single sign-off:
+//!
+//! ```compile_fail,E0599
+//! use module3::problems::Document;
+//!
+//! let doc = Document::new("text".to_string()).submit_for_review();
+//! doc.approve("alice").unwrap().publish();
+//! ```
+//!
+//! Editing a document under review:
+//!
+//! ```compile_fail,E0599
+//! use module3::problems::Document;
+//!
+//! let mut doc = Document::new("text".to_string()).submit_for_review();
+//! doc.edit("sneaky change".to_string());
+//! ```
+//!
+//! Approving a published document:
+//!
+//! ```compile_fail,E0599
+//! use module3::problems::Document;
+//!
+//! let doc = Document::new("text".to_string()).submit_for_review();
+//! let doc = doc.approve("alice").unwrap().approve("bob").unwrap().publish();
+//! doc.approve("carol");
+//! ```
+//!
+//! Using a draft after submitting it:
+//!
+//! ```compile_fail,E0382
+//! use module3::problems::Document;
+//!
+//! let doc = Document::new("text".to_string());
+//! let review = doc.submit_for_review();
+//! doc.archive();
+//! ```
+//!
+//! Counting sign-offs with a type of your own:
+//!
+//! ```compile_fail,E0277
+//! use module3::problems::Count;
+//!
+//! struct Two;
+//! impl Count for Two {
+//!     const VALUE: usize = 2;
+//! }
+//! ```
@@ -0,0 +1,420 @@
+// Module 3: Classifying Errors and Retrying
+// What to do about an error depends on what kind it is, not on which type
+// it has. `Classify` sorts errors into three kinds:
+//
+//   Transient  a timeout or a dropped connection; the same call may work
+//              if tried again, so `retry_with_policy` tries it again
+//   Permanent  a bug or a broken deployment; retrying will fail the same way
+//   User       bad input or a missing record; the caller must change the
+//              request (an HTTP service would answer 4xx rather than 5xx)
+//
+// `context` wraps an error in a description of what was being attempted,
+// and the wrappers stack: each one's `source()` is the error it wraps.
+// `render_chain` walks that chain, outermost first.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    Transient,
+    Permanent,
+    User,
+}
+
+impl fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ErrorClass::Transient => "transient",
+            ErrorClass::Permanent => "permanent",
+            ErrorClass::User => "user",
+        })
+    }
+}
+
+pub trait Classify {
+    fn class(&self) -> ErrorClass;
+
+    fn is_retryable(&self) -> bool {
+        self.class() == ErrorClass::Transient
+    }
+}
+
+impl Classify for io::Error {
+    fn class(&self) -> ErrorClass {
+        use io::ErrorKind::*;
+        match self.kind() {
+            TimedOut | Interrupted | WouldBlock | ConnectionRefused | ConnectionReset
+            | ConnectionAborted | BrokenPipe => ErrorClass::Transient,
+            InvalidInput | InvalidData | NotFound => ErrorClass::User,
+            _ => ErrorClass::Permanent,
+        }
+    }
+}
+
+// The error type from main.rs section 5
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServiceError {
+    NotFound(String),
+    InvalidInput { field: String, message: String },
+    DatabaseError(String),
+}
+
+impl fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ServiceError::NotFound(item) => write!(f, "{} not found", item),
+            ServiceError::InvalidInput { field, message } => {
+                write!(f, "invalid {}: {}", field, message)
+            }
+            ServiceError::DatabaseError(message) => write!(f, "database error: {}", message),
+        }
+    }
+}
+
+impl Error for ServiceError {}
+
+// The database is assumed to be temporarily unreachable rather than broken
+impl Classify for ServiceError {
+    fn class(&self) -> ErrorClass {
+        match self {
+            ServiceError::NotFound(_) | ServiceError::InvalidInput { .. } => ErrorClass::User,
+            ServiceError::DatabaseError(_) => ErrorClass::Transient,
+        }
+    }
+}
+
+pub fn process_request(input: &str) -> Result<String, ServiceError> {
+    if input.is_empty() {
+        return Err(ServiceError::InvalidInput {
+            field: "input".to_string(),
+            message: "Input cannot be empty".to_string(),
+        });
+    }
+
+    if input == "error" {
+        return Err(ServiceError::DatabaseError("Connection failed".to_string()));
+    }
+
+    Ok(format!("Processed: {}", input))
+}
+
+pub fn complex_operation(input: &str) -> Result<String, Contextual<ServiceError>> {
+    let result = process_request(input).context(format!("processing {:?}", input))?;
+    Ok(format!("Complex operation completed: {}", result))
+}
+
+// `error` together with what was being done when it happened. Displays as
+// just the message; the error is its `source()`.
+#[derive(Debug)]
+pub struct Contextual<E> {
+    message: String,
+    error: E,
+}
+
+impl<E> Contextual<E> {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E> fmt::Display for Contextual<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl<E: Error + 'static> Error for Contextual<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+// Context does not change what kind of error it is
+impl<E: Classify> Classify for Contextual<E> {
+    fn class(&self) -> ErrorClass {
+        self.error.class()
+    }
+}
+
+pub trait ResultExt<T, E> {
+    fn context(self, message: impl Into<String>) -> Result<T, Contextual<E>>;
+
+    // Only builds the message if there is an error
+    fn with_context<M: Into<String>>(self, message: impl FnOnce() -> M)
+        -> Result<T, Contextual<E>>;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    fn context(self, message: impl Into<String>) -> Result<T, Contextual<E>> {
+        self.with_context(|| message)
+    }
+
+    fn with_context<M: Into<String>>(
+        self,
+        message: impl FnOnce() -> M,
+    ) -> Result<T, Contextual<E>> {
+        self.map_err(|error| Contextual {
+            message: message().into(),
+            error,
+        })
+    }
+}
+
+// The error and each of its sources in turn
+pub fn chain<'a>(
+    error: &'a (dyn Error + 'static),
+) -> impl Iterator<Item = &'a (dyn Error + 'static)> {
+    fn source<'a>(error: &&'a (dyn Error + 'static)) -> Option<&'a (dyn Error + 'static)> {
+        (*error).source()
+    }
+    std::iter::successors(Some(error), source)
+}
+
+// The outermost message, then every cause numbered from the outside in:
+//
+//   loading the profile
+//
+//   Caused by:
+//       0: processing "error"
+//       1: database error: Connection failed
+pub fn render_chain(error: &(dyn Error + 'static)) -> String {
+    let mut out = error.to_string();
+    let causes: Vec<String> = chain(error).skip(1).map(|e| e.to_string()).collect();
+    if !causes.is_empty() {
+        out.push_str("\n\nCaused by:");
+        for (i, cause) in causes.iter().enumerate() {
+            out.push_str(&format!("\n    {}: {}", i, cause));
+        }
+    }
+    out
+}
+
+// Up to `attempts` tries in all, waiting `initial` before the first retry
+// and twice as long before each one after that, up to `max`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    pub initial: Duration,
+    pub max: Duration,
+    pub attempts: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            initial: Duration::from_millis(100),
+            max: Duration::from_secs(5),
+            attempts: 4,
+        }
+    }
+}
+
+impl RetryPolicy {
+    // The delay before retry number `retry`, counting from 0
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.initial
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max)
+    }
+}
+
+// Why retry_with_policy stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GaveUp {
+    // The error was not transient
+    NotRetryable(ErrorClass),
+    // Every attempt the policy allows failed
+    Exhausted,
+}
+
+// The last error, after `attempts` tries
+#[derive(Debug)]
+pub struct RetryError<E> {
+    pub error: E,
+    pub attempts: u32,
+    pub reason: GaveUp,
+}
+
+impl<E> fmt::Display for RetryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.reason {
+            GaveUp::NotRetryable(class) => {
+                write!(f, "failed with a {} error, not retried", class)
+            }
+            GaveUp::Exhausted => write!(f, "still failing after {} attempts", self.attempts),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for RetryError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<E: Classify> Classify for RetryError<E> {
+    fn class(&self) -> ErrorClass {
+        self.error.class()
+    }
+}
+
+// Calls `operation` with the attempt number (from 1) until it succeeds,
+// fails with an error that is not transient, or has failed
+// `policy.attempts` times. Waits `policy.delay(n)` before retry n; `sleep`
+// is `std::thread::sleep` outside of tests.
+pub fn retry_with_policy<T, E: Classify>(
+    policy: &RetryPolicy,
+    mut operation: impl FnMut(u32) -> Result<T, E>,
+    mut sleep: impl FnMut(Duration),
+) -> Result<T, RetryError<E>> {
+    let mut attempt = 1;
+    loop {
+        let error = match operation(attempt) {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+        let reason = if !error.is_retryable() {
+            GaveUp::NotRetryable(error.class())
+        } else if attempt >= policy.attempts {
+            GaveUp::Exhausted
+        } else {
+            sleep(policy.delay(attempt - 1));
+            attempt += 1;
+            continue;
+        };
+        return Err(RetryError {
+            error,
+            attempts: attempt,
+            reason,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Fails with `errors` in turn, then succeeds
+    fn flaky(mut errors: Vec<ServiceError>) -> impl FnMut(u32) -> Result<u32, ServiceError> {
+        errors.reverse();
+        move |attempt| match errors.pop() {
+            Some(error) => Err(error),
+            None => Ok(attempt),
+        }
+    }
+
+    fn outage() -> ServiceError {
+        ServiceError::DatabaseError("Connection failed".to_string())
+    }
+
+    #[test]
+    fn test_classes() {
+        assert_eq!(outage().class(), ErrorClass::Transient);
+        assert_eq!(
+            ServiceError::NotFound("user 7".to_string()).class(),
+            ErrorClass::User
+        );
+        let timeout = io::Error::new(io::ErrorKind::TimedOut, "slow");
+        assert!(timeout.is_retryable());
+        let denied = io::Error::new(io::ErrorKind::PermissionDenied, "no");
+        assert_eq!(denied.class(), ErrorClass::Permanent);
+        let wrapped = Err::<(), _>(denied).context("opening the log").unwrap_err();
+        assert_eq!(wrapped.class(), ErrorClass::Permanent);
+    }
+
+    #[test]
+    fn test_retries_transient_errors_until_success() {
+        let mut slept = Vec::new();
+        let policy = RetryPolicy::default();
+        let result = retry_with_policy(&policy, flaky(vec![outage(), outage()]), |delay| {
+            slept.push(delay)
+        });
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(
+            slept,
+            [Duration::from_millis(100), Duration::from_millis(200)]
+        );
+    }
+
+    #[test]
+    fn test_gives_up() {
+        let policy = RetryPolicy {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(3),
+            attempts: 4,
+        };
+        let mut slept = Vec::new();
+        let result = retry_with_policy(&policy, flaky(vec![outage(); 10]), |d| slept.push(d));
+        let Err(error) = result else {
+            panic!("expected to give up");
+        };
+        assert_eq!((error.attempts, error.reason), (4, GaveUp::Exhausted));
+        assert_eq!(slept, [1, 2, 3].map(Duration::from_secs));
+        assert_eq!(error.to_string(), "still failing after 4 attempts");
+
+        // User errors are not retried at all
+        let invalid = ServiceError::InvalidInput {
+            field: "name".to_string(),
+            message: "empty".to_string(),
+        };
+        let result = retry_with_policy(&policy, flaky(vec![outage(), invalid]), |_| {});
+        let Err(error) = result else {
+            panic!("expected to give up");
+        };
+        assert_eq!(error.attempts, 2);
+        assert_eq!(error.reason, GaveUp::NotRetryable(ErrorClass::User));
+        assert_eq!(error.to_string(), "failed with a user error, not retried");
+    }
+
+    #[test]
+    fn test_context_stacks() {
+        let error = complex_operation("error")
+            .context("loading the profile")
+            .unwrap_err();
+        assert_eq!(error.message(), "loading the profile");
+        assert_eq!(error.error().message(), "processing \"error\"");
+        assert_eq!(error.class(), ErrorClass::Transient);
+        let messages: Vec<String> = chain(&error).map(|e| e.to_string()).collect();
+        assert_eq!(
+            messages,
+            [
+                "loading the profile",
+                "processing \"error\"",
+                "database error: Connection failed"
+            ]
+        );
+        assert_eq!(error.into_error().into_error(), outage());
+        assert!(complex_operation("fine").is_ok());
+    }
+
+    #[test]
+    fn test_render_chain() {
+        let error = complex_operation("").unwrap_err();
+        assert_eq!(
+            render_chain(&error),
+            "processing \"\"\n\nCaused by:\n    0: invalid input: Input cannot be empty"
+        );
+        assert_eq!(render_chain(&outage()), "database error: Connection failed");
+
+        let policy = RetryPolicy {
+            attempts: 2,
+            ..RetryPolicy::default()
+        };
+        let error = retry_with_policy(&policy, |_| complex_operation("error"), |_| {}).unwrap_err();
+        assert_eq!(
+            render_chain(&error),
+            "still failing after 2 attempts\n\nCaused by:\n    \
+             0: processing \"error\"\n    1: database error: Connection failed"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+// Module 3: Hints
+// Progressive hints for the exercises in problems.rs, one list per exercise,
+// shown by `rustpath run 3 --exercise <n> --hint <level>`. Level 1 is the
+// exercise's "This exercise demonstrates" list; these are levels 2 and up,
+// each giving away a little more than the one before.
+
+pub const HINTS: [&[&str]; 5] = [
+    // Exercise 1: Memory Layout Optimization
+    &[
+        "Fields are aligned to their own size, so a `bool` in front of a `String` leaves 7 bytes \
+         of padding before the String can start.",
+        "Order the fields from largest to smallest alignment: both Strings first, then the \
+         `u32`, then the `bool`. The padding is then confined to the end of the struct.",
+        "`analyze_layouts` can print `PersonBad::layout().render()` and the same for \
+         `PersonGood` (bring `rust_basics::layout::Inspect` into scope), then the difference of \
+         `std::mem::size_of` for the two as the memory saved.",
+    ],
+    // Exercise 2: Tagged Union Implementation
+    &[
+        "`as_bool` is a single match: `JsonValue::Boolean(b) => Some(*b)`, with `_ => None` for \
+         everything else.",
+        "`pretty_print` matches on `self`. Scalars format directly; strings are wrapped in \
+         quotes. Arrays and objects call `pretty_print` on each element with a deeper indent.",
+        "Put each element on its own line, prefixed with `\" \".repeat(indent + 2)` and joined \
+         with `\",\\n\"`. The closing `]` or `}` goes on its own line at `indent`. Empty \
+         containers print as `[]` and `{}`, the same as in `to_compact`.",
+        "For `parse`, write a small parser struct holding the text and a byte position, with one \
+         method per grammar rule (value, string, number, array, object) that starts at the \
+         position and leaves it just past what it read. Peek at the next character to choose \
+         the rule. Work out the line and column only when building an error, by counting the \
+         newlines and characters in `text[..pos]`.",
+        "A `\\u` escape between D800 and DBFF is the first half of a UTF-16 surrogate pair: \
+         it must be followed by a second `\\uXXXX` between DC00 and DFFF, and the two combine \
+         to `0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)`. For `get_path`, loop over \
+         the path, stripping `[` or `.` off the front of what is left at each step.",
+    ],
+    // Exercise 3: Advanced Pattern Matching
+    &[
+        "Peel the line apart from the front: `split_once(' ')` gives the level word, and \
+         `strip_prefix('[')` followed by `split_once(']')` gives the timestamp. `?` works on \
+         Options, so every failed step returns None.",
+        "Match the level word against string literals (`\"INFO\" => LogLevel::Info`), with \
+         `_ => return None` for anything else. Parse the timestamp with `.parse().ok()?`.",
+        "The rest is the message, optionally followed by a `{key=value, ...}` block. Use \
+         `find('{')` to split the two apart, then split the block on `,` and each pair on `=`, \
+         trimming as you go.",
+        "`LogReader` wraps `reader.lines()` and counts lines as it goes. Its `next` is a loop: \
+         return the I/O error or the parsed entry, and on a line that does not parse, push a \
+         `MalformedLine` and go round again. `process_log` can then iterate over \
+         `reader.by_ref()` and still call `reader.into_errors()` at the end.",
+        "Each `LogFilter` condition is an Option, and `Option::is_none_or` says \"unset, or \
+         satisfied\" in one call. For `top_messages`, collect the counts into a Vec and sort \
+         with `b.1.cmp(&a.1).then(a.0.cmp(b.0))`; for the table, work out every column's width \
+         before writing the first row, and pad with `format!(\"{:<width$}\", cell)`.",
+    ],
+    // Exercise 4: Type State Programming
+    &[
+        "The state is only a type parameter. `PhantomData<State>` lets Document mention it \
+         without storing a value.",
+        "Each transition takes `self` by value and rebuilds the document with a different state \
+         type: `Document { content: self.content, state: PhantomData }`.",
+        "Because `publish` only exists on `Document<UnderReview<Succ<Succ<N>>>>`, publishing a \
+         draft, or a document with one sign-off, is a compile error. No runtime checks are \
+         needed for that.",
+        "All the transitions look alike, so write one private helper, generic over both \
+         states, that pushes an Event onto the record's history and rebuilds the Document with \
+         the same record and a new PhantomData. `approvals` is just `N::VALUE`.",
+        "Each TryFrom is a match with a guard, such as \
+         `AnyDocument::UnderReview(r) if r.reviewers.len() == N::VALUE`. On a mismatch, put the \
+         whole AnyDocument into the error instead of dropping it.",
+    ],
+    // Exercise 5: Error Type Design
+    &[
+        "Parse line by line with `enumerate()` so errors can report a 1-based line number. Skip \
+         blank lines and `#` comments.",
+        "`line.split_once('=')` separates the key and value. A line without one, or a port that \
+         fails `parse::<u16>()`, is a `ParseError`.",
+        "`validate` checks the fields that must be present (such as `database_url`) and returns \
+         `MissingField` for the first one missing. `From<std::io::Error>` wraps the error in the \
+         Io variant.",
+        "Keep byte ranges while parsing a line (where the key starts and ends, where the value \
+         does) and convert to a column only when building the error: \
+         `line[..start].chars().count() + 1`. One helper that takes the line and a range can \
+         then build every ParseError, snippet included.",
+        "Give every source the same way in: parse INI lines, TOML lines and environment \
+         variables into a key and a raw value, then have one `set(field, value, origin)` method \
+         convert the value for that field and record the origin. INI and environment values \
+         are untyped text; TOML ones already are a string, an integer or an array.",
+    ],
+];
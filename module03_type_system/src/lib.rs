@@ -0,0 +1,18 @@
+// Module 3: Type System Deep Dive
+// Library target exposing the exercises so the `rustpath` runner can drive them.
+
+pub mod problems;
+
+// Error classification, context and retries, shown in main.rs
+pub mod errors;
+
+// Progressive hints shown by `rustpath run --hint`
+pub mod hints;
+
+// Compile-fail checks for the exercise 4 type states, run as doctests
+#[cfg(doctest)]
+mod compile_fail;
+
+// Reference solutions, compiled only with the `solutions` feature
+#[cfg(feature = "solutions")]
+pub mod solutions;
@@ -7,7 +7,7 @@
 // - Error handling patterns
 // Comparisons with other languages are provided to highlight Rust's unique approach.
 
-mod problems;
+use module3::problems;
 
 use std::mem;
 
@@ -153,29 +153,12 @@ fn main() {
     println!("\n5. Error Handling Patterns:");
     println!("-----------------------");
 
-    // Custom error type
-    #[derive(Debug)]
-    enum ServiceError {
-        NotFound(String),
-        InvalidInput { field: String, message: String },
-        DatabaseError(String),
-    }
-
-    // Function that returns Result with custom error
-    fn process_request(input: &str) -> Result<String, ServiceError> {
-        if input.is_empty() {
-            return Err(ServiceError::InvalidInput {
-                field: "input".to_string(),
-                message: "Input cannot be empty".to_string(),
-            });
-        }
-
-        if input == "error" {
-            return Err(ServiceError::DatabaseError("Connection failed".to_string()));
-        }
-
-        Ok(format!("Processed: {}", input))
-    }
+    // Custom error type, classified as a user, transient or permanent
+    // failure (see src/errors.rs)
+    use module3::errors::{
+        complex_operation, process_request, render_chain, retry_with_policy, Classify, ResultExt,
+        RetryPolicy, ServiceError,
+    };
 
     // Error handling with pattern matching
     match process_request("") {
@@ -187,14 +170,36 @@ fn main() {
         Err(ServiceError::NotFound(item)) => println!("Not found: {}", item),
     }
 
-    // Using the ? operator for error propagation
-    fn complex_operation(input: &str) -> Result<String, ServiceError> {
-        let result = process_request(input)?;
-        Ok(format!("Complex operation completed: {}", result))
-    }
-
+    // Using the ? operator for error propagation; complex_operation adds
+    // context on the way out
     if let Err(e) = complex_operation("error") {
         println!("Complex operation failed: {:?}", e);
+        println!("  ({} error, retryable: {})", e.class(), e.is_retryable());
+    }
+
+    // Retrying a transient failure: the database is down for two attempts
+    let policy = RetryPolicy {
+        initial: std::time::Duration::from_millis(1),
+        ..RetryPolicy::default()
+    };
+    let result = retry_with_policy(
+        &policy,
+        |attempt| {
+            let input = if attempt < 3 { "error" } else { "order 42" };
+            complex_operation(input)
+        },
+        |delay| {
+            println!("  retrying in {:?}", delay);
+            std::thread::sleep(delay)
+        },
+    );
+    println!("After retries: {:?}", result);
+
+    // User errors are not retried; the whole chain is shown
+    let result = retry_with_policy(&policy, |_| complex_operation(""), std::thread::sleep)
+        .context("handling the request");
+    if let Err(e) = result {
+        println!("{}", render_chain(&e));
     }
 }
 
@@ -4,7 +4,12 @@
 // comparisons with how similar problems would be solved in other languages.
 
 use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead, Write};
 use std::mem;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::str::FromStr;
 
 pub fn run_exercises() {
     println!("Module 3 Exercises - Type System Deep Dive");
@@ -17,18 +22,30 @@ pub fn run_exercises() {
     exercise5();
 }
 
+// Exercise entry points in order, used by `rustpath run <module> --exercise <n>`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Hidden per-exercise checks, run against this file by `rustpath grade`.
+#[cfg(all(test, feature = "grading"))]
+mod grading;
+
 // Exercise 1: Memory Layout Optimization
 // This exercise demonstrates:
 // - How struct field ordering affects memory usage
 // - Alignment and padding in structs
 // - Performance implications of memory layout
-fn exercise1() {
-    println!("Exercise 1: Memory Layout Optimization");
-    println!("----------------------------------");
-    println!("TODO: Implement optimized versions of the structs\n");
 
+// `inspect_layout!` comes from module 1 (see its layout.rs): it defines the
+// structs as written and adds `PersonBad::layout()`, which reports every
+// field's offset and the padding between them. `#[repr(C)]` keeps the fields
+// in the declared order; without it the compiler would reorder them itself
+// and both structs would end up the same size.
+use rust_basics::layout::Inspect;
+
+rust_basics::inspect_layout! {
     // Original struct with suboptimal memory layout
     #[derive(Debug)]
+    #[repr(C)]
     struct PersonBad {
         active: bool,  // 1 byte + 7 bytes padding
         name: String,  // 24 bytes
@@ -39,17 +56,26 @@ fn exercise1() {
     // TODO: Create an optimized version of the struct
     // Hint: Reorder fields to minimize padding
     #[derive(Debug)]
+    #[repr(C)]
     struct PersonGood {
         name: String,  // 24 bytes
         email: String, // 24 bytes
         age: u32,      // 4 bytes
         active: bool,  // 1 byte + 3 bytes padding
     }
+}
 
-    // Function to analyze and compare memory layouts
-    fn analyze_layouts() {
-        unimplemented!("Implement analyze_layouts to compare struct sizes");
-    }
+// Function to analyze and compare memory layouts
+// `PersonBad::layout().render()` draws a struct's fields and padding byte
+// by byte; print it for both structs along with the bytes saved
+fn analyze_layouts() {
+    unimplemented!("Implement analyze_layouts to compare struct sizes");
+}
+
+fn exercise1() {
+    println!("Exercise 1: Memory Layout Optimization");
+    println!("----------------------------------");
+    println!("TODO: Implement optimized versions of the structs\n");
 
     // Test your implementation:
     // analyze_layouts();
@@ -60,39 +86,219 @@ fn exercise1() {
 // - How Rust enums work under the hood
 // - Memory layout of different enum variants
 // - Pattern matching optimization
-fn exercise2() {
-    println!("\nExercise 2: Tagged Union Implementation");
-    println!("-----------------------------------");
-    println!("TODO: Implement the JsonValue enum and its methods\n");
+// - Parsing text into a recursive enum, and reporting where it went wrong
+// - Conversions between enums and std types with From and TryFrom
+
+// A simplified JSON value type
+#[derive(Debug, Clone, PartialEq)]
+enum JsonValue {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(HashMap<String, JsonValue>),
+}
 
-    // A simplified JSON value type
-    #[derive(Debug)]
-    enum JsonValue {
-        Null,
-        Boolean(bool),
-        Number(f64),
-        String(String),
-        Array(Vec<JsonValue>),
-        Object(HashMap<String, JsonValue>),
-    }
-
-    impl JsonValue {
-        // Create a new JSON value from a Rust value
-        fn from_bool(b: bool) -> Self {
-            unimplemented!("Implement from_bool");
-        }
+// Where and why parsing failed. Lines and columns count from 1, columns in
+// characters rather than bytes.
+#[derive(Debug, Clone, PartialEq)]
+struct JsonError {
+    line: usize,
+    column: usize,
+    message: String,
+}
 
-        // Safely get a boolean value
-        fn as_bool(&self) -> Option<bool> {
-            unimplemented!("Implement as_bool");
-        }
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
 
-        // Pretty print the JSON value
-        fn pretty_print(&self, indent: usize) -> String {
-            unimplemented!("Implement pretty_print");
-        }
+// A TryFrom conversion out of the wrong variant
+#[derive(Debug, Clone, PartialEq)]
+struct JsonTypeError {
+    expected: &'static str,
+    found: &'static str,
+}
+
+impl fmt::Display for JsonTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected {}, found {}", self.expected, self.found)
+    }
+}
+
+// Deeper nesting is rejected rather than risking a stack overflow
+const MAX_DEPTH: usize = 128;
+
+impl JsonValue {
+    // Create a new JSON value from a Rust value
+    fn from_bool(b: bool) -> Self {
+        unimplemented!("Implement from_bool");
+    }
+
+    // Safely get a boolean value
+    fn as_bool(&self) -> Option<bool> {
+        unimplemented!("Implement as_bool");
+    }
+
+    // "null", "boolean", "number", "string", "array" or "object"
+    fn type_name(&self) -> &'static str {
+        unimplemented!("Implement type_name");
+    }
+
+    // Parse a JSON document (RFC 8259): exactly one value with optional
+    // whitespace around it. Reject anything the RFC does not allow: trailing
+    // commas, leading zeros, single quotes, unescaped control characters in
+    // strings, unpaired `\u` surrogates, numbers too large for an f64 and
+    // nesting deeper than MAX_DEPTH.
+    fn parse(text: &str) -> Result<JsonValue, JsonError> {
+        unimplemented!("Implement parse");
+    }
+
+    // Serialize without any whitespace, object keys in sorted order. NaN and
+    // the infinities have no JSON form and are written as null.
+    fn to_compact(&self) -> String {
+        unimplemented!("Implement to_compact");
+    }
+
+    // Pretty print the JSON value: one element or member per line, indented
+    // `indent + 2` spaces, object keys in sorted order
+    fn pretty_print(&self, indent: usize) -> String {
+        unimplemented!("Implement pretty_print");
     }
 
+    // Look up a path such as `a.b[2]`: `name` (or `.name` after the first
+    // step) selects an object member, `[n]` an array element and `["name"]`
+    // a member whose name contains `.` or `[`. The empty path is the value
+    // itself.
+    fn get_path(&self, path: &str) -> Option<&JsonValue> {
+        unimplemented!("Implement get_path");
+    }
+}
+
+impl FromStr for JsonValue {
+    type Err = JsonError;
+
+    fn from_str(text: &str) -> Result<Self, JsonError> {
+        JsonValue::parse(text)
+    }
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_compact())
+    }
+}
+
+impl From<bool> for JsonValue {
+    fn from(b: bool) -> Self {
+        unimplemented!("Implement From<bool>");
+    }
+}
+
+impl From<f64> for JsonValue {
+    fn from(n: f64) -> Self {
+        unimplemented!("Implement From<f64>");
+    }
+}
+
+impl From<i32> for JsonValue {
+    fn from(n: i32) -> Self {
+        unimplemented!("Implement From<i32>");
+    }
+}
+
+impl From<&str> for JsonValue {
+    fn from(s: &str) -> Self {
+        unimplemented!("Implement From<&str>");
+    }
+}
+
+impl From<String> for JsonValue {
+    fn from(s: String) -> Self {
+        unimplemented!("Implement From<String>");
+    }
+}
+
+impl<T: Into<JsonValue>> From<Vec<T>> for JsonValue {
+    fn from(items: Vec<T>) -> Self {
+        unimplemented!("Implement From<Vec<T>>");
+    }
+}
+
+impl<T: Into<JsonValue>> From<HashMap<String, T>> for JsonValue {
+    fn from(map: HashMap<String, T>) -> Self {
+        unimplemented!("Implement From<HashMap<String, T>>");
+    }
+}
+
+// None becomes null
+impl<T: Into<JsonValue>> From<Option<T>> for JsonValue {
+    fn from(value: Option<T>) -> Self {
+        unimplemented!("Implement From<Option<T>>");
+    }
+}
+
+impl TryFrom<JsonValue> for bool {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        unimplemented!("Implement TryFrom<JsonValue> for bool");
+    }
+}
+
+impl TryFrom<JsonValue> for f64 {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        unimplemented!("Implement TryFrom<JsonValue> for f64");
+    }
+}
+
+// Only for whole numbers that an f64 holds exactly (|n| <= 2^53); anything
+// else is an error expecting "integer"
+impl TryFrom<JsonValue> for i64 {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        unimplemented!("Implement TryFrom<JsonValue> for i64");
+    }
+}
+
+impl TryFrom<JsonValue> for String {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        unimplemented!("Implement TryFrom<JsonValue> for String");
+    }
+}
+
+impl TryFrom<JsonValue> for Vec<JsonValue> {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        unimplemented!("Implement TryFrom<JsonValue> for Vec<JsonValue>");
+    }
+}
+
+impl TryFrom<JsonValue> for HashMap<String, JsonValue> {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        unimplemented!("Implement TryFrom<JsonValue> for HashMap<String, JsonValue>");
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Tagged Union Implementation");
+    println!("-----------------------------------");
+    println!("TODO: Implement the JsonValue enum and its methods\n");
+
     // Test your implementation:
     // let json = JsonValue::Object({
     //     let mut map = HashMap::new();
@@ -102,6 +308,10 @@ fn exercise2() {
     //     map
     // });
     // println!("JSON:\n{}", json.pretty_print(2));
+    //
+    // let doc: JsonValue = r#"{"user": {"tags": ["a", "b", "c"]}}"#.parse().unwrap();
+    // println!("{:?}", doc.get_path("user.tags[2]"));
+    // println!("{}", JsonValue::parse("[1, 2,]").unwrap_err());
 }
 
 // Exercise 3: Advanced Pattern Matching
@@ -110,34 +320,204 @@ fn exercise2() {
 // - Match guards and bindings
 // - Exhaustiveness checking
 // - Pattern matching optimization
-fn exercise3() {
-    println!("\nExercise 3: Advanced Pattern Matching");
-    println!("----------------------------------");
-    println!("TODO: Implement the parse_log_entry function\n");
+// - Streaming a file through BufRead instead of reading it all at once
+// - Deriving Ord on an enum to compare its variants
+
+// Log entry types, declared from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum LogLevel {
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
 
-    // Log entry types
-    #[derive(Debug)]
-    enum LogLevel {
-        Debug,
-        Info,
-        Warning,
-        Error,
+impl LogLevel {
+    const ALL: [LogLevel; 4] = [
+        LogLevel::Debug,
+        LogLevel::Info,
+        LogLevel::Warning,
+        LogLevel::Error,
+    ];
+
+    // As written in a log line: "DEBUG", "INFO", "WARNING" or "ERROR"
+    fn name(self) -> &'static str {
+        unimplemented!("Implement LogLevel::name");
     }
+}
 
-    #[derive(Debug)]
-    struct LogEntry {
-        level: LogLevel,
-        timestamp: u64,
-        message: String,
-        context: HashMap<String, String>,
+#[derive(Debug, Clone, PartialEq)]
+struct LogEntry {
+    level: LogLevel,
+    timestamp: u64,
+    message: String,
+    context: HashMap<String, String>,
+}
+
+// Parse a log entry from a string with format:
+// LEVEL [timestamp] message {key1=value1, key2=value2}
+fn parse_log_entry(line: &str) -> Option<LogEntry> {
+    unimplemented!("Implement parse_log_entry");
+}
+
+impl LogEntry {
+    // One line of JSON with the keys "context", "level", "message" and
+    // "timestamp", built with exercise 2's JsonValue
+    fn to_json_line(&self) -> String {
+        unimplemented!("Implement to_json_line");
+    }
+}
+
+// Which entries to keep. LogFilter::default() keeps everything; each
+// builder method adds a condition that must also hold.
+#[derive(Debug, Clone, Default)]
+struct LogFilter {
+    min_level: Option<LogLevel>,
+    since: Option<u64>,
+    until: Option<u64>,
+    context_key: Option<String>,
+}
+
+impl LogFilter {
+    fn min_level(mut self, level: LogLevel) -> Self {
+        unimplemented!("Implement min_level");
+    }
+
+    // Timestamps from `since` to `until`, both included
+    fn between(mut self, since: u64, until: u64) -> Self {
+        unimplemented!("Implement between");
+    }
+
+    // Entries whose context has this key
+    fn with_key(mut self, key: &str) -> Self {
+        unimplemented!("Implement with_key");
+    }
+
+    fn matches(&self, entry: &LogEntry) -> bool {
+        unimplemented!("Implement matches");
+    }
+}
+
+// A line parse_log_entry rejected, numbered from 1
+#[derive(Debug, Clone, PartialEq)]
+struct MalformedLine {
+    line_number: usize,
+    line: String,
+}
+
+impl fmt::Display for MalformedLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: cannot parse {:?}", self.line_number, self.line)
+    }
+}
+
+// Parses a log one line at a time, so the whole file is never in memory.
+// Blank lines are skipped. Malformed ones are skipped too, but kept in
+// `errors` for a report at the end; only I/O errors reach the items.
+struct LogReader<R> {
+    lines: io::Lines<R>,
+    line_number: usize,
+    errors: Vec<MalformedLine>,
+}
+
+impl<R: BufRead> LogReader<R> {
+    fn new(reader: R) -> Self {
+        unimplemented!("Implement LogReader::new");
+    }
+
+    fn errors(&self) -> &[MalformedLine] {
+        unimplemented!("Implement errors");
+    }
+
+    fn into_errors(self) -> Vec<MalformedLine> {
+        unimplemented!("Implement into_errors");
+    }
+}
+
+impl<R: BufRead> Iterator for LogReader<R> {
+    type Item = io::Result<LogEntry>;
+
+    fn next(&mut self) -> Option<io::Result<LogEntry>> {
+        unimplemented!("Implement LogReader::next");
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct LogStats {
+    total: usize,
+    per_level: HashMap<LogLevel, usize>,
+    messages: HashMap<String, usize>,
+}
+
+impl LogStats {
+    fn add(&mut self, entry: &LogEntry) {
+        unimplemented!("Implement add");
     }
 
-    // Parse a log entry from a string with format:
-    // LEVEL [timestamp] message {key1=value1, key2=value2}
-    fn parse_log_entry(line: &str) -> Option<LogEntry> {
-        unimplemented!("Implement parse_log_entry");
+    fn count(&self, level: LogLevel) -> usize {
+        unimplemented!("Implement count");
     }
 
+    // The `n` most frequent messages, most frequent first and ties in
+    // alphabetical order
+    fn top_messages(&self, n: usize) -> Vec<(&str, usize)> {
+        unimplemented!("Implement top_messages");
+    }
+
+    // "<total> entries: DEBUG <n>, INFO <n>, WARNING <n>, ERROR <n>", then
+    // one line per top message: its count right-aligned in 6 columns, two
+    // spaces and the message
+    fn render(&self, top: usize) -> String {
+        unimplemented!("Implement render");
+    }
+}
+
+// A header row (LEVEL, TIMESTAMP, MESSAGE, CONTEXT) and one row per entry.
+// Each column is as wide as its widest cell, columns are separated by two
+// spaces, trailing spaces are trimmed, and context pairs are written
+// `key=value` sorted by key and joined with ", ".
+fn render_table(entries: &[LogEntry]) -> String {
+    unimplemented!("Implement render_table");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    JsonLines,
+    Table,
+}
+
+// What process_log saw: statistics over the entries that passed the
+// filter, and every line it could not parse
+#[derive(Debug, Clone, Default, PartialEq)]
+struct LogSummary {
+    stats: LogStats,
+    errors: Vec<MalformedLine>,
+}
+
+impl LogSummary {
+    // The statistics, then "<n> malformed lines:" and each of them
+    // indented by two spaces (nothing if there were none)
+    fn report(&self, top: usize) -> String {
+        unimplemented!("Implement report");
+    }
+}
+
+// The whole pipeline: parse `input` line by line, keep what `filter`
+// matches and write it to `out` in `format`
+fn process_log<R: BufRead, W: Write>(
+    input: R,
+    filter: &LogFilter,
+    format: OutputFormat,
+    out: &mut W,
+) -> io::Result<LogSummary> {
+    unimplemented!("Implement process_log");
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Advanced Pattern Matching");
+    println!("----------------------------------");
+    println!("TODO: Implement the parse_log_entry function\n");
+
     // Test your implementation:
     // let log_lines = vec![
     //     "ERROR [1621234567] Failed to connect {host=db.example.com, port=5432}",
@@ -151,6 +531,12 @@ fn exercise3() {
     //         None => println!("Failed to parse: {}", line),
     //     }
     // }
+    //
+    // let log = log_lines.join("\n") + "\nnot a log line\n";
+    // let filter = LogFilter::default().min_level(LogLevel::Info);
+    // let summary =
+    //     process_log(log.as_bytes(), &filter, OutputFormat::Table, &mut io::stdout()).unwrap();
+    // print!("{}", summary.report(3));
 }
 
 // Exercise 4: Type State Programming
@@ -158,54 +544,264 @@ fn exercise3() {
 // - Using the type system to enforce state machine constraints
 // - Zero-cost abstractions
 // - Compile-time state checking
-fn exercise4() {
-    println!("\nExercise 4: Type State Programming");
-    println!("--------------------------------");
-    println!("TODO: Implement the Document state machine\n");
+// - Counting at the type level, and sealing a trait against outside impls
+// - Moving between typed states and a runtime enum with From and TryFrom
+
+use std::marker::PhantomData;
+
+// Document states
+#[derive(Debug)]
+pub struct Draft;
 
-    // Document states
-    struct Draft;
-    struct UnderReview;
-    struct Published;
+// `N` counts the sign-offs so far: Zero, Succ<Zero>, Succ<Succ<Zero>>, ...
+#[derive(Debug)]
+pub struct UnderReview<N>(PhantomData<N>);
+
+#[derive(Debug)]
+pub struct Published;
+
+#[derive(Debug)]
+pub struct Archived;
+
+#[derive(Debug)]
+pub struct Zero;
+
+#[derive(Debug)]
+pub struct Succ<N>(PhantomData<N>);
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::Zero {}
+    impl<N: Sealed> Sealed for super::Succ<N> {}
+}
+
+// A number written as a type. Sealed, so that no type outside this module
+// can claim to be a count and forge its way to `publish`.
+pub trait Count: sealed::Sealed {
+    const VALUE: usize;
+}
+
+impl Count for Zero {
+    const VALUE: usize = 0;
+}
+
+impl<N: Count> Count for Succ<N> {
+    const VALUE: usize = N::VALUE + 1;
+}
+
+// `publish` is only implemented for UnderReview<Succ<Succ<N>>>
+pub const REQUIRED_APPROVALS: usize = 2;
+
+// What has happened to a document, oldest first
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Created,
+    Edited { previous: String },
+    Submitted,
+    Approved { reviewer: String },
+    Rejected { reviewer: String, reason: String },
+    Published,
+    Archived,
+}
+
+// Everything a document holds, whatever its state
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub content: String,
+    // Who has signed off on the current review, in order
+    pub reviewers: Vec<String>,
+    pub history: Vec<Event>,
+}
+
+// Document with type-level state
+#[derive(Debug)]
+pub struct Document<State> {
+    record: Record,
+    state: PhantomData<State>,
+}
 
-    // Document with type-level state
-    struct Document<State> {
-        content: String,
-        state: std::marker::PhantomData<State>,
+impl<State> Document<State> {
+    pub fn content(&self) -> &str {
+        &self.record.content
     }
 
-    // Implement state transitions
-    impl Document<Draft> {
-        fn new(content: String) -> Self {
-            unimplemented!("Implement new");
-        }
+    pub fn reviewers(&self) -> &[String] {
+        &self.record.reviewers
+    }
 
-        fn submit_for_review(self) -> Document<UnderReview> {
-            unimplemented!("Implement submit_for_review");
-        }
+    pub fn history(&self) -> &[Event] {
+        &self.record.history
     }
 
-    impl Document<UnderReview> {
-        fn approve(self) -> Document<Published> {
-            unimplemented!("Implement approve");
-        }
+    // 1 for the original content, plus one per edit
+    pub fn revision(&self) -> usize {
+        unimplemented!("Implement revision");
+    }
+}
 
-        fn reject(self) -> Document<Draft> {
-            unimplemented!("Implement reject");
-        }
+// Implement state transitions. Each one records an Event.
+impl Document<Draft> {
+    pub fn new(content: String) -> Self {
+        unimplemented!("Implement new");
     }
 
-    impl<State> Document<State> {
-        fn content(&self) -> &str {
-            &self.content
-        }
+    // Keep the old content in the history
+    pub fn edit(&mut self, content: String) {
+        unimplemented!("Implement edit");
+    }
+
+    pub fn submit_for_review(self) -> Document<UnderReview<Zero>> {
+        unimplemented!("Implement submit_for_review");
+    }
+
+    pub fn archive(self) -> Document<Archived> {
+        unimplemented!("Implement archive");
+    }
+}
+
+impl<N: Count> Document<UnderReview<N>> {
+    pub fn approvals(&self) -> usize {
+        unimplemented!("Implement approvals");
+    }
+
+    // Give the document back unchanged if `reviewer` already signed off
+    pub fn approve(self, reviewer: &str) -> Result<Document<UnderReview<Succ<N>>>, Self> {
+        unimplemented!("Implement approve");
     }
 
+    // Back to a draft; the sign-offs so far no longer count
+    pub fn reject(self, reviewer: &str, reason: &str) -> Document<Draft> {
+        unimplemented!("Implement reject");
+    }
+}
+
+// At least two sign-offs
+impl<N: Count> Document<UnderReview<Succ<Succ<N>>>> {
+    pub fn publish(self) -> Document<Published> {
+        unimplemented!("Implement publish");
+    }
+}
+
+impl Document<Published> {
+    pub fn archive(self) -> Document<Archived> {
+        unimplemented!("Implement archive");
+    }
+}
+
+// The state as a value rather than a type, for storing documents and
+// loading them back
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyDocument {
+    Draft(Record),
+    UnderReview(Record),
+    Published(Record),
+    Archived(Record),
+}
+
+impl AnyDocument {
+    pub fn record(&self) -> &Record {
+        unimplemented!("Implement record");
+    }
+
+    // "a draft", "a document under review with <n> sign-offs", "a published
+    // document" or "an archived document"
+    pub fn describe(&self) -> String {
+        unimplemented!("Implement describe");
+    }
+}
+
+impl From<Document<Draft>> for AnyDocument {
+    fn from(doc: Document<Draft>) -> Self {
+        unimplemented!("Implement From<Document<Draft>>");
+    }
+}
+
+impl<N: Count> From<Document<UnderReview<N>>> for AnyDocument {
+    fn from(doc: Document<UnderReview<N>>) -> Self {
+        unimplemented!("Implement From<Document<UnderReview<N>>>");
+    }
+}
+
+impl From<Document<Published>> for AnyDocument {
+    fn from(doc: Document<Published>) -> Self {
+        unimplemented!("Implement From<Document<Published>>");
+    }
+}
+
+impl From<Document<Archived>> for AnyDocument {
+    fn from(doc: Document<Archived>) -> Self {
+        unimplemented!("Implement From<Document<Archived>>");
+    }
+}
+
+// A failed TryFrom, holding the document so that nothing is lost
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrongState {
+    pub expected: String,
+    pub document: AnyDocument,
+}
+
+impl fmt::Display for WrongState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected {}, found {}",
+            self.expected,
+            self.document.describe()
+        )
+    }
+}
+
+// Each TryFrom checks that the record could really be in that state: a
+// draft has no sign-offs, a document under review has exactly N distinct
+// ones and a published one at least REQUIRED_APPROVALS distinct ones.
+// `expected` is worded like `describe`.
+impl TryFrom<AnyDocument> for Document<Draft> {
+    type Error = WrongState;
+
+    fn try_from(document: AnyDocument) -> Result<Self, WrongState> {
+        unimplemented!("Implement TryFrom<AnyDocument> for Document<Draft>");
+    }
+}
+
+impl<N: Count> TryFrom<AnyDocument> for Document<UnderReview<N>> {
+    type Error = WrongState;
+
+    fn try_from(document: AnyDocument) -> Result<Self, WrongState> {
+        unimplemented!("Implement TryFrom<AnyDocument> for Document<UnderReview<N>>");
+    }
+}
+
+impl TryFrom<AnyDocument> for Document<Published> {
+    type Error = WrongState;
+
+    fn try_from(document: AnyDocument) -> Result<Self, WrongState> {
+        unimplemented!("Implement TryFrom<AnyDocument> for Document<Published>");
+    }
+}
+
+impl TryFrom<AnyDocument> for Document<Archived> {
+    type Error = WrongState;
+
+    fn try_from(document: AnyDocument) -> Result<Self, WrongState> {
+        unimplemented!("Implement TryFrom<AnyDocument> for Document<Archived>");
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Type State Programming");
+    println!("--------------------------------");
+    println!("TODO: Implement the Document state machine\n");
+
     // Test your implementation:
     // let doc = Document::new("Draft content".to_string());
     // let doc = doc.submit_for_review();
-    // let doc = doc.approve();
+    // let doc = doc.approve("alice").unwrap().approve("bob").unwrap();
+    // let doc = doc.publish();
     // println!("Published content: {}", doc.content());
+    //
+    // let stored = AnyDocument::from(doc);
+    // println!("{:?}", Document::<Draft>::try_from(stored).map(|_| ()));
 }
 
 // Exercise 5: Error Type Design
@@ -214,245 +810,191 @@ fn exercise4() {
 // - Error conversion and the From trait
 // - Error handling best practices
 // - Error context and wrapping
-fn exercise5() {
-    println!("\nExercise 5: Error Type Design");
-    println!("---------------------------");
-    println!("TODO: Implement the Configuration system\n");
+// - Pointing at the exact place in the input that caused an error
+// - Layering configuration sources and remembering where each value came from
 
-    // Custom error type
-    #[derive(Debug)]
-    enum ConfigError {
-        IoError(std::io::Error),
-        ParseError { line: usize, message: String },
-        ValidationError(String),
-        MissingField(String),
+// Custom error type
+#[derive(Debug)]
+enum ConfigError {
+    IoError(std::io::Error),
+    // `line` and `column` count from 1, columns in characters. `snippet`
+    // shows the line with the offending part underlined, like rustc:
+    //
+    //    --> app.toml:2:8
+    //     |
+    //   2 | port = "8080"
+    //     |        ^^^^^^
+    //
+    // The gutter is as wide as the line number; an empty part still gets
+    // one `^`.
+    ParseError {
+        line: usize,
+        column: usize,
+        message: String,
+        snippet: String,
+    },
+    // An APP_* environment variable that could not be used
+    EnvError {
+        name: String,
+        message: String,
+    },
+    ValidationError(String),
+    MissingField(String),
+}
+
+// Where a value came from
+#[derive(Debug, Clone, PartialEq)]
+enum Origin {
+    Default,
+    File { name: String, line: usize },
+    Env(String),
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Origin::Default => write!(f, "the defaults"),
+            Origin::File { name, line } => write!(f, "{}:{}", name, line),
+            Origin::Env(name) => write!(f, "${}", name),
+        }
     }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    // `key=value` lines, `#` or `;` comments; values are unquoted
+    Ini,
+    // `key = value` lines where a value is a "string" (escapes \" \\ \n \t),
+    // an integer (underscores allowed) or an array of strings, with `#`
+    // comments, also after a value; no tables
+    Toml,
+}
 
-    // Configuration type
-    struct Configuration {
-        database_url: String,
-        port: u16,
-        api_key: Option<String>,
-        features: Vec<String>,
+const FIELDS: [&str; 4] = ["database_url", "port", "api_key", "features"];
+const PORTS: RangeInclusive<u16> = 1024..=65535;
+const DATABASE_SCHEMES: [&str; 3] = ["postgres", "mysql", "sqlite"];
+
+// Configuration type
+struct Configuration {
+    database_url: String,
+    port: u16,
+    api_key: Option<String>,
+    features: Vec<String>,
+    // By field name; fields never set have no entry
+    origins: HashMap<String, Origin>,
+}
+
+impl Configuration {
+    // The bottom layer of ConfigLoader: port 8080 and features ["web"],
+    // both from Origin::Default. database_url stays unset.
+    fn defaults() -> Self {
+        unimplemented!("Implement defaults");
     }
 
-    impl Configuration {
-        // Parse configuration from string
-        fn from_string(content: &str) -> Result<Self, ConfigError> {
-            unimplemented!("Implement from_string");
-        }
+    // Parse configuration from string, in INI format: only what the text
+    // sets, no defaults. Origins name the file "<string>".
+    fn from_string(content: &str) -> Result<Self, ConfigError> {
+        unimplemented!("Implement from_string");
+    }
 
-        // Validate configuration
-        fn validate(&self) -> Result<(), ConfigError> {
-            unimplemented!("Implement validate");
-        }
+    fn origin(&self, field: &str) -> Option<&Origin> {
+        unimplemented!("Implement origin");
     }
 
-    // Implement From for std::io::Error
-    impl From<std::io::Error> for ConfigError {
-        fn from(error: std::io::Error) -> Self {
-            unimplemented!("Implement From for std::io::Error");
-        }
+    // Validate configuration: database_url is set and starts with one of
+    // DATABASE_SCHEMES and "://", port is in PORTS and features is not
+    // empty. Messages say where the offending value came from.
+    fn validate(&self) -> Result<(), ConfigError> {
+        unimplemented!("Implement validate");
     }
+}
 
-    // Test your implementation:
-    // let config_str = r#"
-    //     database_url=postgres://localhost/mydb
-    //     port=8080
-    //     features=["auth", "api", "web"]
-    // "#;
-    //
-    // match Configuration::from_string(config_str) {
-    //     Ok(config) => match config.validate() {
-    //         Ok(()) => println!("Valid configuration"),
-    //         Err(e) => println!("Invalid configuration: {:?}", e),
-    //     },
-    //     Err(e) => println!("Failed to parse configuration: {:?}", e),
-    // }
+// Implement From for std::io::Error
+impl From<std::io::Error> for ConfigError {
+    fn from(error: std::io::Error) -> Self {
+        unimplemented!("Implement From for std::io::Error");
+    }
 }
 
-/* Example Solutions (Try to solve the exercises before looking at these!)
+// A ParseError displays as "line L, column C: message", a newline and the
+// snippet
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        unimplemented!("Implement Display for ConfigError");
+    }
+}
 
-// Exercise 1 Solution:
-#[derive(Debug)]
-struct PersonGood {
-    name: String,     // 24 bytes
-    email: String,    // 24 bytes
-    age: u32,        // 4 bytes
-    active: bool,    // 1 byte + 3 bytes padding
+impl std::error::Error for ConfigError {
+    // The io::Error behind an IoError
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        unimplemented!("Implement source");
+    }
 }
 
-fn analyze_layouts() {
-    println!("PersonBad size: {} bytes", mem::size_of::<PersonBad>());
-    println!("PersonGood size: {} bytes", mem::size_of::<PersonGood>());
-    println!("Memory saved: {} bytes",
-        mem::size_of::<PersonBad>() - mem::size_of::<PersonGood>());
+// Builds a Configuration from layers, each overriding the ones before it:
+// Configuration::defaults(), then each file in the order added, then the
+// APP_* environment variables (APP_PORT sets `port` and so on). The result
+// is validated.
+#[derive(Debug, Default)]
+struct ConfigLoader {
+    files: Vec<(String, String, Format)>,
+    env: Vec<(String, String)>,
 }
 
-// Exercise 2 Solution:
-impl JsonValue {
-    fn from_bool(b: bool) -> Self {
-        JsonValue::Boolean(b)
+impl ConfigLoader {
+    fn new() -> Self {
+        ConfigLoader::default()
     }
 
-    fn as_bool(&self) -> Option<bool> {
-        match self {
-            JsonValue::Boolean(b) => Some(*b),
-            _ => None,
-        }
+    // `name` is only used in errors and origins
+    fn file(mut self, name: &str, content: &str, format: Format) -> Self {
+        unimplemented!("Implement file");
     }
 
-    fn pretty_print(&self, indent: usize) -> String {
-        match self {
-            JsonValue::Null => "null".to_string(),
-            JsonValue::Boolean(b) => b.to_string(),
-            JsonValue::Number(n) => n.to_string(),
-            JsonValue::String(s) => format!("\"{}\"", s),
-            JsonValue::Array(arr) => {
-                let items: Vec<String> = arr
-                    .iter()
-                    .map(|v| format!("{}{}",
-                        " ".repeat(indent + 2),
-                        v.pretty_print(indent + 2)))
-                    .collect();
-                format!("[\n{}\n{}]",
-                    items.join(",\n"),
-                    " ".repeat(indent))
-            }
-            JsonValue::Object(obj) => {
-                let items: Vec<String> = obj
-                    .iter()
-                    .map(|(k, v)| format!("{}\"{}\": {}",
-                        " ".repeat(indent + 2),
-                        k,
-                        v.pretty_print(indent + 2)))
-                    .collect();
-                format!("{{\n{}\n{}}}",
-                    items.join(",\n"),
-                    " ".repeat(indent))
-            }
-        }
+    // Reads `path` straight away. A .toml file is TOML, anything else INI.
+    fn read_file(self, path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        unimplemented!("Implement read_file");
     }
-}
 
-// Exercise 3 Solution:
-fn parse_log_entry(line: &str) -> Option<LogEntry> {
-    let parts: Vec<&str> = line.splitn(4, ' ').collect();
-    if parts.len() != 4 {
-        return None;
-    }
-
-    let level = match parts[0] {
-        "DEBUG" => LogLevel::Debug,
-        "INFO" => LogLevel::Info,
-        "WARNING" => LogLevel::Warning,
-        "ERROR" => LogLevel::Error,
-        _ => return None,
-    };
-
-    let timestamp = parts[1]
-        .trim_matches(|c| c == '[' || c == ']')
-        .parse()
-        .ok()?;
-
-    let message = parts[2].to_string();
-
-    let context = parts[3]
-        .trim_matches(|c| c == '{' || c == '}')
-        .split(',')
-        .filter_map(|pair| {
-            let kv: Vec<&str> = pair.split('=').collect();
-            if kv.len() == 2 {
-                Some((kv[0].trim().to_string(), kv[1].trim().to_string()))
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    Some(LogEntry {
-        level,
-        timestamp,
-        message,
-        context,
-    })
-}
-
-// Exercise 4 Solution:
-impl Document<Draft> {
-    fn new(content: String) -> Self {
-        Document {
-            content,
-            state: std::marker::PhantomData,
-        }
+    // `std::env::vars()` in a real program; only APP_* variables are used
+    fn env(mut self, vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        unimplemented!("Implement env");
     }
 
-    fn submit_for_review(self) -> Document<UnderReview> {
-        Document {
-            content: self.content,
-            state: std::marker::PhantomData,
-        }
+    fn load(&self) -> Result<Configuration, ConfigError> {
+        unimplemented!("Implement load");
     }
 }
 
-// Exercise 5 Solution:
-impl Configuration {
-    fn from_string(content: &str) -> Result<Self, ConfigError> {
-        let mut config = Configuration {
-            database_url: String::new(),
-            port: 0,
-            api_key: None,
-            features: Vec::new(),
-        };
-
-        for (line_num, line) in content.lines().enumerate() {
-            let line = line.trim();
-            if line.is_empty() || line.starts_with('#') {
-                continue;
-            }
-
-            let parts: Vec<&str> = line.splitn(2, '=').collect();
-            if parts.len() != 2 {
-                return Err(ConfigError::ParseError {
-                    line: line_num + 1,
-                    message: "Invalid line format".to_string(),
-                });
-            }
-
-            match parts[0].trim() {
-                "database_url" => config.database_url = parts[1].trim().to_string(),
-                "port" => config.port = parts[1].trim().parse().map_err(|_|
-                    ConfigError::ParseError {
-                        line: line_num + 1,
-                        message: "Invalid port number".to_string(),
-                    })?,
-                "api_key" => config.api_key = Some(parts[1].trim().to_string()),
-                "features" => {
-                    let features = parts[1]
-                        .trim_matches(|c| c == '[' || c == ']')
-                        .split(',')
-                        .map(|s| s.trim().trim_matches('"').to_string())
-                        .collect();
-                    config.features = features;
-                }
-                _ => return Err(ConfigError::ParseError {
-                    line: line_num + 1,
-                    message: format!("Unknown field: {}", parts[0]),
-                }),
-            }
-        }
-
-        Ok(config)
-    }
+fn exercise5() {
+    println!("\nExercise 5: Error Type Design");
+    println!("---------------------------");
+    println!("TODO: Implement the Configuration system\n");
 
-    fn validate(&self) -> Result<(), ConfigError> {
-        if self.database_url.is_empty() {
-            return Err(ConfigError::MissingField("database_url".to_string()));
-        }
-        if self.port == 0 {
-            return Err(ConfigError::ValidationError("Port cannot be 0".to_string()));
-        }
-        Ok(())
-    }
+    // Test your implementation:
+    // let config_str = r#"
+    //     database_url=postgres://localhost/mydb
+    //     port=8080
+    //     features=["auth", "api", "web"]
+    // "#;
+    //
+    // match Configuration::from_string(config_str) {
+    //     Ok(config) => match config.validate() {
+    //         Ok(()) => println!("Valid configuration"),
+    //         Err(e) => println!("Invalid configuration: {:?}", e),
+    //     },
+    //     Err(e) => println!("Failed to parse configuration: {:?}", e),
+    // }
+    //
+    // let loader = ConfigLoader::new()
+    //     .file("app.toml", "port = \"8080\"", Format::Toml)
+    //     .env(std::env::vars());
+    // if let Err(e) = loader.load() {
+    //     println!("{}", e);
+    // }
 }
-*/
+
+// Example Solutions
+// The reference answers live in src/solutions.rs and are compiled with the
+// `solutions` feature (`rustpath run <module> --solution`). Try solving the
+// exercises yourself first!
@@ -0,0 +1,686 @@
+// Grading suite for the module 3 exercises
+// These checks are hidden from the exercise walkthrough: `rustpath grade 3`
+// runs them against your implementations in problems.rs.
+
+use super::*;
+
+mod exercise1 {
+    use super::*;
+
+    #[test]
+    fn test_analyze_layouts_runs() {
+        analyze_layouts();
+    }
+
+    #[test]
+    fn test_person_good_has_less_padding() {
+        let (bad, good) = (PersonBad::layout(), PersonGood::layout());
+        assert_eq!(bad.padding_bytes(), 11);
+        assert_eq!(
+            good.padding_bytes(),
+            3,
+            "PersonGood should only pad at the end"
+        );
+        assert_eq!(good.size, good.optimal_size());
+        assert!(good.size < bad.size);
+    }
+}
+
+mod exercise2 {
+    use super::*;
+
+    #[test]
+    fn test_bool_round_trip() {
+        assert_eq!(JsonValue::from_bool(true).as_bool(), Some(true));
+        assert_eq!(JsonValue::from_bool(false).as_bool(), Some(false));
+        assert_eq!(JsonValue::Null.as_bool(), None);
+        assert_eq!(JsonValue::Number(1.0).as_bool(), None);
+    }
+
+    #[test]
+    fn test_pretty_print_scalars() {
+        assert_eq!(JsonValue::Null.pretty_print(0), "null");
+        assert_eq!(JsonValue::Boolean(true).pretty_print(0), "true");
+        assert_eq!(JsonValue::Number(30.0).pretty_print(0), "30");
+        assert_eq!(JsonValue::Number(2.5).pretty_print(0), "2.5");
+        assert_eq!(
+            JsonValue::String("Alice".into()).pretty_print(0),
+            "\"Alice\""
+        );
+    }
+
+    #[test]
+    fn test_pretty_print_nested() {
+        let mut object = HashMap::new();
+        object.insert(
+            "tags".to_string(),
+            JsonValue::Array(vec![JsonValue::Number(1.0), JsonValue::Boolean(false)]),
+        );
+        let json = JsonValue::Object(object);
+        assert_eq!(
+            json.pretty_print(0),
+            "{\n  \"tags\": [\n    1,\n    false\n  ]\n}"
+        );
+    }
+
+    #[test]
+    fn test_parse_values() {
+        let doc = JsonValue::parse(
+            " {\"a\": [1, -2.5e1, 0.25, true, null], \"b\": {\"c\": \"x\"}, \"d\": []}\n",
+        )
+        .unwrap();
+        assert_eq!(doc.get_path("a[1]"), Some(&JsonValue::Number(-25.0)));
+        assert_eq!(doc.get_path("a[4]"), Some(&JsonValue::Null));
+        assert_eq!(doc.get_path("b.c"), Some(&JsonValue::String("x".into())));
+        assert_eq!(doc.get_path("d"), Some(&JsonValue::Array(vec![])));
+        assert_eq!(doc.get_path(""), Some(&doc));
+        assert_eq!("false".parse::<JsonValue>(), Ok(JsonValue::Boolean(false)));
+    }
+
+    #[test]
+    fn test_string_escapes_round_trip() {
+        let doc =
+            JsonValue::parse(r#""tab\t quote\" slash\/ \u00e9 \ud83e\udd80 \u0001""#).unwrap();
+        assert_eq!(
+            doc,
+            JsonValue::String("tab\t quote\" slash/ é 🦀 \u{1}".into())
+        );
+        assert_eq!(doc.to_compact(), r#""tab\t quote\" slash/ é 🦀 \u0001""#);
+        assert_eq!(JsonValue::parse(&doc.to_compact()), Ok(doc));
+    }
+
+    #[test]
+    fn test_errors_report_line_and_column() {
+        let error = JsonValue::parse("{\n  \"a\": [1, 2,]\n}").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 14));
+        assert!(error.to_string().starts_with("line 2, column 14: "));
+
+        let error = JsonValue::parse("[\"é\", tru]").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+
+        for bad in [
+            "",
+            "[1,]",
+            "{\"a\" 1}",
+            "{a: 1}",
+            "01",
+            "1.",
+            "-",
+            "+1",
+            ".5",
+            "1e",
+            "'a'",
+            "\"\u{1}\"",
+            "\"\\x\"",
+            "\"\\ud800\"",
+            "\"\\udc00\"",
+            "\"abc",
+            "1e400",
+            "[1] 2",
+            "NaN",
+        ] {
+            assert!(JsonValue::parse(bad).is_err(), "{:?} should not parse", bad);
+        }
+        let deep = "[".repeat(MAX_DEPTH + 1) + &"]".repeat(MAX_DEPTH + 1);
+        assert!(JsonValue::parse(&deep).is_err());
+        let ok = "[".repeat(MAX_DEPTH) + &"]".repeat(MAX_DEPTH);
+        assert!(JsonValue::parse(&ok).is_ok());
+    }
+
+    #[test]
+    fn test_serializers() {
+        let doc = JsonValue::parse(r#"{"b": [1, {}], "a": "x", "c": []}"#).unwrap();
+        assert_eq!(doc.to_compact(), r#"{"a":"x","b":[1,{}],"c":[]}"#);
+        assert_eq!(doc.to_string(), doc.to_compact());
+        assert_eq!(
+            doc.pretty_print(0),
+            "{\n  \"a\": \"x\",\n  \"b\": [\n    1,\n    {}\n  ],\n  \"c\": []\n}"
+        );
+        assert_eq!(JsonValue::parse(&doc.pretty_print(4)), Ok(doc));
+        assert_eq!(JsonValue::Number(f64::NAN).to_compact(), "null");
+        assert_eq!(JsonValue::Number(-0.5).to_compact(), "-0.5");
+    }
+
+    #[test]
+    fn test_get_path() {
+        let doc = JsonValue::parse(r#"[{"a.b": {"c": [10, 20, 30]}}]"#).unwrap();
+        assert_eq!(
+            doc.get_path(r#"[0]["a.b"].c[2]"#),
+            Some(&JsonValue::Number(30.0))
+        );
+        assert_eq!(doc.get_path(r#"[0]["a.b"].c[3]"#), None);
+        assert_eq!(doc.get_path("[0].a"), None);
+        assert_eq!(doc.get_path("[x]"), None);
+        assert_eq!(doc.get_path("[0]..c"), None);
+        assert_eq!(doc.get_path("a"), None);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let mut map = HashMap::new();
+        map.insert("n".to_string(), Some(3));
+        map.insert("none".to_string(), None);
+        let value = JsonValue::from(map);
+        assert_eq!(value.to_compact(), r#"{"n":3,"none":null}"#);
+        assert_eq!(
+            JsonValue::from(vec!["a", "b"]),
+            JsonValue::Array(vec![JsonValue::from("a"), JsonValue::from("b".to_string())])
+        );
+        assert_eq!(JsonValue::from(true), JsonValue::Boolean(true));
+        assert_eq!(JsonValue::from(1.5), JsonValue::Number(1.5));
+
+        assert_eq!(i64::try_from(JsonValue::Number(-7.0)), Ok(-7));
+        assert_eq!(
+            i64::try_from(JsonValue::Number(7.5)),
+            Err(JsonTypeError {
+                expected: "integer",
+                found: "number"
+            })
+        );
+        assert!(i64::try_from(JsonValue::Number(1e300)).is_err());
+        assert_eq!(f64::try_from(JsonValue::Number(7.5)), Ok(7.5));
+        assert_eq!(bool::try_from(JsonValue::Boolean(true)), Ok(true));
+        assert_eq!(String::try_from(JsonValue::from("s")), Ok("s".to_string()));
+        let error = String::try_from(JsonValue::Null).unwrap_err();
+        assert_eq!(error.to_string(), "expected string, found null");
+        assert_eq!(
+            Vec::<JsonValue>::try_from(JsonValue::Array(vec![])),
+            Ok(vec![])
+        );
+        let object = HashMap::<String, JsonValue>::try_from(value).unwrap();
+        assert_eq!(object.len(), 2);
+        assert!(HashMap::<String, JsonValue>::try_from(JsonValue::Null).is_err());
+    }
+}
+
+mod exercise3 {
+    use super::*;
+
+    #[test]
+    fn test_parses_level_timestamp_and_message() {
+        let entry = parse_log_entry(
+            "ERROR [1621234567] Failed to connect {host=db.example.com, port=5432}",
+        )
+        .unwrap();
+        assert!(matches!(entry.level, LogLevel::Error));
+        assert_eq!(entry.timestamp, 1621234567);
+        assert_eq!(entry.message, "Failed to connect");
+    }
+
+    #[test]
+    fn test_parses_context_pairs() {
+        let entry =
+            parse_log_entry("INFO [1621234568] User logged in {user_id=123, ip=192.168.1.1}")
+                .unwrap();
+        assert!(matches!(entry.level, LogLevel::Info));
+        assert_eq!(entry.context.len(), 2);
+        assert_eq!(entry.context["user_id"], "123");
+        assert_eq!(entry.context["ip"], "192.168.1.1");
+    }
+
+    #[test]
+    fn test_rejects_malformed_lines() {
+        assert!(parse_log_entry("").is_none());
+        assert!(parse_log_entry("TRACE [1] Unknown level {a=b}").is_none());
+        assert!(parse_log_entry("INFO [soon] Bad timestamp {a=b}").is_none());
+    }
+
+    const LOG: &str = "\
+INFO [100] Server started {port=8080}
+ERROR [105] Failed to connect {host=db, port=5432}
+
+WARNING [110] Slow query {ms=912}
+garbage
+ERROR [120] Failed to connect {host=db, port=5432}
+DEBUG [130] Cache miss
+TRACE [140] Unknown level
+";
+
+    #[test]
+    fn test_levels_are_ordered_by_severity() {
+        assert!(LogLevel::Debug < LogLevel::Info);
+        assert!(LogLevel::Warning < LogLevel::Error);
+        let names: Vec<&str> = LogLevel::ALL.iter().map(|level| level.name()).collect();
+        assert_eq!(names, ["DEBUG", "INFO", "WARNING", "ERROR"]);
+    }
+
+    #[test]
+    fn test_reader_streams_and_reports_malformed_lines() {
+        let mut reader = LogReader::new(LOG.as_bytes());
+        let first = reader.next().unwrap().unwrap();
+        assert_eq!((first.level, first.timestamp), (LogLevel::Info, 100));
+        assert!(reader.errors().is_empty());
+
+        let rest: Vec<LogEntry> = reader.by_ref().map(Result::unwrap).collect();
+        assert_eq!(rest.len(), 4);
+        assert_eq!(
+            reader.errors(),
+            [
+                MalformedLine {
+                    line_number: 5,
+                    line: "garbage".to_string()
+                },
+                MalformedLine {
+                    line_number: 8,
+                    line: "TRACE [140] Unknown level".to_string()
+                },
+            ]
+        );
+        assert_eq!(
+            reader.into_errors()[0].to_string(),
+            "line 5: cannot parse \"garbage\""
+        );
+
+        let mut invalid_utf8 = LogReader::new(&b"INFO [1] ok\n\xff\n"[..]);
+        assert!(invalid_utf8.next().unwrap().is_ok());
+        assert!(invalid_utf8.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_filters_combine() {
+        let entries: Vec<LogEntry> = LogReader::new(LOG.as_bytes()).map(Result::unwrap).collect();
+        let kept = |filter: &LogFilter| {
+            entries
+                .iter()
+                .filter(|entry| filter.matches(entry))
+                .map(|entry| entry.timestamp)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(kept(&LogFilter::default()), [100, 105, 110, 120, 130]);
+        assert_eq!(
+            kept(&LogFilter::default().min_level(LogLevel::Warning)),
+            [105, 110, 120]
+        );
+        assert_eq!(
+            kept(&LogFilter::default().between(105, 120)),
+            [105, 110, 120]
+        );
+        assert_eq!(
+            kept(&LogFilter::default().with_key("port")),
+            [100, 105, 120]
+        );
+        let filter = LogFilter::default()
+            .with_key("port")
+            .min_level(LogLevel::Error)
+            .between(0, 110);
+        assert_eq!(kept(&filter), [105]);
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut stats = LogStats::default();
+        for entry in LogReader::new(LOG.as_bytes()) {
+            stats.add(&entry.unwrap());
+        }
+        assert_eq!(stats.total, 5);
+        assert_eq!(stats.count(LogLevel::Error), 2);
+        assert_eq!(stats.count(LogLevel::Info), 1);
+        assert_eq!(
+            stats.top_messages(2),
+            [("Failed to connect", 2), ("Cache miss", 1)]
+        );
+        assert_eq!(
+            stats.render(1),
+            "5 entries: DEBUG 1, INFO 1, WARNING 1, ERROR 2\n     2  Failed to connect\n"
+        );
+    }
+
+    #[test]
+    fn test_process_log_outputs() {
+        let mut out = Vec::new();
+        let filter = LogFilter::default().min_level(LogLevel::Error);
+        let summary =
+            process_log(LOG.as_bytes(), &filter, OutputFormat::JsonLines, &mut out).unwrap();
+        let line = r#"{"context":{"host":"db","port":"5432"},"level":"ERROR","message":"Failed to connect","timestamp":105}"#;
+        assert_eq!(String::from_utf8(out).unwrap().lines().next(), Some(line));
+        assert_eq!(summary.stats.total, 2);
+        assert_eq!(summary.errors.len(), 2);
+        assert!(summary.report(0).ends_with(
+            "2 malformed lines:\n  line 5: cannot parse \"garbage\"\n  \
+             line 8: cannot parse \"TRACE [140] Unknown level\"\n"
+        ));
+
+        let mut out = Vec::new();
+        let filter = LogFilter::default().between(110, 130);
+        process_log(LOG.as_bytes(), &filter, OutputFormat::Table, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\
+LEVEL    TIMESTAMP  MESSAGE            CONTEXT
+WARNING  110        Slow query         ms=912
+ERROR    120        Failed to connect  host=db, port=5432
+DEBUG    130        Cache miss
+"
+        );
+    }
+}
+
+mod exercise4 {
+    use super::*;
+
+    // Signed off by alice and bob
+    fn reviewed(draft: Document<Draft>) -> Document<UnderReview<Succ<Succ<Zero>>>> {
+        let doc = draft.submit_for_review();
+        doc.approve("alice").unwrap().approve("bob").unwrap()
+    }
+
+    #[test]
+    fn test_publish_keeps_content() {
+        let doc = reviewed(Document::new("Draft content".to_string())).publish();
+        assert_eq!(doc.content(), "Draft content");
+        assert_eq!(doc.reviewers(), ["alice", "bob"]);
+    }
+
+    #[test]
+    fn test_reject_returns_to_draft() {
+        let doc = Document::new("Needs work".to_string()).submit_for_review();
+        let doc = doc.approve("alice").unwrap();
+        let draft: Document<Draft> = doc.reject("bob", "too short");
+        assert_eq!(draft.content(), "Needs work");
+        assert!(draft.reviewers().is_empty());
+        // A rejected draft can be resubmitted, and needs every sign-off again
+        let doc = draft.submit_for_review();
+        assert_eq!((doc.content(), doc.approvals()), ("Needs work", 0));
+    }
+
+    #[test]
+    fn test_sign_offs_are_counted_once_per_reviewer() {
+        let doc = Document::new("x".to_string()).submit_for_review();
+        let doc = doc.approve("alice").unwrap();
+        assert_eq!(doc.approvals(), 1);
+        let doc = doc.approve("alice").unwrap_err();
+        assert_eq!((doc.approvals(), doc.reviewers().len()), (1, 1));
+        let doc = doc.approve("bob").unwrap();
+        assert_eq!(doc.approvals(), REQUIRED_APPROVALS);
+        // Further sign-offs are allowed and still publishable
+        assert_eq!(doc.approve("carol").unwrap().publish().reviewers().len(), 3);
+    }
+
+    #[test]
+    fn test_history_follows_the_document() {
+        let mut draft = Document::new("v1".to_string());
+        draft.edit("v2".to_string());
+        let mut draft = draft.submit_for_review().reject("alice", "typo");
+        draft.edit("v3".to_string());
+        assert_eq!(draft.revision(), 3);
+        let doc = reviewed(draft).publish().archive();
+        assert_eq!(doc.content(), "v3");
+        assert_eq!(doc.revision(), 3);
+        assert_eq!(
+            doc.history(),
+            [
+                Event::Created,
+                Event::Edited {
+                    previous: "v1".to_string()
+                },
+                Event::Submitted,
+                Event::Rejected {
+                    reviewer: "alice".to_string(),
+                    reason: "typo".to_string()
+                },
+                Event::Edited {
+                    previous: "v2".to_string()
+                },
+                Event::Submitted,
+                Event::Approved {
+                    reviewer: "alice".to_string()
+                },
+                Event::Approved {
+                    reviewer: "bob".to_string()
+                },
+                Event::Published,
+                Event::Archived,
+            ]
+        );
+        let archived_draft = Document::new("old".to_string()).archive();
+        assert_eq!(archived_draft.history().last(), Some(&Event::Archived));
+    }
+
+    #[test]
+    fn test_any_document_retypes_only_to_its_own_state() {
+        let stored = AnyDocument::from(reviewed(Document::new("text".to_string())));
+        assert_eq!(stored.record().reviewers, ["alice", "bob"]);
+        assert_eq!(
+            stored.describe(),
+            "a document under review with 2 sign-offs"
+        );
+
+        let error = Document::<UnderReview<Succ<Zero>>>::try_from(stored.clone()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected a document under review with 1 sign-offs, \
+             found a document under review with 2 sign-offs"
+        );
+        assert_eq!(error.document, stored);
+        assert!(Document::<Draft>::try_from(stored.clone()).is_err());
+        let doc = Document::<UnderReview<Succ<Succ<Zero>>>>::try_from(stored).unwrap();
+        assert_eq!(doc.approvals(), 2);
+
+        let published = AnyDocument::from(doc.publish());
+        let doc = Document::<Published>::try_from(published).unwrap();
+        assert!(matches!(
+            AnyDocument::from(doc.archive()),
+            AnyDocument::Archived(_)
+        ));
+
+        // Records that no sequence of transitions could have produced
+        let record = |reviewers: &[&str]| Record {
+            content: String::new(),
+            reviewers: reviewers.iter().map(|r| r.to_string()).collect(),
+            history: Vec::new(),
+        };
+        assert!(Document::<Published>::try_from(AnyDocument::Published(record(&["a"]))).is_err());
+        let twice = AnyDocument::UnderReview(record(&["a", "a"]));
+        assert!(Document::<UnderReview<Succ<Succ<Zero>>>>::try_from(twice).is_err());
+        assert!(Document::<Draft>::try_from(AnyDocument::Draft(record(&["a"]))).is_err());
+        assert!(Document::<Draft>::try_from(AnyDocument::Draft(record(&[]))).is_ok());
+    }
+}
+
+mod exercise5 {
+    use super::*;
+
+    const VALID: &str = r#"
+        database_url=postgres://localhost/mydb
+        port=8080
+        features=["auth", "api", "web"]
+    "#;
+
+    #[test]
+    fn test_parses_all_fields() {
+        let config = Configuration::from_string(VALID).unwrap();
+        assert_eq!(config.database_url, "postgres://localhost/mydb");
+        assert_eq!(config.port, 8080);
+        assert_eq!(config.api_key, None);
+        assert_eq!(config.features, ["auth", "api", "web"]);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_port_reports_line() {
+        let result = Configuration::from_string("database_url=x\nport=eighty");
+        assert!(matches!(
+            result,
+            Err(ConfigError::ParseError { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_reports_missing_database_url() {
+        let config = Configuration::from_string("port=8080").unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::MissingField(_))
+        ));
+    }
+
+    #[test]
+    fn test_io_errors_convert() {
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+        assert!(matches!(ConfigError::from(io), ConfigError::IoError(_)));
+    }
+
+    const TOML: &str = r#"
+# app.toml
+database_url = "postgres://db.internal/app"   # primary
+port = 9_000
+features = ["auth", "a \"quoted\" name", ]
+"#;
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_the_problem() {
+        let Err(error) = Configuration::from_string("database_url=x\nport = eighty") else {
+            panic!("expected a parse error");
+        };
+        let ConfigError::ParseError {
+            line,
+            column,
+            ref snippet,
+            ..
+        } = error
+        else {
+            panic!("expected a parse error, got {:?}", error);
+        };
+        assert_eq!((line, column), (2, 8));
+        assert_eq!(
+            snippet,
+            " --> <string>:2:8\n  |\n2 | port = eighty\n  |        ^^^^^^"
+        );
+        assert_eq!(
+            error.to_string(),
+            format!("line 2, column 8: Invalid port number\n{}", snippet)
+        );
+
+        // An unknown field is blamed on its key
+        let Err(ConfigError::ParseError {
+            column, snippet, ..
+        }) = Configuration::from_string("  colour = blue")
+        else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(column, 3);
+        assert!(snippet.ends_with("\n  |   ^^^^^^"), "{}", snippet);
+    }
+
+    #[test]
+    fn test_toml_subset() {
+        let config = ConfigLoader::new()
+            .file("app.toml", TOML, Format::Toml)
+            .load()
+            .unwrap();
+        assert_eq!(config.database_url, "postgres://db.internal/app");
+        assert_eq!(config.port, 9000);
+        assert_eq!(config.features, ["auth", "a \"quoted\" name"]);
+
+        let column_of = |line: &str| match ConfigLoader::new()
+            .file("t.toml", line, Format::Toml)
+            .load()
+        {
+            Err(ConfigError::ParseError { column, .. }) => column,
+            other => panic!("{:?} should not parse, got {:?}", line, other.map(|_| ())),
+        };
+        assert_eq!(column_of("port = \"8080\""), 8);
+        assert_eq!(column_of("port = 70000"), 8);
+        assert_eq!(column_of("database_url = postgres://x"), 16);
+        assert_eq!(column_of("features = \"web\""), 12);
+        assert_eq!(column_of("features = [\"a\" \"b\"]"), 17);
+        assert_eq!(column_of("api_key = \"unterminated"), 11);
+        assert_eq!(column_of("api_key = \"a\" extra"), 15);
+        assert_eq!(column_of("[server]"), 1);
+        assert_eq!(column_of("nokey"), 1);
+    }
+
+    #[test]
+    fn test_layers_record_origins() {
+        let config = ConfigLoader::new()
+            .file(
+                "base.ini",
+                "database_url=sqlite://base.db\nport=9000",
+                Format::Ini,
+            )
+            .file("app.toml", TOML, Format::Toml)
+            .env(env(&[
+                ("APP_PORT", "9100"),
+                ("HOME", "/root"),
+                ("APP_API_KEY", "k"),
+            ]))
+            .load()
+            .unwrap();
+        assert_eq!(config.port, 9100);
+        assert_eq!(config.api_key.as_deref(), Some("k"));
+        assert_eq!(
+            config.origin("port"),
+            Some(&Origin::Env("APP_PORT".to_string()))
+        );
+        assert_eq!(
+            config.origin("database_url"),
+            Some(&Origin::File {
+                name: "app.toml".to_string(),
+                line: 3
+            })
+        );
+
+        let config = ConfigLoader::new()
+            .file("base.ini", "database_url=mysql://db", Format::Ini)
+            .load()
+            .unwrap();
+        assert_eq!(
+            (config.port, config.features.clone()),
+            (8080, vec!["web".to_string()])
+        );
+        assert_eq!(config.origin("port"), Some(&Origin::Default));
+        assert_eq!(config.origin("api_key"), None);
+
+        let result = ConfigLoader::new()
+            .file("base.ini", "database_url=mysql://db", Format::Ini)
+            .env(env(&[("APP_COLOUR", "blue")]))
+            .load();
+        assert!(matches!(result, Err(ConfigError::EnvError { name, .. }) if name == "APP_COLOUR"));
+    }
+
+    #[test]
+    fn test_validation_constraints() {
+        let message = |ini: &str, vars: &[(&str, &str)]| match ConfigLoader::new()
+            .file("c.ini", ini, Format::Ini)
+            .env(env(vars))
+            .load()
+        {
+            Err(ConfigError::ValidationError(message)) => message,
+            other => panic!("expected a validation error, got {:?}", other.map(|_| ())),
+        };
+        let url = "database_url=postgres://db\n";
+        assert_eq!(
+            message(url, &[("APP_PORT", "80")]),
+            "port 80 (from $APP_PORT) must be between 1024 and 65535"
+        );
+        assert!(message("database_url=http://db", &[]).contains("(from c.ini:1)"));
+        assert!(message(&format!("{}features=[]", url), &[]).starts_with("features (from c.ini:2)"));
+        assert!(matches!(
+            ConfigLoader::new().load(),
+            Err(ConfigError::MissingField(field)) if field == "database_url"
+        ));
+    }
+
+    #[test]
+    fn test_display_and_source() {
+        use std::error::Error;
+
+        let result = ConfigLoader::new().read_file("/nonexistent/app.toml");
+        let Err(error) = result else {
+            panic!("expected an I/O error");
+        };
+        assert!(matches!(error, ConfigError::IoError(_)));
+        assert!(error.source().is_some());
+        assert!(error.to_string().starts_with("cannot read configuration: "));
+
+        let error = ConfigError::MissingField("port".to_string());
+        assert_eq!(error.to_string(), "missing required field `port`");
+        assert!(error.source().is_none());
+    }
+}
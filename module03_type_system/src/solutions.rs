@@ -0,0 +1,2036 @@
+// Module 3: Reference Solutions
+// Worked answers to the exercises in problems.rs, compiled only with the
+// `solutions` feature. Try solving the exercises yourself first!
+// The types and signatures mirror problems.rs, so the hidden grading suite
+// runs unchanged against these answers (see the tests at the end).
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::mem;
+use std::ops::{Range, RangeInclusive};
+use std::path::Path;
+use std::str::FromStr;
+
+pub fn run_exercises() {
+    println!("Module 3 Reference Solutions - Type System Deep Dive");
+    println!("===============================================\n");
+
+    exercise1();
+    exercise2();
+    exercise3();
+    exercise4();
+    exercise5();
+}
+
+// Solution entry points in order, used by `rustpath run <module> --solution`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Exercise 1 Solution: Memory Layout Optimization
+
+use rust_basics::layout::Inspect;
+
+rust_basics::inspect_layout! {
+    #[derive(Debug)]
+    #[repr(C)]
+    struct PersonBad {
+        active: bool,
+        name: String,
+        age: u32,
+        email: String,
+    }
+
+    // Largest fields first, so the small ones share the trailing padding
+    #[derive(Debug)]
+    #[repr(C)]
+    struct PersonGood {
+        name: String,
+        email: String,
+        age: u32,
+        active: bool,
+    }
+}
+
+fn analyze_layouts() {
+    print!("{}", PersonBad::layout().render());
+    println!();
+    print!("{}", PersonGood::layout().render());
+    println!();
+    println!(
+        "Memory saved: {} bytes",
+        mem::size_of::<PersonBad>() - mem::size_of::<PersonGood>()
+    );
+}
+
+fn exercise1() {
+    println!("Exercise 1: Memory Layout Optimization");
+    println!("----------------------------------");
+
+    analyze_layouts();
+}
+
+// Exercise 2 Solution: Tagged Union Implementation
+
+#[derive(Debug, Clone, PartialEq)]
+enum JsonValue {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(HashMap<String, JsonValue>),
+}
+
+// Where and why parsing failed. Lines and columns count from 1, columns in
+// characters rather than bytes.
+#[derive(Debug, Clone, PartialEq)]
+struct JsonError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+// A TryFrom conversion out of the wrong variant
+#[derive(Debug, Clone, PartialEq)]
+struct JsonTypeError {
+    expected: &'static str,
+    found: &'static str,
+}
+
+impl fmt::Display for JsonTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected {}, found {}", self.expected, self.found)
+    }
+}
+
+// Deeper nesting is rejected rather than risking a stack overflow
+const MAX_DEPTH: usize = 128;
+
+impl JsonValue {
+    fn from_bool(b: bool) -> Self {
+        JsonValue::Boolean(b)
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            JsonValue::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            JsonValue::Null => "null",
+            JsonValue::Boolean(_) => "boolean",
+            JsonValue::Number(_) => "number",
+            JsonValue::String(_) => "string",
+            JsonValue::Array(_) => "array",
+            JsonValue::Object(_) => "object",
+        }
+    }
+
+    // RFC 8259: exactly one value, with optional whitespace around it
+    fn parse(text: &str) -> Result<JsonValue, JsonError> {
+        let mut parser = JsonParser {
+            text,
+            pos: 0,
+            depth: 0,
+        };
+        parser.skip_whitespace();
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos < text.len() {
+            return Err(parser.error("unexpected characters after the value"));
+        }
+        Ok(value)
+    }
+
+    // No whitespace at all; object keys in sorted order, so equal values
+    // always serialize the same way
+    fn to_compact(&self) -> String {
+        match self {
+            JsonValue::Null => "null".to_string(),
+            JsonValue::Boolean(b) => b.to_string(),
+            // JSON cannot express NaN or the infinities
+            JsonValue::Number(n) if !n.is_finite() => "null".to_string(),
+            JsonValue::Number(n) => n.to_string(),
+            JsonValue::String(s) => quote(s),
+            JsonValue::Array(items) => {
+                let items: Vec<String> = items.iter().map(JsonValue::to_compact).collect();
+                format!("[{}]", items.join(","))
+            }
+            JsonValue::Object(map) => {
+                let members: Vec<String> = sorted(map)
+                    .into_iter()
+                    .map(|(key, value)| format!("{}:{}", quote(key), value.to_compact()))
+                    .collect();
+                format!("{{{}}}", members.join(","))
+            }
+        }
+    }
+
+    // One element or member per line, nested `indent + 2` spaces deeper
+    fn pretty_print(&self, indent: usize) -> String {
+        let pad = |width: usize| " ".repeat(width);
+        match self {
+            JsonValue::Array(items) if !items.is_empty() => {
+                let items: Vec<String> = items
+                    .iter()
+                    .map(|v| format!("{}{}", pad(indent + 2), v.pretty_print(indent + 2)))
+                    .collect();
+                format!("[\n{}\n{}]", items.join(",\n"), pad(indent))
+            }
+            JsonValue::Object(map) if !map.is_empty() => {
+                let members: Vec<String> = sorted(map)
+                    .into_iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "{}{}: {}",
+                            pad(indent + 2),
+                            quote(key),
+                            value.pretty_print(indent + 2)
+                        )
+                    })
+                    .collect();
+                format!("{{\n{}\n{}}}", members.join(",\n"), pad(indent))
+            }
+            // Scalars and empty containers look the same either way
+            _ => self.to_compact(),
+        }
+    }
+
+    // Follows a path such as `a.b[2]`: `name` (or `.name` after the first
+    // step) selects an object member, `[n]` an array element and `["name"]`
+    // a member whose name contains `.` or `[`. The empty path is the value
+    // itself. None if anything along the way is missing or malformed.
+    fn get_path(&self, path: &str) -> Option<&JsonValue> {
+        fn member<'a>(value: &'a JsonValue, key: &str) -> Option<&'a JsonValue> {
+            match value {
+                JsonValue::Object(map) => map.get(key),
+                _ => None,
+            }
+        }
+        let mut current = self;
+        let mut rest = path;
+        let mut first = true;
+        while !rest.is_empty() {
+            if let Some(inner) = rest.strip_prefix('[') {
+                let (index, after) = inner.split_once(']')?;
+                current = match index.strip_prefix('"').and_then(|k| k.strip_suffix('"')) {
+                    Some(key) => member(current, key)?,
+                    None => match current {
+                        JsonValue::Array(items) => items.get(index.parse::<usize>().ok()?)?,
+                        _ => return None,
+                    },
+                };
+                rest = after;
+            } else {
+                let step = if first { rest } else { rest.strip_prefix('.')? };
+                let end = step.find(['.', '[']).unwrap_or(step.len());
+                if end == 0 {
+                    return None;
+                }
+                current = member(current, &step[..end])?;
+                rest = &step[end..];
+            }
+            first = false;
+        }
+        Some(current)
+    }
+}
+
+fn sorted(map: &HashMap<String, JsonValue>) -> Vec<(&String, &JsonValue)> {
+    let mut members: Vec<_> = map.iter().collect();
+    members.sort_by(|a, b| a.0.cmp(b.0));
+    members
+}
+
+// A JSON string literal. Only `"`, `\` and control characters must be
+// escaped; everything else, non-ASCII included, is written as UTF-8.
+fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c < '\u{20}' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// A recursive descent parser: one method per grammar rule, each starting at
+// `pos` and leaving it just past what it read
+struct JsonParser<'a> {
+    text: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl JsonParser<'_> {
+    fn error(&self, message: impl Into<String>) -> JsonError {
+        self.error_at(self.pos, message)
+    }
+
+    fn error_at(&self, pos: usize, message: impl Into<String>) -> JsonError {
+        let before = &self.text[..pos];
+        JsonError {
+            line: before.matches('\n').count() + 1,
+            column: before.rsplit('\n').next().unwrap_or("").chars().count() + 1,
+            message: message.into(),
+        }
+    }
+
+    // An error naming what was found instead of `expected`
+    fn unexpected(&self, expected: &str) -> JsonError {
+        match self.peek() {
+            Some(c) => self.error(format!("expected {}, found {:?}", expected, c)),
+            None => self.error(format!("expected {}, found the end of the input", expected)),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += c.len_utf8();
+        }
+        found
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), JsonError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("{:?}", c)))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn value(&mut self) -> Result<JsonValue, JsonError> {
+        match self.peek() {
+            Some('n') => self.literal("null", JsonValue::Null),
+            Some('t') => self.literal("true", JsonValue::Boolean(true)),
+            Some('f') => self.literal("false", JsonValue::Boolean(false)),
+            Some('"') => self.string().map(JsonValue::String),
+            Some('[') => self.array(),
+            Some('{') => self.object(),
+            Some('-' | '0'..='9') => self.number(),
+            _ => Err(self.unexpected("a value")),
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: JsonValue) -> Result<JsonValue, JsonError> {
+        if self.text[self.pos..].starts_with(word) {
+            self.pos += word.len();
+            Ok(value)
+        } else {
+            Err(self.error(format!("invalid literal, expected `{}`", word)))
+        }
+    }
+
+    fn digits(&mut self) -> bool {
+        let start = self.pos;
+        while matches!(self.peek(), Some('0'..='9')) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+
+    // -?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?, stricter than what
+    // f64's FromStr accepts: no leading `+`, leading zeros, `.5` or `inf`
+    fn number(&mut self) -> Result<JsonValue, JsonError> {
+        let start = self.pos;
+        self.eat('-');
+        if !self.eat('0') && !self.digits() {
+            return Err(self.unexpected("a digit"));
+        }
+        if self.eat('.') && !self.digits() {
+            return Err(self.unexpected("a digit after the decimal point"));
+        }
+        if self.eat('e') || self.eat('E') {
+            let _ = self.eat('+') || self.eat('-');
+            if !self.digits() {
+                return Err(self.unexpected("a digit in the exponent"));
+            }
+        }
+        let n: f64 = self.text[start..self.pos]
+            .parse()
+            .map_err(|_| self.error_at(start, "invalid number"))?;
+        // Such as 1e400, which f64 rounds to infinity
+        if n.is_infinite() {
+            return Err(self.error_at(start, "number out of range"));
+        }
+        Ok(JsonValue::Number(n))
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            let start = self.pos;
+            let Some(c) = self.peek() else {
+                return Err(self.error("unterminated string"));
+            };
+            self.pos += c.len_utf8();
+            match c {
+                '"' => return Ok(out),
+                '\\' => {
+                    let escaped = match self.peek() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            self.pos += 1;
+                            out.push(self.unicode_escape(start)?);
+                            continue;
+                        }
+                        _ => return Err(self.error_at(start, "invalid escape sequence")),
+                    };
+                    self.pos += 1;
+                    out.push(escaped);
+                }
+                c if c < '\u{20}' => {
+                    return Err(self.error_at(start, "unescaped control character in string"))
+                }
+                c => out.push(c),
+            }
+        }
+    }
+
+    // Just after `\u`: four hex digits, which may be the first half of a
+    // UTF-16 surrogate pair that a second `\uXXXX` must complete
+    fn unicode_escape(&mut self, start: usize) -> Result<char, JsonError> {
+        let high = self.hex4()?;
+        let code = match high {
+            0xD800..=0xDBFF => {
+                if !self.text[self.pos..].starts_with("\\u") {
+                    return Err(self.error_at(start, "unpaired surrogate in \\u escape"));
+                }
+                self.pos += 2;
+                let low = self.hex4()?;
+                if !(0xDC00..=0xDFFF).contains(&low) {
+                    return Err(self.error_at(start, "unpaired surrogate in \\u escape"));
+                }
+                0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+            }
+            0xDC00..=0xDFFF => return Err(self.error_at(start, "unpaired surrogate in \\u escape")),
+            code => code,
+        };
+        Ok(char::from_u32(code).expect("surrogates are handled above"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, JsonError> {
+        let digits = self
+            .text
+            .get(self.pos..self.pos + 4)
+            .filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("expected four hex digits"))?;
+        self.pos += 4;
+        Ok(u32::from_str_radix(digits, 16).expect("checked to be hex digits"))
+    }
+
+    fn enter(&mut self) -> Result<(), JsonError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(self.error(format!("nested more than {} levels deep", MAX_DEPTH)));
+        }
+        Ok(())
+    }
+
+    fn array(&mut self) -> Result<JsonValue, JsonError> {
+        self.enter()?;
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if !self.eat(']') {
+            loop {
+                self.skip_whitespace();
+                items.push(self.value()?);
+                self.skip_whitespace();
+                if self.eat(']') {
+                    break;
+                }
+                if !self.eat(',') {
+                    return Err(self.unexpected("',' or ']'"));
+                }
+            }
+        }
+        self.depth -= 1;
+        Ok(JsonValue::Array(items))
+    }
+
+    // A repeated key keeps its last value
+    fn object(&mut self) -> Result<JsonValue, JsonError> {
+        self.enter()?;
+        self.expect('{')?;
+        let mut map = HashMap::new();
+        self.skip_whitespace();
+        if !self.eat('}') {
+            loop {
+                self.skip_whitespace();
+                if self.peek() != Some('"') {
+                    return Err(self.unexpected("a string key"));
+                }
+                let key = self.string()?;
+                self.skip_whitespace();
+                self.expect(':')?;
+                self.skip_whitespace();
+                map.insert(key, self.value()?);
+                self.skip_whitespace();
+                if self.eat('}') {
+                    break;
+                }
+                if !self.eat(',') {
+                    return Err(self.unexpected("',' or '}'"));
+                }
+            }
+        }
+        self.depth -= 1;
+        Ok(JsonValue::Object(map))
+    }
+}
+
+impl FromStr for JsonValue {
+    type Err = JsonError;
+
+    fn from_str(text: &str) -> Result<Self, JsonError> {
+        JsonValue::parse(text)
+    }
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_compact())
+    }
+}
+
+impl From<bool> for JsonValue {
+    fn from(b: bool) -> Self {
+        JsonValue::Boolean(b)
+    }
+}
+
+impl From<f64> for JsonValue {
+    fn from(n: f64) -> Self {
+        JsonValue::Number(n)
+    }
+}
+
+// Every i32 fits in an f64 exactly
+impl From<i32> for JsonValue {
+    fn from(n: i32) -> Self {
+        JsonValue::Number(n.into())
+    }
+}
+
+impl From<&str> for JsonValue {
+    fn from(s: &str) -> Self {
+        JsonValue::String(s.to_string())
+    }
+}
+
+impl From<String> for JsonValue {
+    fn from(s: String) -> Self {
+        JsonValue::String(s)
+    }
+}
+
+impl<T: Into<JsonValue>> From<Vec<T>> for JsonValue {
+    fn from(items: Vec<T>) -> Self {
+        JsonValue::Array(items.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<JsonValue>> From<HashMap<String, T>> for JsonValue {
+    fn from(map: HashMap<String, T>) -> Self {
+        JsonValue::Object(map.into_iter().map(|(k, v)| (k, v.into())).collect())
+    }
+}
+
+impl<T: Into<JsonValue>> From<Option<T>> for JsonValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(JsonValue::Null, Into::into)
+    }
+}
+
+impl TryFrom<JsonValue> for bool {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        match value {
+            JsonValue::Boolean(b) => Ok(b),
+            other => Err(JsonTypeError {
+                expected: "boolean",
+                found: other.type_name(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<JsonValue> for f64 {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        match value {
+            JsonValue::Number(n) => Ok(n),
+            other => Err(JsonTypeError {
+                expected: "number",
+                found: other.type_name(),
+            }),
+        }
+    }
+}
+
+// Only for whole numbers that an f64 holds exactly (|n| <= 2^53)
+impl TryFrom<JsonValue> for i64 {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        const EXACT: f64 = (1u64 << 53) as f64;
+        match value {
+            JsonValue::Number(n) if n.fract() == 0.0 && n.abs() <= EXACT => Ok(n as i64),
+            other => Err(JsonTypeError {
+                expected: "integer",
+                found: other.type_name(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<JsonValue> for String {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        match value {
+            JsonValue::String(s) => Ok(s),
+            other => Err(JsonTypeError {
+                expected: "string",
+                found: other.type_name(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<JsonValue> for Vec<JsonValue> {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        match value {
+            JsonValue::Array(items) => Ok(items),
+            other => Err(JsonTypeError {
+                expected: "array",
+                found: other.type_name(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<JsonValue> for HashMap<String, JsonValue> {
+    type Error = JsonTypeError;
+
+    fn try_from(value: JsonValue) -> Result<Self, JsonTypeError> {
+        match value {
+            JsonValue::Object(map) => Ok(map),
+            other => Err(JsonTypeError {
+                expected: "object",
+                found: other.type_name(),
+            }),
+        }
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Tagged Union Implementation");
+    println!("-----------------------------------");
+
+    let json = JsonValue::Object({
+        let mut map = HashMap::new();
+        map.insert("name".to_string(), JsonValue::String("Alice".to_string()));
+        map.insert("age".to_string(), JsonValue::Number(30.0));
+        map.insert("is_student".to_string(), JsonValue::from_bool(true));
+        map
+    });
+    println!("JSON:\n{}", json.pretty_print(2));
+
+    let text = r#"{
+        "user": {"name": "Zoë \"Z\" Smith", "emoji": "🦀"},
+        "scores": [12, 7.5, -3e2],
+        "tags": []
+    }"#;
+    let doc: JsonValue = text.parse().unwrap();
+    println!("Compact: {}", doc);
+    for path in ["user.name", "user.emoji", "scores[2]", "scores[9]"] {
+        match doc.get_path(path) {
+            Some(value) => println!("{} = {}", path, value),
+            None => println!("{} is missing", path),
+        }
+    }
+    let score = doc.get_path("scores[0]").cloned().map(i64::try_from);
+    println!("scores[0] as i64: {:?}", score);
+
+    let broken = "{\n  \"a\": [1, 2,]\n}";
+    if let Err(error) = JsonValue::parse(broken) {
+        println!("Parsing {:?} fails at {}", broken, error);
+    }
+    let built = JsonValue::from(vec![Some("x"), None]);
+    println!("From a Vec<Option<&str>>: {}", built);
+}
+
+// Exercise 3 Solution: Advanced Pattern Matching
+
+// Declared from least to most severe, so the derived Ord ranks them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum LogLevel {
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
+
+impl LogLevel {
+    const ALL: [LogLevel; 4] = [
+        LogLevel::Debug,
+        LogLevel::Info,
+        LogLevel::Warning,
+        LogLevel::Error,
+    ];
+
+    // As written in a log line
+    fn name(self) -> &'static str {
+        match self {
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warning => "WARNING",
+            LogLevel::Error => "ERROR",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct LogEntry {
+    level: LogLevel,
+    timestamp: u64,
+    message: String,
+    context: HashMap<String, String>,
+}
+
+// LEVEL [timestamp] message {key1=value1, key2=value2}
+// The message runs up to the context block, so it may contain spaces
+fn parse_log_entry(line: &str) -> Option<LogEntry> {
+    let (level, rest) = line.split_once(' ')?;
+    let level = match level {
+        "DEBUG" => LogLevel::Debug,
+        "INFO" => LogLevel::Info,
+        "WARNING" => LogLevel::Warning,
+        "ERROR" => LogLevel::Error,
+        _ => return None,
+    };
+
+    let (timestamp, rest) = rest.strip_prefix('[')?.split_once(']')?;
+    let timestamp = timestamp.parse().ok()?;
+
+    let (message, context) = match rest.find('{') {
+        Some(start) => (&rest[..start], rest[start..].strip_suffix('}')?),
+        None => (rest, "{"),
+    };
+
+    let context = context[1..]
+        .split(',')
+        .filter_map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            Some((key.trim().to_string(), value.trim().to_string()))
+        })
+        .collect();
+
+    Some(LogEntry {
+        level,
+        timestamp,
+        message: message.trim().to_string(),
+        context,
+    })
+}
+
+impl LogEntry {
+    // One line of JSON, built with exercise 2's JsonValue. Timestamps are
+    // exact as JSON numbers up to 2^53, far past any Unix time.
+    fn to_json_line(&self) -> String {
+        let mut object = HashMap::new();
+        object.insert("level".to_string(), JsonValue::from(self.level.name()));
+        object.insert(
+            "timestamp".to_string(),
+            JsonValue::Number(self.timestamp as f64),
+        );
+        object.insert(
+            "message".to_string(),
+            JsonValue::from(self.message.as_str()),
+        );
+        object.insert("context".to_string(), JsonValue::from(self.context.clone()));
+        JsonValue::Object(object).to_compact()
+    }
+}
+
+// Which entries to keep. LogFilter::default() keeps everything; each
+// builder method adds a condition that must also hold.
+#[derive(Debug, Clone, Default)]
+struct LogFilter {
+    min_level: Option<LogLevel>,
+    since: Option<u64>,
+    until: Option<u64>,
+    context_key: Option<String>,
+}
+
+impl LogFilter {
+    fn min_level(mut self, level: LogLevel) -> Self {
+        self.min_level = Some(level);
+        self
+    }
+
+    // Timestamps from `since` to `until`, both included
+    fn between(mut self, since: u64, until: u64) -> Self {
+        self.since = Some(since);
+        self.until = Some(until);
+        self
+    }
+
+    fn with_key(mut self, key: &str) -> Self {
+        self.context_key = Some(key.to_string());
+        self
+    }
+
+    fn matches(&self, entry: &LogEntry) -> bool {
+        self.min_level.is_none_or(|level| entry.level >= level)
+            && self.since.is_none_or(|since| entry.timestamp >= since)
+            && self.until.is_none_or(|until| entry.timestamp <= until)
+            && self
+                .context_key
+                .as_ref()
+                .is_none_or(|key| entry.context.contains_key(key))
+    }
+}
+
+// A line parse_log_entry rejected, numbered from 1
+#[derive(Debug, Clone, PartialEq)]
+struct MalformedLine {
+    line_number: usize,
+    line: String,
+}
+
+impl fmt::Display for MalformedLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: cannot parse {:?}", self.line_number, self.line)
+    }
+}
+
+// Parses a log one line at a time, so the whole file is never in memory.
+// Blank lines are skipped. Malformed ones are skipped too, but kept in
+// `errors` for a report at the end; only I/O errors reach the items.
+struct LogReader<R> {
+    lines: io::Lines<R>,
+    line_number: usize,
+    errors: Vec<MalformedLine>,
+}
+
+impl<R: BufRead> LogReader<R> {
+    fn new(reader: R) -> Self {
+        LogReader {
+            lines: reader.lines(),
+            line_number: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    fn errors(&self) -> &[MalformedLine] {
+        &self.errors
+    }
+
+    fn into_errors(self) -> Vec<MalformedLine> {
+        self.errors
+    }
+}
+
+impl<R: BufRead> Iterator for LogReader<R> {
+    type Item = io::Result<LogEntry>;
+
+    fn next(&mut self) -> Option<io::Result<LogEntry>> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(error) => return Some(Err(error)),
+            };
+            self.line_number += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match parse_log_entry(&line) {
+                Some(entry) => return Some(Ok(entry)),
+                None => self.errors.push(MalformedLine {
+                    line_number: self.line_number,
+                    line,
+                }),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct LogStats {
+    total: usize,
+    per_level: HashMap<LogLevel, usize>,
+    messages: HashMap<String, usize>,
+}
+
+impl LogStats {
+    fn add(&mut self, entry: &LogEntry) {
+        self.total += 1;
+        *self.per_level.entry(entry.level).or_default() += 1;
+        *self.messages.entry(entry.message.clone()).or_default() += 1;
+    }
+
+    fn count(&self, level: LogLevel) -> usize {
+        self.per_level.get(&level).copied().unwrap_or(0)
+    }
+
+    // The `n` most frequent messages, most frequent first and ties in
+    // alphabetical order
+    fn top_messages(&self, n: usize) -> Vec<(&str, usize)> {
+        let mut messages: Vec<(&str, usize)> = self
+            .messages
+            .iter()
+            .map(|(message, &count)| (message.as_str(), count))
+            .collect();
+        messages.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        messages.truncate(n);
+        messages
+    }
+
+    fn render(&self, top: usize) -> String {
+        let levels: Vec<String> = LogLevel::ALL
+            .iter()
+            .map(|&level| format!("{} {}", level.name(), self.count(level)))
+            .collect();
+        let mut out = format!("{} entries: {}\n", self.total, levels.join(", "));
+        for (message, count) in self.top_messages(top) {
+            out.push_str(&format!("{:>6}  {}\n", count, message));
+        }
+        out
+    }
+}
+
+// Columns as wide as their widest cell; context pairs sorted by key
+fn render_table(entries: &[LogEntry]) -> String {
+    let header = ["LEVEL", "TIMESTAMP", "MESSAGE", "CONTEXT"].map(String::from);
+    let rows: Vec<[String; 4]> = entries
+        .iter()
+        .map(|entry| {
+            let mut context: Vec<String> = entry
+                .context
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect();
+            context.sort();
+            [
+                entry.level.name().to_string(),
+                entry.timestamp.to_string(),
+                entry.message.clone(),
+                context.join(", "),
+            ]
+        })
+        .collect();
+
+    let mut widths = [0; 4];
+    for row in std::iter::once(&header).chain(&rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut out = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        out.push_str(cells.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    JsonLines,
+    Table,
+}
+
+// What process_log saw: statistics over the entries that passed the
+// filter, and every line it could not parse
+#[derive(Debug, Clone, Default, PartialEq)]
+struct LogSummary {
+    stats: LogStats,
+    errors: Vec<MalformedLine>,
+}
+
+impl LogSummary {
+    // The statistics, then the malformed lines, one per line
+    fn report(&self, top: usize) -> String {
+        let mut out = self.stats.render(top);
+        if !self.errors.is_empty() {
+            out.push_str(&format!("{} malformed lines:\n", self.errors.len()));
+            for error in &self.errors {
+                out.push_str(&format!("  {}\n", error));
+            }
+        }
+        out
+    }
+}
+
+// The whole pipeline: parse `input` line by line, keep what `filter`
+// matches and write it to `out`. JSON lines go out as they are parsed; a
+// table has to wait until its widest cell is known.
+fn process_log<R: BufRead, W: Write>(
+    input: R,
+    filter: &LogFilter,
+    format: OutputFormat,
+    out: &mut W,
+) -> io::Result<LogSummary> {
+    let mut reader = LogReader::new(input);
+    let mut stats = LogStats::default();
+    let mut table = Vec::new();
+    for entry in reader.by_ref() {
+        let entry = entry?;
+        if !filter.matches(&entry) {
+            continue;
+        }
+        stats.add(&entry);
+        match format {
+            OutputFormat::JsonLines => writeln!(out, "{}", entry.to_json_line())?,
+            OutputFormat::Table => table.push(entry),
+        }
+    }
+    if format == OutputFormat::Table {
+        out.write_all(render_table(&table).as_bytes())?;
+    }
+    Ok(LogSummary {
+        stats,
+        errors: reader.into_errors(),
+    })
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Advanced Pattern Matching");
+    println!("----------------------------------");
+
+    let log_lines = vec![
+        "ERROR [1621234567] Failed to connect {host=db.example.com, port=5432}",
+        "INFO [1621234568] User logged in {user_id=123, ip=192.168.1.1}",
+        "DEBUG [1621234569] Cache miss {key=user_123, cache=users}",
+    ];
+
+    for line in log_lines {
+        match parse_log_entry(line) {
+            Some(entry) => println!("Parsed: {:?}", entry),
+            None => println!("Failed to parse: {}", line),
+        }
+    }
+
+    let log = "\
+INFO [1621234560] Server started {port=8080}
+ERROR [1621234567] Failed to connect {host=db.example.com, port=5432}
+WARNING [1621234568] Slow query {ms=912}
+this line is not a log entry
+ERROR [1621234570] Failed to connect {host=db.example.com, port=5432}
+DEBUG [1621234571] Cache miss {key=user_123}
+";
+    let stdout = io::stdout();
+    println!("\nWARNING and up, as a table:");
+    let filter = LogFilter::default().min_level(LogLevel::Warning);
+    let summary = process_log(
+        log.as_bytes(),
+        &filter,
+        OutputFormat::Table,
+        &mut stdout.lock(),
+    );
+    print!("{}", summary.unwrap().report(2));
+
+    println!("\nEntries with a port, as JSON lines:");
+    let filter = LogFilter::default()
+        .with_key("port")
+        .between(1621234560, 1621234569);
+    let summary = process_log(
+        log.as_bytes(),
+        &filter,
+        OutputFormat::JsonLines,
+        &mut stdout.lock(),
+    );
+    print!("{}", summary.unwrap().report(0));
+}
+
+// Exercise 4 Solution: Type State Programming
+
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub struct Draft;
+
+// `N` counts the sign-offs so far: Zero, Succ<Zero>, Succ<Succ<Zero>>, ...
+#[derive(Debug)]
+pub struct UnderReview<N>(PhantomData<N>);
+
+#[derive(Debug)]
+pub struct Published;
+
+#[derive(Debug)]
+pub struct Archived;
+
+#[derive(Debug)]
+pub struct Zero;
+
+#[derive(Debug)]
+pub struct Succ<N>(PhantomData<N>);
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::Zero {}
+    impl<N: Sealed> Sealed for super::Succ<N> {}
+}
+
+// A number written as a type. Sealed, so that no type outside this module
+// can claim to be a count and forge its way to `publish`.
+pub trait Count: sealed::Sealed {
+    const VALUE: usize;
+}
+
+impl Count for Zero {
+    const VALUE: usize = 0;
+}
+
+impl<N: Count> Count for Succ<N> {
+    const VALUE: usize = N::VALUE + 1;
+}
+
+// `publish` is only implemented for UnderReview<Succ<Succ<N>>>
+pub const REQUIRED_APPROVALS: usize = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Created,
+    Edited { previous: String },
+    Submitted,
+    Approved { reviewer: String },
+    Rejected { reviewer: String, reason: String },
+    Published,
+    Archived,
+}
+
+// Everything a document holds, whatever its state
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub content: String,
+    // Who has signed off on the current review, in order
+    pub reviewers: Vec<String>,
+    pub history: Vec<Event>,
+}
+
+#[derive(Debug)]
+pub struct Document<State> {
+    record: Record,
+    state: PhantomData<State>,
+}
+
+impl<State> Document<State> {
+    // Only called with a record that suits `To`
+    fn into_state<To>(mut self, event: Event) -> Document<To> {
+        self.record.history.push(event);
+        Document {
+            record: self.record,
+            state: PhantomData,
+        }
+    }
+
+    pub fn content(&self) -> &str {
+        &self.record.content
+    }
+
+    pub fn reviewers(&self) -> &[String] {
+        &self.record.reviewers
+    }
+
+    pub fn history(&self) -> &[Event] {
+        &self.record.history
+    }
+
+    // 1 for the original content, plus one per edit
+    pub fn revision(&self) -> usize {
+        let edits = self.record.history.iter();
+        1 + edits.filter(|e| matches!(e, Event::Edited { .. })).count()
+    }
+}
+
+impl Document<Draft> {
+    pub fn new(content: String) -> Self {
+        Document {
+            record: Record {
+                content,
+                reviewers: Vec::new(),
+                history: vec![Event::Created],
+            },
+            state: PhantomData,
+        }
+    }
+
+    // The old content is kept in the history
+    pub fn edit(&mut self, content: String) {
+        let previous = mem::replace(&mut self.record.content, content);
+        self.record.history.push(Event::Edited { previous });
+    }
+
+    pub fn submit_for_review(self) -> Document<UnderReview<Zero>> {
+        self.into_state(Event::Submitted)
+    }
+
+    pub fn archive(self) -> Document<Archived> {
+        self.into_state(Event::Archived)
+    }
+}
+
+impl<N: Count> Document<UnderReview<N>> {
+    pub fn approvals(&self) -> usize {
+        N::VALUE
+    }
+
+    // Gives the document back unchanged if `reviewer` already signed off,
+    // since a sign-off only counts once
+    pub fn approve(mut self, reviewer: &str) -> Result<Document<UnderReview<Succ<N>>>, Self> {
+        if self.record.reviewers.iter().any(|r| r == reviewer) {
+            return Err(self);
+        }
+        self.record.reviewers.push(reviewer.to_string());
+        Ok(self.into_state(Event::Approved {
+            reviewer: reviewer.to_string(),
+        }))
+    }
+
+    // Back to a draft; the sign-offs so far no longer count
+    pub fn reject(mut self, reviewer: &str, reason: &str) -> Document<Draft> {
+        self.record.reviewers.clear();
+        self.into_state(Event::Rejected {
+            reviewer: reviewer.to_string(),
+            reason: reason.to_string(),
+        })
+    }
+}
+
+// At least two sign-offs
+impl<N: Count> Document<UnderReview<Succ<Succ<N>>>> {
+    pub fn publish(self) -> Document<Published> {
+        self.into_state(Event::Published)
+    }
+}
+
+impl Document<Published> {
+    pub fn archive(self) -> Document<Archived> {
+        self.into_state(Event::Archived)
+    }
+}
+
+// The state as a value rather than a type, for storing documents and
+// loading them back. Converting back to a Document checks the record
+// against the state it claims.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyDocument {
+    Draft(Record),
+    UnderReview(Record),
+    Published(Record),
+    Archived(Record),
+}
+
+impl AnyDocument {
+    pub fn record(&self) -> &Record {
+        match self {
+            AnyDocument::Draft(record)
+            | AnyDocument::UnderReview(record)
+            | AnyDocument::Published(record)
+            | AnyDocument::Archived(record) => record,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            AnyDocument::Draft(_) => "a draft".to_string(),
+            AnyDocument::UnderReview(record) => {
+                format!(
+                    "a document under review with {} sign-offs",
+                    record.reviewers.len()
+                )
+            }
+            AnyDocument::Published(_) => "a published document".to_string(),
+            AnyDocument::Archived(_) => "an archived document".to_string(),
+        }
+    }
+}
+
+impl From<Document<Draft>> for AnyDocument {
+    fn from(doc: Document<Draft>) -> Self {
+        AnyDocument::Draft(doc.record)
+    }
+}
+
+impl<N: Count> From<Document<UnderReview<N>>> for AnyDocument {
+    fn from(doc: Document<UnderReview<N>>) -> Self {
+        AnyDocument::UnderReview(doc.record)
+    }
+}
+
+impl From<Document<Published>> for AnyDocument {
+    fn from(doc: Document<Published>) -> Self {
+        AnyDocument::Published(doc.record)
+    }
+}
+
+impl From<Document<Archived>> for AnyDocument {
+    fn from(doc: Document<Archived>) -> Self {
+        AnyDocument::Archived(doc.record)
+    }
+}
+
+// A failed TryFrom, holding the document so that nothing is lost
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrongState {
+    pub expected: String,
+    pub document: AnyDocument,
+}
+
+impl fmt::Display for WrongState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected {}, found {}",
+            self.expected,
+            self.document.describe()
+        )
+    }
+}
+
+fn has_duplicates(reviewers: &[String]) -> bool {
+    reviewers
+        .iter()
+        .enumerate()
+        .any(|(i, reviewer)| reviewers[..i].contains(reviewer))
+}
+
+// The one place a record becomes a typed document without a transition
+fn retype<State>(
+    document: AnyDocument,
+    expected: String,
+    fits: impl FnOnce(&AnyDocument) -> bool,
+) -> Result<Document<State>, WrongState> {
+    if !fits(&document) {
+        return Err(WrongState { expected, document });
+    }
+    let (AnyDocument::Draft(record)
+    | AnyDocument::UnderReview(record)
+    | AnyDocument::Published(record)
+    | AnyDocument::Archived(record)) = document;
+    Ok(Document {
+        record,
+        state: PhantomData,
+    })
+}
+
+impl TryFrom<AnyDocument> for Document<Draft> {
+    type Error = WrongState;
+
+    fn try_from(document: AnyDocument) -> Result<Self, WrongState> {
+        retype(
+            document,
+            "a draft".to_string(),
+            |document| matches!(document, AnyDocument::Draft(r) if r.reviewers.is_empty()),
+        )
+    }
+}
+
+// Only with exactly N distinct sign-offs
+impl<N: Count> TryFrom<AnyDocument> for Document<UnderReview<N>> {
+    type Error = WrongState;
+
+    fn try_from(document: AnyDocument) -> Result<Self, WrongState> {
+        let expected = format!("a document under review with {} sign-offs", N::VALUE);
+        retype(document, expected, |document| {
+            matches!(document, AnyDocument::UnderReview(r)
+                if r.reviewers.len() == N::VALUE && !has_duplicates(&r.reviewers))
+        })
+    }
+}
+
+impl TryFrom<AnyDocument> for Document<Published> {
+    type Error = WrongState;
+
+    fn try_from(document: AnyDocument) -> Result<Self, WrongState> {
+        retype(document, "a published document".to_string(), |document| {
+            matches!(document, AnyDocument::Published(r)
+                if r.reviewers.len() >= REQUIRED_APPROVALS && !has_duplicates(&r.reviewers))
+        })
+    }
+}
+
+impl TryFrom<AnyDocument> for Document<Archived> {
+    type Error = WrongState;
+
+    fn try_from(document: AnyDocument) -> Result<Self, WrongState> {
+        retype(document, "an archived document".to_string(), |document| {
+            matches!(document, AnyDocument::Archived(_))
+        })
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Type State Programming");
+    println!("--------------------------------");
+
+    let mut doc = Document::new("Draft content".to_string());
+    doc.edit("Better content".to_string());
+    let doc = doc.submit_for_review();
+    let doc = doc.approve("alice").unwrap();
+    let doc = match doc.approve("alice") {
+        Ok(_) => unreachable!("alice already signed off"),
+        Err(doc) => {
+            println!("A second sign-off from alice does not count");
+            doc
+        }
+    };
+    let doc = doc.approve("bob").unwrap();
+    println!("{} sign-offs: {:?}", doc.approvals(), doc.reviewers());
+    let doc = doc.publish();
+    println!(
+        "Published content (revision {}): {}",
+        doc.revision(),
+        doc.content()
+    );
+
+    // Stored and loaded back as a value
+    let stored = AnyDocument::from(doc);
+    match Document::<Draft>::try_from(stored.clone()) {
+        Ok(_) => println!("Loaded as a draft"),
+        Err(error) => println!("Cannot load as a draft: {}", error),
+    }
+    let archived = Document::<Published>::try_from(stored).unwrap().archive();
+    println!("History: {:?}", archived.history());
+}
+
+// Exercise 5 Solution: Error Type Design
+
+#[derive(Debug)]
+enum ConfigError {
+    IoError(std::io::Error),
+    ParseError {
+        line: usize,
+        column: usize,
+        message: String,
+        snippet: String,
+    },
+    EnvError {
+        name: String,
+        message: String,
+    },
+    ValidationError(String),
+    MissingField(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::IoError(error) => write!(f, "cannot read configuration: {}", error),
+            ConfigError::ParseError {
+                line,
+                column,
+                message,
+                snippet,
+            } => write!(
+                f,
+                "line {}, column {}: {}\n{}",
+                line, column, message, snippet
+            ),
+            ConfigError::EnvError { name, message } => write!(f, "{}: {}", name, message),
+            ConfigError::ValidationError(message) => {
+                write!(f, "invalid configuration: {}", message)
+            }
+            ConfigError::MissingField(field) => write!(f, "missing required field `{}`", field),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::IoError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+// Where a value came from
+#[derive(Debug, Clone, PartialEq)]
+enum Origin {
+    Default,
+    File { name: String, line: usize },
+    Env(String),
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Origin::Default => write!(f, "the defaults"),
+            Origin::File { name, line } => write!(f, "{}:{}", name, line),
+            Origin::Env(name) => write!(f, "${}", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    // `key=value` lines, `#` or `;` comments; values are unquoted
+    Ini,
+    // `key = value` lines where a value is a "string", an integer or an
+    // array of strings, with `#` comments; no tables
+    Toml,
+}
+
+const FIELDS: [&str; 4] = ["database_url", "port", "api_key", "features"];
+const PORTS: RangeInclusive<u16> = 1024..=65535;
+const DATABASE_SCHEMES: [&str; 3] = ["postgres", "mysql", "sqlite"];
+
+struct Configuration {
+    database_url: String,
+    port: u16,
+    api_key: Option<String>,
+    features: Vec<String>,
+    // By field name; fields never set have no entry
+    origins: HashMap<String, Origin>,
+}
+
+impl Configuration {
+    fn empty() -> Self {
+        Configuration {
+            database_url: String::new(),
+            port: 0,
+            api_key: None,
+            features: Vec::new(),
+            origins: HashMap::new(),
+        }
+    }
+
+    // The bottom layer of ConfigLoader. There is no sensible default
+    // database_url, so that one stays unset.
+    fn defaults() -> Self {
+        let mut config = Configuration::empty();
+        config.port = 8080;
+        config.features = vec!["web".to_string()];
+        for field in ["port", "features"] {
+            config.origins.insert(field.to_string(), Origin::Default);
+        }
+        config
+    }
+
+    // Only what `content` sets, in INI format; no defaults
+    fn from_string(content: &str) -> Result<Self, ConfigError> {
+        let mut config = Configuration::empty();
+        config.merge_file("<string>", content, Format::Ini)?;
+        Ok(config)
+    }
+
+    fn origin(&self, field: &str) -> Option<&Origin> {
+        self.origins.get(field)
+    }
+
+    // Lays the settings in `content` over this configuration
+    fn merge_file(&mut self, name: &str, content: &str, format: Format) -> Result<(), ConfigError> {
+        for (index, text) in content.lines().enumerate() {
+            let line = SourceLine {
+                file: name,
+                number: index + 1,
+                text,
+            };
+            let setting = match format {
+                Format::Ini => parse_ini_line(&line)?,
+                Format::Toml => parse_toml_line(&line)?,
+            };
+            let Some(setting) = setting else {
+                continue;
+            };
+            // An unknown field is the key's fault, anything else the value's
+            let blame = if FIELDS.contains(&setting.key.as_str()) {
+                setting.value_range
+            } else {
+                setting.key_range
+            };
+            let origin = Origin::File {
+                name: name.to_string(),
+                line: line.number,
+            };
+            self.set(&setting.key, setting.value, origin)
+                .map_err(|message| line.error(blame, message))?;
+        }
+        Ok(())
+    }
+
+    // APP_PORT sets `port` and so on; other variables are ignored
+    fn merge_env(
+        &mut self,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<(), ConfigError> {
+        for (name, value) in vars {
+            let Some(field) = name.strip_prefix("APP_") else {
+                continue;
+            };
+            let field = field.to_ascii_lowercase();
+            let origin = Origin::Env(name.clone());
+            self.set(&field, RawValue::Untyped(value), origin)
+                .map_err(|message| ConfigError::EnvError { name, message })?;
+        }
+        Ok(())
+    }
+
+    fn set(&mut self, field: &str, value: RawValue, origin: Origin) -> Result<(), String> {
+        match field {
+            "database_url" => self.database_url = value.into_text()?,
+            "port" => self.port = value.into_port()?,
+            "api_key" => self.api_key = Some(value.into_text()?),
+            "features" => self.features = value.into_list()?,
+            other => return Err(format!("Unknown field: {}", other)),
+        }
+        self.origins.insert(field.to_string(), origin);
+        Ok(())
+    }
+
+    // The first problem found, naming where the offending value came from
+    fn validate(&self) -> Result<(), ConfigError> {
+        let from = |field: &str| match self.origin(field) {
+            Some(origin) => format!(" (from {})", origin),
+            None => String::new(),
+        };
+        if self.database_url.is_empty() {
+            return Err(ConfigError::MissingField("database_url".to_string()));
+        }
+        let scheme = self
+            .database_url
+            .split_once("://")
+            .map(|(scheme, _)| scheme);
+        if !scheme.is_some_and(|scheme| DATABASE_SCHEMES.contains(&scheme)) {
+            return Err(ConfigError::ValidationError(format!(
+                "database_url {:?}{} must start with {}://",
+                self.database_url,
+                from("database_url"),
+                DATABASE_SCHEMES.join("://, ")
+            )));
+        }
+        if !PORTS.contains(&self.port) {
+            return Err(ConfigError::ValidationError(format!(
+                "port {}{} must be between {} and {}",
+                self.port,
+                from("port"),
+                PORTS.start(),
+                PORTS.end()
+            )));
+        }
+        if self.features.is_empty() {
+            return Err(ConfigError::ValidationError(format!(
+                "features{} must name at least one feature",
+                from("features")
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(error: std::io::Error) -> Self {
+        ConfigError::IoError(error)
+    }
+}
+
+// Builds a Configuration from layers, each overriding the ones before it:
+// Configuration::defaults(), then each file in the order added, then the
+// APP_* environment variables. The result is validated.
+#[derive(Debug, Default)]
+struct ConfigLoader {
+    files: Vec<(String, String, Format)>,
+    env: Vec<(String, String)>,
+}
+
+impl ConfigLoader {
+    fn new() -> Self {
+        ConfigLoader::default()
+    }
+
+    // `name` is only used in errors and origins
+    fn file(mut self, name: &str, content: &str, format: Format) -> Self {
+        self.files
+            .push((name.to_string(), content.to_string(), format));
+        self
+    }
+
+    // Reads `path` straight away. A .toml file is TOML, anything else INI.
+    fn read_file(self, path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        let format = match path.extension() {
+            Some(extension) if extension == "toml" => Format::Toml,
+            _ => Format::Ini,
+        };
+        Ok(self.file(&path.display().to_string(), &content, format))
+    }
+
+    // `std::env::vars()` in a real program
+    fn env(mut self, vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.env.extend(vars);
+        self
+    }
+
+    fn load(&self) -> Result<Configuration, ConfigError> {
+        let mut config = Configuration::defaults();
+        for (name, content, format) in &self.files {
+            config.merge_file(name, content, *format)?;
+        }
+        config.merge_env(self.env.iter().cloned())?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+// A value as written, before it is checked against its field
+enum RawValue {
+    // INI values and environment variables, read as whatever the field needs
+    Untyped(String),
+    Text(String),
+    Integer(i64),
+    List(Vec<String>),
+}
+
+impl RawValue {
+    fn kind(&self) -> &'static str {
+        match self {
+            RawValue::Untyped(_) => "text",
+            RawValue::Text(_) => "a string",
+            RawValue::Integer(_) => "an integer",
+            RawValue::List(_) => "an array",
+        }
+    }
+
+    fn into_text(self) -> Result<String, String> {
+        match self {
+            RawValue::Untyped(text) | RawValue::Text(text) => Ok(text),
+            other => Err(format!("expected a string, found {}", other.kind())),
+        }
+    }
+
+    fn into_port(self) -> Result<u16, String> {
+        match self {
+            RawValue::Untyped(text) => text.parse().map_err(|_| "Invalid port number".to_string()),
+            RawValue::Integer(n) => {
+                u16::try_from(n).map_err(|_| format!("port {} does not fit in a u16", n))
+            }
+            other => Err(format!("expected an integer, found {}", other.kind())),
+        }
+    }
+
+    fn into_list(self) -> Result<Vec<String>, String> {
+        match self {
+            // `a, b` or `["a", "b"]`
+            RawValue::Untyped(text) => Ok(text
+                .trim_matches(|c| c == '[' || c == ']')
+                .split(',')
+                .map(|s| s.trim().trim_matches('"').to_string())
+                .filter(|s| !s.is_empty())
+                .collect()),
+            RawValue::List(items) => Ok(items),
+            other => Err(format!(
+                "expected an array of strings, found {}",
+                other.kind()
+            )),
+        }
+    }
+}
+
+// A `key = value` line; the ranges are byte ranges of the line
+struct Setting {
+    key: String,
+    key_range: Range<usize>,
+    value: RawValue,
+    value_range: Range<usize>,
+}
+
+// One line of a file, for pointing into it in errors
+struct SourceLine<'a> {
+    file: &'a str,
+    number: usize,
+    text: &'a str,
+}
+
+impl SourceLine<'_> {
+    // Underlines the byte range `range` of the line, like rustc:
+    //
+    //    --> app.toml:2:8
+    //     |
+    //   2 | port = "8080"
+    //     |        ^^^^^^
+    fn error(&self, range: Range<usize>, message: impl Into<String>) -> ConfigError {
+        let column = self.text[..range.start].chars().count() + 1;
+        let width = self.text[range].chars().count().max(1);
+        let gutter = " ".repeat(self.number.to_string().len());
+        let snippet = format!(
+            "{gutter}--> {}:{}:{}\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+            self.file,
+            self.number,
+            column,
+            self.number,
+            self.text,
+            " ".repeat(column - 1),
+            "^".repeat(width),
+            gutter = gutter
+        );
+        ConfigError::ParseError {
+            line: self.number,
+            column,
+            message: message.into(),
+            snippet,
+        }
+    }
+}
+
+// `range` without the whitespace at either end
+fn trim_range(text: &str, range: Range<usize>) -> Range<usize> {
+    let part = &text[range.clone()];
+    let start = range.start + part.len() - part.trim_start().len();
+    let end = range.end - (part.len() - part.trim_end().len());
+    start..end.max(start)
+}
+
+fn skip_spaces(text: &str, pos: usize) -> usize {
+    let rest = &text[pos..];
+    pos + rest.len() - rest.trim_start().len()
+}
+
+fn parse_ini_line(line: &SourceLine) -> Result<Option<Setting>, ConfigError> {
+    let text = line.text;
+    let trimmed = text.trim();
+    if trimmed.is_empty() || trimmed.starts_with(['#', ';']) {
+        return Ok(None);
+    }
+    let Some(eq) = text.find('=') else {
+        return Err(line.error(trim_range(text, 0..text.len()), "Invalid line format"));
+    };
+    let key_range = trim_range(text, 0..eq);
+    if key_range.is_empty() {
+        return Err(line.error(eq..eq + 1, "missing key before `=`"));
+    }
+    let value_range = trim_range(text, eq + 1..text.len());
+    Ok(Some(Setting {
+        key: text[key_range.clone()].to_string(),
+        key_range,
+        value: RawValue::Untyped(text[value_range.clone()].to_string()),
+        value_range,
+    }))
+}
+
+fn parse_toml_line(line: &SourceLine) -> Result<Option<Setting>, ConfigError> {
+    let text = line.text;
+    let trimmed = trim_range(text, 0..text.len());
+    if trimmed.is_empty() || text[trimmed.clone()].starts_with('#') {
+        return Ok(None);
+    }
+    if text[trimmed.clone()].starts_with('[') {
+        return Err(line.error(trimmed, "tables are not supported"));
+    }
+    let Some(eq) = text.find('=') else {
+        return Err(line.error(trimmed, "expected `key = value`"));
+    };
+    let key_range = trim_range(text, 0..eq);
+    let key = &text[key_range.clone()];
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(line.error(key_range.start..eq.max(key_range.end), "invalid key"));
+    }
+
+    let start = skip_spaces(text, eq + 1);
+    let (value, end) = toml_value(line, start)?;
+    let after = skip_spaces(text, end);
+    if after < text.len() && !text[after..].starts_with('#') {
+        let rest = trim_range(text, after..text.len());
+        return Err(line.error(rest, "unexpected characters after the value"));
+    }
+    Ok(Some(Setting {
+        key: key.to_string(),
+        key_range,
+        value,
+        value_range: start..end,
+    }))
+}
+
+// The value starting at byte `start`, and the byte just past it
+fn toml_value(line: &SourceLine, start: usize) -> Result<(RawValue, usize), ConfigError> {
+    let text = line.text;
+    let word_end = text[start..]
+        .find(|c: char| c.is_whitespace() || c == ',' || c == ']' || c == '#')
+        .map_or(text.len(), |len| start + len);
+    match text[start..].chars().next() {
+        None => Err(line.error(start..start, "missing value")),
+        Some('"') => {
+            let (text, end) = toml_string(line, start)?;
+            Ok((RawValue::Text(text), end))
+        }
+        Some('[') => {
+            let mut items = Vec::new();
+            let mut pos = skip_spaces(text, start + 1);
+            // A trailing comma is allowed
+            while !text[pos..].starts_with(']') {
+                if !text[pos..].starts_with('"') {
+                    let end = pos + text[pos..].chars().next().map_or(0, char::len_utf8);
+                    return Err(line.error(pos..end, "expected a string or `]`"));
+                }
+                let (item, end) = toml_string(line, pos)?;
+                items.push(item);
+                pos = skip_spaces(text, end);
+                if text[pos..].starts_with(',') {
+                    pos = skip_spaces(text, pos + 1);
+                } else if !text[pos..].starts_with(']') {
+                    let end = pos + text[pos..].chars().next().map_or(0, char::len_utf8);
+                    return Err(line.error(pos..end, "expected `,` or `]`"));
+                }
+            }
+            Ok((RawValue::List(items), pos + 1))
+        }
+        Some('0'..='9' | '-' | '+') => {
+            // Underscores may separate digits: 65_535
+            let n = text[start..word_end]
+                .replace('_', "")
+                .parse()
+                .map_err(|_| line.error(start..word_end, "invalid integer"))?;
+            Ok((RawValue::Integer(n), word_end))
+        }
+        Some(_) => Err(line.error(
+            start..word_end.max(start + 1),
+            "expected a \"string\", an integer or an [array]",
+        )),
+    }
+}
+
+// A basic string whose opening `"` is at byte `start`, and the byte just
+// past its closing one. Escapes: \" \\ \n \t.
+fn toml_string(line: &SourceLine, start: usize) -> Result<(String, usize), ConfigError> {
+    let mut out = String::new();
+    let mut chars = line.text[start + 1..].char_indices();
+    while let Some((offset, c)) = chars.next() {
+        let at = start + 1 + offset;
+        match c {
+            '"' => return Ok((out, at + 1)),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => out.push('\n'),
+                Some((_, 't')) => out.push('\t'),
+                Some((_, c @ ('"' | '\\'))) => out.push(c),
+                Some((_, c)) => return Err(line.error(at..at + 1 + c.len_utf8(), "invalid escape")),
+                None => break,
+            },
+            c => out.push(c),
+        }
+    }
+    Err(line.error(start..line.text.len(), "unterminated string"))
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Error Type Design");
+    println!("---------------------------");
+
+    let config_str = r#"
+        database_url=postgres://localhost/mydb
+        port=8080
+        features=["auth", "api", "web"]
+    "#;
+
+    match Configuration::from_string(config_str) {
+        Ok(config) => match config.validate() {
+            Ok(()) => println!("Valid configuration"),
+            Err(e) => println!("Invalid configuration: {:?}", e),
+        },
+        Err(e) => println!("Failed to parse configuration: {:?}", e),
+    }
+
+    let toml = "\
+# app.toml
+database_url = \"postgres://db.internal/app\"
+features = [\"auth\", \"api\"]  # no web UI
+";
+    let env = [("APP_PORT".to_string(), "9090".to_string())];
+    let config = ConfigLoader::new()
+        .file("app.toml", toml, Format::Toml)
+        .env(env)
+        .load()
+        .unwrap();
+    for field in FIELDS {
+        match config.origin(field) {
+            Some(origin) => println!("{:<12} from {}", field, origin),
+            None => println!("{:<12} not set", field),
+        }
+    }
+
+    let loader = ConfigLoader::new().file("bad.toml", "port = \"8080\"", Format::Toml);
+    if let Err(error) = loader.load() {
+        println!("\n{}", error);
+    }
+    let loader = ConfigLoader::new()
+        .file("app.toml", toml, Format::Toml)
+        .env([("APP_PORT".to_string(), "80".to_string())]);
+    if let Err(error) = loader.load() {
+        println!("\n{}", error);
+    }
+}
+
+// The grading suite from problems/grading.rs, run against these answers.
+// With the `grading` feature on too, problems.rs loads the same file for the
+// stubs; the two copies are meant to be separate modules.
+#[cfg(test)]
+#[path = "problems/grading.rs"]
+#[allow(clippy::duplicate_mod)]
+mod grading;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_run_exercises() {
+        super::run_exercises();
+    }
+}
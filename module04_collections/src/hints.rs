@@ -0,0 +1,78 @@
+// Module 4: Hints
+// Progressive hints for the exercises in problems.rs, one list per exercise,
+// shown by `rustpath run 4 --exercise <n> --hint <level>`. Level 1 is the
+// exercise's "This exercise demonstrates" list; these are levels 2 and up,
+// each giving away a little more than the one before.
+
+pub const HINTS: [&[&str]; 5] = [
+    // Exercise 1: Collection Performance Analysis
+    &[
+        "Time each operation with `let start = Instant::now();` and `start.elapsed()`, and \
+         collect `(name, duration)` pairs in order.",
+        "For each pair in the TODO list, run both sides over the same `size` items. `insert(0, x)` \
+         on a Vec shifts every element, while `VecDeque::push_front` does not; a sorted Vec's \
+         `binary_search` competes with `HashMap::get`.",
+        "StringBuilder keeps a single `String` buffer. `append` reserves `s.len()` and \
+         `push_str`es onto it, adding to `total_len`; `finish` hands the buffer back without \
+         copying.",
+    ],
+    // Exercise 2: Advanced String Processing
+    &[
+        "Store the text once and remember where each line starts in `line_offsets`. `get_line` \
+         can then return a `&str` slice of the original text without copying.",
+        "Build `word_count` in `new` so that `word_frequencies` can return a reference to it. \
+         Split on whitespace, lowercase the words and trim punctuation.",
+        "For `utf8_stats`, `len()` counts bytes and `chars().count()` counts characters. A \
+         `String` is always valid UTF-8, so the invalid sequence count is 0.",
+    ],
+    // Exercise 3: Custom Collection Implementation
+    &[
+        "A ring buffer never moves elements. The element at index `i` lives in slot \
+         `(head + i) % capacity`; a small `slot(i)` helper saves writing that everywhere. \
+         Write the methods once for any `S: Slots<T>`, using `self.buffer.as_ref()` and \
+         `as_mut()`, and both the Vec and the array storage get them.",
+        "`push` stores `Some(value)` in `slot(size)`. `push_front` moves `head` back one \
+         (`slot(capacity - 1)`) and stores there. `pop` and `pop_back` `take()` the slot at \
+         either end. A full buffer hands the value back as `Err(value)`, unless it is \
+         overwriting: then pop the other end first. Watch out for a capacity of 0.",
+        "`with_capacity` fills a Vec with `None`s, and `ArrayRingBuffer::new` can use \
+         `std::array::from_fn(|_| None)`, since `[None; N]` needs `T: Copy`.",
+        "The elements occupy `head..head + size` if that fits in the storage, otherwise \
+         `head..capacity` and then `0..` the rest. Those are the two `as_slices`. `iter` is \
+         `front.iter().chain(back).flatten()`, because iterating `&Option<T>` yields the \
+         `&T` inside. For `as_mut_slices`, `split_at_mut(head)` gives two disjoint halves.",
+        "For `drain`, rotate the range to the back of the buffer (three reversals with \
+         `swap`), then shrink `size`. The drained elements now sit just past the end, where \
+         the Drain can `take()` them one by one.",
+    ],
+    // Exercise 4: Error Handling Patterns
+    &[
+        "Display describes this error only. The wrapped error is exposed through \
+         `Error::source`, so reporters can walk the chain without printing anything twice.",
+        "`source` matches the variants that carry an inner error and returns \
+         `Some(inner.as_ref())`. Variants without one return None.",
+        "`error_chain` starts with `self as &dyn Error` and keeps following `.source()` until it \
+         returns None. `add_context` matches on `self` and rebuilds the same variant, \
+         with `format!(\"{}: {}\", context, ...)` in front of its descriptive field.",
+    ],
+    // Exercise 5: Advanced Collection Patterns
+    &[
+        "Start with `List`. `push_front` points the new slot's `next` at the old head and the \
+         old head's `prev` back at it; `unlink` joins a slot's neighbours to each other, \
+         fixing `head` or `tail` when the slot was at an end. `slots` can follow `next` \
+         with `std::iter::successors(self.head, ...)`.",
+        "`Lru` is one list, most recent at the front: insert pushes to the front, an access \
+         unlinks and pushes to the front again, and the victim is the tail. No step searches, \
+         so all of them are O(1).",
+        "`Lfu` keeps a list per use count in the BTreeMap. An access moves the slot from the \
+         list for `count` to the one for `count + 1`. Remove lists that become empty, and the \
+         first one left always holds the victim: its tail.",
+        "In `CacheMap`, a new entry takes a slot from `free` or goes at the end of `entries`, \
+         and `slots` records where. Removing an entry `take()`s it out of its slot, tells the \
+         policy and pushes the slot onto `free`. Keep one private helper that does that for \
+         `remove`, evictions and expiry alike.",
+        "Store `clock() + ttl` as the entry's expiry and compare it with `clock()` when the \
+         entry is read. Keep `hits`, `misses`, `evictions` and `expirations` up to date in \
+         the same helpers, and call `on_evict` with the key, the value and the reason.",
+    ],
+];
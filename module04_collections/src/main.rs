@@ -7,7 +7,7 @@
 // - Custom error type design
 // Comparisons with other languages are provided to highlight Rust's unique approach.
 
-mod problems;
+use module4::problems;
 
 use std::collections::{HashMap, VecDeque};
 use std::error::Error;
@@ -147,6 +147,7 @@ fn main() {
 
     // Custom error type with context
     #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
     enum AppError {
         IoError {
             source: std::io::Error,
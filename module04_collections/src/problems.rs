@@ -3,10 +3,16 @@
 // and advanced error handling patterns. Each problem includes detailed
 // explanations about memory layouts and performance characteristics.
 
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::error::Error;
 use std::fmt;
-use std::time::Instant;
+use std::hash::Hash;
+use std::hint::black_box;
+use std::iter::{Chain, Flatten};
+use std::marker::PhantomData;
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
+use std::slice;
+use std::time::{Duration, Instant};
 
 pub fn run_exercises() {
     println!("Module 4 Exercises - Collections and Error Handling");
@@ -19,45 +25,53 @@ pub fn run_exercises() {
     exercise5();
 }
 
+// Exercise entry points in order, used by `rustpath run <module> --exercise <n>`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Hidden per-exercise checks, run against this file by `rustpath grade`.
+#[cfg(all(test, feature = "grading"))]
+mod grading;
+
 // Exercise 1: Collection Performance Analysis
 // This exercise demonstrates:
 // - Memory layout and performance characteristics of different collections
 // - Capacity management and reallocation strategies
 // - Benchmarking and optimization techniques
-fn exercise1() {
-    println!("Exercise 1: Collection Performance Analysis");
-    println!("--------------------------------------");
-    println!("TODO: Implement the benchmark_collections function\n");
 
-    // Implement this function to compare collection performance
-    fn benchmark_collections(size: usize) -> Vec<(String, std::time::Duration)> {
-        // TODO: Benchmark and compare:
-        // 1. Vec push_back vs VecDeque push_back
-        // 2. Vec insert(0, x) vs VecDeque push_front
-        // 3. Vec binary search vs HashMap lookup
-        // 4. String push_str vs String + &str
-        unimplemented!("Implement benchmark_collections");
-    }
+// Implement this function to compare collection performance
+fn benchmark_collections(size: usize) -> Vec<(String, std::time::Duration)> {
+    // TODO: Benchmark and compare:
+    // 1. Vec push_back vs VecDeque push_back
+    // 2. Vec insert(0, x) vs VecDeque push_front
+    // 3. Vec binary search vs HashMap lookup
+    // 4. String push_str vs String + &str
+    unimplemented!("Implement benchmark_collections");
+}
 
-    // Custom string builder with pre-allocation
-    struct StringBuilder {
-        buffer: String,
-        total_len: usize,
-    }
+// Custom string builder with pre-allocation
+struct StringBuilder {
+    buffer: String,
+    total_len: usize,
+}
 
-    impl StringBuilder {
-        fn new() -> Self {
-            unimplemented!("Implement StringBuilder::new");
-        }
+impl StringBuilder {
+    fn new() -> Self {
+        unimplemented!("Implement StringBuilder::new");
+    }
 
-        fn append(&mut self, s: &str) {
-            unimplemented!("Implement StringBuilder::append");
-        }
+    fn append(&mut self, s: &str) {
+        unimplemented!("Implement StringBuilder::append");
+    }
 
-        fn finish(self) -> String {
-            unimplemented!("Implement StringBuilder::finish");
-        }
+    fn finish(self) -> String {
+        unimplemented!("Implement StringBuilder::finish");
     }
+}
+
+fn exercise1() {
+    println!("Exercise 1: Collection Performance Analysis");
+    println!("--------------------------------------");
+    println!("TODO: Implement the benchmark_collections function\n");
 
     // Test your implementation:
     // let results = benchmark_collections(10_000);
@@ -72,44 +86,45 @@ fn exercise1() {
 // - String memory management
 // - Zero-copy string operations
 // - Performance optimization for string handling
-fn exercise2() {
-    println!("\nExercise 2: Advanced String Processing");
-    println!("----------------------------------");
-    println!("TODO: Implement the TextProcessor struct\n");
 
-    struct TextProcessor {
-        content: String,
-        line_offsets: Vec<usize>,
-        word_count: HashMap<String, usize>,
-    }
+struct TextProcessor {
+    content: String,
+    line_offsets: Vec<usize>,
+    word_count: HashMap<String, usize>,
+}
 
-    impl TextProcessor {
-        // Initialize with pre-calculated indices for O(1) line access
-        fn new(text: &str) -> Self {
-            unimplemented!("Implement TextProcessor::new");
-        }
+impl TextProcessor {
+    // Initialize with pre-calculated indices for O(1) line access
+    fn new(text: &str) -> Self {
+        unimplemented!("Implement TextProcessor::new");
+    }
 
-        // Get line by index without allocating new string
-        fn get_line(&self, line_number: usize) -> Option<&str> {
-            unimplemented!("Implement get_line");
-        }
+    // Get line by index without allocating new string
+    fn get_line(&self, line_number: usize) -> Option<&str> {
+        unimplemented!("Implement get_line");
+    }
 
-        // Find longest common prefix of all lines
-        fn longest_common_prefix(&self) -> &str {
-            unimplemented!("Implement longest_common_prefix");
-        }
+    // Find longest common prefix of all lines
+    fn longest_common_prefix(&self) -> &str {
+        unimplemented!("Implement longest_common_prefix");
+    }
 
-        // Get word frequency statistics
-        fn word_frequencies(&self) -> &HashMap<String, usize> {
-            unimplemented!("Implement word_frequencies");
-        }
+    // Get word frequency statistics
+    fn word_frequencies(&self) -> &HashMap<String, usize> {
+        unimplemented!("Implement word_frequencies");
+    }
 
-        // Check if text is valid UTF-8 and count characters
-        fn utf8_stats(&self) -> (usize, usize, usize) {
-            // (bytes, chars, invalid_sequences)
-            unimplemented!("Implement utf8_stats");
-        }
+    // Check if text is valid UTF-8 and count characters
+    fn utf8_stats(&self) -> (usize, usize, usize) {
+        // (bytes, chars, invalid_sequences)
+        unimplemented!("Implement utf8_stats");
     }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Advanced String Processing");
+    println!("----------------------------------");
+    println!("TODO: Implement the TextProcessor struct\n");
 
     // Test your implementation:
     // let text = "Hello, world!\nRust is great!\nHello, Rust!";
@@ -126,54 +141,180 @@ fn exercise2() {
 // - Memory layout optimization
 // - Iterator implementation
 // - Performance considerations
-fn exercise3() {
-    println!("\nExercise 3: Custom Collection Implementation");
-    println!("----------------------------------------");
-    println!("TODO: Implement the RingBuffer struct\n");
 
-    // A fixed-size ring buffer with O(1) push and pop operations
-    struct RingBuffer<T> {
-        buffer: Vec<Option<T>>,
-        head: usize,
-        tail: usize,
-        size: usize,
+// Where a ring buffer keeps its slots: a Vec or an array of them
+trait Slots<T>: AsRef<[Option<T>]> + AsMut<[Option<T>]> {}
+
+impl<T, S: AsRef<[Option<T>]> + AsMut<[Option<T>]>> Slots<T> for S {}
+
+// A fixed-size ring buffer with O(1) push and pop operations at both ends.
+// The elements fill `size` slots starting at `head`, wrapping around the
+// end of the storage; every other slot is None. Nothing may be allocated
+// after construction.
+struct RingBuffer<T, S = Vec<Option<T>>> {
+    buffer: S,
+    head: usize,
+    size: usize,
+    overwrite: bool,
+    element: PhantomData<T>,
+}
+
+// The same ring buffer with its N slots inline, so no heap at all
+type ArrayRingBuffer<T, const N: usize> = RingBuffer<T, [Option<T>; N]>;
+
+// Borrowing iterators, front to back: the two runs of slots from
+// `as_slices` with the Option of each slot flattened away
+type Iter<'a, T> = Flatten<Chain<slice::Iter<'a, Option<T>>, slice::Iter<'a, Option<T>>>>;
+type IterMut<'a, T> = Flatten<Chain<slice::IterMut<'a, Option<T>>, slice::IterMut<'a, Option<T>>>>;
+
+impl<T> RingBuffer<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        unimplemented!("Implement with_capacity");
     }
+}
 
-    impl<T> RingBuffer<T> {
-        fn with_capacity(capacity: usize) -> Self {
-            unimplemented!("Implement with_capacity");
-        }
+impl<T, const N: usize> ArrayRingBuffer<T, N> {
+    fn new() -> Self {
+        unimplemented!("Implement new");
+    }
+}
 
-        fn push(&mut self, value: T) -> Result<(), T> {
-            unimplemented!("Implement push");
-        }
+impl<T, S: Slots<T>> RingBuffer<T, S> {
+    // When full, `push` and `push_front` make room by dropping the element
+    // at the other end instead of handing the value back
+    fn overwriting(mut self) -> Self {
+        unimplemented!("Implement overwriting");
+    }
 
-        fn pop(&mut self) -> Option<T> {
-            unimplemented!("Implement pop");
-        }
+    fn capacity(&self) -> usize {
+        unimplemented!("Implement capacity");
+    }
 
-        fn is_full(&self) -> bool {
-            unimplemented!("Implement is_full");
-        }
+    fn push(&mut self, value: T) -> Result<(), T> {
+        unimplemented!("Implement push");
+    }
 
-        fn is_empty(&self) -> bool {
-            unimplemented!("Implement is_empty");
-        }
+    fn push_front(&mut self, value: T) -> Result<(), T> {
+        unimplemented!("Implement push_front");
+    }
 
-        fn len(&self) -> usize {
-            unimplemented!("Implement len");
-        }
+    fn pop(&mut self) -> Option<T> {
+        unimplemented!("Implement pop");
     }
 
-    // Implement Iterator for RingBuffer
-    impl<T> Iterator for RingBuffer<T> {
-        type Item = T;
+    fn pop_back(&mut self) -> Option<T> {
+        unimplemented!("Implement pop_back");
+    }
 
-        fn next(&mut self) -> Option<Self::Item> {
-            unimplemented!("Implement next");
-        }
+    fn is_full(&self) -> bool {
+        unimplemented!("Implement is_full");
     }
 
+    fn is_empty(&self) -> bool {
+        unimplemented!("Implement is_empty");
+    }
+
+    fn len(&self) -> usize {
+        unimplemented!("Implement len");
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        unimplemented!("Implement get");
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        unimplemented!("Implement get_mut");
+    }
+
+    // The elements in order, as the slots holding them: from `head`
+    // towards the end of the storage, then any that wrapped around
+    fn as_slices(&self) -> (&[Option<T>], &[Option<T>]) {
+        unimplemented!("Implement as_slices");
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [Option<T>], &mut [Option<T>]) {
+        unimplemented!("Implement as_mut_slices");
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        unimplemented!("Implement iter");
+    }
+
+    fn iter_mut(&mut self) -> IterMut<'_, T> {
+        unimplemented!("Implement iter_mut");
+    }
+
+    // Removes the elements in `range` and yields them in order, without
+    // allocating. Panics if the range is out of bounds.
+    fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, S> {
+        unimplemented!("Implement drain");
+    }
+}
+
+// Implement Index and IndexMut, panicking when out of bounds
+impl<T, S: Slots<T>> Index<usize> for RingBuffer<T, S> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        unimplemented!("Implement index");
+    }
+}
+
+impl<T, S: Slots<T>> IndexMut<usize> for RingBuffer<T, S> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        unimplemented!("Implement index_mut");
+    }
+}
+
+impl<T: fmt::Debug, S: Slots<T>> fmt::Debug for RingBuffer<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Implement Iterator for RingBuffer: iterating the buffer itself consumes
+// it, from either end
+impl<T, S: Slots<T>> Iterator for RingBuffer<T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unimplemented!("Implement next");
+    }
+}
+
+impl<T, S: Slots<T>> DoubleEndedIterator for RingBuffer<T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        unimplemented!("Implement next_back");
+    }
+}
+
+// The elements removed by `drain`. Store whatever you need to find them;
+// the ones not taken are dropped with the Drain.
+struct Drain<'a, T, S: Slots<T>> {
+    buffer: &'a mut RingBuffer<T, S>,
+    next: usize,
+    end: usize,
+}
+
+impl<T, S: Slots<T>> Iterator for Drain<'_, T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unimplemented!("Implement Drain::next");
+    }
+}
+
+impl<T, S: Slots<T>> Drop for Drain<'_, T, S> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Custom Collection Implementation");
+    println!("----------------------------------------");
+    println!("TODO: Implement the RingBuffer struct\n");
+
     // Test your implementation:
     // let mut buffer = RingBuffer::with_capacity(3);
     // buffer.push(1).unwrap();
@@ -181,6 +322,17 @@ fn exercise3() {
     // buffer.push(3).unwrap();
     // assert!(buffer.push(4).is_err()); // Buffer is full
     // println!("Buffer: {:?}", buffer.collect::<Vec<_>>());
+    //
+    // let mut recent = RingBuffer::with_capacity(3).overwriting();
+    // for reading in [10, 20, 30, 40, 50] {
+    //     recent.push(reading).unwrap();
+    // }
+    // println!("Last 3 readings: {:?}", recent);
+    // println!("  stored as {:?}", recent.as_slices());
+    //
+    // let mut inline = ArrayRingBuffer::<char, 8>::new();
+    // inline.push_front('a').unwrap();
+    // println!("Drained: {:?}", inline.drain(..).collect::<String>());
 }
 
 // Exercise 4: Error Handling Patterns
@@ -189,54 +341,56 @@ fn exercise3() {
 // - Error context and wrapping
 // - Error conversion traits
 // - Backtraces and debugging
-fn exercise4() {
-    println!("\nExercise 4: Error Handling Patterns");
-    println!("--------------------------------");
-    println!("TODO: Implement the DatabaseError type\n");
 
-    // Custom error type with context and source
-    #[derive(Debug)]
-    enum DatabaseError {
-        ConnectionError {
-            source: std::io::Error,
-            connection_string: String,
-        },
-        QueryError {
-            source: Box<dyn Error>,
-            query: String,
-            params: Vec<String>,
-        },
-        TransactionError {
-            source: Box<dyn Error>,
-            transaction_id: String,
-        },
-        ValidationError(String),
-    }
+// Custom error type with context and source
+#[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
+enum DatabaseError {
+    ConnectionError {
+        source: std::io::Error,
+        connection_string: String,
+    },
+    QueryError {
+        source: Box<dyn Error>,
+        query: String,
+        params: Vec<String>,
+    },
+    TransactionError {
+        source: Box<dyn Error>,
+        transaction_id: String,
+    },
+    ValidationError(String),
+}
 
-    impl DatabaseError {
-        // Add context to the error
-        fn add_context(self, context: &str) -> Self {
-            unimplemented!("Implement add_context");
-        }
+impl DatabaseError {
+    // Add context to the error
+    fn add_context(self, context: &str) -> Self {
+        unimplemented!("Implement add_context");
+    }
 
-        // Get the error chain as a vector
-        fn error_chain(&self) -> Vec<&dyn Error> {
-            unimplemented!("Implement error_chain");
-        }
+    // Get the error chain as a vector
+    fn error_chain(&self) -> Vec<&dyn Error> {
+        unimplemented!("Implement error_chain");
     }
+}
 
-    // Implement necessary traits for DatabaseError
-    impl fmt::Display for DatabaseError {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            unimplemented!("Implement Display");
-        }
+// Implement necessary traits for DatabaseError
+impl fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        unimplemented!("Implement Display");
     }
+}
 
-    impl Error for DatabaseError {
-        fn source(&self) -> Option<&(dyn Error + 'static)> {
-            unimplemented!("Implement source");
-        }
+impl Error for DatabaseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        unimplemented!("Implement source");
     }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Error Handling Patterns");
+    println!("--------------------------------");
+    println!("TODO: Implement the DatabaseError type\n");
 
     // Test your implementation:
     // let err = DatabaseError::ConnectionError {
@@ -253,148 +407,413 @@ fn exercise4() {
 // - Memory efficiency
 // - Performance optimization
 // - Custom collection composition
-fn exercise5() {
-    println!("\nExercise 5: Advanced Collection Patterns");
-    println!("---------------------------------");
-    println!("TODO: Implement the CacheMap struct\n");
 
-    // A cache with LRU eviction policy and size limit
-    struct CacheMap<K, V> {
-        data: HashMap<K, V>,
-        access_order: VecDeque<K>,
-        capacity: usize,
-    }
+// Doubly linked lists threaded through slot indices: `prev[slot]` and
+// `next[slot]` are the neighbours of `slot` in whichever list holds it. The
+// lists store no data of their own, so one Links can hold several lists.
+#[derive(Debug, Default)]
+struct Links {
+    prev: Vec<Option<usize>>,
+    next: Vec<Option<usize>>,
+}
 
-    impl<K: Clone + Eq + std::hash::Hash, V> CacheMap<K, V> {
-        fn with_capacity(capacity: usize) -> Self {
-            unimplemented!("Implement with_capacity");
-        }
+// One list in a Links, from its first slot to its last
+#[derive(Debug, Default, Clone, Copy)]
+struct List {
+    head: Option<usize>,
+    tail: Option<usize>,
+}
 
-        fn insert(&mut self, key: K, value: V) -> Option<V> {
-            unimplemented!("Implement insert");
-        }
+impl List {
+    // Grows `links` if `slot` is new to it
+    fn push_front(&mut self, links: &mut Links, slot: usize) {
+        unimplemented!("Implement push_front");
+    }
 
-        fn get(&mut self, key: &K) -> Option<&V> {
-            unimplemented!("Implement get");
-        }
+    fn unlink(&mut self, links: &mut Links, slot: usize) {
+        unimplemented!("Implement unlink");
+    }
 
-        fn remove(&mut self, key: &K) -> Option<V> {
-            unimplemented!("Implement remove");
-        }
+    fn is_empty(&self) -> bool {
+        unimplemented!("Implement is_empty");
+    }
 
-        // Get items ordered by most recently used
-        fn items_by_recent_access(&self) -> Vec<(&K, &V)> {
-            unimplemented!("Implement items_by_recent_access");
-        }
+    fn slots(&self, links: &Links) -> Vec<usize> {
+        unimplemented!("Implement slots");
     }
+}
 
-    // Test your implementation:
-    // let mut cache = CacheMap::with_capacity(2);
-    // cache.insert("a", 1);
-    // cache.insert("b", 2);
-    // cache.insert("c", 3); // Should evict "a"
-    // assert!(cache.get("a").is_none());
-    // println!("Cache items: {:?}", cache.items_by_recent_access());
+// Decides which entry a full cache evicts. The cache reports every insert,
+// access and removal by the slot the entry occupies.
+trait CachePolicy {
+    fn inserted(&mut self, slot: usize);
+    fn accessed(&mut self, slot: usize);
+    fn removed(&mut self, slot: usize);
+    // The slot to evict next
+    fn victim(&self) -> Option<usize>;
+    // Every slot, from the one to keep longest to the victim
+    fn ranking(&self) -> Vec<usize>;
+}
+
+// Least recently used: one list, most recent first. Every operation must
+// be O(1).
+#[derive(Debug, Default)]
+struct Lru {
+    links: Links,
+    list: List,
 }
 
-/* Example Solutions (Try to solve the exercises before looking at these!)
+impl CachePolicy for Lru {
+    fn inserted(&mut self, slot: usize) {
+        unimplemented!("Implement Lru::inserted");
+    }
 
-// Exercise 1 Solution:
-fn benchmark_collections(size: usize) -> Vec<(String, std::time::Duration)> {
-    let mut results = Vec::new();
+    fn accessed(&mut self, slot: usize) {
+        unimplemented!("Implement Lru::accessed");
+    }
 
-    // Vec vs VecDeque push_back
-    let start = Instant::now();
-    let mut vec = Vec::with_capacity(size);
-    for i in 0..size {
-        vec.push(i);
+    fn removed(&mut self, slot: usize) {
+        unimplemented!("Implement Lru::removed");
     }
-    results.push(("Vec push_back".to_string(), start.elapsed()));
 
-    let start = Instant::now();
-    let mut deque = VecDeque::with_capacity(size);
-    for i in 0..size {
-        deque.push_back(i);
+    fn victim(&self) -> Option<usize> {
+        unimplemented!("Implement Lru::victim");
     }
-    results.push(("VecDeque push_back".to_string(), start.elapsed()));
 
-    // More benchmarks...
-    results
+    fn ranking(&self) -> Vec<usize> {
+        unimplemented!("Implement Lru::ranking");
+    }
 }
 
-// Exercise 2 Solution:
-impl TextProcessor {
-    fn new(text: &str) -> Self {
-        let mut line_offsets = vec![0];
-        let mut word_count = HashMap::new();
+// Least frequently used, and least recently used among equals: a list per
+// use count, most recent first, with the use count of each slot
+#[derive(Debug, Default)]
+struct Lfu {
+    links: Links,
+    counts: Vec<u64>,
+    buckets: BTreeMap<u64, List>,
+}
 
-        for (i, c) in text.char_indices() {
-            if c == '\n' {
-                line_offsets.push(i + 1);
-            }
-        }
+impl CachePolicy for Lfu {
+    fn inserted(&mut self, slot: usize) {
+        unimplemented!("Implement Lfu::inserted");
+    }
 
-        for word in text.split_whitespace() {
-            *word_count.entry(word.to_string()).or_insert(0) += 1;
-        }
+    fn accessed(&mut self, slot: usize) {
+        unimplemented!("Implement Lfu::accessed");
+    }
 
-        TextProcessor {
-            content: text.to_string(),
-            line_offsets,
-            word_count,
-        }
+    fn removed(&mut self, slot: usize) {
+        unimplemented!("Implement Lfu::removed");
+    }
+
+    fn victim(&self) -> Option<usize> {
+        unimplemented!("Implement Lfu::victim");
+    }
+
+    fn ranking(&self) -> Vec<usize> {
+        unimplemented!("Implement Lfu::ranking");
     }
 }
 
-// Exercise 3 Solution:
-impl<T> RingBuffer<T> {
+// Why an entry left the cache without being removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Eviction {
+    // Made room for a new entry
+    Capacity,
+    // Outlived its time to live
+    Expired,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct CacheStats {
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+    expirations: u64,
+}
+
+impl CacheStats {
+    // Hits per lookup, or 0 before the first lookup
+    fn hit_rate(&self) -> f64 {
+        unimplemented!("Implement hit_rate");
+    }
+}
+
+type EvictionCallback<K, V> = Box<dyn FnMut(K, V, Eviction)>;
+
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    expires: Option<Instant>,
+}
+
+// A cache with a size limit, an eviction policy and optional expiry. The
+// entries live in an arena: `slots` maps each key to its index in
+// `entries`, and the indices of removed entries are reused from `free`.
+// Expired entries count towards `len` until a lookup, `purge_expired` or an
+// insert into a full cache finds them; that insert drops every expired entry
+// before it evicts a live one, which costs O(n) while any entry can expire.
+struct CacheMap<K, V, P = Lru> {
+    slots: HashMap<K, usize>,
+    entries: Vec<Option<Entry<K, V>>>,
+    free: Vec<usize>,
+    policy: P,
+    capacity: usize,
+    ttl: Option<Duration>,
+    clock: Box<dyn Fn() -> Instant>,
+    on_evict: Option<EvictionCallback<K, V>>,
+    stats: CacheStats,
+    // How many entries have an expiry time, so that caches without one never
+    // scan for expired entries
+    expiring: usize,
+}
+
+impl<K: Clone + Eq + Hash, V> CacheMap<K, V> {
     fn with_capacity(capacity: usize) -> Self {
-        RingBuffer {
-            buffer: (0..capacity).map(|_| None).collect(),
-            head: 0,
-            tail: 0,
-            size: 0,
-        }
+        unimplemented!("Implement with_capacity");
     }
 
-    fn push(&mut self, value: T) -> Result<(), T> {
-        if self.is_full() {
-            return Err(value);
-        }
-        self.buffer[self.tail] = Some(value);
-        self.tail = (self.tail + 1) % self.buffer.len();
-        self.size += 1;
-        Ok(())
+    // Get items ordered by most recently used
+    fn items_by_recent_access(&self) -> Vec<(&K, &V)> {
+        unimplemented!("Implement items_by_recent_access");
     }
 }
 
-// Exercise 4 Solution:
-impl DatabaseError {
-    fn add_context(self, context: &str) -> Self {
-        match self {
-            DatabaseError::ConnectionError { source, connection_string } => {
-                DatabaseError::ConnectionError {
-                    source,
-                    connection_string: format!("{}: {}", context, connection_string),
+impl<K: Clone + Eq + Hash, V, P: CachePolicy> CacheMap<K, V, P> {
+    fn with_policy(capacity: usize, policy: P) -> Self {
+        unimplemented!("Implement with_policy");
+    }
+
+    // How long entries from `insert` live
+    fn with_ttl(mut self, ttl: Duration) -> Self {
+        unimplemented!("Implement with_ttl");
+    }
+
+    // Where expiry times come from, instead of `Instant::now`
+    fn with_clock(mut self, clock: impl Fn() -> Instant + 'static) -> Self {
+        unimplemented!("Implement with_clock");
+    }
+
+    // Called with every entry that is evicted or expires
+    fn on_evict(mut self, callback: impl FnMut(K, V, Eviction) + 'static) -> Self {
+        unimplemented!("Implement on_evict");
+    }
+
+    fn len(&self) -> usize {
+        unimplemented!("Implement len");
+    }
+
+    fn is_empty(&self) -> bool {
+        unimplemented!("Implement is_empty");
+    }
+
+    fn stats(&self) -> CacheStats {
+        unimplemented!("Implement stats");
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        unimplemented!("Implement insert");
+    }
+
+    fn insert_with_ttl(&mut self, key: K, value: V, ttl: Duration) -> Option<V> {
+        unimplemented!("Implement insert_with_ttl");
+    }
+
+    // A lookup of an expired entry is a miss, and evicts it
+    fn get(&mut self, key: &K) -> Option<&V> {
+        unimplemented!("Implement get");
+    }
+
+    // Removing an entry is not an eviction, so the callback is not called,
+    // unless the entry turns out to have expired
+    fn remove(&mut self, key: &K) -> Option<V> {
+        unimplemented!("Implement remove");
+    }
+
+    // Drops every expired entry, returning how many there were
+    fn purge_expired(&mut self) -> usize {
+        unimplemented!("Implement purge_expired");
+    }
+
+    // The live entries, from the one the policy would keep longest to the
+    // one it would evict next
+    fn items_by_rank(&self) -> Vec<(&K, &V)> {
+        unimplemented!("Implement items_by_rank");
+    }
+}
+
+// An LRU cache that keeps recency in a VecDeque, least recent first. Simple,
+// but every hit scans the deque; `benchmark_caches` compares it with
+// CacheMap.
+struct DequeCache<K, V> {
+    data: HashMap<K, V>,
+    access_order: VecDeque<K>,
+    capacity: usize,
+}
+
+impl<K: Clone + Eq + Hash, V> DequeCache<K, V> {
+    fn with_capacity(capacity: usize) -> Self {
+        DequeCache {
+            data: HashMap::with_capacity(capacity),
+            access_order: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.data.contains_key(&key) {
+            self.touch(&key);
+        } else {
+            if self.data.len() >= self.capacity {
+                if let Some(oldest) = self.access_order.pop_front() {
+                    self.data.remove(&oldest);
                 }
             }
-            // Handle other variants...
-            _ => self,
+            if self.capacity == 0 {
+                return None;
+            }
+            self.access_order.push_back(key.clone());
         }
+        self.data.insert(key, value)
     }
-}
 
-// Exercise 5 Solution:
-impl<K: Clone + Eq + std::hash::Hash, V> CacheMap<K, V> {
-    fn insert(&mut self, key: K, value: V) -> Option<V> {
-        if self.data.len() >= self.capacity && !self.data.contains_key(&key) {
-            if let Some(old_key) = self.access_order.pop_front() {
-                self.data.remove(&old_key);
+    fn get(&mut self, key: &K) -> Option<&V> {
+        if self.data.contains_key(key) {
+            self.touch(key);
+        }
+        self.data.get(key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let value = self.data.remove(key)?;
+        self.access_order.retain(|k| k != key);
+        Some(value)
+    }
+
+    fn items_by_recent_access(&self) -> Vec<(&K, &V)> {
+        self.access_order
+            .iter()
+            .rev()
+            .filter_map(|key| self.data.get_key_value(key))
+            .collect()
+    }
+
+    // Moves `key` to the most recently used end
+    fn touch(&mut self, key: &K) {
+        if let Some(pos) = self.access_order.iter().position(|k| k == key) {
+            if let Some(k) = self.access_order.remove(pos) {
+                self.access_order.push_back(k);
             }
         }
+    }
+}
 
-        self.access_order.push_back(key.clone());
-        self.data.insert(key, value)
+// The same mix of lookups and inserts for every cache: one operation in four
+// is an insert, and keys are drawn from twice the capacity, so plenty of
+// lookups miss and inserts evict
+fn workload(capacity: usize, operations: usize) -> impl Iterator<Item = (bool, u64)> {
+    // xorshift64: cheap and the same on every run
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let keys = 2 * capacity as u64 + 1;
+    (0..operations).map(move |_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state.is_multiple_of(4), (state >> 2) % keys)
+    })
+}
+
+// Runs the workload against `cache`, given how to insert into it and how to
+// look a key up, and returns how long it took
+fn time_workload<C>(
+    mut cache: C,
+    capacity: usize,
+    operations: usize,
+    insert: impl Fn(&mut C, u64),
+    get: impl Fn(&mut C, u64),
+) -> Duration {
+    let start = Instant::now();
+    for (is_insert, key) in workload(capacity, operations) {
+        if is_insert {
+            insert(&mut cache, key);
+        } else {
+            get(&mut cache, key);
+        }
     }
+    start.elapsed()
 }
-*/
+
+// Times the workload against the VecDeque cache and both CacheMap policies.
+// Run a release build for meaningful numbers.
+fn benchmark_caches(capacity: usize, operations: usize) -> Vec<(String, Duration)> {
+    let deque = time_workload(
+        DequeCache::with_capacity(capacity),
+        capacity,
+        operations,
+        |cache, key| {
+            cache.insert(key, key);
+        },
+        |cache, key| {
+            black_box(cache.get(&key));
+        },
+    );
+    let lru = time_workload(
+        CacheMap::with_capacity(capacity),
+        capacity,
+        operations,
+        |cache, key| {
+            cache.insert(key, key);
+        },
+        |cache, key| {
+            black_box(cache.get(&key));
+        },
+    );
+    let lfu = time_workload(
+        CacheMap::with_policy(capacity, Lfu::default()),
+        capacity,
+        operations,
+        |cache, key| {
+            cache.insert(key, key);
+        },
+        |cache, key| {
+            black_box(cache.get(&key));
+        },
+    );
+
+    vec![
+        ("VecDeque LRU".to_string(), deque),
+        ("CacheMap LRU".to_string(), lru),
+        ("CacheMap LFU".to_string(), lfu),
+    ]
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Advanced Collection Patterns");
+    println!("---------------------------------");
+    println!("TODO: Implement the CacheMap struct\n");
+
+    // Test your implementation:
+    // let mut cache = CacheMap::with_capacity(2);
+    // cache.insert("a", 1);
+    // cache.insert("b", 2);
+    // cache.insert("c", 3); // Should evict "a"
+    // assert!(cache.get(&"a").is_none());
+    // println!("Cache items: {:?}", cache.items_by_recent_access());
+    //
+    // let mut cache = CacheMap::with_policy(2, Lfu::default());
+    // cache.insert("home", "/");
+    // cache.insert("about", "/about");
+    // cache.get(&"home");
+    // cache.insert("blog", "/blog"); // Should evict "about"
+    // println!("LFU items: {:?}", cache.items_by_rank());
+    //
+    // for (name, elapsed) in benchmark_caches(1_000, 20_000) {
+    //     println!("{:>14}: {:?} for 20000 operations", name, elapsed);
+    // }
+}
+
+// Example Solutions
+// The reference answers live in src/solutions.rs and are compiled with the
+// `solutions` feature (`rustpath run <module> --solution`). Try solving the
+// exercises yourself first!
@@ -0,0 +1,554 @@
+// Grading suite for the module 4 exercises
+// These checks are hidden from the exercise walkthrough: `rustpath grade 4`
+// runs them against your implementations in problems.rs.
+
+use super::*;
+
+mod exercise1 {
+    use super::*;
+
+    #[test]
+    fn test_benchmark_reports_each_collection() {
+        let results = benchmark_collections(1_000);
+        assert!(results.len() >= 2);
+        let mut names: Vec<&str> = results.iter().map(|(name, _)| name.as_str()).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), results.len());
+    }
+
+    #[test]
+    fn test_string_builder_concatenates() {
+        let mut builder = StringBuilder::new();
+        builder.append("Hello");
+        builder.append(", ");
+        builder.append("world");
+        assert_eq!(builder.finish(), "Hello, world");
+        assert_eq!(StringBuilder::new().finish(), "");
+    }
+}
+
+mod exercise2 {
+    use super::*;
+
+    #[test]
+    fn test_get_line_borrows_each_line() {
+        let processor = TextProcessor::new("Hello, world!\nRust is great!\nHello, Rust!");
+        assert_eq!(processor.get_line(0), Some("Hello, world!"));
+        assert_eq!(processor.get_line(1), Some("Rust is great!"));
+        assert_eq!(processor.get_line(2), Some("Hello, Rust!"));
+        assert_eq!(processor.get_line(3), None);
+    }
+
+    #[test]
+    fn test_longest_common_prefix() {
+        assert_eq!(
+            TextProcessor::new("flower\nflow\nflight").longest_common_prefix(),
+            "fl"
+        );
+        assert_eq!(TextProcessor::new("dog\ncat").longest_common_prefix(), "");
+    }
+
+    #[test]
+    fn test_word_frequencies() {
+        let processor = TextProcessor::new("the cat\nthe dog\nthe end");
+        let frequencies = processor.word_frequencies();
+        assert_eq!(frequencies["the"], 3);
+        assert_eq!(frequencies["cat"], 1);
+        assert_eq!(frequencies.len(), 4);
+    }
+
+    #[test]
+    fn test_utf8_stats_counts_bytes_and_chars() {
+        assert_eq!(TextProcessor::new("héllo wörld").utf8_stats(), (13, 11, 0));
+    }
+}
+
+mod exercise3 {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_push_until_full() {
+        let mut buffer = RingBuffer::with_capacity(3);
+        assert!(buffer.is_empty());
+        buffer.push(1).unwrap();
+        buffer.push(2).unwrap();
+        buffer.push(3).unwrap();
+        assert!(buffer.is_full());
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.push(4), Err(4));
+    }
+
+    #[test]
+    fn test_pop_is_fifo_and_wraps_around() {
+        let mut buffer = RingBuffer::with_capacity(2);
+        buffer.push('a').unwrap();
+        buffer.push('b').unwrap();
+        assert_eq!(buffer.pop(), Some('a'));
+        buffer.push('c').unwrap();
+        assert_eq!(buffer.pop(), Some('b'));
+        assert_eq!(buffer.pop(), Some('c'));
+        assert_eq!(buffer.pop(), None);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_iterator_drains_in_order() {
+        let mut buffer = RingBuffer::with_capacity(3);
+        for i in 1..=3 {
+            buffer.push(i).unwrap();
+        }
+        buffer.pop();
+        buffer.push(4).unwrap();
+        assert_eq!(buffer.collect::<Vec<_>>(), [2, 3, 4]);
+    }
+
+    #[test]
+    fn test_overwrite_mode_evicts_the_other_end() {
+        let mut buffer = RingBuffer::with_capacity(3).overwriting();
+        for i in 1..=5 {
+            assert_eq!(buffer.push(i), Ok(()));
+        }
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [3, 4, 5]);
+        assert_eq!(buffer.push_front(0), Ok(()));
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), [0, 3, 4]);
+
+        let mut empty = RingBuffer::with_capacity(0).overwriting();
+        assert_eq!(empty.push('x'), Err('x'));
+    }
+
+    #[test]
+    fn test_deque_ends_and_indexing() {
+        let mut buffer = RingBuffer::with_capacity(4);
+        buffer.push(2).unwrap();
+        buffer.push_front(1).unwrap();
+        buffer.push(3).unwrap();
+        buffer.push_front(0).unwrap();
+        assert_eq!(buffer.push_front(-1), Err(-1));
+        assert_eq!((buffer[0], buffer[3]), (0, 3));
+        buffer[1] = 10;
+        assert_eq!(buffer.get(1), Some(&10));
+        assert_eq!(buffer.get(4), None);
+        assert_eq!(buffer.pop_back(), Some(3));
+        assert_eq!(buffer.pop(), Some(0));
+        assert_eq!(format!("{:?}", buffer), "[10, 2]");
+        assert_eq!(buffer.rev().collect::<Vec<_>>(), [2, 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_index_past_the_end_panics() {
+        let mut buffer = RingBuffer::with_capacity(4);
+        buffer.push(1).unwrap();
+        let _ = buffer[1];
+    }
+
+    #[test]
+    fn test_borrowing_iterators_and_slices() {
+        let mut buffer = RingBuffer::with_capacity(4);
+        for i in 0..4 {
+            buffer.push(i).unwrap();
+        }
+        buffer.pop();
+        buffer.pop();
+        buffer.push(4).unwrap();
+        // 2 and 3 at the end of the storage, 4 wrapped around to the start
+        assert_eq!(
+            buffer.as_slices(),
+            (&[Some(2), Some(3)][..], &[Some(4)][..])
+        );
+        for value in buffer.iter_mut() {
+            *value *= 10;
+        }
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), [&20, &30, &40]);
+        assert_eq!(buffer.len(), 3);
+    }
+
+    #[test]
+    fn test_drain_closes_the_gap() {
+        let mut buffer = RingBuffer::with_capacity(5);
+        for c in "abcde".chars() {
+            buffer.push(c).unwrap();
+        }
+        buffer.pop();
+        buffer.push('f').unwrap();
+        assert_eq!(buffer.drain(1..3).collect::<String>(), "cd");
+        assert_eq!(buffer.iter().collect::<String>(), "bef");
+
+        // Elements not taken are still removed
+        buffer.drain(..=1);
+        assert_eq!(buffer.iter().collect::<String>(), "f");
+        buffer.push('g').unwrap();
+        assert_eq!(buffer.drain(..).collect::<String>(), "fg");
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_array_buffer_has_no_heap() {
+        let mut buffer = ArrayRingBuffer::<u8, 16>::new();
+        assert!(std::mem::size_of_val(&buffer) >= 16 * std::mem::size_of::<Option<u8>>());
+        assert_eq!(buffer.capacity(), 16);
+        for i in 0..20 {
+            let _ = buffer.push(i);
+        }
+        assert!(buffer.is_full());
+        assert_eq!(buffer.drain(14..).collect::<Vec<_>>(), [14, 15]);
+        assert_eq!(buffer.pop_back(), Some(13));
+    }
+
+    #[derive(Debug, Clone)]
+    enum Op {
+        Push(u8),
+        PushFront(u8),
+        Pop,
+        PopBack,
+        Set(usize, u8),
+        Drain(usize, usize),
+    }
+
+    fn op() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            any::<u8>().prop_map(Op::Push),
+            any::<u8>().prop_map(Op::PushFront),
+            Just(Op::Pop),
+            Just(Op::PopBack),
+            (0..8usize, any::<u8>()).prop_map(|(index, value)| Op::Set(index, value)),
+            (0..8usize, 0..8usize).prop_map(|(a, b)| Op::Drain(a.min(b), a.max(b))),
+        ]
+    }
+
+    // Runs `ops` on `buffer` and on a VecDeque held to the same capacity,
+    // checking that they agree after every step
+    fn check_against_deque<S: Slots<u8>>(
+        mut buffer: RingBuffer<u8, S>,
+        overwrite: bool,
+        ops: &[Op],
+    ) -> Result<(), TestCaseError> {
+        let capacity = buffer.capacity();
+        let mut model = VecDeque::new();
+        for op in ops {
+            let full = model.len() == capacity;
+            let fits = !full || (overwrite && capacity > 0);
+            match *op {
+                Op::Push(value) => {
+                    let expected = if fits {
+                        if full {
+                            model.pop_front();
+                        }
+                        model.push_back(value);
+                        Ok(())
+                    } else {
+                        Err(value)
+                    };
+                    prop_assert_eq!(buffer.push(value), expected);
+                }
+                Op::PushFront(value) => {
+                    let expected = if fits {
+                        if full {
+                            model.pop_back();
+                        }
+                        model.push_front(value);
+                        Ok(())
+                    } else {
+                        Err(value)
+                    };
+                    prop_assert_eq!(buffer.push_front(value), expected);
+                }
+                Op::Pop => prop_assert_eq!(buffer.pop(), model.pop_front()),
+                Op::PopBack => prop_assert_eq!(buffer.pop_back(), model.pop_back()),
+                Op::Set(index, value) => {
+                    if index < model.len() {
+                        buffer[index] = value;
+                        model[index] = value;
+                    }
+                    prop_assert_eq!(buffer.get(index), model.get(index));
+                }
+                Op::Drain(start, end) => {
+                    let end = end.min(model.len());
+                    let start = start.min(end);
+                    prop_assert!(buffer.drain(start..end).eq(model.drain(start..end)));
+                }
+            }
+
+            prop_assert_eq!(buffer.len(), model.len());
+            prop_assert_eq!(buffer.is_full(), model.len() == capacity);
+            prop_assert!(buffer.iter().eq(model.iter()));
+            let (front, back) = buffer.as_slices();
+            let slots: Vec<Option<u8>> = front.iter().chain(back).copied().collect();
+            let expected: Vec<Option<u8>> = model.iter().copied().map(Some).collect();
+            prop_assert_eq!(slots, expected);
+        }
+        prop_assert!(buffer.eq(model));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_vec_deque(
+            capacity in 0..6usize,
+            overwrite in any::<bool>(),
+            ops in prop::collection::vec(op(), 0..40),
+        ) {
+            let buffer = RingBuffer::with_capacity(capacity);
+            let buffer = if overwrite { buffer.overwriting() } else { buffer };
+            check_against_deque(buffer, overwrite, &ops)?;
+        }
+
+        #[test]
+        fn test_array_buffer_matches_vec_deque(
+            overwrite in any::<bool>(),
+            ops in prop::collection::vec(op(), 0..40),
+        ) {
+            let buffer = ArrayRingBuffer::<u8, 4>::new();
+            let buffer = if overwrite { buffer.overwriting() } else { buffer };
+            check_against_deque(buffer, overwrite, &ops)?;
+        }
+    }
+}
+
+mod exercise4 {
+    use super::*;
+
+    fn connection_error() -> DatabaseError {
+        DatabaseError::ConnectionError {
+            source: std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "refused"),
+            connection_string: "postgres://localhost".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_display_mentions_connection_string() {
+        assert!(connection_error()
+            .to_string()
+            .contains("postgres://localhost"));
+    }
+
+    #[test]
+    fn test_source_exposes_wrapped_error() {
+        let err = connection_error();
+        assert_eq!(err.source().unwrap().to_string(), "refused");
+        assert!(DatabaseError::ValidationError("bad".into())
+            .source()
+            .is_none());
+    }
+
+    #[test]
+    fn test_error_chain_starts_with_self() {
+        let err = connection_error();
+        let chain = err.error_chain();
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[0].to_string(), err.to_string());
+        assert_eq!(chain[1].to_string(), "refused");
+    }
+
+    #[test]
+    fn test_add_context_shows_in_display() {
+        let err = connection_error().add_context("loading users");
+        assert!(err.to_string().contains("loading users"));
+    }
+}
+
+mod exercise5 {
+    use super::*;
+    use proptest::prelude::*;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut cache = CacheMap::with_capacity(2);
+        assert_eq!(cache.insert("a", 1), None);
+        assert_eq!(cache.insert("a", 2), Some(1));
+        assert_eq!(cache.get(&"a"), Some(&2));
+        assert_eq!(cache.get(&"b"), None);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = CacheMap::with_capacity(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        cache.get(&"a");
+        cache.insert("c", 3);
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.get(&"a"), Some(&1));
+        assert_eq!(cache.get(&"c"), Some(&3));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut cache = CacheMap::with_capacity(2);
+        cache.insert(1, "one");
+        assert_eq!(cache.remove(&1), Some("one"));
+        assert_eq!(cache.remove(&1), None);
+        assert_eq!(cache.get(&1), None);
+    }
+
+    #[test]
+    fn test_items_by_recent_access() {
+        let mut cache = CacheMap::with_capacity(3);
+        cache.insert(1, 'a');
+        cache.insert(2, 'b');
+        cache.insert(3, 'c');
+        cache.get(&1);
+        assert_eq!(
+            cache.items_by_recent_access(),
+            [(&1, &'a'), (&3, &'c'), (&2, &'b')]
+        );
+    }
+
+    #[test]
+    fn test_lfu_evicts_least_frequently_used() {
+        let mut cache = CacheMap::with_policy(3, Lfu::default());
+        cache.insert('a', 1);
+        cache.insert('b', 2);
+        cache.insert('c', 3);
+        cache.get(&'a');
+        cache.get(&'a');
+        cache.get(&'c');
+        // b has one use
+        cache.insert('d', 4);
+        assert_eq!(cache.get(&'b'), None);
+        // d has one use; c has two but was used less recently than a
+        assert_eq!(cache.items_by_rank(), [(&'a', &1), (&'c', &3), (&'d', &4)]);
+        cache.insert('e', 5);
+        assert_eq!(cache.items_by_rank(), [(&'a', &1), (&'c', &3), (&'e', &5)]);
+    }
+
+    #[test]
+    fn test_entries_expire_by_the_clock() {
+        let start = Instant::now();
+        let now = Rc::new(Cell::new(start));
+        let clock = Rc::clone(&now);
+        let mut cache = CacheMap::with_capacity(4)
+            .with_ttl(Duration::from_secs(10))
+            .with_clock(move || clock.get());
+        cache.insert("short", 1);
+        cache.insert_with_ttl("shorter", 2, Duration::from_secs(5));
+        now.set(start + Duration::from_secs(5));
+        assert_eq!(cache.get(&"shorter"), None);
+        assert_eq!(cache.get(&"short"), Some(&1));
+        // Inserting again starts a new time to live
+        cache.insert("short", 3);
+        cache.insert("other", 4);
+        now.set(start + Duration::from_secs(12));
+        assert_eq!(
+            cache.items_by_recent_access(),
+            [(&"other", &4), (&"short", &3)]
+        );
+        now.set(start + Duration::from_secs(15));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.purge_expired(), 2);
+        assert!(cache.is_empty());
+        assert_eq!(cache.stats().expirations, 3);
+    }
+
+    #[test]
+    fn test_full_cache_drops_expired_entries_first() {
+        let start = Instant::now();
+        let now = Rc::new(Cell::new(start));
+        let clock = Rc::clone(&now);
+        let mut cache = CacheMap::with_capacity(2).with_clock(move || clock.get());
+        cache.insert("old", 1);
+        cache.insert_with_ttl("brief", 2, Duration::from_secs(5));
+        now.set(start + Duration::from_secs(5));
+        // "old" is least recently used, but "brief" has expired
+        cache.insert("new", 3);
+        assert_eq!(cache.items_by_recent_access(), [(&"new", &3), (&"old", &1)]);
+        assert_eq!(cache.stats().expirations, 1);
+        assert_eq!(cache.stats().evictions, 0);
+    }
+
+    #[test]
+    fn test_stats_and_eviction_callback() {
+        let evicted = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&evicted);
+        let mut cache = CacheMap::with_capacity(2)
+            .on_evict(move |key, value, reason| log.borrow_mut().push((key, value, reason)));
+        cache.insert(1, "one");
+        cache.insert(2, "two");
+        cache.get(&1);
+        cache.get(&3);
+        cache.insert(3, "three");
+        assert_eq!(cache.remove(&3), Some("three"));
+        assert_eq!(*evicted.borrow(), [(2, "two", Eviction::Capacity)]);
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 1,
+                evictions: 1,
+                expirations: 0
+            }
+        );
+        assert_eq!(cache.stats().hit_rate(), 0.5);
+        assert_eq!(CacheStats::default().hit_rate(), 0.0);
+
+        let mut empty = CacheMap::with_capacity(0);
+        assert_eq!(empty.insert('x', 1), None);
+        assert_eq!(empty.get(&'x'), None);
+        assert_eq!(empty.stats().evictions, 1);
+    }
+
+    #[test]
+    fn test_slots_are_reused() {
+        let mut cache = CacheMap::with_capacity(3);
+        for i in 0..1_000 {
+            cache.insert(i, i);
+            if i % 2 == 0 {
+                cache.remove(&i);
+            }
+        }
+        assert_eq!(cache.len(), 3);
+        assert!(cache.entries.len() <= 3);
+        assert_eq!(
+            cache.items_by_recent_access(),
+            [(&999, &999), (&997, &997), (&995, &995)]
+        );
+    }
+
+    #[test]
+    fn test_benchmark_reports_each_cache() {
+        let results = benchmark_caches(50, 1_000);
+        let names: Vec<&str> = results.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["VecDeque LRU", "CacheMap LRU", "CacheMap LFU"]);
+    }
+
+    #[derive(Debug, Clone)]
+    enum Op {
+        Insert(u8, u32),
+        Get(u8),
+        Remove(u8),
+    }
+
+    fn op() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            (0..8u8, any::<u32>()).prop_map(|(key, value)| Op::Insert(key, value)),
+            (0..8u8).prop_map(Op::Get),
+            (0..8u8).prop_map(Op::Remove),
+        ]
+    }
+
+    proptest! {
+        // The O(1) LRU evicts exactly what the VecDeque version does
+        #[test]
+        fn test_lru_matches_deque_cache(
+            capacity in 0..5usize,
+            ops in prop::collection::vec(op(), 0..60),
+        ) {
+            let mut cache = CacheMap::with_capacity(capacity);
+            let mut model = DequeCache::with_capacity(capacity);
+            for op in ops {
+                match op {
+                    Op::Insert(key, value) => {
+                        prop_assert_eq!(cache.insert(key, value), model.insert(key, value))
+                    }
+                    Op::Get(key) => prop_assert_eq!(cache.get(&key), model.get(&key)),
+                    Op::Remove(key) => prop_assert_eq!(cache.remove(&key), model.remove(&key)),
+                }
+                prop_assert_eq!(cache.items_by_recent_access(), model.items_by_recent_access());
+            }
+        }
+    }
+}
@@ -0,0 +1,1292 @@
+// Module 4: Reference Solutions
+// Worked answers to the exercises in problems.rs, compiled only with the
+// `solutions` feature. Try solving the exercises yourself first!
+// The types and signatures mirror problems.rs, so the hidden grading suite
+// runs unchanged against these answers (see the tests at the end).
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use std::hint::black_box;
+use std::iter::{Chain, Flatten};
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+use std::slice;
+use std::time::{Duration, Instant};
+
+pub fn run_exercises() {
+    println!("Module 4 Reference Solutions - Collections and Error Handling");
+    println!("========================================================\n");
+
+    exercise1();
+    exercise2();
+    exercise3();
+    exercise4();
+    exercise5();
+}
+
+// Solution entry points in order, used by `rustpath run <module> --solution`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Exercise 1 Solution: Collection Performance Analysis
+
+// `s = s + ...` is spelled out on purpose, to time it against `push_str`
+#[allow(clippy::assign_op_pattern)]
+fn benchmark_collections(size: usize) -> Vec<(String, std::time::Duration)> {
+    let mut results = Vec::new();
+
+    // 1. Appending: both are amortised O(1)
+    let start = Instant::now();
+    let mut vec = Vec::with_capacity(size);
+    for i in 0..size {
+        vec.push(i);
+    }
+    results.push(("Vec push".to_string(), start.elapsed()));
+
+    let start = Instant::now();
+    let mut deque = VecDeque::with_capacity(size);
+    for i in 0..size {
+        deque.push_back(i);
+    }
+    results.push(("VecDeque push_back".to_string(), start.elapsed()));
+
+    // 2. Prepending: Vec shifts every element, VecDeque just moves its head
+    let start = Instant::now();
+    let mut vec = Vec::new();
+    for i in 0..size {
+        vec.insert(0, i);
+    }
+    results.push(("Vec insert(0, x)".to_string(), start.elapsed()));
+
+    let start = Instant::now();
+    let mut deque = VecDeque::new();
+    for i in 0..size {
+        deque.push_front(i);
+    }
+    results.push(("VecDeque push_front".to_string(), start.elapsed()));
+
+    // 3. Lookup: O(log n) binary search against O(1) hashing
+    let sorted: Vec<usize> = (0..size).collect();
+    let start = Instant::now();
+    let found = (0..size)
+        .filter(|i| sorted.binary_search(i).is_ok())
+        .count();
+    results.push(("Vec binary_search".to_string(), start.elapsed()));
+
+    let map: HashMap<usize, usize> = (0..size).map(|i| (i, i)).collect();
+    let start = Instant::now();
+    let hits = (0..size).filter(|i| map.contains_key(i)).count();
+    results.push(("HashMap lookup".to_string(), start.elapsed()));
+    assert_eq!(found, hits);
+
+    // 4. Concatenation: push_str reuses one buffer, `+` moves it along
+    let start = Instant::now();
+    let mut s = String::new();
+    for _ in 0..size {
+        s.push_str("xy");
+    }
+    results.push(("String push_str".to_string(), start.elapsed()));
+
+    let start = Instant::now();
+    let mut s = String::new();
+    for _ in 0..size {
+        s = s + "xy";
+    }
+    results.push(("String + &str".to_string(), start.elapsed()));
+
+    results
+}
+
+struct StringBuilder {
+    buffer: String,
+    total_len: usize,
+}
+
+impl StringBuilder {
+    fn new() -> Self {
+        StringBuilder {
+            buffer: String::with_capacity(64),
+            total_len: 0,
+        }
+    }
+
+    fn append(&mut self, s: &str) {
+        self.total_len += s.len();
+        // Grow once to the exact size instead of letting push_str guess
+        self.buffer.reserve(s.len());
+        self.buffer.push_str(s);
+    }
+
+    fn finish(self) -> String {
+        debug_assert_eq!(self.buffer.len(), self.total_len);
+        self.buffer
+    }
+}
+
+fn exercise1() {
+    println!("Exercise 1: Collection Performance Analysis");
+    println!("--------------------------------------");
+
+    let results = benchmark_collections(10_000);
+    for (name, duration) in results {
+        println!("{}: {:?}", name, duration);
+    }
+}
+
+// Exercise 2 Solution: Advanced String Processing
+
+struct TextProcessor {
+    content: String,
+    line_offsets: Vec<usize>,
+    word_count: HashMap<String, usize>,
+}
+
+impl TextProcessor {
+    fn new(text: &str) -> Self {
+        let mut line_offsets = vec![0];
+        for (i, c) in text.char_indices() {
+            if c == '\n' {
+                line_offsets.push(i + 1);
+            }
+        }
+
+        let mut word_count = HashMap::new();
+        for word in text.split_whitespace() {
+            *word_count.entry(word.to_string()).or_insert(0) += 1;
+        }
+
+        TextProcessor {
+            content: text.to_string(),
+            line_offsets,
+            word_count,
+        }
+    }
+
+    fn get_line(&self, line_number: usize) -> Option<&str> {
+        let start = *self.line_offsets.get(line_number)?;
+        let end = self
+            .line_offsets
+            .get(line_number + 1)
+            .map_or(self.content.len(), |next| next - 1);
+        Some(self.content[start..end].trim_end_matches('\r'))
+    }
+
+    fn longest_common_prefix(&self) -> &str {
+        let mut lines = (0..self.line_offsets.len()).filter_map(|i| self.get_line(i));
+        let Some(mut prefix) = lines.next() else {
+            return "";
+        };
+        for line in lines {
+            // Compare by char so the prefix never ends inside a code point
+            let len = prefix
+                .char_indices()
+                .zip(line.chars())
+                .find(|((_, a), b)| a != b)
+                .map_or(prefix.len().min(line.len()), |((i, _), _)| i);
+            prefix = &prefix[..len];
+        }
+        prefix
+    }
+
+    fn word_frequencies(&self) -> &HashMap<String, usize> {
+        &self.word_count
+    }
+
+    // A `String` is always valid UTF-8, so there are never invalid sequences
+    fn utf8_stats(&self) -> (usize, usize, usize) {
+        (self.content.len(), self.content.chars().count(), 0)
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Advanced String Processing");
+    println!("----------------------------------");
+
+    let text = "Hello, world!\nRust is great!\nHello, Rust!";
+    let processor = TextProcessor::new(text);
+    println!("Line 1: {:?}", processor.get_line(0));
+    println!("Common prefix: {}", processor.longest_common_prefix());
+    println!("Word frequencies: {:?}", processor.word_frequencies());
+    println!("UTF-8 stats: {:?}", processor.utf8_stats());
+}
+
+// Exercise 3 Solution: Custom Collection Implementation
+
+// Where a ring buffer keeps its slots: a Vec or an array of them
+trait Slots<T>: AsRef<[Option<T>]> + AsMut<[Option<T>]> {}
+
+impl<T, S: AsRef<[Option<T>]> + AsMut<[Option<T>]>> Slots<T> for S {}
+
+// A fixed-capacity ring buffer with O(1) operations at both ends. The
+// elements fill `size` slots starting at `head`, wrapping around the end of
+// the storage; every other slot is None. Nothing is allocated after
+// construction, and the Option slots keep it all safe code (module 10 has
+// the MaybeUninit version).
+struct RingBuffer<T, S = Vec<Option<T>>> {
+    buffer: S,
+    head: usize,
+    size: usize,
+    overwrite: bool,
+    element: PhantomData<T>,
+}
+
+// The same ring buffer with its N slots inline, so no heap at all
+type ArrayRingBuffer<T, const N: usize> = RingBuffer<T, [Option<T>; N]>;
+
+// Borrowing iterators, front to back: the two runs of slots from
+// `as_slices` with the Option of each slot flattened away
+type Iter<'a, T> = Flatten<Chain<slice::Iter<'a, Option<T>>, slice::Iter<'a, Option<T>>>>;
+type IterMut<'a, T> = Flatten<Chain<slice::IterMut<'a, Option<T>>, slice::IterMut<'a, Option<T>>>>;
+
+impl<T> RingBuffer<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        RingBuffer::from_slots((0..capacity).map(|_| None).collect())
+    }
+}
+
+impl<T, const N: usize> ArrayRingBuffer<T, N> {
+    fn new() -> Self {
+        RingBuffer::from_slots(std::array::from_fn(|_| None))
+    }
+}
+
+impl<T, S: Slots<T>> RingBuffer<T, S> {
+    fn from_slots(buffer: S) -> Self {
+        RingBuffer {
+            buffer,
+            head: 0,
+            size: 0,
+            overwrite: false,
+            element: PhantomData,
+        }
+    }
+
+    // When full, `push` and `push_front` make room by dropping the element
+    // at the other end instead of handing the value back
+    fn overwriting(mut self) -> Self {
+        self.overwrite = true;
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        self.buffer.as_ref().len()
+    }
+
+    // The slot holding the element at `index`, counting from the front
+    fn slot(&self, index: usize) -> usize {
+        (self.head + index) % self.capacity()
+    }
+
+    // Whether there is room for one more element, after evicting one if
+    // the buffer overwrites
+    fn make_room(&mut self, evict: fn(&mut Self) -> Option<T>) -> bool {
+        if !self.is_full() {
+            return true;
+        }
+        if !self.overwrite || self.capacity() == 0 {
+            return false;
+        }
+        evict(self);
+        true
+    }
+
+    fn push(&mut self, value: T) -> Result<(), T> {
+        if !self.make_room(Self::pop) {
+            return Err(value);
+        }
+        let tail = self.slot(self.size);
+        self.buffer.as_mut()[tail] = Some(value);
+        self.size += 1;
+        Ok(())
+    }
+
+    fn push_front(&mut self, value: T) -> Result<(), T> {
+        if !self.make_room(Self::pop_back) {
+            return Err(value);
+        }
+        self.head = self.slot(self.capacity() - 1);
+        self.buffer.as_mut()[self.head] = Some(value);
+        self.size += 1;
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let value = self.buffer.as_mut()[self.head].take();
+        self.head = self.slot(1);
+        self.size -= 1;
+        value
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.size -= 1;
+        let tail = self.slot(self.size);
+        self.buffer.as_mut()[tail].take()
+    }
+
+    fn is_full(&self) -> bool {
+        self.size == self.capacity()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    fn len(&self) -> usize {
+        self.size
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.size {
+            return None;
+        }
+        self.buffer.as_ref()[self.slot(index)].as_ref()
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.size {
+            return None;
+        }
+        let slot = self.slot(index);
+        self.buffer.as_mut()[slot].as_mut()
+    }
+
+    // The occupied slots as two ranges: from `head` towards the end of the
+    // storage, then the part that wrapped around to the start (often empty)
+    fn ranges(&self) -> (Range<usize>, Range<usize>) {
+        let end = self.head + self.size;
+        let capacity = self.capacity();
+        if end <= capacity {
+            (self.head..end, 0..0)
+        } else {
+            (self.head..capacity, 0..end - capacity)
+        }
+    }
+
+    // The elements in order, as the slots holding them. Every slot in the
+    // two slices is Some.
+    fn as_slices(&self) -> (&[Option<T>], &[Option<T>]) {
+        let (front, back) = self.ranges();
+        let slots = self.buffer.as_ref();
+        (&slots[front], &slots[back])
+    }
+
+    fn as_mut_slices(&mut self) -> (&mut [Option<T>], &mut [Option<T>]) {
+        let (front, back) = self.ranges();
+        // The wrapped part lies before `head`, so one split separates them
+        let (start, end) = self.buffer.as_mut().split_at_mut(front.start);
+        (&mut end[..front.len()], &mut start[back])
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        front.iter().chain(back).flatten()
+    }
+
+    fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        front.iter_mut().chain(back).flatten()
+    }
+
+    // Removes the elements in `range` and yields them in order. They are
+    // rotated to the back first, so the elements after the range close the
+    // gap in place.
+    fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, S> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.size,
+        };
+        assert!(
+            start <= end && end <= self.size,
+            "drain range {}..{} out of bounds for length {}",
+            start,
+            end,
+            self.size
+        );
+
+        // Rotating left by `end - start` is three reversals
+        self.reverse(start, end);
+        self.reverse(end, self.size);
+        self.reverse(start, self.size);
+        self.size -= end - start;
+        Drain {
+            next: self.size,
+            end: self.size + end - start,
+            buffer: self,
+        }
+    }
+
+    // Reverses the order of the elements from `start` up to `end`
+    fn reverse(&mut self, mut start: usize, mut end: usize) {
+        while start + 1 < end {
+            end -= 1;
+            let (a, b) = (self.slot(start), self.slot(end));
+            self.buffer.as_mut().swap(a, b);
+            start += 1;
+        }
+    }
+}
+
+impl<T, S: Slots<T>> Index<usize> for RingBuffer<T, S> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.size, index
+            ),
+        }
+    }
+}
+
+impl<T, S: Slots<T>> IndexMut<usize> for RingBuffer<T, S> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.size;
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                len, index
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Debug, S: Slots<T>> fmt::Debug for RingBuffer<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Iterating the buffer itself consumes it, from either end
+impl<T, S: Slots<T>> Iterator for RingBuffer<T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pop()
+    }
+}
+
+impl<T, S: Slots<T>> DoubleEndedIterator for RingBuffer<T, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.pop_back()
+    }
+}
+
+// The elements removed by `drain`. Until taken they wait in the slots just
+// past the end of the buffer; whatever is left is dropped with the Drain.
+struct Drain<'a, T, S: Slots<T>> {
+    buffer: &'a mut RingBuffer<T, S>,
+    next: usize,
+    end: usize,
+}
+
+impl<T, S: Slots<T>> Iterator for Drain<'_, T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.end {
+            return None;
+        }
+        let slot = self.buffer.slot(self.next);
+        self.next += 1;
+        self.buffer.buffer.as_mut()[slot].take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, S: Slots<T>> ExactSizeIterator for Drain<'_, T, S> {}
+
+impl<T, S: Slots<T>> Drop for Drain<'_, T, S> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Custom Collection Implementation");
+    println!("----------------------------------------");
+
+    let mut buffer = RingBuffer::with_capacity(3);
+    buffer.push(1).unwrap();
+    buffer.push(2).unwrap();
+    buffer.push(3).unwrap();
+    assert!(buffer.push(4).is_err()); // Buffer is full
+    println!("Buffer: {:?}", buffer.collect::<Vec<_>>());
+
+    // Overwriting: the oldest readings make way for new ones
+    let mut recent = RingBuffer::with_capacity(3).overwriting();
+    for reading in [10, 20, 30, 40, 50] {
+        recent.push(reading).unwrap();
+    }
+    println!("Last 3 readings: {:?}", recent);
+    println!("  stored as {:?}", recent.as_slices());
+
+    // Both ends, indexing and borrowing iterators
+    recent.pop_back();
+    recent.push_front(0).unwrap();
+    recent[1] += 1;
+    for reading in recent.iter_mut() {
+        *reading *= 2;
+    }
+    println!(
+        "After editing: {:?} (sum {})",
+        recent,
+        recent.iter().sum::<i32>()
+    );
+
+    // An inline buffer: the slots are part of the value itself
+    let mut inline = ArrayRingBuffer::<char, 8>::new();
+    for c in "ring buffer".chars() {
+        if inline.push(c).is_err() {
+            break;
+        }
+    }
+    let drained: String = inline.drain(2..5).collect();
+    println!(
+        "Drained {:?}, left {:?} ({} bytes, no heap)",
+        drained,
+        inline.iter().collect::<String>(),
+        std::mem::size_of_val(&inline)
+    );
+}
+
+// Exercise 4 Solution: Error Handling Patterns
+
+#[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
+enum DatabaseError {
+    ConnectionError {
+        source: std::io::Error,
+        connection_string: String,
+    },
+    QueryError {
+        source: Box<dyn Error>,
+        query: String,
+        params: Vec<String>,
+    },
+    TransactionError {
+        source: Box<dyn Error>,
+        transaction_id: String,
+    },
+    ValidationError(String),
+}
+
+impl DatabaseError {
+    // The variants have no dedicated context field, so the context is
+    // prepended to the field that describes what was being attempted
+    fn add_context(self, context: &str) -> Self {
+        match self {
+            DatabaseError::ConnectionError {
+                source,
+                connection_string,
+            } => DatabaseError::ConnectionError {
+                source,
+                connection_string: format!("{}: {}", context, connection_string),
+            },
+            DatabaseError::QueryError {
+                source,
+                query,
+                params,
+            } => DatabaseError::QueryError {
+                source,
+                query: format!("{}: {}", context, query),
+                params,
+            },
+            DatabaseError::TransactionError {
+                source,
+                transaction_id,
+            } => DatabaseError::TransactionError {
+                source,
+                transaction_id: format!("{}: {}", context, transaction_id),
+            },
+            DatabaseError::ValidationError(message) => {
+                DatabaseError::ValidationError(format!("{}: {}", context, message))
+            }
+        }
+    }
+
+    fn error_chain(&self) -> Vec<&dyn Error> {
+        let mut chain: Vec<&dyn Error> = vec![self];
+        let mut current = self.source();
+        while let Some(error) = current {
+            chain.push(error);
+            current = error.source();
+        }
+        chain
+    }
+}
+
+// The message describes this error only; the cause is reached via `source`
+impl fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatabaseError::ConnectionError {
+                connection_string, ..
+            } => write!(f, "failed to connect to {}", connection_string),
+            DatabaseError::QueryError { query, params, .. } => {
+                write!(f, "query failed: {} with {:?}", query, params)
+            }
+            DatabaseError::TransactionError { transaction_id, .. } => {
+                write!(f, "transaction {} failed", transaction_id)
+            }
+            DatabaseError::ValidationError(message) => {
+                write!(f, "validation failed: {}", message)
+            }
+        }
+    }
+}
+
+impl Error for DatabaseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DatabaseError::ConnectionError { source, .. } => Some(source),
+            DatabaseError::QueryError { source, .. } => Some(source.as_ref()),
+            DatabaseError::TransactionError { source, .. } => Some(source.as_ref()),
+            DatabaseError::ValidationError(_) => None,
+        }
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Error Handling Patterns");
+    println!("--------------------------------");
+
+    let err = DatabaseError::ConnectionError {
+        source: std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "Connection refused"),
+        connection_string: "postgres://localhost".to_string(),
+    };
+    println!("Error: {}", err);
+    println!("Error chain: {:?}", err.error_chain());
+}
+
+// Exercise 5 Solution: Advanced Collection Patterns
+
+// Doubly linked lists threaded through slot indices: `prev[slot]` and
+// `next[slot]` are the neighbours of `slot` in whichever list holds it. The
+// lists store no data of their own, so one Links can hold several lists.
+#[derive(Debug, Default)]
+struct Links {
+    prev: Vec<Option<usize>>,
+    next: Vec<Option<usize>>,
+}
+
+// One list in a Links, from its first slot to its last
+#[derive(Debug, Default, Clone, Copy)]
+struct List {
+    head: Option<usize>,
+    tail: Option<usize>,
+}
+
+impl List {
+    fn push_front(&mut self, links: &mut Links, slot: usize) {
+        if slot >= links.next.len() {
+            links.prev.resize(slot + 1, None);
+            links.next.resize(slot + 1, None);
+        }
+        links.prev[slot] = None;
+        links.next[slot] = self.head;
+        match self.head {
+            Some(head) => links.prev[head] = Some(slot),
+            None => self.tail = Some(slot),
+        }
+        self.head = Some(slot);
+    }
+
+    fn unlink(&mut self, links: &mut Links, slot: usize) {
+        let (prev, next) = (links.prev[slot], links.next[slot]);
+        match prev {
+            Some(prev) => links.next[prev] = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => links.prev[next] = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    fn slots(&self, links: &Links) -> Vec<usize> {
+        std::iter::successors(self.head, |&slot| links.next[slot]).collect()
+    }
+}
+
+// Decides which entry a full cache evicts. The cache reports every insert,
+// access and removal by the slot the entry occupies.
+trait CachePolicy {
+    fn inserted(&mut self, slot: usize);
+    fn accessed(&mut self, slot: usize);
+    fn removed(&mut self, slot: usize);
+    // The slot to evict next
+    fn victim(&self) -> Option<usize>;
+    // Every slot, from the one to keep longest to the victim
+    fn ranking(&self) -> Vec<usize>;
+}
+
+// Least recently used: one list, most recent first. Every operation is O(1).
+#[derive(Debug, Default)]
+struct Lru {
+    links: Links,
+    list: List,
+}
+
+impl CachePolicy for Lru {
+    fn inserted(&mut self, slot: usize) {
+        self.list.push_front(&mut self.links, slot);
+    }
+
+    fn accessed(&mut self, slot: usize) {
+        self.list.unlink(&mut self.links, slot);
+        self.list.push_front(&mut self.links, slot);
+    }
+
+    fn removed(&mut self, slot: usize) {
+        self.list.unlink(&mut self.links, slot);
+    }
+
+    fn victim(&self) -> Option<usize> {
+        self.list.tail
+    }
+
+    fn ranking(&self) -> Vec<usize> {
+        self.list.slots(&self.links)
+    }
+}
+
+// Least frequently used, and least recently used among equals: a list per
+// use count, most recent first. The BTreeMap finds the lowest count in
+// O(log n) of the distinct counts; empty lists are removed from it.
+#[derive(Debug, Default)]
+struct Lfu {
+    links: Links,
+    counts: Vec<u64>,
+    buckets: BTreeMap<u64, List>,
+}
+
+impl Lfu {
+    fn enter(&mut self, slot: usize, count: u64) {
+        if slot >= self.counts.len() {
+            self.counts.resize(slot + 1, 0);
+        }
+        self.counts[slot] = count;
+        self.buckets
+            .entry(count)
+            .or_default()
+            .push_front(&mut self.links, slot);
+    }
+
+    fn leave(&mut self, slot: usize) -> u64 {
+        let count = self.counts[slot];
+        if let Some(bucket) = self.buckets.get_mut(&count) {
+            bucket.unlink(&mut self.links, slot);
+            if bucket.is_empty() {
+                self.buckets.remove(&count);
+            }
+        }
+        count
+    }
+}
+
+impl CachePolicy for Lfu {
+    fn inserted(&mut self, slot: usize) {
+        self.enter(slot, 1);
+    }
+
+    fn accessed(&mut self, slot: usize) {
+        let count = self.leave(slot);
+        self.enter(slot, count + 1);
+    }
+
+    fn removed(&mut self, slot: usize) {
+        self.leave(slot);
+    }
+
+    fn victim(&self) -> Option<usize> {
+        self.buckets.values().next()?.tail
+    }
+
+    fn ranking(&self) -> Vec<usize> {
+        self.buckets
+            .values()
+            .rev()
+            .flat_map(|bucket| bucket.slots(&self.links))
+            .collect()
+    }
+}
+
+// Why an entry left the cache without being removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Eviction {
+    // Made room for a new entry
+    Capacity,
+    // Outlived its time to live
+    Expired,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct CacheStats {
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+    expirations: u64,
+}
+
+impl CacheStats {
+    fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+type EvictionCallback<K, V> = Box<dyn FnMut(K, V, Eviction)>;
+
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    expires: Option<Instant>,
+}
+
+// A cache with a size limit, an eviction policy and optional expiry. The
+// entries live in an arena: `slots` maps each key to its index in
+// `entries`, and the indices of removed entries are reused from `free`.
+// Expired entries count towards `len` until a lookup, `purge_expired` or an
+// insert into a full cache finds them; that insert drops every expired entry
+// before it evicts a live one, which costs O(n) while any entry can expire.
+struct CacheMap<K, V, P = Lru> {
+    slots: HashMap<K, usize>,
+    entries: Vec<Option<Entry<K, V>>>,
+    free: Vec<usize>,
+    policy: P,
+    capacity: usize,
+    ttl: Option<Duration>,
+    clock: Box<dyn Fn() -> Instant>,
+    on_evict: Option<EvictionCallback<K, V>>,
+    stats: CacheStats,
+    // How many entries have an expiry time, so that caches without one never
+    // scan for expired entries
+    expiring: usize,
+}
+
+impl<K: Clone + Eq + Hash, V> CacheMap<K, V> {
+    fn with_capacity(capacity: usize) -> Self {
+        CacheMap::with_policy(capacity, Lru::default())
+    }
+
+    // Get items ordered by most recently used
+    fn items_by_recent_access(&self) -> Vec<(&K, &V)> {
+        self.items_by_rank()
+    }
+}
+
+impl<K: Clone + Eq + Hash, V, P: CachePolicy> CacheMap<K, V, P> {
+    fn with_policy(capacity: usize, policy: P) -> Self {
+        CacheMap {
+            slots: HashMap::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            policy,
+            capacity,
+            ttl: None,
+            clock: Box::new(Instant::now),
+            on_evict: None,
+            stats: CacheStats::default(),
+            expiring: 0,
+        }
+    }
+
+    // How long entries from `insert` live
+    fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    // Where expiry times come from, instead of `Instant::now`
+    fn with_clock(mut self, clock: impl Fn() -> Instant + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    // Called with every entry that is evicted or expires
+    fn on_evict(mut self, callback: impl FnMut(K, V, Eviction) + 'static) -> Self {
+        self.on_evict = Some(Box::new(callback));
+        self
+    }
+
+    fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let expires = self.ttl.map(|ttl| (self.clock)() + ttl);
+        self.insert_until(key, value, expires)
+    }
+
+    fn insert_with_ttl(&mut self, key: K, value: V, ttl: Duration) -> Option<V> {
+        let expires = (self.clock)() + ttl;
+        self.insert_until(key, value, Some(expires))
+    }
+
+    fn insert_until(&mut self, key: K, value: V, expires: Option<Instant>) -> Option<V> {
+        if let Some(&slot) = self.slots.get(&key) {
+            if self.is_expired(slot) {
+                self.evict(slot, Eviction::Expired);
+            } else {
+                self.policy.accessed(slot);
+                let entry = self.entry_mut(slot);
+                let was_expiring = entry.expires.is_some();
+                entry.expires = expires;
+                let old = mem::replace(&mut entry.value, value);
+                self.expiring = self.expiring - was_expiring as usize + expires.is_some() as usize;
+                return Some(old);
+            }
+        }
+
+        if self.capacity == 0 {
+            self.stats.evictions += 1;
+            if let Some(on_evict) = &mut self.on_evict {
+                on_evict(key, value, Eviction::Capacity);
+            }
+            return None;
+        }
+        if self.len() >= self.capacity && self.expiring > 0 {
+            self.purge_expired();
+        }
+        if self.len() >= self.capacity {
+            if let Some(victim) = self.policy.victim() {
+                self.evict(victim, Eviction::Capacity);
+            }
+        }
+
+        let entry = Some(Entry {
+            key: key.clone(),
+            value,
+            expires,
+        });
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.entries[slot] = entry;
+                slot
+            }
+            None => {
+                self.entries.push(entry);
+                self.entries.len() - 1
+            }
+        };
+        self.slots.insert(key, slot);
+        self.policy.inserted(slot);
+        self.expiring += expires.is_some() as usize;
+        None
+    }
+
+    fn get(&mut self, key: &K) -> Option<&V> {
+        let Some(&slot) = self.slots.get(key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        if self.is_expired(slot) {
+            self.evict(slot, Eviction::Expired);
+            self.stats.misses += 1;
+            return None;
+        }
+        self.stats.hits += 1;
+        self.policy.accessed(slot);
+        Some(&self.entry_mut(slot).value)
+    }
+
+    // Removing an entry is not an eviction, so the callback is not called,
+    // unless the entry turns out to have expired
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let slot = *self.slots.get(key)?;
+        if self.is_expired(slot) {
+            self.evict(slot, Eviction::Expired);
+            return None;
+        }
+        Some(self.take(slot).value)
+    }
+
+    // Drops every expired entry, returning how many there were. O(n).
+    fn purge_expired(&mut self) -> usize {
+        let expired: Vec<usize> = (0..self.entries.len())
+            .filter(|&slot| self.is_expired(slot))
+            .collect();
+        for &slot in &expired {
+            self.evict(slot, Eviction::Expired);
+        }
+        expired.len()
+    }
+
+    // The live entries, from the one the policy would keep longest to the
+    // one it would evict next
+    fn items_by_rank(&self) -> Vec<(&K, &V)> {
+        self.policy
+            .ranking()
+            .into_iter()
+            .filter(|&slot| !self.is_expired(slot))
+            .filter_map(|slot| self.entries[slot].as_ref())
+            .map(|entry| (&entry.key, &entry.value))
+            .collect()
+    }
+
+    fn entry_mut(&mut self, slot: usize) -> &mut Entry<K, V> {
+        self.entries[slot]
+            .as_mut()
+            .expect("`slots` only holds occupied slots")
+    }
+
+    fn is_expired(&self, slot: usize) -> bool {
+        self.entries[slot]
+            .as_ref()
+            .and_then(|entry| entry.expires)
+            .is_some_and(|expires| (self.clock)() >= expires)
+    }
+
+    fn take(&mut self, slot: usize) -> Entry<K, V> {
+        let entry = self.entries[slot]
+            .take()
+            .expect("`slots` only holds occupied slots");
+        self.slots.remove(&entry.key);
+        self.policy.removed(slot);
+        self.free.push(slot);
+        self.expiring -= entry.expires.is_some() as usize;
+        entry
+    }
+
+    fn evict(&mut self, slot: usize, reason: Eviction) {
+        let Entry { key, value, .. } = self.take(slot);
+        match reason {
+            Eviction::Capacity => self.stats.evictions += 1,
+            Eviction::Expired => self.stats.expirations += 1,
+        }
+        if let Some(on_evict) = &mut self.on_evict {
+            on_evict(key, value, reason);
+        }
+    }
+}
+
+// An LRU cache that keeps recency in a VecDeque, least recent first. Simple,
+// but every hit scans the deque; `benchmark_caches` compares it with
+// CacheMap.
+struct DequeCache<K, V> {
+    data: HashMap<K, V>,
+    access_order: VecDeque<K>,
+    capacity: usize,
+}
+
+impl<K: Clone + Eq + Hash, V> DequeCache<K, V> {
+    fn with_capacity(capacity: usize) -> Self {
+        DequeCache {
+            data: HashMap::with_capacity(capacity),
+            access_order: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.data.contains_key(&key) {
+            self.touch(&key);
+        } else {
+            if self.data.len() >= self.capacity {
+                if let Some(oldest) = self.access_order.pop_front() {
+                    self.data.remove(&oldest);
+                }
+            }
+            if self.capacity == 0 {
+                return None;
+            }
+            self.access_order.push_back(key.clone());
+        }
+        self.data.insert(key, value)
+    }
+
+    fn get(&mut self, key: &K) -> Option<&V> {
+        if self.data.contains_key(key) {
+            self.touch(key);
+        }
+        self.data.get(key)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let value = self.data.remove(key)?;
+        self.access_order.retain(|k| k != key);
+        Some(value)
+    }
+
+    fn items_by_recent_access(&self) -> Vec<(&K, &V)> {
+        self.access_order
+            .iter()
+            .rev()
+            .filter_map(|key| self.data.get_key_value(key))
+            .collect()
+    }
+
+    // Moves `key` to the most recently used end
+    fn touch(&mut self, key: &K) {
+        if let Some(pos) = self.access_order.iter().position(|k| k == key) {
+            if let Some(k) = self.access_order.remove(pos) {
+                self.access_order.push_back(k);
+            }
+        }
+    }
+}
+
+// The same mix of lookups and inserts for every cache: one operation in four
+// is an insert, and keys are drawn from twice the capacity, so plenty of
+// lookups miss and inserts evict
+fn workload(capacity: usize, operations: usize) -> impl Iterator<Item = (bool, u64)> {
+    // xorshift64: cheap and the same on every run
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let keys = 2 * capacity as u64 + 1;
+    (0..operations).map(move |_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state.is_multiple_of(4), (state >> 2) % keys)
+    })
+}
+
+// Runs the workload against `cache`, given how to insert into it and how to
+// look a key up, and returns how long it took
+fn time_workload<C>(
+    mut cache: C,
+    capacity: usize,
+    operations: usize,
+    insert: impl Fn(&mut C, u64),
+    get: impl Fn(&mut C, u64),
+) -> Duration {
+    let start = Instant::now();
+    for (is_insert, key) in workload(capacity, operations) {
+        if is_insert {
+            insert(&mut cache, key);
+        } else {
+            get(&mut cache, key);
+        }
+    }
+    start.elapsed()
+}
+
+// Times the workload against the VecDeque cache and both CacheMap policies.
+// Run a release build for meaningful numbers.
+fn benchmark_caches(capacity: usize, operations: usize) -> Vec<(String, Duration)> {
+    let deque = time_workload(
+        DequeCache::with_capacity(capacity),
+        capacity,
+        operations,
+        |cache, key| {
+            cache.insert(key, key);
+        },
+        |cache, key| {
+            black_box(cache.get(&key));
+        },
+    );
+    let lru = time_workload(
+        CacheMap::with_capacity(capacity),
+        capacity,
+        operations,
+        |cache, key| {
+            cache.insert(key, key);
+        },
+        |cache, key| {
+            black_box(cache.get(&key));
+        },
+    );
+    let lfu = time_workload(
+        CacheMap::with_policy(capacity, Lfu::default()),
+        capacity,
+        operations,
+        |cache, key| {
+            cache.insert(key, key);
+        },
+        |cache, key| {
+            black_box(cache.get(&key));
+        },
+    );
+
+    vec![
+        ("VecDeque LRU".to_string(), deque),
+        ("CacheMap LRU".to_string(), lru),
+        ("CacheMap LFU".to_string(), lfu),
+    ]
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Advanced Collection Patterns");
+    println!("---------------------------------");
+
+    let mut cache = CacheMap::with_capacity(2);
+    cache.insert("a", 1);
+    cache.insert("b", 2);
+    cache.insert("c", 3); // Evicts "a"
+    assert!(cache.get(&"a").is_none());
+    println!("Cache items: {:?}", cache.items_by_recent_access());
+
+    // LFU keeps the popular entry, and the callback sees what goes
+    let mut cache = CacheMap::with_policy(2, Lfu::default())
+        .on_evict(|key, value, reason| println!("  {:?}: {} = {}", reason, key, value));
+    cache.insert("home", "/");
+    cache.insert("about", "/about");
+    cache.get(&"home");
+    cache.insert("blog", "/blog"); // Evicts "about", used once
+    println!("LFU items: {:?}", cache.items_by_rank());
+
+    // Expiry with a short time to live
+    let mut sessions = CacheMap::with_capacity(10).with_ttl(Duration::from_millis(20));
+    sessions.insert(7, "ferris");
+    println!("Session 7: {:?}", sessions.get(&7));
+    std::thread::sleep(Duration::from_millis(25));
+    println!("Session 7 later: {:?}", sessions.get(&7));
+    println!("Stats: {:?}", sessions.stats());
+
+    for (name, elapsed) in benchmark_caches(1_000, 20_000) {
+        println!("{:>14}: {:?} for 20000 operations", name, elapsed);
+    }
+}
+
+// The grading suite from problems/grading.rs, run against these answers.
+// With the `grading` feature on too, problems.rs loads the same file for the
+// stubs; the two copies are meant to be separate modules.
+#[cfg(test)]
+#[path = "problems/grading.rs"]
+#[allow(clippy::duplicate_mod)]
+mod grading;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_run_exercises() {
+        super::run_exercises();
+    }
+}
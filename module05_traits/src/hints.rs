@@ -0,0 +1,50 @@
+// Module 5: Hints
+// Progressive hints for the exercises in problems.rs, one list per exercise,
+// shown by `rustpath run 5 --exercise <n> --hint <level>`. Level 1 is the
+// exercise's "This exercise demonstrates" list; these are levels 2 and up,
+// each giving away a little more than the one before.
+
+pub const HINTS: [&[&str]; 5] = [
+    // Exercise 1: Generic Data Structure with Traits
+    &[
+        "`add` and `count` are thin wrappers around the inner `Vec`: `push` and `len`.",
+        "`mean` can only use what `Averageable` promises: `T::zero()`, `+`, and multiplying by \
+         an `f64`. There is no division, so multiply by `1.0 / len as f64` instead.",
+        "Return None for an empty collection. Otherwise fold a cloned iterator from \
+         `T::zero()` with `|acc, x| acc + x` and scale the sum.",
+    ],
+    // Exercise 2: Builder Pattern with Generics
+    &[
+        "Every field of RequestBuilder is an Option. `new` sets them all to None, and each \
+         setter takes `self` by value, fills in one field and returns `Self` so calls chain.",
+        "The setters can use struct update syntax: `RequestBuilder { url: Some(url), ..self }`.",
+        "`build` turns missing required fields into errors with `ok_or`: `let method = \
+         self.method.ok_or(\"Method is required\")?;`. The body stays optional.",
+    ],
+    // Exercise 3: Type Conversion Traits
+    &[
+        "The formulas are in the comments above each impl. `f64` has `cos`, `sin`, `sqrt` and \
+         `atan2` as methods.",
+        "`y.atan2(x)` takes the y coordinate as the receiver, which is easy to get backwards.",
+        "Once `From` is implemented, `Into` comes for free: `let polar: PolarCoord = \
+         cart.into();` works without writing any more code.",
+    ],
+    // Exercise 4: Iterator Implementation
+    &[
+        "Fibonacci keeps the two most recent numbers. `new` starts them at 0 and 1, and each \
+         call to `next` shifts them along by one.",
+        "`u64` overflows after 93 terms. `checked_add` returns None on overflow, and returning \
+         None from `next` ends the iteration cleanly.",
+        "`let new_next = self.curr.checked_add(self.next)?;` then `self.curr = self.next; \
+         self.next = new_next;`, and return `Some(self.curr)`.",
+    ],
+    // Exercise 5: Advanced Trait Bounds
+    &[
+        "The HashMap is keyed by the associated type `T::Key`. The `Eq + Hash` bound on the \
+         trait is what makes that legal.",
+        "`insert` stores the item under `item.get_key()`. `get` should treat an item that is no \
+         longer valid as a miss.",
+        "`self.items.get(key).filter(|item| item.is_valid())` handles `get`, and \
+         `self.items.retain(|_, item| item.is_valid())` handles `remove_invalid`.",
+    ],
+];
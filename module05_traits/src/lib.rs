@@ -0,0 +1,11 @@
+// Module 5: Traits and Generics Deep Dive
+// Library target exposing the exercises so the `rustpath` runner can drive them.
+
+pub mod problems;
+
+// Progressive hints shown by `rustpath run --hint`
+pub mod hints;
+
+// Reference solutions, compiled only with the `solutions` feature
+#[cfg(feature = "solutions")]
+pub mod solutions;
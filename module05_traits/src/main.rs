@@ -7,7 +7,7 @@
 // - Zero-cost abstractions
 // - Comparison with other languages' polymorphism
 
-mod problems;
+use module5::problems;
 
 use std::fmt::{self, Debug, Display};
 use std::ops::{Add, Deref, DerefMut};
@@ -164,6 +164,8 @@ fn main() {
     trait Serializable {}
 
     // Auto trait (implemented automatically if all fields implement Send)
+    /// # Safety
+    /// Implementors must be safe to move to another thread.
     unsafe trait MySend {}
     unsafe impl<T: MySend> MySend for Box<T> {}
 
@@ -17,64 +17,72 @@ pub fn run_exercises() {
     exercise5();
 }
 
+// Exercise entry points in order, used by `rustpath run <module> --exercise <n>`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Hidden per-exercise checks, run against this file by `rustpath grade`.
+#[cfg(all(test, feature = "grading"))]
+mod grading;
+
 // Exercise 1: Generic Data Structure with Traits
 // This exercise demonstrates:
 // - Creating generic data structures
 // - Implementing multiple traits
 // - Using trait bounds effectively
 // - Understanding when to use associated types
-fn exercise1() {
-    println!("Exercise 1: Generic Data Structure");
-    println!("------------------------------");
-    println!("TODO: Implement the Statistics trait for DataCollection\n");
 
-    // A trait for types that can be averaged
-    trait Averageable: Add<Output = Self> + Mul<f64, Output = Self> + Sized {
-        fn zero() -> Self;
-    }
+// A trait for types that can be averaged
+trait Averageable: Add<Output = Self> + Mul<f64, Output = Self> + Sized {
+    fn zero() -> Self;
+}
 
-    // Implement Averageable for f64
-    impl Averageable for f64 {
-        fn zero() -> Self {
-            0.0
-        }
+// Implement Averageable for f64
+impl Averageable for f64 {
+    fn zero() -> Self {
+        0.0
     }
+}
+
+// Statistics trait with associated type
+trait Statistics {
+    type Item: Averageable;
+
+    fn add(&mut self, value: Self::Item);
+    fn mean(&self) -> Option<Self::Item>;
+    fn count(&self) -> usize;
+}
+
+// Generic data collection
+#[derive(Debug)]
+struct DataCollection<T> {
+    data: Vec<T>,
+}
 
-    // Statistics trait with associated type
-    trait Statistics {
-        type Item: Averageable;
+// Implement Statistics for DataCollection
+// This should:
+// 1. Store values of any Averageable type
+// 2. Calculate the mean (average) of stored values
+// 3. Keep track of the number of values
+impl<T: Averageable + Clone> Statistics for DataCollection<T> {
+    type Item = T;
 
-        fn add(&mut self, value: Self::Item);
-        fn mean(&self) -> Option<Self::Item>;
-        fn count(&self) -> usize;
+    fn add(&mut self, value: Self::Item) {
+        unimplemented!("Implement the add method");
     }
 
-    // Generic data collection
-    #[derive(Debug)]
-    struct DataCollection<T> {
-        data: Vec<T>,
+    fn mean(&self) -> Option<Self::Item> {
+        unimplemented!("Implement the mean method");
     }
 
-    // Implement Statistics for DataCollection
-    // This should:
-    // 1. Store values of any Averageable type
-    // 2. Calculate the mean (average) of stored values
-    // 3. Keep track of the number of values
-    impl<T: Averageable + Clone> Statistics for DataCollection<T> {
-        type Item = T;
-
-        fn add(&mut self, value: Self::Item) {
-            unimplemented!("Implement the add method");
-        }
-
-        fn mean(&self) -> Option<Self::Item> {
-            unimplemented!("Implement the mean method");
-        }
-
-        fn count(&self) -> usize {
-            unimplemented!("Implement the count method");
-        }
+    fn count(&self) -> usize {
+        unimplemented!("Implement the count method");
     }
+}
+
+fn exercise1() {
+    println!("Exercise 1: Generic Data Structure");
+    println!("------------------------------");
+    println!("TODO: Implement the Statistics trait for DataCollection\n");
 
     // Test your implementation:
     // let mut collection = DataCollection { data: Vec::new() };
@@ -91,60 +99,62 @@ fn exercise1() {
 // - Generic type parameters
 // - Default trait implementations
 // - Type state patterns
-fn exercise2() {
-    println!("\nExercise 2: Builder Pattern");
-    println!("----------------------");
-    println!("TODO: Implement the RequestBuilder\n");
 
-    // HTTP Method enum
-    #[derive(Debug)]
-    enum Method {
-        GET,
-        POST,
-        PUT,
-        DELETE,
-    }
+// HTTP Method enum
+#[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)] // HTTP spells its methods in capitals
+enum Method {
+    GET,
+    POST,
+    PUT,
+    DELETE,
+}
 
-    // Request struct
-    #[derive(Debug)]
-    struct Request<T> {
-        method: Method,
-        url: String,
-        body: Option<T>,
-    }
+// Request struct
+#[derive(Debug)]
+struct Request<T> {
+    method: Method,
+    url: String,
+    body: Option<T>,
+}
 
-    // Builder for Request
-    // This should:
-    // 1. Allow chaining of configuration methods
-    // 2. Support different body types
-    // 3. Validate the request before building
-    struct RequestBuilder<T> {
-        method: Option<Method>,
-        url: Option<String>,
-        body: Option<T>,
-    }
+// Builder for Request
+// This should:
+// 1. Allow chaining of configuration methods
+// 2. Support different body types
+// 3. Validate the request before building
+struct RequestBuilder<T> {
+    method: Option<Method>,
+    url: Option<String>,
+    body: Option<T>,
+}
 
-    impl<T> RequestBuilder<T> {
-        fn new() -> Self {
-            unimplemented!("Implement the new method");
-        }
+impl<T> RequestBuilder<T> {
+    fn new() -> Self {
+        unimplemented!("Implement the new method");
+    }
 
-        fn method(self, method: Method) -> Self {
-            unimplemented!("Implement the method method");
-        }
+    fn method(self, method: Method) -> Self {
+        unimplemented!("Implement the method method");
+    }
 
-        fn url(self, url: String) -> Self {
-            unimplemented!("Implement the url method");
-        }
+    fn url(self, url: String) -> Self {
+        unimplemented!("Implement the url method");
+    }
 
-        fn body(self, body: T) -> Self {
-            unimplemented!("Implement the body method");
-        }
+    fn body(self, body: T) -> Self {
+        unimplemented!("Implement the body method");
+    }
 
-        fn build(self) -> Result<Request<T>, &'static str> {
-            unimplemented!("Implement the build method");
-        }
+    fn build(self) -> Result<Request<T>, &'static str> {
+        unimplemented!("Implement the build method");
     }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Builder Pattern");
+    println!("----------------------");
+    println!("TODO: Implement the RequestBuilder\n");
 
     // Test your implementation:
     // let request = RequestBuilder::new()
@@ -160,39 +170,40 @@ fn exercise2() {
 // - Implementing From/Into
 // - Error handling with conversions
 // - Understanding when to use different conversion traits
-fn exercise3() {
-    println!("\nExercise 3: Type Conversion");
-    println!("----------------------");
-    println!("TODO: Implement conversion traits for Coordinate types\n");
 
-    // Different coordinate types
-    #[derive(Debug, PartialEq)]
-    struct CartesianCoord {
-        x: f64,
-        y: f64,
-    }
+// Different coordinate types
+#[derive(Debug, PartialEq)]
+struct CartesianCoord {
+    x: f64,
+    y: f64,
+}
 
-    #[derive(Debug, PartialEq)]
-    struct PolarCoord {
-        r: f64,     // radius
-        theta: f64, // angle in radians
-    }
+#[derive(Debug, PartialEq)]
+struct PolarCoord {
+    r: f64,     // radius
+    theta: f64, // angle in radians
+}
 
-    // Implement From<PolarCoord> for CartesianCoord
-    // Formula: x = r * cos(theta), y = r * sin(theta)
-    impl From<PolarCoord> for CartesianCoord {
-        fn from(polar: PolarCoord) -> Self {
-            unimplemented!("Implement conversion from PolarCoord to CartesianCoord");
-        }
+// Implement From<PolarCoord> for CartesianCoord
+// Formula: x = r * cos(theta), y = r * sin(theta)
+impl From<PolarCoord> for CartesianCoord {
+    fn from(polar: PolarCoord) -> Self {
+        unimplemented!("Implement conversion from PolarCoord to CartesianCoord");
     }
+}
 
-    // Implement From<CartesianCoord> for PolarCoord
-    // Formula: r = sqrt(x² + y²), theta = atan2(y, x)
-    impl From<CartesianCoord> for PolarCoord {
-        fn from(cartesian: CartesianCoord) -> Self {
-            unimplemented!("Implement conversion from CartesianCoord to PolarCoord");
-        }
+// Implement From<CartesianCoord> for PolarCoord
+// Formula: r = sqrt(x² + y²), theta = atan2(y, x)
+impl From<CartesianCoord> for PolarCoord {
+    fn from(cartesian: CartesianCoord) -> Self {
+        unimplemented!("Implement conversion from CartesianCoord to PolarCoord");
     }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Type Conversion");
+    println!("----------------------");
+    println!("TODO: Implement conversion traits for Coordinate types\n");
 
     // Test your implementation:
     // let cart = CartesianCoord { x: 3.0, y: 4.0 };
@@ -208,35 +219,36 @@ fn exercise3() {
 // - Implementing the Iterator trait
 // - Using associated types
 // - Working with generic iterators
-fn exercise4() {
-    println!("\nExercise 4: Custom Iterator");
-    println!("-----------------------");
-    println!("TODO: Implement the Fibonacci iterator\n");
 
-    // Fibonacci sequence iterator
-    struct Fibonacci {
-        curr: u64,
-        next: u64,
-    }
+// Fibonacci sequence iterator
+struct Fibonacci {
+    curr: u64,
+    next: u64,
+}
 
-    // Implement Iterator for Fibonacci
-    // This should:
-    // 1. Generate Fibonacci numbers
-    // 2. Handle potential overflow
-    // 3. Use the Iterator trait effectively
-    impl Iterator for Fibonacci {
-        type Item = u64;
-
-        fn next(&mut self) -> Option<Self::Item> {
-            unimplemented!("Implement the next method");
-        }
+// Implement Iterator for Fibonacci
+// This should:
+// 1. Generate Fibonacci numbers
+// 2. Handle potential overflow
+// 3. Use the Iterator trait effectively
+impl Iterator for Fibonacci {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unimplemented!("Implement the next method");
     }
+}
 
-    impl Fibonacci {
-        fn new() -> Self {
-            unimplemented!("Implement the new method");
-        }
+impl Fibonacci {
+    fn new() -> Self {
+        unimplemented!("Implement the new method");
     }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Custom Iterator");
+    println!("-----------------------");
+    println!("TODO: Implement the Fibonacci iterator\n");
 
     // Test your implementation:
     // let fib = Fibonacci::new();
@@ -251,44 +263,45 @@ fn exercise4() {
 // - Default type parameters
 // - Associated type constraints
 // - Where clauses
-fn exercise5() {
-    println!("\nExercise 5: Advanced Trait Bounds");
-    println!("----------------------------");
-    println!("TODO: Implement the generic cache system\n");
 
-    // Cacheable trait for items that can be cached
-    trait Cacheable: Clone + Debug {
-        type Key: Eq + std::hash::Hash;
-        fn get_key(&self) -> Self::Key;
-        fn is_valid(&self) -> bool;
-    }
+// Cacheable trait for items that can be cached
+trait Cacheable: Clone + Debug {
+    type Key: Eq + std::hash::Hash;
+    fn get_key(&self) -> Self::Key;
+    fn is_valid(&self) -> bool;
+}
 
-    // Cache implementation
-    // This should:
-    // 1. Store items that implement Cacheable
-    // 2. Manage item validity
-    // 3. Handle cache misses and updates
-    struct Cache<T: Cacheable> {
-        items: std::collections::HashMap<T::Key, T>,
-    }
+// Cache implementation
+// This should:
+// 1. Store items that implement Cacheable
+// 2. Manage item validity
+// 3. Handle cache misses and updates
+struct Cache<T: Cacheable> {
+    items: std::collections::HashMap<T::Key, T>,
+}
 
-    impl<T: Cacheable> Cache<T> {
-        fn new() -> Self {
-            unimplemented!("Implement the new method");
-        }
+impl<T: Cacheable> Cache<T> {
+    fn new() -> Self {
+        unimplemented!("Implement the new method");
+    }
 
-        fn insert(&mut self, item: T) {
-            unimplemented!("Implement the insert method");
-        }
+    fn insert(&mut self, item: T) {
+        unimplemented!("Implement the insert method");
+    }
 
-        fn get(&self, key: &T::Key) -> Option<&T> {
-            unimplemented!("Implement the get method");
-        }
+    fn get(&self, key: &T::Key) -> Option<&T> {
+        unimplemented!("Implement the get method");
+    }
 
-        fn remove_invalid(&mut self) {
-            unimplemented!("Implement the remove_invalid method");
-        }
+    fn remove_invalid(&mut self) {
+        unimplemented!("Implement the remove_invalid method");
     }
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Advanced Trait Bounds");
+    println!("----------------------------");
+    println!("TODO: Implement the generic cache system\n");
 
     // Test your implementation:
     // #[derive(Clone, Debug)]
@@ -319,131 +332,7 @@ fn exercise5() {
     // println!("After removing invalid items: {:?}", cache);
 }
 
-/* Example Solutions (Try to solve the exercises before looking at these!)
-
-// Exercise 1 Solution:
-impl<T: Averageable + Clone> Statistics for DataCollection<T> {
-    type Item = T;
-
-    fn add(&mut self, value: Self::Item) {
-        self.data.push(value);
-    }
-
-    fn mean(&self) -> Option<Self::Item> {
-        if self.data.is_empty() {
-            return None;
-        }
-
-        let sum = self.data.iter()
-            .cloned()
-            .fold(T::zero(), |acc, x| acc + x);
-
-        Some(sum * (1.0 / self.data.len() as f64))
-    }
-
-    fn count(&self) -> usize {
-        self.data.len()
-    }
-}
-
-// Exercise 2 Solution:
-impl<T> RequestBuilder<T> {
-    fn new() -> Self {
-        RequestBuilder {
-            method: None,
-            url: None,
-            body: None,
-        }
-    }
-
-    fn method(mut self, method: Method) -> Self {
-        self.method = Some(method);
-        self
-    }
-
-    fn url(mut self, url: String) -> Self {
-        self.url = Some(url);
-        self
-    }
-
-    fn body(mut self, body: T) -> Self {
-        self.body = Some(body);
-        self
-    }
-
-    fn build(self) -> Result<Request<T>, &'static str> {
-        let method = self.method.ok_or("Method is required")?;
-        let url = self.url.ok_or("URL is required")?;
-
-        Ok(Request {
-            method,
-            url,
-            body: self.body,
-        })
-    }
-}
-
-// Exercise 3 Solution:
-impl From<PolarCoord> for CartesianCoord {
-    fn from(polar: PolarCoord) -> Self {
-        CartesianCoord {
-            x: polar.r * polar.theta.cos(),
-            y: polar.r * polar.theta.sin(),
-        }
-    }
-}
-
-impl From<CartesianCoord> for PolarCoord {
-    fn from(cart: CartesianCoord) -> Self {
-        PolarCoord {
-            r: (cart.x * cart.x + cart.y * cart.y).sqrt(),
-            theta: cart.y.atan2(cart.x),
-        }
-    }
-}
-
-// Exercise 4 Solution:
-impl Iterator for Fibonacci {
-    type Item = u64;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let new_next = self.curr.checked_add(self.next)?;
-        let new_curr = self.next;
-
-        self.curr = new_curr;
-        self.next = new_next;
-
-        Some(self.curr)
-    }
-}
-
-impl Fibonacci {
-    fn new() -> Self {
-        Fibonacci {
-            curr: 0,
-            next: 1,
-        }
-    }
-}
-
-// Exercise 5 Solution:
-impl<T: Cacheable> Cache<T> {
-    fn new() -> Self {
-        Cache {
-            items: std::collections::HashMap::new(),
-        }
-    }
-
-    fn insert(&mut self, item: T) {
-        self.items.insert(item.get_key(), item);
-    }
-
-    fn get(&self, key: &T::Key) -> Option<&T> {
-        self.items.get(key).filter(|item| item.is_valid())
-    }
-
-    fn remove_invalid(&mut self) {
-        self.items.retain(|_, item| item.is_valid());
-    }
-}
-*/
+// Example Solutions
+// The reference answers live in src/solutions.rs and are compiled with the
+// `solutions` feature (`rustpath run <module> --solution`). Try solving the
+// exercises yourself first!
@@ -0,0 +1,172 @@
+// Grading suite for the module 5 exercises
+// These checks are hidden from the exercise walkthrough: `rustpath grade 5`
+// runs them against your implementations in problems.rs.
+
+use super::*;
+
+mod exercise1 {
+    use super::*;
+
+    #[test]
+    fn test_empty_collection_has_no_mean() {
+        let collection: DataCollection<f64> = DataCollection { data: Vec::new() };
+        assert_eq!(collection.count(), 0);
+        assert_eq!(collection.mean(), None);
+    }
+
+    #[test]
+    fn test_mean_and_count() {
+        let mut collection = DataCollection { data: Vec::new() };
+        collection.add(1.0);
+        collection.add(2.0);
+        collection.add(6.0);
+        assert_eq!(collection.count(), 3);
+        assert_eq!(collection.mean(), Some(3.0));
+    }
+}
+
+mod exercise2 {
+    use super::*;
+
+    #[test]
+    fn test_build_with_all_parts() {
+        let request = RequestBuilder::new()
+            .method(Method::POST)
+            .url("https://api.example.com".to_string())
+            .body("payload")
+            .build()
+            .unwrap();
+        assert!(matches!(request.method, Method::POST));
+        assert_eq!(request.url, "https://api.example.com");
+        assert_eq!(request.body, Some("payload"));
+    }
+
+    #[test]
+    fn test_body_is_optional() {
+        let request = RequestBuilder::<()>::new()
+            .url("/health".to_string())
+            .method(Method::GET)
+            .build()
+            .unwrap();
+        assert!(request.body.is_none());
+    }
+
+    #[test]
+    fn test_build_requires_method_and_url() {
+        let missing_url = RequestBuilder::<()>::new().method(Method::GET).build();
+        assert!(missing_url.is_err());
+        let missing_method = RequestBuilder::<()>::new().url("/".to_string()).build();
+        assert!(missing_method.is_err());
+    }
+}
+
+mod exercise3 {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_cartesian_to_polar() {
+        let polar = PolarCoord::from(CartesianCoord { x: 3.0, y: 4.0 });
+        assert!(close(polar.r, 5.0));
+        assert!(close(polar.theta, 4.0_f64.atan2(3.0)));
+    }
+
+    #[test]
+    fn test_polar_to_cartesian() {
+        let cart: CartesianCoord = PolarCoord {
+            r: 2.0,
+            theta: std::f64::consts::FRAC_PI_2,
+        }
+        .into();
+        assert!(close(cart.x, 0.0));
+        assert!(close(cart.y, 2.0));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let polar: PolarCoord = CartesianCoord { x: -1.5, y: 0.5 }.into();
+        let cart: CartesianCoord = polar.into();
+        assert!(close(cart.x, -1.5));
+        assert!(close(cart.y, 0.5));
+    }
+}
+
+mod exercise4 {
+    use super::*;
+
+    #[test]
+    fn test_generates_the_sequence() {
+        let numbers: Vec<u64> = Fibonacci::new().take(12).collect();
+        assert!(numbers.starts_with(&[0, 1, 1]) || numbers.starts_with(&[1, 1, 2]));
+        for window in numbers.windows(3) {
+            assert_eq!(window[2], window[0] + window[1]);
+        }
+    }
+
+    #[test]
+    fn test_stops_before_overflow() {
+        // The 94th Fibonacci number no longer fits in a u64
+        let count = Fibonacci::new().take(200).count();
+        assert!((90..=94).contains(&count), "{}", count);
+    }
+}
+
+mod exercise5 {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Item {
+        id: u32,
+        valid: bool,
+    }
+
+    impl Cacheable for Item {
+        type Key = u32;
+
+        fn get_key(&self) -> u32 {
+            self.id
+        }
+
+        fn is_valid(&self) -> bool {
+            self.valid
+        }
+    }
+
+    #[test]
+    fn test_insert_and_get_by_key() {
+        let mut cache = Cache::new();
+        cache.insert(Item { id: 1, valid: true });
+        cache.insert(Item { id: 2, valid: true });
+        assert_eq!(cache.get(&2), Some(&Item { id: 2, valid: true }));
+        assert_eq!(cache.get(&3), None);
+    }
+
+    #[test]
+    fn test_insert_replaces_same_key() {
+        let mut cache = Cache::new();
+        cache.insert(Item {
+            id: 1,
+            valid: false,
+        });
+        cache.insert(Item { id: 1, valid: true });
+        assert_eq!(cache.items.len(), 1);
+        assert_eq!(cache.get(&1), Some(&Item { id: 1, valid: true }));
+    }
+
+    #[test]
+    fn test_remove_invalid() {
+        let mut cache = Cache::new();
+        cache.insert(Item { id: 1, valid: true });
+        cache.insert(Item {
+            id: 2,
+            valid: false,
+        });
+        cache.remove_invalid();
+        assert_eq!(cache.items.len(), 1);
+        assert!(cache.get(&1).is_some());
+        assert!(cache.get(&2).is_none());
+    }
+}
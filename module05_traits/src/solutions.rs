@@ -0,0 +1,323 @@
+// Module 5: Reference Solutions
+// Worked answers to the exercises in problems.rs, compiled only with the
+// `solutions` feature. Try solving the exercises yourself first!
+// The types and signatures mirror problems.rs, so the hidden grading suite
+// runs unchanged against these answers (see the tests at the end).
+
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Mul};
+
+pub fn run_exercises() {
+    println!("Module 5 Reference Solutions - Traits and Generics");
+    println!("=============================================\n");
+
+    exercise1();
+    exercise2();
+    exercise3();
+    exercise4();
+    exercise5();
+}
+
+// Solution entry points in order, used by `rustpath run <module> --solution`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Exercise 1 Solution: Generic Data Structure with Traits
+
+trait Averageable: Add<Output = Self> + Mul<f64, Output = Self> + Sized {
+    fn zero() -> Self;
+}
+
+impl Averageable for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+}
+
+trait Statistics {
+    type Item: Averageable;
+
+    fn add(&mut self, value: Self::Item);
+    fn mean(&self) -> Option<Self::Item>;
+    fn count(&self) -> usize;
+}
+
+#[derive(Debug)]
+struct DataCollection<T> {
+    data: Vec<T>,
+}
+
+impl<T: Averageable + Clone> Statistics for DataCollection<T> {
+    type Item = T;
+
+    fn add(&mut self, value: Self::Item) {
+        self.data.push(value);
+    }
+
+    fn mean(&self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let sum = self.data.iter().cloned().fold(T::zero(), |acc, x| acc + x);
+        Some(sum * (1.0 / self.data.len() as f64))
+    }
+
+    fn count(&self) -> usize {
+        self.data.len()
+    }
+}
+
+fn exercise1() {
+    println!("Exercise 1: Generic Data Structure");
+    println!("------------------------------");
+
+    let mut collection = DataCollection { data: Vec::new() };
+    collection.add(1.0);
+    collection.add(2.0);
+    collection.add(3.0);
+    println!("Mean: {:?}", collection.mean());
+    println!("Count: {}", collection.count());
+}
+
+// Exercise 2 Solution: Builder Pattern with Generics
+
+#[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)] // HTTP spells its methods in capitals
+enum Method {
+    GET,
+    POST,
+    PUT,
+    DELETE,
+}
+
+#[derive(Debug)]
+struct Request<T> {
+    method: Method,
+    url: String,
+    body: Option<T>,
+}
+
+struct RequestBuilder<T> {
+    method: Option<Method>,
+    url: Option<String>,
+    body: Option<T>,
+}
+
+impl<T> RequestBuilder<T> {
+    fn new() -> Self {
+        RequestBuilder {
+            method: None,
+            url: None,
+            body: None,
+        }
+    }
+
+    fn method(mut self, method: Method) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    fn url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    fn body(mut self, body: T) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    fn build(self) -> Result<Request<T>, &'static str> {
+        let method = self.method.ok_or("Method is required")?;
+        let url = self.url.ok_or("URL is required")?;
+
+        Ok(Request {
+            method,
+            url,
+            body: self.body,
+        })
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Builder Pattern");
+    println!("----------------------");
+
+    let request = RequestBuilder::new()
+        .method(Method::POST)
+        .url("https://api.example.com".to_string())
+        .body(r#"{ "key": "value" }"#)
+        .build();
+    println!("Request: {:?}", request);
+}
+
+// Exercise 3 Solution: Type Conversion Traits
+
+#[derive(Debug, PartialEq)]
+struct CartesianCoord {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, PartialEq)]
+struct PolarCoord {
+    r: f64,
+    theta: f64,
+}
+
+impl From<PolarCoord> for CartesianCoord {
+    fn from(polar: PolarCoord) -> Self {
+        CartesianCoord {
+            x: polar.r * polar.theta.cos(),
+            y: polar.r * polar.theta.sin(),
+        }
+    }
+}
+
+impl From<CartesianCoord> for PolarCoord {
+    fn from(cart: CartesianCoord) -> Self {
+        PolarCoord {
+            r: cart.x.hypot(cart.y),
+            theta: cart.y.atan2(cart.x),
+        }
+    }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Type Conversion");
+    println!("----------------------");
+
+    // `into` consumes its argument, so print each value before converting it
+    let cart = CartesianCoord { x: 3.0, y: 4.0 };
+    print!("Cartesian: {:?} -> ", cart);
+    let polar: PolarCoord = cart.into();
+    println!("Polar: {:?}", polar);
+
+    print!("Polar: {:?} -> ", polar);
+    let cart2: CartesianCoord = polar.into();
+    println!("Cartesian: {:?}", cart2);
+}
+
+// Exercise 4 Solution: Iterator Implementation
+
+struct Fibonacci {
+    curr: u64,
+    next: u64,
+}
+
+// `checked_add` ends the sequence instead of overflowing
+impl Iterator for Fibonacci {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let new_next = self.curr.checked_add(self.next)?;
+        self.curr = self.next;
+        self.next = new_next;
+        Some(self.curr)
+    }
+}
+
+impl Fibonacci {
+    fn new() -> Self {
+        Fibonacci { curr: 0, next: 1 }
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Custom Iterator");
+    println!("-----------------------");
+
+    let fib = Fibonacci::new();
+    for (i, num) in fib.take(10).enumerate() {
+        println!("Fibonacci number {}: {}", i, num);
+    }
+}
+
+// Exercise 5 Solution: Advanced Trait Bounds
+
+trait Cacheable: Clone + Debug {
+    type Key: Eq + std::hash::Hash;
+    fn get_key(&self) -> Self::Key;
+    fn is_valid(&self) -> bool;
+}
+
+struct Cache<T: Cacheable> {
+    items: std::collections::HashMap<T::Key, T>,
+}
+
+impl<T: Cacheable> Cache<T> {
+    fn new() -> Self {
+        Cache {
+            items: std::collections::HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, item: T) {
+        self.items.insert(item.get_key(), item);
+    }
+
+    // Invalid items count as cache misses until they are removed
+    fn get(&self, key: &T::Key) -> Option<&T> {
+        self.items.get(key).filter(|item| item.is_valid())
+    }
+
+    fn remove_invalid(&mut self) {
+        self.items.retain(|_, item| item.is_valid());
+    }
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Advanced Trait Bounds");
+    println!("----------------------------");
+
+    #[derive(Clone, Debug)]
+    struct CacheItem {
+        id: u32,
+        name: String,
+        valid: bool,
+    }
+
+    impl Cacheable for CacheItem {
+        type Key = u32;
+
+        fn get_key(&self) -> Self::Key {
+            self.id
+        }
+
+        fn is_valid(&self) -> bool {
+            self.valid
+        }
+    }
+
+    let mut cache = Cache::new();
+    cache.insert(CacheItem {
+        id: 1,
+        name: "Item 1".to_string(),
+        valid: true,
+    });
+    cache.insert(CacheItem {
+        id: 2,
+        name: "Item 2".to_string(),
+        valid: false,
+    });
+
+    println!("Cache item 1: {:?}", cache.get(&1));
+    cache.remove_invalid();
+    println!("After removing invalid items: {:?}", cache.items);
+}
+
+// The grading suite from problems/grading.rs, run against these answers.
+// With the `grading` feature on too, problems.rs loads the same file for the
+// stubs; the two copies are meant to be separate modules.
+#[cfg(test)]
+#[path = "problems/grading.rs"]
+#[allow(clippy::duplicate_mod)]
+mod grading;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_run_exercises() {
+        super::run_exercises();
+    }
+}
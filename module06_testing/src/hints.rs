@@ -0,0 +1,59 @@
+// Module 6: Hints
+// Progressive hints for the exercises in problems.rs, one list per exercise,
+// shown by `rustpath run 6 --exercise <n> --hint <level>`. Level 1 is the
+// exercise's "This exercise demonstrates" list; these are levels 2 and up,
+// each giving away a little more than the one before.
+
+pub const HINTS: [&[&str]; 5] = [
+    // Exercise 1: Unit Testing
+    &[
+        "The arithmetic is one line each. The interesting method is `divide`, which must return \
+         `Err` when `b == 0` instead of panicking.",
+        "Test both branches of `divide`: `assert_eq!(calc.divide(10, 2), Ok(5))` and \
+         `assert!(calc.divide(1, 0).is_err())`. Include negative numbers and zero in the others.",
+        "Put the tests in a `#[cfg(test)] mod tests` with `use super::*;`, one `#[test]` per \
+         behaviour, and run them with `cargo test -p module6`.",
+    ],
+    // Exercise 2: Test Doubles
+    &[
+        "UserService is generic over `T: UserDatabase`, so the tests can inject any \
+         implementation. The mock is just a `Vec<User>` behind the same trait.",
+        "The mock's `get_user` finds the user by id and rebuilds a `User` from it; \
+         `delete_user` uses `position` and returns `Err` when the id is unknown.",
+        "`register_user` needs a fresh id: take the first id from `1..` for which \
+         `self.database.get_user(id)` is None, save the user with `?`, and return the id.",
+    ],
+    // Exercise 3: Documentation Tests
+    &[
+        "Doc tests are code blocks inside `///` comments, under an `# Examples` heading. \
+         `cargo test --doc` compiles and runs each one.",
+        "Each example should `assert_eq!` on a real call, such as `to_title_case(\"hello \
+         world\")`. Add a `# Panics` section where a function can panic, for example \
+         `truncate` with a tiny `max_length`.",
+        "Count and cut by `chars()`, not bytes, so `truncate` and `is_palindrome` behave with \
+         non-ASCII text. For palindromes, keep only alphanumerics, lowercased, and compare the \
+         sequence with its reverse.",
+    ],
+    // Exercise 4: Integration Testing
+    &[
+        "Integration tests live in `tests/*.rs` and can only use the crate's public API. That \
+         is why `config` is a `pub mod`.",
+        "Use a `key=value` line format. `load_from_file` skips blank and `#` lines and \
+         `split_once('=')`s the rest; `save_to_file` writes the pairs back out, sorted so the \
+         file is stable.",
+        "In the test, save a config to a file under `std::env::temp_dir()`, load it back, \
+         compare the values, then remove the file. Also test that loading a missing file \
+         returns `Err`.",
+    ],
+    // Exercise 5: Property-Based Testing
+    &[
+        "A property is something true for every input, such as \"the output is sorted\" or \
+         \"sorting twice changes nothing\". proptest generates the inputs for you.",
+        "Write the sorts first. For quicksort, `split_first()` gives a pivot and the rest; \
+         partition the rest into smaller and not-smaller, sort each recursively and join them \
+         around the pivot.",
+        "Inside `proptest! { ... }`, write tests like `fn sorted(list in \
+         prop::collection::vec(0..100i32, 0..100))` and use `prop_assert!`. Comparing each \
+         sort against `slice::sort` on the same input is a strong property.",
+    ],
+];
@@ -9,7 +9,7 @@
 // - Benchmarking and criterion.rs
 // - Continuous Integration setup
 
-mod problems;
+use module6::problems;
 
 use std::fmt;
 
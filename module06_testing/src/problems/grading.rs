@@ -0,0 +1,195 @@
+// Grading suite for the module 6 exercises
+// These checks are hidden from the exercise walkthrough: `rustpath grade 6`
+// runs them against your implementations in problems.rs. Writing your own
+// tests is still part of each exercise; this suite only checks the code
+// under test.
+
+use super::*;
+
+mod exercise1 {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let calc = Calculator::new();
+        assert_eq!(calc.add(2, 3), 5);
+        assert_eq!(calc.add(-2, 3), 1);
+        assert_eq!(calc.subtract(3, 5), -2);
+        assert_eq!(calc.multiply(-2, 3), -6);
+    }
+
+    #[test]
+    fn test_divide() {
+        let calc = Calculator::new();
+        assert_eq!(calc.divide(6, 3), Ok(2));
+        assert_eq!(calc.divide(5, 2), Ok(2));
+        assert!(calc.divide(1, 0).is_err());
+    }
+}
+
+mod exercise2 {
+    use super::*;
+
+    // The grader's own in-memory database, independent of your mock
+    #[derive(Default)]
+    struct MemoryDatabase {
+        users: Vec<User>,
+    }
+
+    impl UserDatabase for MemoryDatabase {
+        fn get_user(&self, id: u64) -> Option<User> {
+            self.users.iter().find(|u| u.id == id).map(|u| User {
+                id: u.id,
+                name: u.name.clone(),
+                email: u.email.clone(),
+            })
+        }
+
+        fn save_user(&mut self, user: User) -> Result<(), String> {
+            if self.users.iter().any(|u| u.id == user.id) {
+                return Err("User already exists".to_string());
+            }
+            self.users.push(user);
+            Ok(())
+        }
+
+        fn delete_user(&mut self, id: u64) -> Result<(), String> {
+            let before = self.users.len();
+            self.users.retain(|u| u.id != id);
+            if self.users.len() == before {
+                Err("User not found".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn alice() -> User {
+        User {
+            id: 1,
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_get_user_name() {
+        let service = UserService::new(MemoryDatabase {
+            users: vec![alice()],
+        });
+        assert_eq!(service.get_user_name(1), Some("Alice".to_string()));
+        assert_eq!(service.get_user_name(2), None);
+    }
+
+    #[test]
+    fn test_register_user_saves_to_database() {
+        let mut service = UserService::new(MemoryDatabase::default());
+        let id = service
+            .register_user("Bob".to_string(), "bob@example.com".to_string())
+            .unwrap();
+        assert_eq!(service.get_user_name(id), Some("Bob".to_string()));
+        assert_eq!(service.database.users.len(), 1);
+    }
+
+    #[test]
+    fn test_delete_user() {
+        let mut service = UserService::new(MemoryDatabase {
+            users: vec![alice()],
+        });
+        assert!(service.delete_user(1).is_ok());
+        assert_eq!(service.get_user_name(1), None);
+        assert!(service.delete_user(1).is_err());
+    }
+}
+
+mod exercise3 {
+    use super::string_utils::*;
+
+    #[test]
+    fn test_to_title_case() {
+        assert_eq!(to_title_case("hello world"), "Hello World");
+        assert_eq!(to_title_case("RUST PROGRAMMING"), "Rust Programming");
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("Hello, world!", 8), "Hello...");
+        assert_eq!(truncate("Short", 10), "Short");
+    }
+
+    #[test]
+    fn test_word_count() {
+        assert_eq!(word_count("the quick  brown\nfox"), 4);
+        assert_eq!(word_count("   "), 0);
+    }
+
+    #[test]
+    fn test_is_palindrome() {
+        assert!(is_palindrome("racecar"));
+        assert!(is_palindrome("abba"));
+        assert!(!is_palindrome("rust"));
+    }
+}
+
+mod exercise4 {
+    use super::config::Config;
+    use std::fs;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_get_and_set() {
+        let mut config = Config::new();
+        assert_eq!(config.get("key"), None);
+        config.set("key".to_string(), "value".to_string());
+        assert_eq!(config.get("key"), Some(&"value".to_string()));
+    }
+
+    #[test]
+    fn test_load_from_file() {
+        let file = NamedTempFile::new().unwrap();
+        fs::write(file.path(), "key1=value1\nkey2 = value2\n").unwrap();
+
+        let config = Config::load_from_file(file.path()).unwrap();
+        assert_eq!(config.get("key1"), Some(&"value1".to_string()));
+        assert_eq!(config.get("key2"), Some(&"value2".to_string()));
+    }
+
+    #[test]
+    fn test_save_then_load_round_trips() {
+        let mut config = Config::new();
+        config.set("name".to_string(), "module6".to_string());
+        let file = NamedTempFile::new().unwrap();
+        config.save_to_file(file.path()).unwrap();
+
+        let loaded = Config::load_from_file(file.path()).unwrap();
+        assert_eq!(loaded.get("name"), Some(&"module6".to_string()));
+    }
+
+    #[test]
+    fn test_missing_file_is_an_error() {
+        assert!(Config::load_from_file("/nonexistent/rustpath/config.ini").is_err());
+    }
+}
+
+mod exercise5 {
+    use super::sorting::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_is_sorted() {
+        assert!(is_sorted::<i32>(&[]));
+        assert!(is_sorted(&[1, 2, 2, 3]));
+        assert!(!is_sorted(&[2, 1]));
+    }
+
+    proptest! {
+        #[test]
+        fn test_sorts_agree_with_std(list in prop::collection::vec(-100..100i32, 0..64)) {
+            let mut expected = list.clone();
+            expected.sort();
+            prop_assert_eq!(&bubble_sort(&list), &expected);
+            prop_assert_eq!(&insertion_sort(&list), &expected);
+            prop_assert_eq!(&quick_sort(&list), &expected);
+        }
+    }
+}
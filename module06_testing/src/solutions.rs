@@ -0,0 +1,561 @@
+// Module 6: Reference Solutions
+// Worked answers to the exercises in problems.rs, compiled only with the
+// `solutions` feature. Try solving the exercises yourself first!
+// The types and signatures mirror problems.rs, so the hidden grading suite
+// runs unchanged against these answers (see the tests at the end). The
+// exercises here are about tests and docs, so the answers include them: unit
+// tests next to each exercise, doc tests on `string_utils`, and the Config
+// integration tests in tests/solution_config.rs.
+
+pub fn run_exercises() {
+    println!("Module 6 Reference Solutions - Testing and Documentation");
+    println!("===================================================\n");
+
+    exercise1();
+    exercise2();
+    exercise3();
+    exercise4();
+    exercise5();
+}
+
+// Solution entry points in order, used by `rustpath run <module> --solution`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Exercise 1 Solution: Unit Testing
+
+struct Calculator;
+
+impl Calculator {
+    fn new() -> Self {
+        Calculator
+    }
+
+    fn add(&self, a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    fn subtract(&self, a: i32, b: i32) -> i32 {
+        a - b
+    }
+
+    fn multiply(&self, a: i32, b: i32) -> i32 {
+        a * b
+    }
+
+    fn divide(&self, a: i32, b: i32) -> Result<i32, String> {
+        if b == 0 {
+            Err("Division by zero".to_string())
+        } else {
+            Ok(a / b)
+        }
+    }
+}
+
+fn exercise1() {
+    println!("Exercise 1: Unit Testing");
+    println!("--------------------");
+
+    let calc = Calculator::new();
+    println!("2 + 3 = {}", calc.add(2, 3));
+    println!("7 / 0 = {:?}", calc.divide(7, 0));
+}
+
+#[cfg(test)]
+mod calculator_tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        let calc = Calculator::new();
+        assert_eq!(calc.add(2, 3), 5);
+        assert_eq!(calc.add(-2, 3), 1);
+        assert_eq!(calc.add(0, 0), 0);
+    }
+
+    #[test]
+    fn test_subtract() {
+        let calc = Calculator::new();
+        assert_eq!(calc.subtract(5, 3), 2);
+        assert_eq!(calc.subtract(3, 5), -2);
+        assert_eq!(calc.subtract(0, 0), 0);
+    }
+
+    #[test]
+    fn test_multiply() {
+        let calc = Calculator::new();
+        assert_eq!(calc.multiply(2, 3), 6);
+        assert_eq!(calc.multiply(-2, 3), -6);
+        assert_eq!(calc.multiply(0, 5), 0);
+    }
+
+    #[test]
+    fn test_divide_valid() {
+        let calc = Calculator::new();
+        assert_eq!(calc.divide(6, 3), Ok(2));
+        assert_eq!(calc.divide(5, 2), Ok(2)); // Integer division
+        assert_eq!(calc.divide(0, 5), Ok(0));
+    }
+
+    #[test]
+    fn test_divide_by_zero() {
+        let calc = Calculator::new();
+        assert!(calc.divide(6, 0).is_err());
+    }
+}
+
+// Exercise 2 Solution: Test Doubles
+
+#[derive(Debug, Clone)]
+struct User {
+    id: u64,
+    name: String,
+    email: String,
+}
+
+trait UserDatabase {
+    fn get_user(&self, id: u64) -> Option<User>;
+    fn save_user(&mut self, user: User) -> Result<(), String>;
+    fn delete_user(&mut self, id: u64) -> Result<(), String>;
+}
+
+struct UserService<T: UserDatabase> {
+    database: T,
+}
+
+impl<T: UserDatabase> UserService<T> {
+    fn new(database: T) -> Self {
+        UserService { database }
+    }
+
+    fn get_user_name(&self, id: u64) -> Option<String> {
+        self.database.get_user(id).map(|user| user.name)
+    }
+
+    // The service keeps no state of its own, so the new id is the first one
+    // the database does not know yet
+    fn register_user(&mut self, name: String, email: String) -> Result<u64, String> {
+        let id = (1..)
+            .find(|&id| self.database.get_user(id).is_none())
+            .ok_or("No free user id")?;
+        self.database.save_user(User { id, name, email })?;
+        Ok(id)
+    }
+
+    fn delete_user(&mut self, id: u64) -> Result<(), String> {
+        self.database.delete_user(id)
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Test Doubles");
+    println!("--------------------");
+
+    println!("UserService takes its database as a type parameter, so tests can");
+    println!("inject an in-memory mock instead of a real database.");
+}
+
+#[cfg(test)]
+mod user_service_tests {
+    use super::*;
+
+    struct MockUserDatabase {
+        users: Vec<User>,
+    }
+
+    impl MockUserDatabase {
+        fn new() -> Self {
+            MockUserDatabase { users: Vec::new() }
+        }
+    }
+
+    impl UserDatabase for MockUserDatabase {
+        fn get_user(&self, id: u64) -> Option<User> {
+            self.users.iter().find(|u| u.id == id).cloned()
+        }
+
+        fn save_user(&mut self, user: User) -> Result<(), String> {
+            if self.users.iter().any(|u| u.id == user.id) {
+                return Err("User already exists".to_string());
+            }
+            self.users.push(user);
+            Ok(())
+        }
+
+        fn delete_user(&mut self, id: u64) -> Result<(), String> {
+            let initial_len = self.users.len();
+            self.users.retain(|u| u.id != id);
+            if self.users.len() == initial_len {
+                Err("User not found".to_string())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn alice() -> User {
+        User {
+            id: 1,
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_get_user_name() {
+        let mut db = MockUserDatabase::new();
+        db.users.push(alice());
+
+        let service = UserService::new(db);
+        assert_eq!(service.get_user_name(1), Some("Alice".to_string()));
+        assert_eq!(service.get_user_name(2), None);
+    }
+
+    #[test]
+    fn test_register_user() {
+        let mut db = MockUserDatabase::new();
+        db.users.push(alice());
+        let mut service = UserService::new(db);
+
+        let id = service
+            .register_user("Bob".to_string(), "bob@example.com".to_string())
+            .unwrap();
+        assert_ne!(id, 1);
+        assert_eq!(service.get_user_name(id), Some("Bob".to_string()));
+    }
+
+    #[test]
+    fn test_delete_user() {
+        let mut db = MockUserDatabase::new();
+        db.users.push(alice());
+
+        let mut service = UserService::new(db);
+        assert!(service.delete_user(1).is_ok());
+        assert_eq!(service.get_user_name(1), None);
+        assert!(service.delete_user(2).is_err());
+    }
+}
+
+// Exercise 3 Solution: Documentation Tests
+
+pub mod string_utils {
+    /// Converts a string to title case.
+    ///
+    /// The first letter of each word is capitalized and the rest are
+    /// lowercased. Words are separated by single spaces in the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use module6::solutions::string_utils::to_title_case;
+    ///
+    /// assert_eq!(to_title_case("hello world"), "Hello World");
+    /// assert_eq!(to_title_case("RUST PROGRAMMING"), "Rust Programming");
+    /// ```
+    pub fn to_title_case(s: &str) -> String {
+        s.split_whitespace()
+            .map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    None => String::new(),
+                    Some(first) => first
+                        .to_uppercase()
+                        .chain(chars.flat_map(char::to_lowercase))
+                        .collect(),
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Truncates a string to the specified length.
+    ///
+    /// If the string has more than `max_length` characters, it is cut short
+    /// and an ellipsis ("...") is appended, so that the result is exactly
+    /// `max_length` characters long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use module6::solutions::string_utils::truncate;
+    ///
+    /// assert_eq!(truncate("Hello, world!", 8), "Hello...");
+    /// assert_eq!(truncate("Short", 10), "Short");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string needs truncating and `max_length` is less than 3,
+    /// as that leaves no room for the ellipsis.
+    ///
+    /// ```should_panic
+    /// use module6::solutions::string_utils::truncate;
+    ///
+    /// truncate("Hello", 2);
+    /// ```
+    pub fn truncate(s: &str, max_length: usize) -> String {
+        if s.chars().count() <= max_length {
+            return s.to_string();
+        }
+        assert!(
+            max_length >= 3,
+            "max_length must be at least 3 to accommodate the ellipsis"
+        );
+        let kept: String = s.chars().take(max_length - 3).collect();
+        format!("{}...", kept)
+    }
+
+    /// Counts the number of words in a string.
+    ///
+    /// Words are separated by any amount of whitespace, including newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use module6::solutions::string_utils::word_count;
+    ///
+    /// assert_eq!(word_count("the quick  brown\nfox"), 4);
+    /// assert_eq!(word_count(""), 0);
+    /// ```
+    pub fn word_count(s: &str) -> usize {
+        s.split_whitespace().count()
+    }
+
+    /// Checks if a string is a palindrome.
+    ///
+    /// The check ignores case and anything that is not alphanumeric, so
+    /// whole sentences can be palindromes too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use module6::solutions::string_utils::is_palindrome;
+    ///
+    /// assert!(is_palindrome("racecar"));
+    /// assert!(is_palindrome("A man, a plan, a canal: Panama"));
+    /// assert!(!is_palindrome("rust"));
+    /// ```
+    pub fn is_palindrome(s: &str) -> bool {
+        let letters: Vec<char> = s
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect();
+        letters.iter().eq(letters.iter().rev())
+    }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Documentation Tests");
+    println!("---------------------------");
+
+    use string_utils::*;
+    println!("{}", to_title_case("documentation tests"));
+    println!(
+        "{}",
+        truncate("Examples in doc comments are compiled and run", 20)
+    );
+    println!("Words: {}", word_count("cargo test --doc runs them"));
+    println!("'Racecar' is a palindrome: {}", is_palindrome("Racecar"));
+}
+
+// Exercise 4 Solution: Integration Testing
+// The integration tests are in tests/solution_config.rs; like any
+// integration test they can only use the crate's public API.
+
+pub mod config {
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::Path;
+
+    /// Configuration structure
+    pub struct Config {
+        values: HashMap<String, String>,
+    }
+
+    impl Config {
+        /// Creates a new empty configuration
+        pub fn new() -> Self {
+            Config {
+                values: HashMap::new(),
+            }
+        }
+
+        /// Loads `key=value` lines from a file, skipping blank lines and
+        /// `#` comments
+        pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+            let content =
+                fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
+
+            let mut config = Config::new();
+            for line in content.lines() {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+
+                let (key, value) = line
+                    .split_once('=')
+                    .ok_or_else(|| format!("Invalid line format: {}", line))?;
+                config.set(key.trim().to_string(), value.trim().to_string());
+            }
+
+            Ok(config)
+        }
+
+        /// Gets a configuration value
+        pub fn get(&self, key: &str) -> Option<&String> {
+            self.values.get(key)
+        }
+
+        /// Sets a configuration value
+        pub fn set(&mut self, key: String, value: String) {
+            self.values.insert(key, value);
+        }
+
+        /// Saves configuration to a file, one `key=value` line per entry
+        /// in key order
+        pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+            let mut entries: Vec<_> = self.values.iter().collect();
+            entries.sort();
+
+            let content: String = entries
+                .into_iter()
+                .map(|(key, value)| format!("{}={}\n", key, value))
+                .collect();
+
+            fs::write(path, content).map_err(|e| format!("Failed to write file: {}", e))
+        }
+    }
+
+    impl Default for Config {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Integration Testing");
+    println!("--------------------------");
+
+    let mut config = config::Config::new();
+    config.set("name".to_string(), "module6".to_string());
+    println!("name = {:?}", config.get("name"));
+    println!("The integration tests live in tests/solution_config.rs.");
+}
+
+// Exercise 5 Solution: Property-Based Testing
+
+mod sorting {
+    /// Sorts a vector using bubble sort
+    pub fn bubble_sort<T: Ord + Clone>(slice: &[T]) -> Vec<T> {
+        let mut result = slice.to_vec();
+        let n = result.len();
+
+        for i in 0..n {
+            let mut swapped = false;
+            for j in 0..n - i - 1 {
+                if result[j] > result[j + 1] {
+                    result.swap(j, j + 1);
+                    swapped = true;
+                }
+            }
+            if !swapped {
+                break;
+            }
+        }
+
+        result
+    }
+
+    /// Sorts a vector using insertion sort
+    pub fn insertion_sort<T: Ord + Clone>(slice: &[T]) -> Vec<T> {
+        let mut result = slice.to_vec();
+
+        for i in 1..result.len() {
+            let mut j = i;
+            while j > 0 && result[j - 1] > result[j] {
+                result.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+
+        result
+    }
+
+    /// Sorts a vector using quicksort
+    pub fn quick_sort<T: Ord + Clone>(slice: &[T]) -> Vec<T> {
+        let Some((pivot, rest)) = slice.split_first() else {
+            return Vec::new();
+        };
+
+        let smaller: Vec<T> = rest.iter().filter(|x| *x < pivot).cloned().collect();
+        let larger: Vec<T> = rest.iter().filter(|x| *x >= pivot).cloned().collect();
+
+        let mut result = quick_sort(&smaller);
+        result.push(pivot.clone());
+        result.extend(quick_sort(&larger));
+        result
+    }
+
+    /// Checks if a slice is sorted
+    pub fn is_sorted<T: Ord>(slice: &[T]) -> bool {
+        slice.windows(2).all(|w| w[0] <= w[1])
+    }
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Property-Based Testing");
+    println!("------------------------------");
+
+    let list = [5, 3, 8, 1, 9, 2];
+    println!("bubble_sort:    {:?}", sorting::bubble_sort(&list));
+    println!("insertion_sort: {:?}", sorting::insertion_sort(&list));
+    println!("quick_sort:     {:?}", sorting::quick_sort(&list));
+}
+
+#[cfg(test)]
+mod sorting_tests {
+    use super::sorting::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_sort_idempotence(list in prop::collection::vec(0..100i32, 0..100)) {
+            let sorted = bubble_sort(&list);
+            prop_assert_eq!(bubble_sort(&sorted), sorted);
+        }
+
+        #[test]
+        fn test_sort_result_is_sorted(list in prop::collection::vec(0..100i32, 0..100)) {
+            prop_assert!(is_sorted(&bubble_sort(&list)));
+            prop_assert!(is_sorted(&insertion_sort(&list)));
+            prop_assert!(is_sorted(&quick_sort(&list)));
+        }
+
+        #[test]
+        fn test_sort_preserves_elements(list in prop::collection::vec(0..100i32, 0..100)) {
+            let mut expected = list.clone();
+            expected.sort();
+            prop_assert_eq!(bubble_sort(&list), expected.clone());
+            prop_assert_eq!(insertion_sort(&list), expected.clone());
+            prop_assert_eq!(quick_sort(&list), expected);
+        }
+    }
+}
+
+// The grading suite from problems/grading.rs, run against these answers.
+// With the `grading` feature on too, problems.rs loads the same file for the
+// stubs; the two copies are meant to be separate modules.
+#[cfg(test)]
+#[path = "problems/grading.rs"]
+#[allow(clippy::duplicate_mod)]
+mod grading;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_run_exercises() {
+        super::run_exercises();
+    }
+}
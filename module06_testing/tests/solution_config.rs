@@ -0,0 +1,40 @@
+// Integration tests for the reference Config (Exercise 4 Solution)
+// Each file in tests/ is compiled as its own crate, so these tests only see
+// the public API of module6. They run when the `solutions` feature is on.
+#![cfg(feature = "solutions")]
+
+use module6::solutions::config::Config;
+use std::fs;
+use tempfile::NamedTempFile;
+
+#[test]
+fn test_load_and_save() {
+    let file = NamedTempFile::new().unwrap();
+    fs::write(file.path(), "key1=value1\nkey2=value2\n").unwrap();
+
+    let config = Config::load_from_file(file.path()).unwrap();
+    assert_eq!(config.get("key1"), Some(&"value1".to_string()));
+    assert_eq!(config.get("key2"), Some(&"value2".to_string()));
+
+    let new_file = NamedTempFile::new().unwrap();
+    config.save_to_file(new_file.path()).unwrap();
+    let content = fs::read_to_string(new_file.path()).unwrap();
+    assert_eq!(content, "key1=value1\nkey2=value2\n");
+}
+
+#[test]
+fn test_skips_comments_and_blank_lines() {
+    let file = NamedTempFile::new().unwrap();
+    fs::write(file.path(), "# settings\n\nname = module6\n").unwrap();
+
+    let config = Config::load_from_file(file.path()).unwrap();
+    assert_eq!(config.get("name"), Some(&"module6".to_string()));
+}
+
+#[test]
+fn test_rejects_malformed_lines() {
+    let file = NamedTempFile::new().unwrap();
+    fs::write(file.path(), "not a setting\n").unwrap();
+
+    assert!(Config::load_from_file(file.path()).is_err());
+}
@@ -0,0 +1,57 @@
+// Module 7: Hints
+// Progressive hints for the exercises in problems.rs, one list per exercise,
+// shown by `rustpath run 7 --exercise <n> --hint <level>`. Level 1 is the
+// exercise's "This exercise demonstrates" list; these are levels 2 and up,
+// each giving away a little more than the one before.
+
+pub const HINTS: [&[&str]; 5] = [
+    // Exercise 1: Thread Basics
+    &[
+        "Split the slice into `num_threads` chunks with `data.chunks(chunk_size)`, where \
+         `chunk_size` rounds up (`div_ceil`). Guard against empty data and zero threads first.",
+        "`thread::spawn` needs `'static` data, so it would force you to copy the chunks. \
+         `std::thread::scope` lets threads borrow `data` directly, because they are joined \
+         before the scope ends.",
+        "Inside `thread::scope(|s| ...)`, spawn one `s.spawn(move || chunk.iter().sum::<i32>())` \
+         per chunk, collect the handles, then `join().unwrap()` each one and sum the results.",
+    ],
+    // Exercise 2: Producer-Consumer Pattern
+    &[
+        "Use two `mpsc` channels: one carries tasks to the workers and one carries results \
+         back. WorkQueue keeps the task Sender and the result Receiver.",
+        "A `Receiver` cannot be cloned, so the workers share it as `Arc<Mutex<Receiver<T>>>`. \
+         The handler goes in an `Arc` too, and each worker gets its own clones of both.",
+        "Each worker loops: lock the receiver, `recv()` one task and drop the lock before \
+         running the handler, then send the result. When `recv` fails the queue is gone, so \
+         break out of the loop. `get_result` is `result_receiver.recv().ok()`.",
+    ],
+    // Exercise 3: Reader-Writer Lock
+    &[
+        "The only field needed is `RwLock<HashMap<K, V>>`. Any number of readers may hold the \
+         lock at once; a writer waits until they are all gone.",
+        "`get` takes `self.cache.read().unwrap()` and returns `.get(key).cloned()`. Cloning \
+         the value lets the read guard drop at the end of the statement.",
+        "`insert` and `remove` take the write lock. `remove` returns whether anything was \
+         there: `.remove(key).is_some()`.",
+    ],
+    // Exercise 4: Atomic Operations
+    &[
+        "Wrap an `AtomicUsize`. Every method takes `&self`, because atomics update through a \
+         shared reference without a lock.",
+        "`fetch_add(1, Ordering::SeqCst)` and `fetch_sub` both return the value from before \
+         the update. `load` reads the current value.",
+        "Share the counter across threads with `Arc`. After all threads are joined, `get()` \
+         equals the number of increments minus decrements, however they interleaved.",
+    ],
+    // Exercise 5: Async Programming
+    &[
+        "An executor polls a future and, on `Poll::Pending`, waits until the future's waker is \
+         called before polling again.",
+        "Implement `std::task::Wake` for a small struct holding `thread::current()`, with \
+         `wake` calling `unpark()`. Build the `Waker` with `Waker::from(Arc::new(...))`, and \
+         a `Context` from it.",
+        "Pin the future with `std::pin::pin!`, then loop: `Ready(output)` returns the output \
+         and `Pending` calls `thread::park()`. For Delay, record a deadline on the first poll \
+         and spawn a thread that sleeps and then wakes the waker.",
+    ],
+];
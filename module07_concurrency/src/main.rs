@@ -9,7 +9,7 @@
 // - Tokio runtime deep dive
 // - Common concurrency patterns
 
-mod problems;
+use module7::problems;
 
 use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
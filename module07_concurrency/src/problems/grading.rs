@@ -0,0 +1,165 @@
+// Grading suite for the module 7 exercises
+// These checks are hidden from the exercise walkthrough: `rustpath grade 7`
+// runs them against your implementations in problems.rs.
+
+use super::*;
+
+mod exercise1 {
+    use super::*;
+
+    #[test]
+    fn test_sums_with_several_threads() {
+        let data: Vec<i32> = (1..=1000).collect();
+        for threads in [1, 2, 3, 4, 7] {
+            assert_eq!(parallel_sum(&data, threads), 500500, "{} threads", threads);
+        }
+    }
+
+    #[test]
+    fn test_more_threads_than_items() {
+        assert_eq!(parallel_sum(&[5, -2, 4], 8), 7);
+        assert_eq!(parallel_sum(&[], 4), 0);
+    }
+}
+
+mod exercise2 {
+    use super::*;
+
+    #[test]
+    fn test_every_task_produces_a_result() {
+        let queue = WorkQueue::new(4, |n: i32| n * n);
+        for i in 1..=10 {
+            queue.add_task(i);
+        }
+        let mut results: Vec<i32> = (0..10).filter_map(|_| queue.get_result()).collect();
+        results.sort();
+        assert_eq!(results, [1, 4, 9, 16, 25, 36, 49, 64, 81, 100]);
+    }
+
+    #[test]
+    fn test_work_runs_off_the_calling_thread() {
+        let caller = thread::current().id();
+        let queue = WorkQueue::new(2, move |_: ()| thread::current().id() != caller);
+        queue.add_task(());
+        assert_eq!(queue.get_result(), Some(true));
+    }
+}
+
+mod exercise3 {
+    use super::*;
+
+    #[test]
+    fn test_insert_get_remove() {
+        let cache = ConcurrentCache::new();
+        assert_eq!(cache.get(&1), None);
+        cache.insert(1, "one".to_string());
+        assert_eq!(cache.get(&1), Some("one".to_string()));
+        assert!(cache.remove(&1));
+        assert!(!cache.remove(&1));
+        assert_eq!(cache.get(&1), None);
+    }
+
+    #[test]
+    fn test_concurrent_writers_and_readers() {
+        let cache = Arc::new(ConcurrentCache::new());
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    for i in 0..50 {
+                        cache.insert(t * 100 + i, i);
+                        cache.get(&(t * 100));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        for t in 0..4 {
+            assert_eq!(cache.get(&(t * 100 + 49)), Some(49));
+        }
+    }
+}
+
+mod exercise4 {
+    use super::*;
+
+    #[test]
+    fn test_returns_previous_value() {
+        let counter = AtomicCounter::new();
+        assert_eq!(counter.get(), 0);
+        assert_eq!(counter.increment(), 0);
+        assert_eq!(counter.increment(), 1);
+        assert_eq!(counter.decrement(), 2);
+        assert_eq!(counter.get(), 1);
+    }
+
+    #[test]
+    fn test_concurrent_updates_are_not_lost() {
+        let counter = Arc::new(AtomicCounter::new());
+        let mut handles = Vec::new();
+        for _ in 0..5 {
+            let counter = Arc::clone(&counter);
+            handles.push(thread::spawn(move || {
+                for _ in 0..1000 {
+                    counter.increment();
+                }
+            }));
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        let mut handles = Vec::new();
+        for _ in 0..5 {
+            let counter = Arc::clone(&counter);
+            handles.push(thread::spawn(move || {
+                for _ in 0..500 {
+                    counter.decrement();
+                }
+            }));
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(counter.get(), 2500);
+    }
+}
+
+mod exercise5 {
+    use super::*;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    // Returns `Pending` a few times before completing, waking itself each time
+    struct Countdown(u32);
+
+    impl Future for Countdown {
+        type Output = &'static str;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            if self.0 == 0 {
+                return Poll::Ready("liftoff");
+            }
+            self.0 -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn test_runs_a_ready_future() {
+        assert_eq!(mini_executor(async { 40 + 2 }), 42);
+    }
+
+    #[test]
+    fn test_polls_pending_futures_to_completion() {
+        assert_eq!(mini_executor(Countdown(3)), "liftoff");
+        let chained = async {
+            let first = Countdown(2).await;
+            format!("{}!", first)
+        };
+        assert_eq!(mini_executor(chained), "liftoff!");
+    }
+}
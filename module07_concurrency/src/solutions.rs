@@ -0,0 +1,368 @@
+// Module 7: Reference Solutions
+// Worked answers to the exercises in problems.rs, compiled only with the
+// `solutions` feature. Try solving the exercises yourself first!
+// The types and signatures mirror problems.rs, so the hidden grading suite
+// runs unchanged against these answers (see the tests at the end).
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, RwLock};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub fn run_exercises() {
+    println!("Module 7 Reference Solutions - Concurrency and Parallelism");
+    println!("=====================================================\n");
+
+    exercise1();
+    exercise2();
+    exercise3();
+    exercise4();
+    exercise5();
+}
+
+// Solution entry points in order, used by `rustpath run <module> --solution`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Exercise 1 Solution: Thread Basics
+
+// Scoped threads may borrow `data` directly, so no chunk has to be copied
+fn parallel_sum(data: &[i32], num_threads: usize) -> i32 {
+    if data.is_empty() {
+        return 0;
+    }
+
+    let num_threads = num_threads.clamp(1, data.len());
+    let chunk_size = data.len().div_ceil(num_threads);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = data
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().sum::<i32>()))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    })
+}
+
+fn exercise1() {
+    println!("Exercise 1: Thread Basics");
+    println!("---------------------");
+
+    let data: Vec<i32> = (1..=1000).collect();
+    let sum = parallel_sum(&data, 4);
+    println!("Sum: {}", sum);
+    assert_eq!(sum, data.iter().sum());
+}
+
+// Exercise 2 Solution: Producer-Consumer Pattern
+
+// Workers share one task receiver behind a mutex; each sends its results
+// back over a second channel
+struct WorkQueue<T, R> {
+    task_sender: Sender<T>,
+    result_receiver: Receiver<R>,
+}
+
+impl<T: Send + 'static, R: Send + 'static> WorkQueue<T, R> {
+    fn new<F>(num_workers: usize, handler: F) -> Self
+    where
+        F: Fn(T) -> R + Send + Sync + 'static,
+    {
+        let (task_sender, task_receiver) = mpsc::channel::<T>();
+        let (result_sender, result_receiver) = mpsc::channel();
+        let task_receiver = Arc::new(Mutex::new(task_receiver));
+        let handler = Arc::new(handler);
+
+        for _ in 0..num_workers.max(1) {
+            let task_receiver = Arc::clone(&task_receiver);
+            let result_sender = result_sender.clone();
+            let handler = Arc::clone(&handler);
+
+            thread::spawn(move || loop {
+                // The lock is released as soon as a task is taken, so other
+                // workers can pick up tasks while this one is busy
+                let task = match task_receiver.lock().unwrap().recv() {
+                    Ok(task) => task,
+                    Err(_) => break, // The queue was dropped
+                };
+                if result_sender.send(handler(task)).is_err() {
+                    break;
+                }
+            });
+        }
+
+        WorkQueue {
+            task_sender,
+            result_receiver,
+        }
+    }
+
+    fn add_task(&self, task: T) {
+        self.task_sender.send(task).expect("workers are running");
+    }
+
+    // Blocks until a worker finishes a task
+    fn get_result(&self) -> Option<R> {
+        self.result_receiver.recv().ok()
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Producer-Consumer Pattern");
+    println!("--------------------------------");
+
+    let queue = WorkQueue::new(4, |n: i32| {
+        // Simulate work
+        thread::sleep(Duration::from_millis(10));
+        n * n
+    });
+
+    for i in 1..=10 {
+        queue.add_task(i);
+    }
+
+    let mut results = Vec::new();
+    for _ in 1..=10 {
+        if let Some(result) = queue.get_result() {
+            results.push(result);
+        }
+    }
+
+    println!("Results: {:?}", results);
+    assert_eq!(results.len(), 10);
+}
+
+// Exercise 3 Solution: Reader-Writer Lock
+
+struct ConcurrentCache<K, V> {
+    cache: RwLock<HashMap<K, V>>,
+}
+
+impl<K, V> ConcurrentCache<K, V>
+where
+    K: std::cmp::Eq + std::hash::Hash + Clone + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    fn new() -> Self {
+        ConcurrentCache {
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    // Any number of readers can hold the read lock at once
+    fn get(&self, key: &K) -> Option<V> {
+        self.cache.read().unwrap().get(key).cloned()
+    }
+
+    fn insert(&self, key: K, value: V) {
+        self.cache.write().unwrap().insert(key, value);
+    }
+
+    fn remove(&self, key: &K) -> bool {
+        self.cache.write().unwrap().remove(key).is_some()
+    }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Reader-Writer Lock");
+    println!("--------------------------");
+
+    let cache_arc = Arc::new(ConcurrentCache::new());
+
+    let mut readers = vec![];
+    for _ in 0..5 {
+        let cache = Arc::clone(&cache_arc);
+        readers.push(thread::spawn(move || {
+            let mut hits = 0;
+            for j in 0..100 {
+                if cache.get(&j).is_some() {
+                    hits += 1;
+                }
+                thread::sleep(Duration::from_millis(1));
+            }
+            hits
+        }));
+    }
+
+    let mut writers = vec![];
+    for i in 0..3 {
+        let cache = Arc::clone(&cache_arc);
+        writers.push(thread::spawn(move || {
+            for j in 0..100 {
+                cache.insert(j, format!("value-{}-{}", i, j));
+                thread::sleep(Duration::from_millis(1));
+            }
+        }));
+    }
+
+    for (i, reader) in readers.into_iter().enumerate() {
+        println!("Reader {} found {} of 100 keys", i, reader.join().unwrap());
+    }
+    for writer in writers {
+        writer.join().unwrap();
+    }
+    println!("Key 99 ended up as {:?}", cache_arc.get(&99));
+}
+
+// Exercise 4 Solution: Atomic Operations
+
+struct AtomicCounter {
+    count: AtomicUsize,
+}
+
+impl AtomicCounter {
+    fn new() -> Self {
+        AtomicCounter {
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    // `fetch_*` returns the value from before the update
+    fn increment(&self) -> usize {
+        self.count.fetch_add(1, Ordering::SeqCst)
+    }
+
+    fn decrement(&self) -> usize {
+        self.count.fetch_sub(1, Ordering::SeqCst)
+    }
+
+    fn get(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Atomic Operations");
+    println!("-------------------------");
+
+    let counter = Arc::new(AtomicCounter::new());
+    let mut handles = vec![];
+
+    for _ in 0..5 {
+        let counter = Arc::clone(&counter);
+        handles.push(thread::spawn(move || {
+            for _ in 0..1000 {
+                counter.increment();
+            }
+        }));
+    }
+
+    // Decrements may run before some increments and wrap below zero for a
+    // while; wrapping arithmetic still lands on the right total
+    for _ in 0..5 {
+        let counter = Arc::clone(&counter);
+        handles.push(thread::spawn(move || {
+            for _ in 0..500 {
+                counter.decrement();
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    println!("Final counter value: {}", counter.get());
+    assert_eq!(counter.get(), 2500); // 5*1000 - 5*500 = 2500
+}
+
+// Exercise 5 Solution: Async Programming
+
+// The deadline is set on the first poll; a helper thread wakes the task
+// once it has passed
+struct Delay {
+    duration: Duration,
+    deadline: Option<Instant>,
+}
+
+impl Delay {
+    fn new(duration: Duration) -> Self {
+        Delay {
+            duration,
+            deadline: None,
+        }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let now = Instant::now();
+        let duration = self.duration;
+        let deadline = *self.deadline.get_or_insert(now + duration);
+        if now >= deadline {
+            return Poll::Ready(());
+        }
+
+        let waker = cx.waker().clone();
+        thread::spawn(move || {
+            thread::sleep(deadline - now);
+            waker.wake();
+        });
+        Poll::Pending
+    }
+}
+
+// Waking unparks the thread blocked in `mini_executor`
+struct ThreadWaker(thread::Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// Polls the future on the current thread, parking between polls until its
+// waker is called. A spurious unpark only costs an extra poll.
+fn mini_executor<F>(future: F) -> F::Output
+where
+    F: std::future::Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Async Programming");
+    println!("-------------------------");
+
+    async fn async_task() -> i32 {
+        let delay = Delay::new(Duration::from_millis(100));
+        delay.await;
+        42
+    }
+
+    let start = Instant::now();
+    let result = mini_executor(async_task());
+    println!("Result: {} after {:?}", result, start.elapsed());
+}
+
+// The grading suite from problems/grading.rs, run against these answers.
+// With the `grading` feature on too, problems.rs loads the same file for the
+// stubs; the two copies are meant to be separate modules.
+#[cfg(test)]
+#[path = "problems/grading.rs"]
+#[allow(clippy::duplicate_mod)]
+mod grading;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_run_exercises() {
+        super::run_exercises();
+    }
+}
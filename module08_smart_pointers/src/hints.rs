@@ -0,0 +1,56 @@
+// Module 8: Hints
+// Progressive hints for the exercises in problems.rs, one list per exercise,
+// shown by `rustpath run 8 --exercise <n> --hint <level>`. Level 1 is the
+// exercise's "This exercise demonstrates" list; these are levels 2 and up,
+// each giving away a little more than the one before.
+
+pub const HINTS: [&[&str]; 5] = [
+    // Exercise 1: Custom Smart Pointer
+    &[
+        "SmartString wraps a `String`. With `Target = str`, `deref` returns `&self.data` and \
+         every `&str` method works on a SmartString for free.",
+        "To count accesses in `deref`, which only has `&self`, keep the counter in a \
+         `Cell<usize>` and `set(get() + 1)`. `len` and `is_empty` can go through the deref.",
+        "`deref_mut` returns `self.data.as_mut_str()`. `Drop` just prints the string and the \
+         access count, so you can watch when each SmartString goes away.",
+    ],
+    // Exercise 2: Recursive Data Structure
+    &[
+        "A recursive enum needs indirection: `Node(Box<TreeNode<T>>)`. Without the Box the \
+         type would have infinite size.",
+        "`insert` matches on `self`. An `Empty` slot is replaced with `*self = \
+         BinaryTree::Node(Box::new(TreeNode { ... }))`; otherwise compare the values and \
+         recurse into `left` or `right`.",
+        "`contains`, `height` and `print_in_order` follow the same shape: `Empty` is the base \
+         case, and a node combines the results from both children. For the height that is \
+         `1 + left.max(right)`.",
+    ],
+    // Exercise 3: Shared Cache
+    &[
+        "`new` returns `Rc<Self>`, and every method takes `&self`. The map must therefore \
+         live in a `RefCell<HashMap<K, V>>` so it can be changed through a shared reference.",
+        "`get` borrows: `self.data.borrow().get(key).cloned()`. Return a clone, because a \
+         reference into the map cannot outlive the `Ref` guard.",
+        "`insert` uses `self.data.borrow_mut()`. Keep the guards short-lived; holding a \
+         `borrow()` while calling `borrow_mut()` panics at runtime.",
+    ],
+    // Exercise 4: Object Graph with Weak References
+    &[
+        "The game object owns its components, so it holds `Rc<Component>`s. A component only \
+         points back at its owner with a `Weak<GameObject>`.",
+        "`add_component` takes `&self`, so the list has to be a `RefCell<Vec<Rc<Component>>>`. \
+         `Component::new` stores `Rc::downgrade(game_object)`.",
+        "`game_object()` is `self.game_object.upgrade()`. It returns None once the game object \
+         has been dropped, which is exactly what prevents the cycle.",
+    ],
+    // Exercise 5: Memory Pool
+    &[
+        "Pre-allocate `capacity` slots as `Vec<Option<T>>`, all None. A handle is just a slot \
+         index.",
+        "Keep a stack of free indices. `allocate` pops one (or returns None when the pool is \
+         full), and `deallocate` pushes the index back after `take()`ing the slot.",
+        "Double frees must be refused: `deallocate` returns false unless the slot held a \
+         value. `get` is `self.slots.get(index)?.as_ref()`, and `allocated_count` is the \
+         capacity minus the free list's length.",
+    ],
+];
@@ -0,0 +1,11 @@
+// Module 8: Smart Pointers and Interior Mutability
+// Library target exposing the exercises so the `rustpath` runner can drive them.
+
+pub mod problems;
+
+// Progressive hints shown by `rustpath run --hint`
+pub mod hints;
+
+// Reference solutions, compiled only with the `solutions` feature
+#[cfg(feature = "solutions")]
+pub mod solutions;
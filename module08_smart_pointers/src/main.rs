@@ -8,7 +8,7 @@
 // - Understanding Drop and destructors
 // - Memory leaks and how to prevent them
 
-mod problems;
+use module8::problems;
 
 use std::cell::{Cell, RefCell};
 use std::ops::{Deref, DerefMut};
@@ -18,62 +18,70 @@ pub fn run_exercises() {
     exercise5();
 }
 
+// Exercise entry points in order, used by `rustpath run <module> --exercise <n>`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Hidden per-exercise checks, run against this file by `rustpath grade`.
+#[cfg(all(test, feature = "grading"))]
+mod grading;
+
 // Exercise 1: Custom Smart Pointer
 // This exercise demonstrates:
 // - Implementing Deref and DerefMut traits
 // - Understanding smart pointer behavior
 // - Custom Drop implementation
-fn exercise1() {
-    println!("Exercise 1: Custom Smart Pointer");
-    println!("--------------------------");
-    println!("TODO: Implement the SmartString struct and its traits\n");
 
-    // A smart pointer for strings with additional functionality
-    struct SmartString {
-        // TODO: Implement the fields needed for a smart string
-    }
+// A smart pointer for strings with additional functionality
+struct SmartString {
+    // TODO: Implement the fields needed for a smart string
+}
 
-    impl SmartString {
-        // Create a new SmartString
-        fn new(s: &str) -> Self {
-            unimplemented!("Implement SmartString::new");
-        }
+impl SmartString {
+    // Create a new SmartString
+    fn new(s: &str) -> Self {
+        unimplemented!("Implement SmartString::new");
+    }
 
-        // Get the length of the string
-        fn len(&self) -> usize {
-            unimplemented!("Implement len");
-        }
+    // Get the length of the string
+    fn len(&self) -> usize {
+        unimplemented!("Implement len");
+    }
 
-        // Check if the string is empty
-        fn is_empty(&self) -> bool {
-            unimplemented!("Implement is_empty");
-        }
+    // Check if the string is empty
+    fn is_empty(&self) -> bool {
+        unimplemented!("Implement is_empty");
+    }
 
-        // Convert to uppercase
-        fn to_uppercase(&self) -> SmartString {
-            unimplemented!("Implement to_uppercase");
-        }
+    // Convert to uppercase
+    fn to_uppercase(&self) -> SmartString {
+        unimplemented!("Implement to_uppercase");
     }
+}
 
-    impl Deref for SmartString {
-        type Target = str;
+impl Deref for SmartString {
+    type Target = str;
 
-        fn deref(&self) -> &Self::Target {
-            unimplemented!("Implement deref");
-        }
+    fn deref(&self) -> &Self::Target {
+        unimplemented!("Implement deref");
     }
+}
 
-    impl DerefMut for SmartString {
-        fn deref_mut(&mut self) -> &mut Self::Target {
-            unimplemented!("Implement deref_mut");
-        }
+impl DerefMut for SmartString {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unimplemented!("Implement deref_mut");
     }
+}
 
-    impl Drop for SmartString {
-        fn drop(&mut self) {
-            unimplemented!("Implement drop");
-        }
+impl Drop for SmartString {
+    fn drop(&mut self) {
+        unimplemented!("Implement drop");
     }
+}
+
+fn exercise1() {
+    println!("Exercise 1: Custom Smart Pointer");
+    println!("--------------------------");
+    println!("TODO: Implement the SmartString struct and its traits\n");
 
     // Test your implementation:
     // let mut smart = SmartString::new("Hello, world!");
@@ -99,49 +107,50 @@ fn exercise1() {
 // - Using Box<T> for recursive types
 // - Tree structures with owned nodes
 // - Traversal algorithms
-fn exercise2() {
-    println!("\nExercise 2: Recursive Data Structure");
-    println!("-------------------------------");
-    println!("TODO: Implement the BinaryTree struct and its methods\n");
 
-    // A binary tree with owned nodes
-    enum BinaryTree<T> {
-        Leaf,
-        Node(Box<TreeNode<T>>),
-    }
+// A binary tree with owned nodes
+enum BinaryTree<T> {
+    Leaf,
+    Node(Box<TreeNode<T>>),
+}
 
-    struct TreeNode<T> {
-        value: T,
-        left: BinaryTree<T>,
-        right: BinaryTree<T>,
-    }
+struct TreeNode<T> {
+    value: T,
+    left: BinaryTree<T>,
+    right: BinaryTree<T>,
+}
 
-    impl<T: Ord + std::fmt::Debug> BinaryTree<T> {
-        // Create a new empty tree
-        fn new() -> Self {
-            unimplemented!("Implement BinaryTree::new");
-        }
+impl<T: Ord + std::fmt::Debug> BinaryTree<T> {
+    // Create a new empty tree
+    fn new() -> Self {
+        unimplemented!("Implement BinaryTree::new");
+    }
 
-        // Insert a value into the tree
-        fn insert(&mut self, value: T) {
-            unimplemented!("Implement insert");
-        }
+    // Insert a value into the tree
+    fn insert(&mut self, value: T) {
+        unimplemented!("Implement insert");
+    }
 
-        // Check if the tree contains a value
-        fn contains(&self, value: &T) -> bool {
-            unimplemented!("Implement contains");
-        }
+    // Check if the tree contains a value
+    fn contains(&self, value: &T) -> bool {
+        unimplemented!("Implement contains");
+    }
 
-        // Print the tree in-order
-        fn print_in_order(&self) {
-            unimplemented!("Implement print_in_order");
-        }
+    // Print the tree in-order
+    fn print_in_order(&self) {
+        unimplemented!("Implement print_in_order");
+    }
 
-        // Calculate the height of the tree
-        fn height(&self) -> usize {
-            unimplemented!("Implement height");
-        }
+    // Calculate the height of the tree
+    fn height(&self) -> usize {
+        unimplemented!("Implement height");
     }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Recursive Data Structure");
+    println!("-------------------------------");
+    println!("TODO: Implement the BinaryTree struct and its methods\n");
 
     // Test your implementation:
     // let mut tree = BinaryTree::new();
@@ -164,46 +173,48 @@ fn exercise2() {
 // - Using Rc<T> for shared ownership
 // - Using RefCell<T> for interior mutability
 // - Implementing a cache with shared access
-fn exercise3() {
-    println!("\nExercise 3: Shared Cache");
-    println!("-------------------");
-    println!("TODO: Implement the SharedCache struct and its methods\n");
 
-    // A cache that can be shared between multiple owners
-    struct SharedCache<K, V> {
-        // TODO: Implement the fields needed for a shared cache
-    }
+// A cache that can be shared between multiple owners
+struct SharedCache<K, V> {
+    // TODO: Implement the fields needed for a shared cache
+    _marker: std::marker::PhantomData<(K, V)>,
+}
 
-    impl<K, V> SharedCache<K, V>
-    where
-        K: std::cmp::Eq + std::hash::Hash + Clone,
-        V: Clone,
-    {
-        // Create a new shared cache
-        fn new() -> Rc<Self> {
-            unimplemented!("Implement SharedCache::new");
-        }
+impl<K, V> SharedCache<K, V>
+where
+    K: std::cmp::Eq + std::hash::Hash + Clone,
+    V: Clone,
+{
+    // Create a new shared cache
+    fn new() -> Rc<Self> {
+        unimplemented!("Implement SharedCache::new");
+    }
 
-        // Get a value from the cache
-        fn get(&self, key: &K) -> Option<V> {
-            unimplemented!("Implement get");
-        }
+    // Get a value from the cache
+    fn get(&self, key: &K) -> Option<V> {
+        unimplemented!("Implement get");
+    }
 
-        // Insert a value into the cache
-        fn insert(&self, key: K, value: V) {
-            unimplemented!("Implement insert");
-        }
+    // Insert a value into the cache
+    fn insert(&self, key: K, value: V) {
+        unimplemented!("Implement insert");
+    }
 
-        // Get the number of items in the cache
-        fn len(&self) -> usize {
-            unimplemented!("Implement len");
-        }
+    // Get the number of items in the cache
+    fn len(&self) -> usize {
+        unimplemented!("Implement len");
+    }
 
-        // Check if the cache is empty
-        fn is_empty(&self) -> bool {
-            unimplemented!("Implement is_empty");
-        }
+    // Check if the cache is empty
+    fn is_empty(&self) -> bool {
+        unimplemented!("Implement is_empty");
     }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Shared Cache");
+    println!("-------------------");
+    println!("TODO: Implement the SharedCache struct and its methods\n");
 
     // Test your implementation:
     // let cache = SharedCache::new();
@@ -230,64 +241,65 @@ fn exercise3() {
 // - Using Weak<T> to prevent reference cycles
 // - Building complex object graphs
 // - Parent-child relationships
-fn exercise4() {
-    println!("\nExercise 4: Object Graph with Weak References");
-    println!("----------------------------------------");
-    println!("TODO: Implement the Component and GameObject structs\n");
 
-    // A component in a game object system
-    struct Component {
-        // TODO: Implement the fields needed for a component
-    }
+// A component in a game object system
+struct Component {
+    // TODO: Implement the fields needed for a component
+}
 
-    // A game object that contains components
-    struct GameObject {
-        // TODO: Implement the fields needed for a game object
-    }
+// A game object that contains components
+struct GameObject {
+    // TODO: Implement the fields needed for a game object
+}
 
-    impl Component {
-        // Create a new component
-        fn new(name: &str, game_object: &Rc<GameObject>) -> Rc<Self> {
-            unimplemented!("Implement Component::new");
-        }
+impl Component {
+    // Create a new component
+    fn new(name: &str, game_object: &Rc<GameObject>) -> Rc<Self> {
+        unimplemented!("Implement Component::new");
+    }
 
-        // Get the name of the component
-        fn name(&self) -> &str {
-            unimplemented!("Implement name");
-        }
+    // Get the name of the component
+    fn name(&self) -> &str {
+        unimplemented!("Implement name");
+    }
 
-        // Get the parent game object
-        fn game_object(&self) -> Option<Rc<GameObject>> {
-            unimplemented!("Implement game_object");
-        }
+    // Get the parent game object
+    fn game_object(&self) -> Option<Rc<GameObject>> {
+        unimplemented!("Implement game_object");
     }
+}
 
-    impl GameObject {
-        // Create a new game object
-        fn new(name: &str) -> Rc<Self> {
-            unimplemented!("Implement GameObject::new");
-        }
+impl GameObject {
+    // Create a new game object
+    fn new(name: &str) -> Rc<Self> {
+        unimplemented!("Implement GameObject::new");
+    }
 
-        // Get the name of the game object
-        fn name(&self) -> &str {
-            unimplemented!("Implement name");
-        }
+    // Get the name of the game object
+    fn name(&self) -> &str {
+        unimplemented!("Implement name");
+    }
 
-        // Add a component to the game object
-        fn add_component(&self, component: Rc<Component>) {
-            unimplemented!("Implement add_component");
-        }
+    // Add a component to the game object
+    fn add_component(&self, component: Rc<Component>) {
+        unimplemented!("Implement add_component");
+    }
 
-        // Get a component by name
-        fn get_component(&self, name: &str) -> Option<Rc<Component>> {
-            unimplemented!("Implement get_component");
-        }
+    // Get a component by name
+    fn get_component(&self, name: &str) -> Option<Rc<Component>> {
+        unimplemented!("Implement get_component");
+    }
 
-        // Get all components
-        fn get_components(&self) -> Vec<Rc<Component>> {
-            unimplemented!("Implement get_components");
-        }
+    // Get all components
+    fn get_components(&self) -> Vec<Rc<Component>> {
+        unimplemented!("Implement get_components");
     }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Object Graph with Weak References");
+    println!("----------------------------------------");
+    println!("TODO: Implement the Component and GameObject structs\n");
 
     // Test your implementation:
     // let game_object = GameObject::new("Player");
@@ -314,53 +326,55 @@ fn exercise4() {
 // - Custom memory management
 // - Reusing allocated memory
 // - Safe abstractions over raw memory
-fn exercise5() {
-    println!("\nExercise 5: Memory Pool");
-    println!("------------------");
-    println!("TODO: Implement the MemoryPool struct and its methods\n");
 
-    // A memory pool that allocates and reuses fixed-size blocks
-    struct MemoryPool<T> {
-        // TODO: Implement the fields needed for a memory pool
-    }
+// A memory pool that allocates and reuses fixed-size blocks
+struct MemoryPool<T> {
+    // TODO: Implement the fields needed for a memory pool
+    _marker: std::marker::PhantomData<T>,
+}
 
-    impl<T> MemoryPool<T> {
-        // Create a new memory pool with the given capacity
-        fn new(capacity: usize) -> Self {
-            unimplemented!("Implement MemoryPool::new");
-        }
+impl<T> MemoryPool<T> {
+    // Create a new memory pool with the given capacity
+    fn new(capacity: usize) -> Self {
+        unimplemented!("Implement MemoryPool::new");
+    }
 
-        // Allocate a new object in the pool
-        fn allocate(&mut self, value: T) -> Option<usize> {
-            unimplemented!("Implement allocate");
-        }
+    // Allocate a new object in the pool
+    fn allocate(&mut self, value: T) -> Option<usize> {
+        unimplemented!("Implement allocate");
+    }
 
-        // Deallocate an object from the pool
-        fn deallocate(&mut self, index: usize) -> bool {
-            unimplemented!("Implement deallocate");
-        }
+    // Deallocate an object from the pool
+    fn deallocate(&mut self, index: usize) -> bool {
+        unimplemented!("Implement deallocate");
+    }
 
-        // Get a reference to an object in the pool
-        fn get(&self, index: usize) -> Option<&T> {
-            unimplemented!("Implement get");
-        }
+    // Get a reference to an object in the pool
+    fn get(&self, index: usize) -> Option<&T> {
+        unimplemented!("Implement get");
+    }
 
-        // Get a mutable reference to an object in the pool
-        fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-            unimplemented!("Implement get_mut");
-        }
+    // Get a mutable reference to an object in the pool
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        unimplemented!("Implement get_mut");
+    }
 
-        // Get the number of allocated objects
-        fn allocated_count(&self) -> usize {
-            unimplemented!("Implement allocated_count");
-        }
+    // Get the number of allocated objects
+    fn allocated_count(&self) -> usize {
+        unimplemented!("Implement allocated_count");
     }
+}
 
-    impl<T> Drop for MemoryPool<T> {
-        fn drop(&mut self) {
-            unimplemented!("Implement drop");
-        }
+impl<T> Drop for MemoryPool<T> {
+    fn drop(&mut self) {
+        unimplemented!("Implement drop");
     }
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Memory Pool");
+    println!("------------------");
+    println!("TODO: Implement the MemoryPool struct and its methods\n");
 
     // Test your implementation:
     // let mut pool = MemoryPool::new(10);
@@ -390,289 +404,7 @@ fn exercise5() {
     // println!("New object 4: {}", pool.get(idx4).unwrap());
 }
 
-/* Example Solutions (Try to solve the exercises before looking at these!)
-
-// Exercise 1 Solution:
-struct SmartString {
-    data: String,
-    access_count: Cell<usize>,
-}
-
-impl SmartString {
-    fn new(s: &str) -> Self {
-        SmartString {
-            data: s.to_string(),
-            access_count: Cell::new(0),
-        }
-    }
-
-    fn len(&self) -> usize {
-        self.access_count.set(self.access_count.get() + 1);
-        self.data.len()
-    }
-
-    fn is_empty(&self) -> bool {
-        self.access_count.set(self.access_count.get() + 1);
-        self.data.is_empty()
-    }
-
-    fn to_uppercase(&self) -> SmartString {
-        self.access_count.set(self.access_count.get() + 1);
-        SmartString::new(&self.data.to_uppercase())
-    }
-}
-
-impl Deref for SmartString {
-    type Target = str;
-
-    fn deref(&self) -> &Self::Target {
-        self.access_count.set(self.access_count.get() + 1);
-        &self.data
-    }
-}
-
-impl DerefMut for SmartString {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        self.access_count.set(self.access_count.get() + 1);
-        &mut self.data
-    }
-}
-
-impl Drop for SmartString {
-    fn drop(&mut self) {
-        println!("Dropping SmartString with content '{}', accessed {} times",
-            self.data, self.access_count.get());
-    }
-}
-
-// Exercise 2 Solution:
-impl<T: Ord + std::fmt::Debug> BinaryTree<T> {
-    fn new() -> Self {
-        BinaryTree::Leaf
-    }
-
-    fn insert(&mut self, value: T) {
-        match self {
-            BinaryTree::Leaf => {
-                *self = BinaryTree::Node(Box::new(TreeNode {
-                    value,
-                    left: BinaryTree::Leaf,
-                    right: BinaryTree::Leaf,
-                }));
-            }
-            BinaryTree::Node(ref mut node) => {
-                if value < node.value {
-                    node.left.insert(value);
-                } else {
-                    node.right.insert(value);
-                }
-            }
-        }
-    }
-
-    fn contains(&self, value: &T) -> bool {
-        match self {
-            BinaryTree::Leaf => false,
-            BinaryTree::Node(node) => {
-                if &node.value == value {
-                    true
-                } else if value < &node.value {
-                    node.left.contains(value)
-                } else {
-                    node.right.contains(value)
-                }
-            }
-        }
-    }
-
-    fn print_in_order(&self) {
-        match self {
-            BinaryTree::Leaf => {}
-            BinaryTree::Node(node) => {
-                node.left.print_in_order();
-                println!("{:?}", node.value);
-                node.right.print_in_order();
-            }
-        }
-    }
-
-    fn height(&self) -> usize {
-        match self {
-            BinaryTree::Leaf => 0,
-            BinaryTree::Node(node) => {
-                1 + std::cmp::max(node.left.height(), node.right.height())
-            }
-        }
-    }
-}
-
-// Exercise 3 Solution:
-use std::collections::HashMap;
-
-struct SharedCache<K, V> {
-    data: RefCell<HashMap<K, V>>,
-}
-
-impl<K, V> SharedCache<K, V>
-where
-    K: std::cmp::Eq + std::hash::Hash + Clone,
-    V: Clone,
-{
-    fn new() -> Rc<Self> {
-        Rc::new(SharedCache {
-            data: RefCell::new(HashMap::new()),
-        })
-    }
-
-    fn get(&self, key: &K) -> Option<V> {
-        self.data.borrow().get(key).cloned()
-    }
-
-    fn insert(&self, key: K, value: V) {
-        self.data.borrow_mut().insert(key, value);
-    }
-
-    fn len(&self) -> usize {
-        self.data.borrow().len()
-    }
-
-    fn is_empty(&self) -> bool {
-        self.data.borrow().is_empty()
-    }
-}
-
-// Exercise 4 Solution:
-struct Component {
-    name: String,
-    game_object: Weak<GameObject>,
-}
-
-struct GameObject {
-    name: String,
-    components: RefCell<Vec<Rc<Component>>>,
-}
-
-impl Component {
-    fn new(name: &str, game_object: &Rc<GameObject>) -> Rc<Self> {
-        Rc::new(Component {
-            name: name.to_string(),
-            game_object: Rc::downgrade(game_object),
-        })
-    }
-
-    fn name(&self) -> &str {
-        &self.name
-    }
-
-    fn game_object(&self) -> Option<Rc<GameObject>> {
-        self.game_object.upgrade()
-    }
-}
-
-impl GameObject {
-    fn new(name: &str) -> Rc<Self> {
-        Rc::new(GameObject {
-            name: name.to_string(),
-            components: RefCell::new(Vec::new()),
-        })
-    }
-
-    fn name(&self) -> &str {
-        &self.name
-    }
-
-    fn add_component(&self, component: Rc<Component>) {
-        self.components.borrow_mut().push(component);
-    }
-
-    fn get_component(&self, name: &str) -> Option<Rc<Component>> {
-        self.components.borrow().iter()
-            .find(|c| c.name() == name)
-            .cloned()
-    }
-
-    fn get_components(&self) -> Vec<Rc<Component>> {
-        self.components.borrow().clone()
-    }
-}
-
-// Exercise 5 Solution:
-struct MemoryPool<T> {
-    data: Vec<Option<T>>,
-    free_indices: Vec<usize>,
-    allocated: usize,
-}
-
-impl<T> MemoryPool<T> {
-    fn new(capacity: usize) -> Self {
-        let mut data = Vec::with_capacity(capacity);
-        for _ in 0..capacity {
-            data.push(None);
-        }
-
-        let mut free_indices = Vec::with_capacity(capacity);
-        for i in 0..capacity {
-            free_indices.push(i);
-        }
-
-        MemoryPool {
-            data,
-            free_indices,
-            allocated: 0,
-        }
-    }
-
-    fn allocate(&mut self, value: T) -> Option<usize> {
-        if let Some(index) = self.free_indices.pop() {
-            self.data[index] = Some(value);
-            self.allocated += 1;
-            Some(index)
-        } else {
-            None
-        }
-    }
-
-    fn deallocate(&mut self, index: usize) -> bool {
-        if index >= self.data.len() || self.data[index].is_none() {
-            return false;
-        }
-
-        self.data[index] = None;
-        self.free_indices.push(index);
-        self.allocated -= 1;
-        true
-    }
-
-    fn get(&self, index: usize) -> Option<&T> {
-        if index >= self.data.len() {
-            return None;
-        }
-
-        match &self.data[index] {
-            Some(value) => Some(value),
-            None => None,
-        }
-    }
-
-    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        if index >= self.data.len() {
-            return None;
-        }
-
-        match &mut self.data[index] {
-            Some(value) => Some(value),
-            None => None,
-        }
-    }
-
-    fn allocated_count(&self) -> usize {
-        self.allocated
-    }
-}
-
-impl<T> Drop for MemoryPool<T> {
-    fn drop(&mut self) {
-        println!("Dropping MemoryPool with {} allocated objects", self.allocated);
-    }
-}
-*/
+// Example Solutions
+// The reference answers live in src/solutions.rs and are compiled with the
+// `solutions` feature (`rustpath run <module> --solution`). Try solving the
+// exercises yourself first!
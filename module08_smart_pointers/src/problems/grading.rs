@@ -0,0 +1,172 @@
+// Grading suite for the module 8 exercises
+// These checks are hidden from the exercise walkthrough: `rustpath grade 8`
+// runs them against your implementations in problems.rs.
+
+use super::*;
+
+mod exercise1 {
+    use super::*;
+
+    #[test]
+    fn test_len_and_is_empty() {
+        let smart = SmartString::new("Hello, world!");
+        assert_eq!(smart.len(), 13);
+        assert!(!smart.is_empty());
+        assert!(SmartString::new("").is_empty());
+    }
+
+    #[test]
+    fn test_derefs_to_str() {
+        let smart = SmartString::new("Hello, world!");
+        assert!(smart.contains("world"));
+        let s: &str = &smart;
+        assert_eq!(s, "Hello, world!");
+        assert_eq!(&*smart.to_uppercase(), "HELLO, WORLD!");
+    }
+
+    #[test]
+    fn test_deref_mut_edits_in_place() {
+        let mut smart = SmartString::new("hello");
+        smart.make_ascii_uppercase();
+        assert_eq!(&*smart, "HELLO");
+    }
+}
+
+mod exercise2 {
+    use super::*;
+
+    fn tree(values: &[i32]) -> BinaryTree<i32> {
+        let mut tree = BinaryTree::new();
+        for &value in values {
+            tree.insert(value);
+        }
+        tree
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree: BinaryTree<i32> = BinaryTree::new();
+        assert_eq!(tree.height(), 0);
+        assert!(!tree.contains(&1));
+        tree.print_in_order();
+    }
+
+    #[test]
+    fn test_contains_inserted_values() {
+        let tree = tree(&[5, 3, 7, 2, 4]);
+        for value in [2, 3, 4, 5, 7] {
+            assert!(tree.contains(&value), "{}", value);
+        }
+        assert!(!tree.contains(&6));
+        tree.print_in_order();
+    }
+
+    #[test]
+    fn test_height() {
+        assert_eq!(tree(&[5, 3, 7, 2, 4]).height(), 3);
+        assert_eq!(tree(&[1, 2, 3, 4]).height(), 4);
+    }
+}
+
+mod exercise3 {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_contents() {
+        let cache = SharedCache::new();
+        let clone = Rc::clone(&cache);
+        assert!(cache.is_empty());
+        cache.insert("key1".to_string(), 1);
+        clone.insert("key2".to_string(), 2);
+        assert_eq!(clone.get(&"key1".to_string()), Some(1));
+        assert_eq!(cache.get(&"key2".to_string()), Some(2));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_insert_overwrites() {
+        let cache = SharedCache::new();
+        cache.insert(1, "a");
+        cache.insert(1, "b");
+        assert_eq!(cache.get(&1), Some("b"));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(&2), None);
+    }
+}
+
+mod exercise4 {
+    use super::*;
+
+    #[test]
+    fn test_components_know_their_game_object() {
+        let player = GameObject::new("Player");
+        player.add_component(Component::new("Transform", &player));
+        player.add_component(Component::new("Renderer", &player));
+
+        assert_eq!(player.name(), "Player");
+        let names: Vec<String> = player
+            .get_components()
+            .iter()
+            .map(|c| c.name().to_string())
+            .collect();
+        assert_eq!(names, ["Transform", "Renderer"]);
+
+        let renderer = player.get_component("Renderer").unwrap();
+        assert!(Rc::ptr_eq(&renderer.game_object().unwrap(), &player));
+        assert!(player.get_component("Physics").is_none());
+    }
+
+    #[test]
+    fn test_back_references_are_weak() {
+        let player = GameObject::new("Player");
+        let transform = Component::new("Transform", &player);
+        player.add_component(Rc::clone(&transform));
+
+        assert_eq!(Rc::strong_count(&player), 1);
+        assert_eq!(Rc::weak_count(&player), 1);
+        drop(player);
+        assert!(transform.game_object().is_none());
+    }
+}
+
+mod exercise5 {
+    use super::*;
+
+    #[test]
+    fn test_allocate_get_and_modify() {
+        let mut pool = MemoryPool::new(4);
+        let a = pool.allocate(String::from("a")).unwrap();
+        let b = pool.allocate(String::from("b")).unwrap();
+        assert_ne!(a, b);
+        pool.get_mut(b).unwrap().push('!');
+        assert_eq!(pool.get(a).map(String::as_str), Some("a"));
+        assert_eq!(pool.get(b).map(String::as_str), Some("b!"));
+        assert_eq!(pool.allocated_count(), 2);
+    }
+
+    #[test]
+    fn test_capacity_and_reuse() {
+        let mut pool = MemoryPool::new(2);
+        let a = pool.allocate(1).unwrap();
+        pool.allocate(2).unwrap();
+        assert_eq!(pool.allocate(3), None);
+
+        assert!(pool.deallocate(a));
+        assert!(!pool.deallocate(a));
+        assert_eq!(pool.get(a), None);
+        assert_eq!(pool.allocated_count(), 1);
+        assert!(pool.allocate(4).is_some());
+    }
+
+    #[test]
+    fn test_drop_releases_values() {
+        let value = Rc::new(());
+        {
+            let mut pool = MemoryPool::new(3);
+            pool.allocate(Rc::clone(&value));
+            pool.allocate(Rc::clone(&value));
+            assert_eq!(Rc::strong_count(&value), 3);
+        }
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+}
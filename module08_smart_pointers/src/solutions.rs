@@ -0,0 +1,432 @@
+// Module 8: Reference Solutions
+// Worked answers to the exercises in problems.rs, compiled only with the
+// `solutions` feature. Try solving the exercises yourself first!
+// The types and signatures mirror problems.rs, so the hidden grading suite
+// runs unchanged against these answers (see the tests at the end).
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::rc::{Rc, Weak};
+
+pub fn run_exercises() {
+    println!("Module 8 Reference Solutions - Smart Pointers and Interior Mutability");
+    println!("================================================================\n");
+
+    exercise1();
+    exercise2();
+    exercise3();
+    exercise4();
+    exercise5();
+}
+
+// Solution entry points in order, used by `rustpath run <module> --solution`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Exercise 1 Solution: Custom Smart Pointer
+
+// Counts how often the string is accessed; `Cell` lets `&self` methods
+// update the count
+struct SmartString {
+    data: String,
+    access_count: Cell<usize>,
+}
+
+impl SmartString {
+    fn new(s: &str) -> Self {
+        SmartString {
+            data: s.to_string(),
+            access_count: Cell::new(0),
+        }
+    }
+
+    fn record_access(&self) {
+        self.access_count.set(self.access_count.get() + 1);
+    }
+
+    fn len(&self) -> usize {
+        self.record_access();
+        self.data.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.record_access();
+        self.data.is_empty()
+    }
+
+    fn to_uppercase(&self) -> SmartString {
+        self.record_access();
+        SmartString::new(&self.data.to_uppercase())
+    }
+}
+
+impl Deref for SmartString {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.record_access();
+        &self.data
+    }
+}
+
+impl DerefMut for SmartString {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.record_access();
+        &mut self.data
+    }
+}
+
+impl Drop for SmartString {
+    fn drop(&mut self) {
+        println!(
+            "Dropping SmartString '{}', accessed {} times",
+            self.data,
+            self.access_count.get()
+        );
+    }
+}
+
+fn exercise1() {
+    println!("Exercise 1: Custom Smart Pointer");
+    println!("--------------------------");
+
+    let mut smart = SmartString::new("Hello, world!");
+    println!("Smart string: {}", &*smart);
+    println!("Length: {}", smart.len());
+    println!("Is empty: {}", smart.is_empty());
+
+    // Deref coercion: `str` methods are called straight on the SmartString
+    println!("Contains 'world': {}", smart.contains("world"));
+
+    let upper = smart.to_uppercase();
+    println!("Uppercase: {}", &*upper);
+
+    // DerefMut hands out `&mut str`, which allows in-place edits
+    smart[..1].make_ascii_lowercase();
+    println!("Modified: {}", &*smart);
+}
+
+// Exercise 2 Solution: Recursive Data Structure
+
+enum BinaryTree<T> {
+    Leaf,
+    Node(Box<TreeNode<T>>),
+}
+
+struct TreeNode<T> {
+    value: T,
+    left: BinaryTree<T>,
+    right: BinaryTree<T>,
+}
+
+impl<T: Ord + std::fmt::Debug> BinaryTree<T> {
+    fn new() -> Self {
+        BinaryTree::Leaf
+    }
+
+    // Equal values go to the right, so duplicates are kept
+    fn insert(&mut self, value: T) {
+        match self {
+            BinaryTree::Leaf => {
+                *self = BinaryTree::Node(Box::new(TreeNode {
+                    value,
+                    left: BinaryTree::Leaf,
+                    right: BinaryTree::Leaf,
+                }));
+            }
+            BinaryTree::Node(node) => {
+                if value < node.value {
+                    node.left.insert(value);
+                } else {
+                    node.right.insert(value);
+                }
+            }
+        }
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        match self {
+            BinaryTree::Leaf => false,
+            BinaryTree::Node(node) => match value.cmp(&node.value) {
+                std::cmp::Ordering::Equal => true,
+                std::cmp::Ordering::Less => node.left.contains(value),
+                std::cmp::Ordering::Greater => node.right.contains(value),
+            },
+        }
+    }
+
+    fn print_in_order(&self) {
+        if let BinaryTree::Node(node) = self {
+            node.left.print_in_order();
+            println!("{:?}", node.value);
+            node.right.print_in_order();
+        }
+    }
+
+    fn height(&self) -> usize {
+        match self {
+            BinaryTree::Leaf => 0,
+            BinaryTree::Node(node) => 1 + node.left.height().max(node.right.height()),
+        }
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Recursive Data Structure");
+    println!("-------------------------------");
+
+    let mut tree = BinaryTree::new();
+    tree.insert(5);
+    tree.insert(3);
+    tree.insert(7);
+    tree.insert(2);
+    tree.insert(4);
+
+    println!("Tree height: {}", tree.height());
+    println!("Contains 4: {}", tree.contains(&4));
+    println!("Contains 6: {}", tree.contains(&6));
+
+    println!("In-order traversal:");
+    tree.print_in_order();
+}
+
+// Exercise 3 Solution: Shared Cache
+
+// Owners share the cache through `Rc`; `RefCell` lets them all insert
+struct SharedCache<K, V> {
+    data: RefCell<HashMap<K, V>>,
+}
+
+impl<K, V> SharedCache<K, V>
+where
+    K: std::cmp::Eq + std::hash::Hash + Clone,
+    V: Clone,
+{
+    fn new() -> Rc<Self> {
+        Rc::new(SharedCache {
+            data: RefCell::new(HashMap::new()),
+        })
+    }
+
+    fn get(&self, key: &K) -> Option<V> {
+        self.data.borrow().get(key).cloned()
+    }
+
+    fn insert(&self, key: K, value: V) {
+        self.data.borrow_mut().insert(key, value);
+    }
+
+    fn len(&self) -> usize {
+        self.data.borrow().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.borrow().is_empty()
+    }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Shared Cache");
+    println!("-------------------");
+
+    let cache = SharedCache::new();
+    let cache_clone = Rc::clone(&cache);
+
+    cache.insert("key1".to_string(), "value1".to_string());
+    cache.insert("key2".to_string(), "value2".to_string());
+
+    println!("key1: {:?}", cache_clone.get(&"key1".to_string()));
+    println!("key2: {:?}", cache_clone.get(&"key2".to_string()));
+
+    cache_clone.insert("key3".to_string(), "value3".to_string());
+
+    println!("key3: {:?}", cache.get(&"key3".to_string()));
+    println!("Cache size: {}", cache.len());
+}
+
+// Exercise 4 Solution: Object Graph with Weak References
+
+// Components point back at their owner weakly, so the owner and its
+// components do not keep each other alive
+struct Component {
+    name: String,
+    game_object: Weak<GameObject>,
+}
+
+struct GameObject {
+    name: String,
+    components: RefCell<Vec<Rc<Component>>>,
+}
+
+impl Component {
+    fn new(name: &str, game_object: &Rc<GameObject>) -> Rc<Self> {
+        Rc::new(Component {
+            name: name.to_string(),
+            game_object: Rc::downgrade(game_object),
+        })
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn game_object(&self) -> Option<Rc<GameObject>> {
+        self.game_object.upgrade()
+    }
+}
+
+impl GameObject {
+    fn new(name: &str) -> Rc<Self> {
+        Rc::new(GameObject {
+            name: name.to_string(),
+            components: RefCell::new(Vec::new()),
+        })
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn add_component(&self, component: Rc<Component>) {
+        self.components.borrow_mut().push(component);
+    }
+
+    fn get_component(&self, name: &str) -> Option<Rc<Component>> {
+        self.components
+            .borrow()
+            .iter()
+            .find(|c| c.name() == name)
+            .cloned()
+    }
+
+    fn get_components(&self) -> Vec<Rc<Component>> {
+        self.components.borrow().clone()
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Object Graph with Weak References");
+    println!("----------------------------------------");
+
+    let game_object = GameObject::new("Player");
+    let transform = Component::new("Transform", &game_object);
+    let renderer = Component::new("Renderer", &game_object);
+
+    game_object.add_component(transform);
+    game_object.add_component(renderer);
+
+    println!("Game object: {}", game_object.name());
+    println!("Components:");
+    for component in game_object.get_components() {
+        println!("  - {}", component.name());
+        println!("    Parent: {}", component.game_object().unwrap().name());
+    }
+
+    println!(
+        "Game object strong count: {}",
+        Rc::strong_count(&game_object)
+    );
+    println!("Game object weak count: {}", Rc::weak_count(&game_object));
+}
+
+// Exercise 5 Solution: Memory Pool
+
+// Slots are allocated once up front; freed slots go on a free list and are
+// handed out again by later allocations
+struct MemoryPool<T> {
+    slots: Vec<Option<T>>,
+    free_indices: Vec<usize>,
+}
+
+impl<T> MemoryPool<T> {
+    fn new(capacity: usize) -> Self {
+        MemoryPool {
+            slots: (0..capacity).map(|_| None).collect(),
+            // Reversed so that `pop` hands out the lowest index first
+            free_indices: (0..capacity).rev().collect(),
+        }
+    }
+
+    fn allocate(&mut self, value: T) -> Option<usize> {
+        let index = self.free_indices.pop()?;
+        self.slots[index] = Some(value);
+        Some(index)
+    }
+
+    fn deallocate(&mut self, index: usize) -> bool {
+        match self.slots.get_mut(index).and_then(Option::take) {
+            Some(_) => {
+                self.free_indices.push(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.slots.get(index)?.as_ref()
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.slots.get_mut(index)?.as_mut()
+    }
+
+    fn allocated_count(&self) -> usize {
+        self.slots.len() - self.free_indices.len()
+    }
+}
+
+// The slots drop their values on their own; this only reports what is left
+impl<T> Drop for MemoryPool<T> {
+    fn drop(&mut self) {
+        println!(
+            "Dropping MemoryPool with {} allocated objects",
+            self.allocated_count()
+        );
+    }
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Memory Pool");
+    println!("------------------");
+
+    let mut pool = MemoryPool::new(10);
+
+    let idx1 = pool.allocate(String::from("Object 1")).unwrap();
+    let idx2 = pool.allocate(String::from("Object 2")).unwrap();
+    let idx3 = pool.allocate(String::from("Object 3")).unwrap();
+
+    println!("Object 1: {}", pool.get(idx1).unwrap());
+    println!("Object 2: {}", pool.get(idx2).unwrap());
+    println!("Object 3: {}", pool.get(idx3).unwrap());
+
+    if let Some(obj) = pool.get_mut(idx2) {
+        obj.push_str(" (modified)");
+    }
+    println!("Modified object 2: {}", pool.get(idx2).unwrap());
+
+    pool.deallocate(idx1);
+    println!(
+        "After deallocation, allocated count: {}",
+        pool.allocated_count()
+    );
+
+    let idx4 = pool.allocate(String::from("Object 4")).unwrap();
+    println!("New object 4: {} (slot {})", pool.get(idx4).unwrap(), idx4);
+}
+
+// The grading suite from problems/grading.rs, run against these answers.
+// With the `grading` feature on too, problems.rs loads the same file for the
+// stubs; the two copies are meant to be separate modules.
+#[cfg(test)]
+#[path = "problems/grading.rs"]
+#[allow(clippy::duplicate_mod)]
+mod grading;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_run_exercises() {
+        super::run_exercises();
+    }
+}
@@ -0,0 +1,58 @@
+// Module 9: Hints
+// Progressive hints for the exercises in problems.rs, one list per exercise,
+// shown by `rustpath run 9 --exercise <n> --hint <level>`. Level 1 is the
+// exercise's "This exercise demonstrates" list; these are levels 2 and up,
+// each giving away a little more than the one before.
+
+pub const HINTS: [&[&str]; 5] = [
+    // Exercise 1: Pattern Matching with Complex Enums
+    &[
+        "Match on the whole `Event` with struct patterns, and use `..` to ignore the fields \
+         an arm doesn't care about.",
+        "Put the specific cases first, such as an anonymous user trying `Action::Write(_) | \
+         Action::Delete`. Bind the inner values you need, like `UserId::Registered(id)` or \
+         `Resource::Database { table, id: record }`.",
+        "End with a catch-all arm that binds `user`, `resource` and `action` and formats them \
+         through small `describe_*` helpers. Each helper is a plain match over one enum.",
+    ],
+    // Exercise 2: Custom DSL Parser
+    &[
+        "Use one function per precedence level: `expr` handles `+`/`-`, `term` handles `*`/`/`, \
+         and `factor` handles numbers and parentheses. Thread a `&mut usize` position through \
+         them.",
+        "For left associativity, loop instead of recursing: parse a term, then while the next \
+         token is `+` or `-`, consume it and fold the next term into `Expr::BinaryOp`.",
+        "`factor` matches `tokens.get(*pos)`: `Some(Token::Number(n))`, \
+         `Some(Token::LeftParen)` (parse an expr, then expect `RightParen`), or an error. \
+         Afterwards, reject any leftover tokens; `evaluate` is a recursive match on the tree.",
+    ],
+    // Exercise 3: State Machine with Pattern Matching
+    &[
+        "Match on the pair `(event, &self.state)` so that each transition is one arm of a \
+         table.",
+        "Arm order carries the priorities: a power outage wins everywhere, an `Off` light \
+         ignores everything but `PowerRestored`, and an emergency vehicle beats the normal \
+         cycle.",
+        "For `TimerTick`, bump the timer and then match `(state, self.timer)` against ranges \
+         like `(LightState::Red, 30..)`. Have the arms produce `Option<LightState>`, and reset \
+         the timer whenever the state changes.",
+    ],
+    // Exercise 4: Advanced Error Handling
+    &[
+        "Iterate `input.lines().enumerate()`, skip blank and `#` lines, and `split_once('=')`; \
+         a line without `=` is a ParseError for that 1-based line.",
+        "Collect each field into a local `Option` while parsing. A small generic \
+         `number::<T: FromStr>` helper keeps the per-key ParseErrors consistent.",
+        "Afterwards, turn missing fields into `MissingField` with `ok_or_else`. Then validate \
+         by matching the finished Config against patterns like `Config { port: 0, .. }`.",
+    ],
+    // Exercise 5: Pattern Matching Optimization
+    &[
+        "`match` on an enum compiles to a jump on the discriminant, so the number of arms is \
+         rarely the cost. Building Strings is.",
+        "Group related variants, such as the parameterless ones with `Noop | Clear | Reset`, \
+         and keep each arm's work small.",
+        "Benchmark both versions on the same command list with `Instant::now()`, running \
+         enough iterations to measure. Check that both produce identical output first.",
+    ],
+];
@@ -7,8 +7,10 @@
 // - Ranges and multiple patterns
 // - Pattern matching optimization
 
-mod problems;
+use module9::problems;
 
+// The `ref` examples below match a single binding on purpose
+#[allow(clippy::match_single_binding)]
 fn main() {
     // Run the practice problems
     problems::run_exercises();
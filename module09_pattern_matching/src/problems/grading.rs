@@ -0,0 +1,213 @@
+// Grading suite for the module 9 exercises
+// These checks are hidden from the exercise walkthrough: `rustpath grade 9`
+// runs them against your implementations in problems.rs.
+
+use super::*;
+
+mod exercise1 {
+    use super::*;
+
+    fn event(user: UserId, resource: Resource, action: Action) -> Event {
+        Event {
+            user,
+            resource,
+            action,
+            timestamp: 1621022800,
+        }
+    }
+
+    #[test]
+    fn test_describes_who_did_what_to_which_resource() {
+        let read = process_event(&event(
+            UserId::Anonymous,
+            Resource::File {
+                name: "public.txt".to_string(),
+                size: 100,
+            },
+            Action::Read,
+        ));
+        assert!(read.contains("public.txt"), "{}", read);
+
+        let write = process_event(&event(
+            UserId::Registered(42),
+            Resource::Database {
+                table: "users".to_string(),
+                id: 123,
+            },
+            Action::Write("update name".to_string()),
+        ));
+        assert!(write.contains("42") && write.contains("users"), "{}", write);
+
+        let create = process_event(&event(
+            UserId::Admin("root".to_string()),
+            Resource::Network {
+                url: "api.example.com".to_string(),
+                port: 443,
+            },
+            Action::Create {
+                template: Some("default".to_string()),
+            },
+        ));
+        assert!(
+            create.contains("root") && create.contains("default"),
+            "{}",
+            create
+        );
+    }
+
+    #[test]
+    fn test_different_actions_get_different_messages() {
+        let file = || Resource::File {
+            name: "a.txt".to_string(),
+            size: 1,
+        };
+        let read = process_event(&event(UserId::Admin("root".into()), file(), Action::Read));
+        let delete = process_event(&event(UserId::Admin("root".into()), file(), Action::Delete));
+        assert_ne!(read, delete);
+    }
+}
+
+mod exercise2 {
+    use super::*;
+
+    fn eval(input: &str) -> i32 {
+        evaluate(&parse(&tokenize(input)).unwrap())
+    }
+
+    #[test]
+    fn test_parses_a_number() {
+        assert_eq!(parse(&tokenize("42")), Ok(Expr::Number(42)));
+    }
+
+    #[test]
+    fn test_precedence_and_parentheses() {
+        assert_eq!(eval("3 + 4 * (2 - 1)"), 7);
+        assert_eq!(eval("2 * 3 + 4"), 10);
+        assert_eq!(eval("(2 + 3) * 4"), 20);
+        assert_eq!(eval("8 / 2 / 2 + 1"), 3);
+    }
+
+    #[test]
+    fn test_operators_are_left_associative() {
+        assert_eq!(eval("10 - 2 - 3"), 5);
+        assert_eq!(eval("100 / 10 / 5"), 2);
+    }
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        assert!(parse(&tokenize("")).is_err());
+        assert!(parse(&tokenize("1 +")).is_err());
+        assert!(parse(&tokenize("(1 + 2")).is_err());
+        assert!(parse(&tokenize("1 2")).is_err());
+    }
+}
+
+mod exercise3 {
+    use super::*;
+
+    #[test]
+    fn test_starts_red() {
+        let light = TrafficLight::new();
+        assert_eq!(light.state(), &LightState::Red);
+        assert_eq!(light.timer(), 0);
+    }
+
+    #[test]
+    fn test_power_outage_and_restore() {
+        let mut light = TrafficLight::new();
+        light.process_event(LightEvent::PowerOutage);
+        assert_eq!(light.state(), &LightState::Off);
+        // Nothing but power coming back changes an unlit light
+        light.process_event(LightEvent::TimerTick);
+        light.process_event(LightEvent::EmergencyVehicle);
+        assert_eq!(light.state(), &LightState::Off);
+        light.process_event(LightEvent::PowerRestored);
+        assert_eq!(light.state(), &LightState::Red);
+    }
+
+    #[test]
+    fn test_emergency_vehicle_blinks_yellow() {
+        let mut light = TrafficLight::new();
+        light.process_event(LightEvent::EmergencyVehicle);
+        assert_eq!(light.state(), &LightState::BlinkingYellow);
+    }
+
+    #[test]
+    fn test_timer_ticks_cycle_through_colours() {
+        let mut light = TrafficLight::new();
+        let mut seen = Vec::new();
+        for _ in 0..1000 {
+            light.process_event(LightEvent::TimerTick);
+            let state = format!("{:?}", light.state());
+            if seen.last() != Some(&state) {
+                seen.push(state);
+            }
+        }
+        assert!(seen.len() >= 4, "{:?}", seen);
+        assert_eq!(seen[..4], ["Red", "Green", "Yellow", "Red"]);
+    }
+}
+
+mod exercise4 {
+    use super::*;
+
+    #[test]
+    fn test_parses_valid_config() {
+        let config = parse_config("server=localhost\nport=8080\n# comment\ntimeout=30\n").unwrap();
+        assert_eq!(config.server, "localhost");
+        assert_eq!(config.port, 8080);
+        assert_eq!(config.timeout, 30);
+        assert_eq!(config.max_connections, None);
+
+        let config = parse_config("server=a\nport=1\ntimeout=2\nmax_connections=100").unwrap();
+        assert_eq!(config.max_connections, Some(100));
+    }
+
+    #[test]
+    fn test_parse_errors_carry_line_numbers() {
+        let result = parse_config("server=localhost\nport=invalid\ntimeout=30");
+        assert!(matches!(
+            result,
+            Err(ConfigError::ParseError { line: 2, .. })
+        ));
+        let result = parse_config("server=localhost\nport 8080");
+        assert!(matches!(
+            result,
+            Err(ConfigError::ParseError { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let result = parse_config("server=localhost\ntimeout=30");
+        assert!(matches!(result, Err(ConfigError::MissingField(field)) if field == "port"));
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let result = parse_config("server=localhost\nport=0\ntimeout=30");
+        assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+    }
+}
+
+mod exercise5 {
+    use super::*;
+
+    #[test]
+    fn test_optimized_match_agrees_with_naive_match() {
+        let commands = [
+            Command::Noop,
+            Command::Clear,
+            Command::Reset,
+            Command::Move(10, 20),
+            Command::Color(255, 0, 0),
+            Command::Line(0, 0, 100, 100),
+            Command::Rect(10, 10, 50, 50),
+            Command::Circle(50, 50, 25),
+            Command::Text('A', 10, 10),
+        ];
+        for cmd in commands {
+            assert_eq!(optimized_match(cmd), naive_match(cmd), "{:?}", cmd);
+        }
+    }
+}
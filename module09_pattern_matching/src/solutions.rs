@@ -0,0 +1,695 @@
+// Module 9: Reference Solutions
+// Worked answers to the exercises in problems.rs, compiled only with the
+// `solutions` feature. Try solving the exercises yourself first!
+// The types and signatures mirror problems.rs, so the hidden grading suite
+// runs unchanged against these answers (see the tests at the end).
+
+pub fn run_exercises() {
+    println!("Module 9 Reference Solutions - Advanced Pattern Matching");
+    println!("==================================================\n");
+
+    exercise1();
+    exercise2();
+    exercise3();
+    exercise4();
+    exercise5();
+}
+
+// Solution entry points in order, used by `rustpath run <module> --solution`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Exercise 1 Solution: Pattern Matching with Complex Enums
+
+#[derive(Debug)]
+enum UserId {
+    Anonymous,
+    Registered(u64),
+    Admin(String),
+}
+
+#[derive(Debug)]
+enum Resource {
+    File { name: String, size: usize },
+    Database { table: String, id: u64 },
+    Network { url: String, port: u16 },
+}
+
+#[derive(Debug)]
+enum Action {
+    Read,
+    Write(String),
+    Delete,
+    Create { template: Option<String> },
+}
+
+#[derive(Debug)]
+struct Event {
+    user: UserId,
+    resource: Resource,
+    action: Action,
+    timestamp: u64,
+}
+
+// Special cases are matched on the whole event first; everything else is
+// described piece by piece
+fn process_event(event: &Event) -> String {
+    match event {
+        Event {
+            user: UserId::Anonymous,
+            action: Action::Write(_) | Action::Delete,
+            ..
+        } => "Anonymous user denied: writes need an account".to_string(),
+
+        Event {
+            user: UserId::Registered(id),
+            resource: Resource::Database { table, id: record },
+            action: Action::Write(data),
+            ..
+        } => format!(
+            "User {} wrote '{}' to table '{}', record {}",
+            id, data, table, record
+        ),
+
+        Event {
+            user: UserId::Admin(name),
+            resource,
+            action: Action::Delete,
+            ..
+        } => format!("Admin '{}' deleted {}", name, describe_resource(resource)),
+
+        Event {
+            user,
+            resource,
+            action,
+            ..
+        } => format!(
+            "{} {} {}",
+            describe_user(user),
+            describe_action(action),
+            describe_resource(resource)
+        ),
+    }
+}
+
+fn describe_user(user: &UserId) -> String {
+    match user {
+        UserId::Anonymous => "Anonymous user".to_string(),
+        UserId::Registered(id) => format!("User {}", id),
+        UserId::Admin(name) => format!("Admin '{}'", name),
+    }
+}
+
+fn describe_resource(resource: &Resource) -> String {
+    match resource {
+        Resource::File { name, size } => format!("file '{}' ({} bytes)", name, size),
+        Resource::Database { table, id } => format!("record {} in table '{}'", id, table),
+        Resource::Network { url, port: 443 } => format!("https://{}", url),
+        Resource::Network { url, port } => format!("{}:{}", url, port),
+    }
+}
+
+fn describe_action(action: &Action) -> String {
+    match action {
+        Action::Read => "read".to_string(),
+        Action::Write(data) => format!("wrote '{}' to", data),
+        Action::Delete => "deleted".to_string(),
+        Action::Create {
+            template: Some(template),
+        } => format!("created (from template '{}')", template),
+        Action::Create { template: None } => "created".to_string(),
+    }
+}
+
+fn exercise1() {
+    println!("Exercise 1: Pattern Matching with Complex Enums");
+    println!("----------------------------------------");
+
+    let events = vec![
+        Event {
+            user: UserId::Anonymous,
+            resource: Resource::File {
+                name: "public.txt".to_string(),
+                size: 100,
+            },
+            action: Action::Read,
+            timestamp: 1621022800,
+        },
+        Event {
+            user: UserId::Registered(42),
+            resource: Resource::Database {
+                table: "users".to_string(),
+                id: 123,
+            },
+            action: Action::Write("update name".to_string()),
+            timestamp: 1621022900,
+        },
+        Event {
+            user: UserId::Admin("root".to_string()),
+            resource: Resource::Network {
+                url: "api.example.com".to_string(),
+                port: 443,
+            },
+            action: Action::Create {
+                template: Some("default".to_string()),
+            },
+            timestamp: 1621023000,
+        },
+    ];
+
+    for event in &events {
+        println!("[{}] {}", event.timestamp, process_event(event));
+    }
+}
+
+// Exercise 2 Solution: Custom DSL Parser
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Number(i32),
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+    LeftParen,
+    RightParen,
+}
+
+#[derive(Debug, PartialEq)]
+enum Expr {
+    Number(i32),
+    BinaryOp {
+        op: Op,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+enum Op {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '0'..='9' => {
+                let mut number = 0;
+                while let Some(&c) = chars.peek() {
+                    if c.is_ascii_digit() {
+                        number = number * 10 + c.to_digit(10).unwrap() as i32;
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(Token::Number(number));
+            }
+            '+' => {
+                tokens.push(Token::Plus);
+                chars.next();
+            }
+            '-' => {
+                tokens.push(Token::Minus);
+                chars.next();
+            }
+            '*' => {
+                tokens.push(Token::Multiply);
+                chars.next();
+            }
+            '/' => {
+                tokens.push(Token::Divide);
+                chars.next();
+            }
+            '(' => {
+                tokens.push(Token::LeftParen);
+                chars.next();
+            }
+            ')' => {
+                tokens.push(Token::RightParen);
+                chars.next();
+            }
+            ' ' => {
+                chars.next();
+            }
+            _ => panic!("Unexpected character: {}", c),
+        }
+    }
+
+    tokens
+}
+
+// Recursive descent, one function per precedence level:
+//   expr   := term (('+' | '-') term)*
+//   term   := factor (('*' | '/') factor)*
+//   factor := number | '(' expr ')'
+// The loops fold operands onto the left, so "10 - 2 - 3" is (10 - 2) - 3
+fn parse(tokens: &[Token]) -> Result<Expr, String> {
+    fn binary(op: Op, left: Expr, right: Expr) -> Expr {
+        Expr::BinaryOp {
+            op,
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    fn parse_expr(tokens: &[Token], pos: &mut usize) -> Result<Expr, String> {
+        let mut left = parse_term(tokens, pos)?;
+        loop {
+            let op = match tokens.get(*pos) {
+                Some(Token::Plus) => Op::Add,
+                Some(Token::Minus) => Op::Subtract,
+                _ => return Ok(left),
+            };
+            *pos += 1;
+            left = binary(op, left, parse_term(tokens, pos)?);
+        }
+    }
+
+    fn parse_term(tokens: &[Token], pos: &mut usize) -> Result<Expr, String> {
+        let mut left = parse_factor(tokens, pos)?;
+        loop {
+            let op = match tokens.get(*pos) {
+                Some(Token::Multiply) => Op::Multiply,
+                Some(Token::Divide) => Op::Divide,
+                _ => return Ok(left),
+            };
+            *pos += 1;
+            left = binary(op, left, parse_factor(tokens, pos)?);
+        }
+    }
+
+    fn parse_factor(tokens: &[Token], pos: &mut usize) -> Result<Expr, String> {
+        match tokens.get(*pos) {
+            Some(Token::Number(n)) => {
+                *pos += 1;
+                Ok(Expr::Number(*n))
+            }
+            Some(Token::LeftParen) => {
+                *pos += 1;
+                let expr = parse_expr(tokens, pos)?;
+                match tokens.get(*pos) {
+                    Some(Token::RightParen) => {
+                        *pos += 1;
+                        Ok(expr)
+                    }
+                    _ => Err("Expected closing parenthesis".to_string()),
+                }
+            }
+            Some(token) => Err(format!("Unexpected token: {:?}", token)),
+            None => Err("Unexpected end of input".to_string()),
+        }
+    }
+
+    let mut pos = 0;
+    let expr = parse_expr(tokens, &mut pos)?;
+    match &tokens[pos..] {
+        [] => Ok(expr),
+        rest => Err(format!("Unexpected tokens after expression: {:?}", rest)),
+    }
+}
+
+fn evaluate(expr: &Expr) -> i32 {
+    match expr {
+        Expr::Number(n) => *n,
+        Expr::BinaryOp { op, left, right } => {
+            let (left, right) = (evaluate(left), evaluate(right));
+            match op {
+                Op::Add => left + right,
+                Op::Subtract => left - right,
+                Op::Multiply => left * right,
+                Op::Divide => left / right,
+            }
+        }
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Custom DSL Parser");
+    println!("------------------------");
+
+    let input = "3 + 4 * (2 - 1)";
+    let tokens = tokenize(input);
+    println!("Tokens: {:?}", tokens);
+
+    match parse(&tokens) {
+        Ok(expr) => {
+            println!("Expression: {:?}", expr);
+            println!("Result: {}", evaluate(&expr));
+        }
+        Err(e) => println!("Parse error: {}", e),
+    }
+}
+
+// Exercise 3 Solution: State Machine with Pattern Matching
+
+#[derive(Debug, PartialEq)]
+enum LightState {
+    Red,
+    Yellow,
+    Green,
+    BlinkingYellow,
+    Off,
+}
+
+#[derive(Debug, PartialEq)]
+enum LightEvent {
+    TimerTick,
+    PowerOutage,
+    PowerRestored,
+    CarDetected,
+    EmergencyVehicle,
+}
+
+struct TrafficLight {
+    state: LightState,
+    timer: u32,
+}
+
+impl TrafficLight {
+    fn new() -> Self {
+        TrafficLight {
+            state: LightState::Red,
+            timer: 0,
+        }
+    }
+
+    // Matching on (event, state) pairs keeps every transition in one table
+    fn process_event(&mut self, event: LightEvent) {
+        let next = match (event, &self.state) {
+            (LightEvent::PowerOutage, _) => Some(LightState::Off),
+            (LightEvent::PowerRestored, LightState::Off) => Some(LightState::Red),
+            // An unlit light ignores everything until the power comes back
+            (_, LightState::Off) => None,
+            (LightEvent::EmergencyVehicle, _) => Some(LightState::BlinkingYellow),
+
+            // Normal cycle: each colour holds for a number of ticks
+            (LightEvent::TimerTick, state) => {
+                self.timer += 1;
+                match (state, self.timer) {
+                    (LightState::Red, 30..) => Some(LightState::Green),
+                    (LightState::Green, 20..) => Some(LightState::Yellow),
+                    (LightState::Yellow, 5..) => Some(LightState::Red),
+                    (LightState::BlinkingYellow, 60..) => Some(LightState::Red),
+                    _ => None,
+                }
+            }
+
+            // A waiting car cuts a fresh green short, but never extends it
+            (LightEvent::CarDetected, LightState::Green) => {
+                self.timer = self.timer.max(10);
+                None
+            }
+
+            _ => None,
+        };
+
+        if let Some(state) = next {
+            self.state = state;
+            self.timer = 0;
+        }
+    }
+
+    fn state(&self) -> &LightState {
+        &self.state
+    }
+
+    fn timer(&self) -> u32 {
+        self.timer
+    }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: State Machine with Pattern Matching");
+    println!("------------------------------------------");
+
+    let mut light = TrafficLight::new();
+    println!(
+        "Initial state: {:?}, Timer: {}",
+        light.state(),
+        light.timer()
+    );
+
+    let events = vec![
+        LightEvent::TimerTick,
+        LightEvent::TimerTick,
+        LightEvent::PowerOutage,
+        LightEvent::PowerRestored,
+        LightEvent::EmergencyVehicle,
+        LightEvent::TimerTick,
+    ];
+
+    for event in events {
+        println!("Processing event: {:?}", event);
+        light.process_event(event);
+        println!("New state: {:?}, Timer: {}", light.state(), light.timer());
+    }
+}
+
+// Exercise 4 Solution: Advanced Error Handling
+
+#[derive(Debug)]
+enum ConfigError {
+    IoError(std::io::Error),
+    ParseError { line: usize, message: String },
+    ValidationError(String),
+    MissingField(String),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ConfigError::IoError(e) => write!(f, "IO error: {}", e),
+            ConfigError::ParseError { line, message } => {
+                write!(f, "Parse error at line {}: {}", line, message)
+            }
+            ConfigError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            ConfigError::MissingField(field) => write!(f, "Missing field: {}", field),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(error: std::io::Error) -> Self {
+        ConfigError::IoError(error)
+    }
+}
+
+#[derive(Debug)]
+struct Config {
+    server: String,
+    port: u16,
+    timeout: u32,
+    max_connections: Option<u32>,
+}
+
+fn parse_config(input: &str) -> Result<Config, ConfigError> {
+    // Parses a number, reporting failures against the (1-based) line
+    fn number<T: std::str::FromStr>(value: &str, line: usize, key: &str) -> Result<T, ConfigError> {
+        value.parse().map_err(|_| ConfigError::ParseError {
+            line,
+            message: format!("Invalid {} value: {}", key, value),
+        })
+    }
+
+    let mut server = None;
+    let mut port = None;
+    let mut timeout = None;
+    let mut max_connections = None;
+
+    for (index, line) in input.lines().enumerate() {
+        let line_num = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(ConfigError::ParseError {
+                line: line_num,
+                message: "Expected key=value".to_string(),
+            });
+        };
+
+        match (key.trim(), value.trim()) {
+            ("server", value) => server = Some(value.to_string()),
+            (key @ "port", value) => port = Some(number::<u16>(value, line_num, key)?),
+            (key @ "timeout", value) => timeout = Some(number(value, line_num, key)?),
+            (key @ "max_connections", value) => {
+                max_connections = Some(number(value, line_num, key)?)
+            }
+            (key, _) => {
+                return Err(ConfigError::ParseError {
+                    line: line_num,
+                    message: format!("Unknown configuration key: {}", key),
+                })
+            }
+        }
+    }
+
+    let missing = |field: &str| ConfigError::MissingField(field.to_string());
+    let config = Config {
+        server: server.ok_or_else(|| missing("server"))?,
+        port: port.ok_or_else(|| missing("port"))?,
+        timeout: timeout.ok_or_else(|| missing("timeout"))?,
+        max_connections,
+    };
+
+    match config {
+        Config { port: 0, .. } => Err(ConfigError::ValidationError("Port cannot be 0".to_string())),
+        Config { timeout: 0, .. } => Err(ConfigError::ValidationError(
+            "Timeout cannot be 0".to_string(),
+        )),
+        Config {
+            max_connections: Some(0),
+            ..
+        } => Err(ConfigError::ValidationError(
+            "max_connections cannot be 0".to_string(),
+        )),
+        config => Ok(config),
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Advanced Error Handling");
+    println!("------------------------------");
+
+    let config_str = r#"
+        server=localhost
+        port=8080
+        timeout=30
+        # max_connections is optional
+        # max_connections=100
+    "#;
+
+    match parse_config(config_str) {
+        Ok(config) => println!("Parsed config: {:?}", config),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    // Test with invalid config
+    let invalid_config = r#"
+        server=localhost
+        port=invalid
+        timeout=30
+    "#;
+
+    match parse_config(invalid_config) {
+        Ok(config) => println!("Parsed config: {:?}", config),
+        Err(e) => println!("Error: {}", e),
+    }
+}
+
+// Exercise 5 Solution: Pattern Matching Optimization
+
+#[derive(Debug, Clone, Copy)]
+enum Command {
+    Noop,
+    Clear,
+    Reset,
+    Move(i32, i32),
+    Color(u8, u8, u8),
+    Line(i32, i32, i32, i32),
+    Rect(i32, i32, i32, i32),
+    Circle(i32, i32, i32),
+    Text(char, i32, i32),
+}
+
+fn naive_match(cmd: Command) -> String {
+    match cmd {
+        Command::Noop => "No operation".to_string(),
+        Command::Clear => "Clear screen".to_string(),
+        Command::Reset => "Reset state".to_string(),
+        Command::Move(x, y) => format!("Move to ({}, {})", x, y),
+        Command::Color(r, g, b) => format!("Set color to RGB({}, {}, {})", r, g, b),
+        Command::Line(x1, y1, x2, y2) => {
+            format!("Draw line from ({}, {}) to ({}, {})", x1, y1, x2, y2)
+        }
+        Command::Rect(x, y, w, h) => {
+            format!("Draw rectangle at ({}, {}) with size {}x{}", x, y, w, h)
+        }
+        Command::Circle(x, y, r) => format!("Draw circle at ({}, {}) with radius {}", x, y, r),
+        Command::Text(c, x, y) => format!("Draw text '{}' at ({}, {})", c, x, y),
+    }
+}
+
+// Arms are grouped by shape: the parameterless commands share one static
+// lookup and the drawing commands sit together. A match on the enum
+// discriminant compiles to a jump table either way, so the grouping is about
+// readability; measure (e.g. with criterion) before trusting any "faster"
+// arrangement.
+fn optimized_match(cmd: Command) -> String {
+    match cmd {
+        // Simple commands with no parameters
+        Command::Noop | Command::Clear | Command::Reset => {
+            let text = match cmd {
+                Command::Noop => "No operation",
+                Command::Clear => "Clear screen",
+                _ => "Reset state",
+            };
+            text.to_string()
+        }
+
+        // Commands with a single position
+        Command::Move(x, y) => format!("Move to ({}, {})", x, y),
+        Command::Text(c, x, y) => format!("Draw text '{}' at ({}, {})", c, x, y),
+
+        // Shapes
+        Command::Line(x1, y1, x2, y2) => {
+            format!("Draw line from ({}, {}) to ({}, {})", x1, y1, x2, y2)
+        }
+        Command::Rect(x, y, w, h) => {
+            format!("Draw rectangle at ({}, {}) with size {}x{}", x, y, w, h)
+        }
+        Command::Circle(x, y, r) => format!("Draw circle at ({}, {}) with radius {}", x, y, r),
+
+        Command::Color(r, g, b) => format!("Set color to RGB({}, {}, {})", r, g, b),
+    }
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Pattern Matching Optimization");
+    println!("---------------------------------");
+
+    let commands = vec![
+        Command::Noop,
+        Command::Move(10, 20),
+        Command::Color(255, 0, 0),
+        Command::Line(0, 0, 100, 100),
+        Command::Rect(10, 10, 50, 50),
+        Command::Circle(50, 50, 25),
+        Command::Text('A', 10, 10),
+    ];
+
+    for cmd in &commands {
+        let naive = naive_match(*cmd);
+        let optimized = optimized_match(*cmd);
+        println!("Command: {:?}", cmd);
+        println!("  Naive: {}", naive);
+        println!("  Optimized: {}", optimized);
+        assert_eq!(naive, optimized);
+    }
+}
+
+// The grading suite from problems/grading.rs, run against these answers.
+// With the `grading` feature on too, problems.rs loads the same file for the
+// stubs; the two copies are meant to be separate modules.
+#[cfg(test)]
+#[path = "problems/grading.rs"]
+#[allow(clippy::duplicate_mod)]
+mod grading;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_run_exercises() {
+        super::run_exercises();
+    }
+}
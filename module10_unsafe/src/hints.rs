@@ -0,0 +1,62 @@
+// Module 10: Hints
+// Progressive hints for the exercises in problems.rs, one list per exercise,
+// shown by `rustpath run 10 --exercise <n> --hint <level>`. Level 1 is the
+// exercise's "This exercise demonstrates" list; these are levels 2 and up,
+// each giving away a little more than the one before.
+
+pub const HINTS: [&[&str]; 5] = [
+    // Exercise 1: Safe Abstraction over Raw Pointers
+    &[
+        "Store `rows`, `cols` and a single raw buffer of `rows * cols` slots, indexed \
+         row-major as `row * cols + col`. Every public method checks bounds before touching \
+         the pointer.",
+        "Slots start out uninitialised, so make each one an `Option<T>`. Allocate with \
+         `std::alloc::alloc(Layout::array::<Option<T>>(len))`, check for null, and `write` \
+         None into every slot.",
+        "`set` assigns through `&mut *ptr.add(i)`, which drops the old value properly. `Drop` \
+         must `drop_in_place` every slot and then `dealloc` with the same Layout. Skip the \
+         allocation entirely for zero-sized layouts.",
+    ],
+    // Exercise 2: FFI and C Interop
+    &[
+        "`#[repr(C)]` gives Point the same layout as the C struct, which is what makes it safe \
+         to pass across the boundary.",
+        "The safe wrappers turn references into raw pointers (`p1 as *const Point`) and call \
+         the unsafe functions inside an `unsafe` block. References are never null and always \
+         aligned, which upholds the contract.",
+        "For `safe_midpoint`, create a zeroed `Point { x: 0.0, y: 0.0 }` on the stack and \
+         pass `&mut result` as the `*mut Point` out-parameter. Write a `// SAFETY:` comment on \
+         each unsafe block saying why it holds.",
+    ],
+    // Exercise 3: Custom Memory Allocator
+    &[
+        "A bump allocator owns one big buffer and an offset. Allocating moves the offset \
+         forward; nothing is ever freed individually, and `reset` sets the offset back to 0.",
+        "Align the actual address, not the offset: round `base as usize + offset` up to \
+         `layout.align()` (see `checked_next_multiple_of`), then convert back to an offset.",
+        "Return `std::ptr::null_mut()` when the aligned start plus `layout.size()` would pass \
+         the capacity. Free the buffer in `Drop` with the Layout it was allocated with.",
+    ],
+    // Exercise 4: Safe Wrapper for SIMD Operations
+    &[
+        "Keep the values in a plain `Vec<f32>`. The SIMD work happens four lanes at a time, \
+         and a scalar loop handles the leftover elements.",
+        "On x86_64, SSE is always available: `_mm_loadu_ps`, `_mm_add_ps`/`_mm_mul_ps` and \
+         `_mm_storeu_ps` from `std::arch::x86_64`. Put them behind `#[cfg(target_arch = \
+         \"x86_64\")]` with a scalar fallback for other targets.",
+        "`slice.as_chunks::<4>()` splits a slice into `[f32; 4]` chunks plus a remainder, \
+         which makes the unaligned loads obviously in bounds. `dot` can be `mul` followed by \
+         a sum.",
+    ],
+    // Exercise 5: Procedural Macro (Conceptual)
+    &[
+        "A derive macro lives in its own crate with `proc-macro = true`, takes the struct's \
+         `TokenStream` and returns new items. The syn and quote crates do the parsing and \
+         generation.",
+        "Before designing the macro, write by hand the code it would generate for Person: a \
+         PersonBuilder with an `Option` per field, one setter per field, and a `build` method.",
+        "In `build`, required fields use `ok_or(\"name is required\")?`. A field that is \
+         already an `Option<T>` stays optional, so the macro has to notice `Option` in the \
+         field's type.",
+    ],
+];
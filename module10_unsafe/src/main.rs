@@ -9,7 +9,7 @@
 // - Custom allocators
 // - Advanced type system features
 
-mod problems;
+use module10::problems;
 
 use std::alloc::{GlobalAlloc, Layout};
 use std::ptr;
@@ -44,12 +44,12 @@ fn main() {
     // Example of a derive macro (conceptual)
     println!("\nExample derive macro:");
     println!("  #[derive(Serialize, Deserialize)]");
-    println!("  struct User { name: String, email: String }");
+    println!("  struct User {{ name: String, email: String }}");
 
     // Example of an attribute macro (conceptual)
     println!("\nExample attribute macro:");
-    println!("  #[route(GET, \"/users/{id}\")]");
-    println!("  fn get_user(id: u32) -> User { ... }");
+    println!("  #[route(GET, \"/users/{{id}}\")]");
+    println!("  fn get_user(id: u32) -> User {{ ... }}");
 
     // ===============================
     // 2. Raw Pointers and Unsafe Blocks
@@ -231,6 +231,8 @@ fn main() {
     // - unsafe impl: Implementation upholds the required safety invariants
 
     // Unsafe trait example
+    /// # Safety
+    /// Implementors must uphold the invariants `unsafe_method` relies on.
     unsafe trait UnsafeTrait {
         unsafe fn unsafe_method(&self);
     }
@@ -17,58 +17,67 @@ pub fn run_exercises() {
     exercise5();
 }
 
+// Exercise entry points in order, used by `rustpath run <module> --exercise <n>`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Hidden per-exercise checks, run against this file by `rustpath grade`.
+#[cfg(all(test, feature = "grading"))]
+mod grading;
+
 // Exercise 1: Safe Abstraction over Raw Pointers
 // This exercise demonstrates:
 // - Working with raw pointers
 // - Building safe abstractions
 // - Implementing Drop for resource cleanup
-fn exercise1() {
-    println!("Exercise 1: Safe Abstraction over Raw Pointers");
-    println!("------------------------------------------");
-    println!("TODO: Implement the Vec2D struct and its methods\n");
 
-    // A 2D vector implementation using raw pointers
-    struct Vec2D<T> {
-        // TODO: Implement the fields needed for a 2D vector
+// A 2D vector implementation using raw pointers
+struct Vec2D<T> {
+    // TODO: Implement the fields needed for a 2D vector
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T> Vec2D<T> {
+    // Create a new 2D vector with the given dimensions
+    fn new(rows: usize, cols: usize) -> Self {
+        unimplemented!("Implement Vec2D::new");
+    }
+
+    // Get a reference to the element at the given position
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        unimplemented!("Implement get");
+    }
+
+    // Get a mutable reference to the element at the given position
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        unimplemented!("Implement get_mut");
+    }
+
+    // Set the element at the given position
+    fn set(&mut self, row: usize, col: usize, value: T) -> bool {
+        unimplemented!("Implement set");
     }
 
-    impl<T> Vec2D<T> {
-        // Create a new 2D vector with the given dimensions
-        fn new(rows: usize, cols: usize) -> Self {
-            unimplemented!("Implement Vec2D::new");
-        }
-
-        // Get a reference to the element at the given position
-        fn get(&self, row: usize, col: usize) -> Option<&T> {
-            unimplemented!("Implement get");
-        }
-
-        // Get a mutable reference to the element at the given position
-        fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
-            unimplemented!("Implement get_mut");
-        }
-
-        // Set the element at the given position
-        fn set(&mut self, row: usize, col: usize, value: T) -> bool {
-            unimplemented!("Implement set");
-        }
-
-        // Get the number of rows
-        fn rows(&self) -> usize {
-            unimplemented!("Implement rows");
-        }
-
-        // Get the number of columns
-        fn cols(&self) -> usize {
-            unimplemented!("Implement cols");
-        }
+    // Get the number of rows
+    fn rows(&self) -> usize {
+        unimplemented!("Implement rows");
     }
 
-    impl<T> Drop for Vec2D<T> {
-        fn drop(&mut self) {
-            unimplemented!("Implement drop");
-        }
+    // Get the number of columns
+    fn cols(&self) -> usize {
+        unimplemented!("Implement cols");
+    }
+}
+
+impl<T> Drop for Vec2D<T> {
+    fn drop(&mut self) {
+        unimplemented!("Implement drop");
     }
+}
+
+fn exercise1() {
+    println!("Exercise 1: Safe Abstraction over Raw Pointers");
+    println!("------------------------------------------");
+    println!("TODO: Implement the Vec2D struct and its methods\n");
 
     // Test your implementation:
     // let mut vec = Vec2D::new(3, 4);
@@ -93,64 +102,65 @@ fn exercise1() {
 // - Calling C functions from Rust
 // - Creating C-compatible interfaces
 // - Working with raw pointers across FFI boundary
-fn exercise2() {
-    println!("\nExercise 2: FFI and C Interop");
-    println!("-------------------------");
-    println!("TODO: Implement the FFI functions\n");
 
-    // C-compatible struct
-    #[repr(C)]
-    struct Point {
-        x: f64,
-        y: f64,
-    }
+// C-compatible struct
+#[repr(C)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
 
-    // External C functions (these would normally be in a C library)
-    extern "C" {
-        // Calculate the distance between two points
-        fn distance(p1: *const Point, p2: *const Point) -> f64;
+// External C functions (these would normally be in a C library)
+extern "C" {
+    // Calculate the distance between two points
+    fn distance(p1: *const Point, p2: *const Point) -> f64;
 
-        // Calculate the midpoint between two points
-        fn midpoint(p1: *const Point, p2: *const Point, result: *mut Point);
-    }
+    // Calculate the midpoint between two points
+    fn midpoint(p1: *const Point, p2: *const Point, result: *mut Point);
+}
 
-    // Safe Rust wrapper for distance function
-    fn safe_distance(p1: &Point, p2: &Point) -> f64 {
-        unimplemented!("Implement safe_distance");
-    }
+// Safe Rust wrapper for distance function
+fn safe_distance(p1: &Point, p2: &Point) -> f64 {
+    unimplemented!("Implement safe_distance");
+}
 
-    // Safe Rust wrapper for midpoint function
-    fn safe_midpoint(p1: &Point, p2: &Point) -> Point {
-        unimplemented!("Implement safe_midpoint");
-    }
+// Safe Rust wrapper for midpoint function
+fn safe_midpoint(p1: &Point, p2: &Point) -> Point {
+    unimplemented!("Implement safe_midpoint");
+}
 
-    // Rust function to be called from C
-    #[no_mangle]
-    pub extern "C" fn scale_point(point: *mut Point, factor: f64) {
-        unimplemented!("Implement scale_point");
-    }
+// Rust function to be called from C
+#[no_mangle]
+pub extern "C" fn scale_point(point: *mut Point, factor: f64) {
+    unimplemented!("Implement scale_point");
+}
+
+// Simulated C functions
+unsafe fn simulate_distance(p1: *const Point, p2: *const Point) -> f64 {
+    let p1 = &*p1;
+    let p2 = &*p2;
+    let dx = p2.x - p1.x;
+    let dy = p2.y - p1.y;
+    (dx * dx + dy * dy).sqrt()
+}
+
+unsafe fn simulate_midpoint(p1: *const Point, p2: *const Point, result: *mut Point) {
+    let p1 = &*p1;
+    let p2 = &*p2;
+    let mid = &mut *result;
+    mid.x = (p1.x + p2.x) / 2.0;
+    mid.y = (p1.y + p2.y) / 2.0;
+}
+
+fn exercise2() {
+    println!("\nExercise 2: FFI and C Interop");
+    println!("-------------------------");
+    println!("TODO: Implement the FFI functions\n");
 
     // Test your implementation:
     // Note: In a real scenario, you would link against a C library
     // For this exercise, we'll just simulate the C functions
 
-    // Simulated C functions
-    unsafe fn simulate_distance(p1: *const Point, p2: *const Point) -> f64 {
-        let p1 = &*p1;
-        let p2 = &*p2;
-        let dx = p2.x - p1.x;
-        let dy = p2.y - p1.y;
-        (dx * dx + dy * dy).sqrt()
-    }
-
-    unsafe fn simulate_midpoint(p1: *const Point, p2: *const Point, result: *mut Point) {
-        let p1 = &*p1;
-        let p2 = &*p2;
-        let mid = &mut *result;
-        mid.x = (p1.x + p2.x) / 2.0;
-        mid.y = (p1.y + p2.y) / 2.0;
-    }
-
     // Override the external functions with our simulations
     let distance_ptr: unsafe fn(*const Point, *const Point) -> f64 = simulate_distance;
     let midpoint_ptr: unsafe fn(*const Point, *const Point, *mut Point) = simulate_midpoint;
@@ -176,49 +186,50 @@ fn exercise2() {
 // - Implementing a custom allocator
 // - Memory alignment and layout
 // - Safe abstractions over memory management
-fn exercise3() {
-    println!("\nExercise 3: Custom Memory Allocator");
-    println!("-------------------------------");
-    println!("TODO: Implement the BumpAllocator struct and its methods\n");
 
-    // A simple bump allocator that allocates memory from a pre-allocated buffer
-    struct BumpAllocator {
-        // TODO: Implement the fields needed for a bump allocator
+// A simple bump allocator that allocates memory from a pre-allocated buffer
+struct BumpAllocator {
+    // TODO: Implement the fields needed for a bump allocator
+}
+
+impl BumpAllocator {
+    // Create a new bump allocator with the given capacity
+    fn new(capacity: usize) -> Self {
+        unimplemented!("Implement BumpAllocator::new");
+    }
+
+    // Allocate memory with the given layout
+    fn alloc(&mut self, layout: Layout) -> *mut u8 {
+        unimplemented!("Implement alloc");
     }
 
-    impl BumpAllocator {
-        // Create a new bump allocator with the given capacity
-        fn new(capacity: usize) -> Self {
-            unimplemented!("Implement BumpAllocator::new");
-        }
-
-        // Allocate memory with the given layout
-        fn alloc(&mut self, layout: Layout) -> *mut u8 {
-            unimplemented!("Implement alloc");
-        }
-
-        // Reset the allocator (free all allocations)
-        fn reset(&mut self) {
-            unimplemented!("Implement reset");
-        }
-
-        // Get the current usage
-        fn used(&self) -> usize {
-            unimplemented!("Implement used");
-        }
-
-        // Get the capacity
-        fn capacity(&self) -> usize {
-            unimplemented!("Implement capacity");
-        }
+    // Reset the allocator (free all allocations)
+    fn reset(&mut self) {
+        unimplemented!("Implement reset");
     }
 
-    impl Drop for BumpAllocator {
-        fn drop(&mut self) {
-            unimplemented!("Implement drop");
-        }
+    // Get the current usage
+    fn used(&self) -> usize {
+        unimplemented!("Implement used");
     }
 
+    // Get the capacity
+    fn capacity(&self) -> usize {
+        unimplemented!("Implement capacity");
+    }
+}
+
+impl Drop for BumpAllocator {
+    fn drop(&mut self) {
+        unimplemented!("Implement drop");
+    }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Custom Memory Allocator");
+    println!("-------------------------------");
+    println!("TODO: Implement the BumpAllocator struct and its methods\n");
+
     // Test your implementation:
     // let mut allocator = BumpAllocator::new(1024);
     // println!("Capacity: {} bytes", allocator.capacity());
@@ -249,43 +260,45 @@ fn exercise3() {
 // - Using SIMD intrinsics
 // - Runtime feature detection
 // - Building safe abstractions over platform-specific code
-fn exercise4() {
-    println!("\nExercise 4: Safe Wrapper for SIMD Operations");
-    println!("---------------------------------------");
-    println!("TODO: Implement the SimdVector struct and its methods\n");
 
-    // A safe wrapper for SIMD vector operations
-    struct SimdVector<T> {
-        // TODO: Implement the fields needed for a SIMD vector
+// A safe wrapper for SIMD vector operations
+struct SimdVector<T> {
+    // TODO: Implement the fields needed for a SIMD vector
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl SimdVector<f32> {
+    // Create a new SIMD vector with the given values
+    fn new(values: &[f32]) -> Self {
+        unimplemented!("Implement SimdVector::new");
+    }
+
+    // Add another SIMD vector
+    fn add(&self, other: &Self) -> Self {
+        unimplemented!("Implement add");
     }
 
-    impl SimdVector<f32> {
-        // Create a new SIMD vector with the given values
-        fn new(values: &[f32]) -> Self {
-            unimplemented!("Implement SimdVector::new");
-        }
-
-        // Add another SIMD vector
-        fn add(&self, other: &Self) -> Self {
-            unimplemented!("Implement add");
-        }
-
-        // Multiply by another SIMD vector
-        fn mul(&self, other: &Self) -> Self {
-            unimplemented!("Implement mul");
-        }
-
-        // Calculate the dot product
-        fn dot(&self, other: &Self) -> f32 {
-            unimplemented!("Implement dot");
-        }
-
-        // Get the values as a slice
-        fn as_slice(&self) -> &[f32] {
-            unimplemented!("Implement as_slice");
-        }
+    // Multiply by another SIMD vector
+    fn mul(&self, other: &Self) -> Self {
+        unimplemented!("Implement mul");
     }
 
+    // Calculate the dot product
+    fn dot(&self, other: &Self) -> f32 {
+        unimplemented!("Implement dot");
+    }
+
+    // Get the values as a slice
+    fn as_slice(&self) -> &[f32] {
+        unimplemented!("Implement as_slice");
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Safe Wrapper for SIMD Operations");
+    println!("---------------------------------------");
+    println!("TODO: Implement the SimdVector struct and its methods\n");
+
     // Test your implementation:
     // Note: This is a simplified example. In a real implementation,
     // you would use actual SIMD intrinsics and feature detection.
@@ -357,7 +370,7 @@ fn exercise5() {
     println!("- Providing good error messages");
 }
 
-/* Example Solutions:
- * For complete solutions to these exercises, refer to the documentation
- * or the companion guide. Try solving them yourself first!
- */
+// Example Solutions
+// The reference answers live in src/solutions.rs and are compiled with the
+// `solutions` feature (`rustpath run <module> --solution`). Try solving the
+// exercises yourself first!
@@ -0,0 +1,144 @@
+// Grading suite for the module 10 exercises
+// These checks are hidden from the exercise walkthrough: `rustpath grade 10`
+// runs them against your implementations in problems.rs. Exercise 5 is
+// conceptual and has no checks.
+
+use super::*;
+
+mod exercise1 {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_dimensions() {
+        let vec: Vec2D<i32> = Vec2D::new(3, 4);
+        assert_eq!(vec.rows(), 3);
+        assert_eq!(vec.cols(), 4);
+    }
+
+    #[test]
+    fn test_set_then_get() {
+        let mut vec = Vec2D::new(3, 4);
+        assert!(vec.set(0, 0, 1));
+        assert!(vec.set(2, 3, 3));
+        assert_eq!(vec.get(0, 0), Some(&1));
+        assert_eq!(vec.get(2, 3), Some(&3));
+        *vec.get_mut(2, 3).unwrap() = 30;
+        assert_eq!(vec.get(2, 3), Some(&30));
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let mut vec = Vec2D::new(2, 2);
+        assert!(!vec.set(2, 0, 1));
+        assert!(!vec.set(0, 2, 1));
+        assert_eq!(vec.get(3, 3), None);
+        assert!(vec.get_mut(0, 5).is_none());
+    }
+
+    #[test]
+    fn test_drop_releases_elements() {
+        let value = Rc::new(());
+        {
+            let mut vec = Vec2D::new(2, 2);
+            vec.set(0, 0, Rc::clone(&value));
+            vec.set(1, 1, Rc::clone(&value));
+            // Overwriting drops the previous element
+            vec.set(1, 1, Rc::clone(&value));
+            assert_eq!(Rc::strong_count(&value), 3);
+        }
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+}
+
+mod exercise2 {
+    use super::*;
+
+    #[test]
+    fn test_safe_distance() {
+        let p1 = Point { x: 0.0, y: 0.0 };
+        let p2 = Point { x: 3.0, y: 4.0 };
+        assert_eq!(safe_distance(&p1, &p2), 5.0);
+    }
+
+    #[test]
+    fn test_safe_midpoint() {
+        let mid = safe_midpoint(&Point { x: 0.0, y: 0.0 }, &Point { x: 3.0, y: -4.0 });
+        assert_eq!((mid.x, mid.y), (1.5, -2.0));
+    }
+
+    #[test]
+    fn test_scale_point() {
+        let mut p = Point { x: 2.0, y: 3.0 };
+        scale_point(&mut p, 2.0);
+        assert_eq!((p.x, p.y), (4.0, 6.0));
+    }
+}
+
+mod exercise3 {
+    use super::*;
+
+    #[test]
+    fn test_allocations_are_aligned_and_counted() {
+        let mut allocator = BumpAllocator::new(1024);
+        assert_eq!(allocator.capacity(), 1024);
+        assert_eq!(allocator.used(), 0);
+
+        let first = allocator.alloc(Layout::from_size_align(100, 8).unwrap());
+        let second = allocator.alloc(Layout::from_size_align(200, 16).unwrap());
+        assert!(!first.is_null() && !second.is_null());
+        assert_eq!(first as usize % 8, 0);
+        assert_eq!(second as usize % 16, 0);
+        assert!(second as usize >= first as usize + 100);
+        assert!(allocator.used() >= 300);
+    }
+
+    #[test]
+    fn test_exhausted_allocator_returns_null() {
+        let mut allocator = BumpAllocator::new(64);
+        assert!(!allocator
+            .alloc(Layout::from_size_align(48, 8).unwrap())
+            .is_null());
+        assert!(allocator
+            .alloc(Layout::from_size_align(32, 8).unwrap())
+            .is_null());
+    }
+
+    #[test]
+    fn test_reset_reuses_the_buffer() {
+        let mut allocator = BumpAllocator::new(128);
+        let layout = Layout::from_size_align(100, 8).unwrap();
+        let first = allocator.alloc(layout);
+        allocator.reset();
+        assert_eq!(allocator.used(), 0);
+        assert_eq!(allocator.alloc(layout), first);
+    }
+}
+
+mod exercise4 {
+    use super::*;
+
+    #[test]
+    fn test_add_and_mul() {
+        let v1 = SimdVector::new(&[1.0, 2.0, 3.0, 4.0]);
+        let v2 = SimdVector::new(&[5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(v1.add(&v2).as_slice(), [6.0, 8.0, 10.0, 12.0]);
+        assert_eq!(v1.mul(&v2).as_slice(), [5.0, 12.0, 21.0, 32.0]);
+    }
+
+    #[test]
+    fn test_dot() {
+        let v1 = SimdVector::new(&[1.0, 2.0, 3.0, 4.0]);
+        let v2 = SimdVector::new(&[5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(v1.dot(&v2), 70.0);
+    }
+
+    #[test]
+    fn test_lengths_that_are_not_a_lane_multiple() {
+        let values: Vec<f32> = (1..=7).map(|n| n as f32).collect();
+        let v = SimdVector::new(&values);
+        assert_eq!(v.as_slice(), &values[..]);
+        assert_eq!(v.add(&v).as_slice()[6], 14.0);
+        assert_eq!(v.dot(&v), 140.0);
+    }
+}
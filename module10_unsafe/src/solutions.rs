@@ -0,0 +1,512 @@
+// Module 10: Reference Solutions
+// Worked answers to the exercises in problems.rs, compiled only with the
+// `solutions` feature. Try solving the exercises yourself first!
+// The types and signatures mirror problems.rs, so the hidden grading suite
+// runs unchanged against these answers (see the tests at the end).
+
+use std::alloc::{alloc, dealloc, Layout};
+use std::ptr;
+
+pub fn run_exercises() {
+    println!("Module 10 Reference Solutions - Advanced Features and Unsafe Rust");
+    println!("===========================================================\n");
+
+    exercise1();
+    exercise2();
+    exercise3();
+    exercise4();
+    exercise5();
+}
+
+// Solution entry points in order, used by `rustpath run <module> --solution`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Exercise 1 Solution: Safe Abstraction over Raw Pointers
+
+// A row-major grid in one heap allocation. Slots start out empty, so `new`
+// needs no `T: Default`; every slot always holds an initialised `Option<T>`,
+// which is the invariant the unsafe blocks below rely on.
+struct Vec2D<T> {
+    ptr: *mut Option<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Vec2D<T> {
+    fn layout(len: usize) -> Layout {
+        Layout::array::<Option<T>>(len).expect("Vec2D is too large")
+    }
+
+    fn new(rows: usize, cols: usize) -> Self {
+        let len = rows.checked_mul(cols).expect("Vec2D is too large");
+        let layout = Self::layout(len);
+
+        let ptr = if layout.size() == 0 {
+            // Zero-sized allocations are not allowed; a dangling, aligned
+            // pointer is valid for zero-sized reads and writes
+            ptr::NonNull::dangling().as_ptr()
+        } else {
+            // SAFETY: the layout has a non-zero size
+            let ptr = unsafe { alloc(layout) } as *mut Option<T>;
+            if ptr.is_null() {
+                std::alloc::handle_alloc_error(layout);
+            }
+            ptr
+        };
+
+        for i in 0..len {
+            // SAFETY: `i` is within the allocation, and `write` does not drop
+            // the uninitialised memory it overwrites
+            unsafe { ptr.add(i).write(None) };
+        }
+
+        Vec2D { ptr, rows, cols }
+    }
+
+    // The single bounds check every accessor goes through
+    fn slot(&self, row: usize, col: usize) -> Option<*mut Option<T>> {
+        if row < self.rows && col < self.cols {
+            // SAFETY: the offset is below rows * cols, inside the allocation
+            Some(unsafe { self.ptr.add(row * self.cols + col) })
+        } else {
+            None
+        }
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        let slot = self.slot(row, col)?;
+        // SAFETY: the slot is in bounds and initialised; the returned
+        // reference borrows `self`, so the grid outlives it
+        unsafe { (*slot).as_ref() }
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        let slot = self.slot(row, col)?;
+        // SAFETY: as for `get`, and `&mut self` makes the borrow unique
+        unsafe { (*slot).as_mut() }
+    }
+
+    fn set(&mut self, row: usize, col: usize, value: T) -> bool {
+        match self.slot(row, col) {
+            Some(slot) => {
+                // SAFETY: the slot is in bounds and initialised, so the
+                // assignment may drop the previous element
+                unsafe { *slot = Some(value) };
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn cols(&self) -> usize {
+        self.cols
+    }
+}
+
+impl<T> Drop for Vec2D<T> {
+    fn drop(&mut self) {
+        let len = self.rows * self.cols;
+        let layout = Self::layout(len);
+        // SAFETY: all `len` slots are initialised and dropped exactly once;
+        // the buffer was allocated in `new` with this same layout
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr, len));
+            if layout.size() != 0 {
+                dealloc(self.ptr as *mut u8, layout);
+            }
+        }
+    }
+}
+
+fn exercise1() {
+    println!("Exercise 1: Safe Abstraction over Raw Pointers");
+    println!("------------------------------------------");
+
+    let mut vec = Vec2D::new(3, 4);
+    vec.set(0, 0, 1);
+    vec.set(1, 1, 2);
+    vec.set(2, 2, 3);
+
+    println!("Vec2D dimensions: {} x {}", vec.rows(), vec.cols());
+    println!("Element at (0, 0): {:?}", vec.get(0, 0));
+    println!("Element at (1, 1): {:?}", vec.get(1, 1));
+    println!("Element at (2, 2): {:?}", vec.get(2, 2));
+    println!("Element at (3, 3): {:?}", vec.get(3, 3)); // Out of bounds
+
+    if let Some(elem) = vec.get_mut(1, 1) {
+        *elem = 20;
+    }
+    println!("Modified element at (1, 1): {:?}", vec.get(1, 1));
+}
+
+// Exercise 2 Solution: FFI and C Interop
+
+#[repr(C)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+// There is no C library to link against, so the wrappers call the Rust
+// stand-ins below instead of `extern "C"` declarations. With a real library
+// only the function called inside each `unsafe` block would change.
+unsafe fn simulate_distance(p1: *const Point, p2: *const Point) -> f64 {
+    let p1 = &*p1;
+    let p2 = &*p2;
+    let dx = p2.x - p1.x;
+    let dy = p2.y - p1.y;
+    (dx * dx + dy * dy).sqrt()
+}
+
+unsafe fn simulate_midpoint(p1: *const Point, p2: *const Point, result: *mut Point) {
+    let p1 = &*p1;
+    let p2 = &*p2;
+    let mid = &mut *result;
+    mid.x = (p1.x + p2.x) / 2.0;
+    mid.y = (p1.y + p2.y) / 2.0;
+}
+
+fn safe_distance(p1: &Point, p2: &Point) -> f64 {
+    // SAFETY: references are always non-null, aligned and valid for reads
+    unsafe { simulate_distance(p1, p2) }
+}
+
+fn safe_midpoint(p1: &Point, p2: &Point) -> Point {
+    let mut result = Point { x: 0.0, y: 0.0 };
+    // SAFETY: the inputs are valid references and `result` is a live local
+    // that the callee only writes to
+    unsafe { simulate_midpoint(p1, p2, &mut result) };
+    result
+}
+
+// Called from C, so a null pointer has to be tolerated rather than trusted.
+// Unlike problems.rs this copy is not `#[no_mangle]`: both files are compiled
+// together and two exports of one symbol would clash at link time.
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn scale_point(point: *mut Point, factor: f64) {
+    // SAFETY: C callers pass either null or a pointer to a valid Point that
+    // nothing else accesses during the call
+    if let Some(point) = unsafe { point.as_mut() } {
+        point.x *= factor;
+        point.y *= factor;
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: FFI and C Interop");
+    println!("-------------------------");
+
+    let p1 = Point { x: 0.0, y: 0.0 };
+    let p2 = Point { x: 3.0, y: 4.0 };
+
+    println!("Distance: {}", safe_distance(&p1, &p2));
+    let mid = safe_midpoint(&p1, &p2);
+    println!("Midpoint: ({}, {})", mid.x, mid.y);
+
+    // Test the C-callable function
+    let mut p = Point { x: 2.0, y: 3.0 };
+    scale_point(&mut p, 2.0);
+    println!("Scaled point: ({}, {})", p.x, p.y);
+}
+
+// Exercise 3 Solution: Custom Memory Allocator
+
+// Hands out consecutive, aligned pieces of one buffer and frees them all at
+// once on `reset`. Returned pointers stay valid until the next reset or
+// until the allocator is dropped.
+struct BumpAllocator {
+    buffer: *mut u8,
+    capacity: usize,
+    offset: usize,
+}
+
+impl BumpAllocator {
+    fn buffer_layout(capacity: usize) -> Layout {
+        // At least one byte, since zero-sized allocations are not allowed
+        Layout::from_size_align(capacity.max(1), 16).expect("capacity is too large")
+    }
+
+    fn new(capacity: usize) -> Self {
+        let layout = Self::buffer_layout(capacity);
+        // SAFETY: the layout has a non-zero size
+        let buffer = unsafe { alloc(layout) };
+        if buffer.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
+        BumpAllocator {
+            buffer,
+            capacity,
+            offset: 0,
+        }
+    }
+
+    // Returns null when the request does not fit, like `GlobalAlloc::alloc`
+    fn alloc(&mut self, layout: Layout) -> *mut u8 {
+        // Align the absolute address, not the offset, so alignments larger
+        // than the buffer's own are honoured too
+        let base = self.buffer as usize;
+        let start = match (base + self.offset).checked_next_multiple_of(layout.align()) {
+            Some(address) => address - base,
+            None => return ptr::null_mut(),
+        };
+        match start.checked_add(layout.size()) {
+            Some(end) if end <= self.capacity => {
+                self.offset = end;
+                // SAFETY: `start..end` lies within the buffer
+                unsafe { self.buffer.add(start) }
+            }
+            _ => ptr::null_mut(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.offset = 0;
+    }
+
+    // Includes the padding inserted for alignment
+    fn used(&self) -> usize {
+        self.offset
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl Drop for BumpAllocator {
+    fn drop(&mut self) {
+        // SAFETY: the buffer was allocated in `new` with this same layout
+        unsafe { dealloc(self.buffer, Self::buffer_layout(self.capacity)) };
+    }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Custom Memory Allocator");
+    println!("-------------------------------");
+
+    let mut allocator = BumpAllocator::new(1024);
+    println!("Capacity: {} bytes", allocator.capacity());
+
+    // Allocate some memory
+    let layout1 = Layout::from_size_align(100, 8).unwrap();
+    let ptr1 = allocator.alloc(layout1);
+    println!("Allocated 100 bytes at {:?}", ptr1);
+    println!("Used: {} bytes", allocator.used());
+
+    let layout2 = Layout::from_size_align(200, 16).unwrap();
+    let ptr2 = allocator.alloc(layout2);
+    println!("Allocated 200 bytes at {:?}", ptr2);
+    println!("Used: {} bytes", allocator.used());
+
+    // Reset the allocator
+    allocator.reset();
+    println!("After reset, used: {} bytes", allocator.used());
+
+    // Allocate again
+    let ptr3 = allocator.alloc(layout1);
+    println!("Allocated 100 bytes at {:?}", ptr3);
+    println!("Used: {} bytes", allocator.used());
+}
+
+// Exercise 4 Solution: Safe Wrapper for SIMD Operations
+
+// Four f32 lanes, the width of an SSE register
+const LANES: usize = 4;
+
+// Lane-wise kernels. SSE is part of the x86_64 baseline, so no runtime
+// feature detection is needed there; other targets use plain loops, which
+// the compiler is free to vectorise on its own.
+#[cfg(target_arch = "x86_64")]
+mod lanes {
+    use super::LANES;
+    use std::arch::x86_64::{__m128, _mm_add_ps, _mm_loadu_ps, _mm_mul_ps, _mm_storeu_ps};
+
+    // Unaligned load/store, so plain arrays need no special alignment
+    unsafe fn load(values: &[f32; LANES]) -> __m128 {
+        _mm_loadu_ps(values.as_ptr())
+    }
+
+    unsafe fn store(values: __m128) -> [f32; LANES] {
+        let mut out = [0.0; LANES];
+        _mm_storeu_ps(out.as_mut_ptr(), values);
+        out
+    }
+
+    pub fn add(a: &[f32; LANES], b: &[f32; LANES]) -> [f32; LANES] {
+        // SAFETY: SSE is always available on x86_64, and each pointer covers
+        // exactly four f32s
+        unsafe { store(_mm_add_ps(load(a), load(b))) }
+    }
+
+    pub fn mul(a: &[f32; LANES], b: &[f32; LANES]) -> [f32; LANES] {
+        // SAFETY: as for `add`
+        unsafe { store(_mm_mul_ps(load(a), load(b))) }
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+mod lanes {
+    use super::LANES;
+
+    pub fn add(a: &[f32; LANES], b: &[f32; LANES]) -> [f32; LANES] {
+        std::array::from_fn(|i| a[i] + b[i])
+    }
+
+    pub fn mul(a: &[f32; LANES], b: &[f32; LANES]) -> [f32; LANES] {
+        std::array::from_fn(|i| a[i] * b[i])
+    }
+}
+
+struct SimdVector<T> {
+    values: Vec<T>,
+}
+
+impl SimdVector<f32> {
+    fn new(values: &[f32]) -> Self {
+        SimdVector {
+            values: values.to_vec(),
+        }
+    }
+
+    // Runs `lanes` over each full group of four and `scalar` over the tail
+    fn zip_with(
+        &self,
+        other: &Self,
+        lanes: fn(&[f32; LANES], &[f32; LANES]) -> [f32; LANES],
+        scalar: fn(f32, f32) -> f32,
+    ) -> Self {
+        assert_eq!(
+            self.values.len(),
+            other.values.len(),
+            "SimdVector lengths differ"
+        );
+        let mut values = Vec::with_capacity(self.values.len());
+
+        let (a_chunks, a_tail) = self.values.as_chunks::<LANES>();
+        let (b_chunks, b_tail) = other.values.as_chunks::<LANES>();
+        for (a, b) in a_chunks.iter().zip(b_chunks) {
+            values.extend(lanes(a, b));
+        }
+        values.extend(a_tail.iter().zip(b_tail).map(|(&a, &b)| scalar(a, b)));
+
+        SimdVector { values }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self.zip_with(other, lanes::add, |a, b| a + b)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self.zip_with(other, lanes::mul, |a, b| a * b)
+    }
+
+    fn dot(&self, other: &Self) -> f32 {
+        self.mul(other).values.iter().sum()
+    }
+
+    fn as_slice(&self) -> &[f32] {
+        &self.values
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Safe Wrapper for SIMD Operations");
+    println!("---------------------------------------");
+
+    let v1 = SimdVector::new(&[1.0, 2.0, 3.0, 4.0]);
+    let v2 = SimdVector::new(&[5.0, 6.0, 7.0, 8.0]);
+
+    let sum = v1.add(&v2);
+    println!("Sum: {:?}", sum.as_slice());
+
+    let product = v1.mul(&v2);
+    println!("Product: {:?}", product.as_slice());
+
+    let dot = v1.dot(&v2);
+    println!("Dot product: {}", dot);
+}
+
+// Exercise 5 Solution: Procedural Macro (Conceptual)
+
+// What `#[derive(Builder)]` would generate for Person, written out by hand.
+// The derive itself lives in a `proc-macro = true` crate: it parses the
+// struct with `syn`, wraps every field type in Option (leaving types that
+// already are Option alone), and emits this code with `quote!`.
+#[derive(Debug)]
+struct Person {
+    name: String,
+    age: u32,
+    email: Option<String>,
+}
+
+#[derive(Default)]
+struct PersonBuilder {
+    name: Option<String>,
+    age: Option<u32>,
+    email: Option<String>,
+}
+
+impl PersonBuilder {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    fn age(mut self, age: u32) -> Self {
+        self.age = Some(age);
+        self
+    }
+
+    fn email(mut self, email: String) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    // Required fields are checked here; optional ones pass straight through
+    fn build(self) -> Result<Person, String> {
+        Ok(Person {
+            name: self.name.ok_or("missing field `name`")?,
+            age: self.age.ok_or("missing field `age`")?,
+            email: self.email,
+        })
+    }
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Procedural Macro (Conceptual)");
+    println!("------------------------------------");
+
+    let person = PersonBuilder::new()
+        .name("Ferris".to_string())
+        .age(8)
+        .email("ferris@example.com".to_string())
+        .build();
+    println!("Built: {:?}", person);
+
+    let incomplete = PersonBuilder::new().name("Nobody".to_string()).build();
+    println!("Without an age: {:?}", incomplete);
+}
+
+// The grading suite from problems/grading.rs, run against these answers.
+// With the `grading` feature on too, problems.rs loads the same file for the
+// stubs; the two copies are meant to be separate modules.
+#[cfg(test)]
+#[path = "problems/grading.rs"]
+#[allow(clippy::duplicate_mod)]
+mod grading;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_run_exercises() {
+        super::run_exercises();
+    }
+}
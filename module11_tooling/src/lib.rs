@@ -0,0 +1,4 @@
+// Module 11: Rust Tooling and Ecosystem
+// Library target exposing the exercises so the `rustpath` runner can drive them.
+
+pub mod problems;
@@ -8,7 +8,7 @@
 // - Popular crates and their internals
 // - Contributing to the Rust ecosystem
 
-mod problems;
+use module11::problems;
 
 fn main() {
     // Run the practice problems
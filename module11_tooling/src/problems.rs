@@ -14,6 +14,9 @@ pub fn run_exercises() {
     exercise5();
 }
 
+// Exercise entry points in order, used by `rustpath run <module> --exercise <n>`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
 // Exercise 1: Cargo Features and Conditional Compilation
 // This exercise demonstrates:
 // - Using cargo features
@@ -42,28 +45,28 @@ fn exercise1() {
     println!("name = \"data_processor\"");
     println!("version = \"0.1.0\"");
     println!("edition = \"2021\"");
-    println!("");
+    println!();
     println!("[dependencies]");
     println!("serde = \"1.0\"");
     println!("log = {{ version = \"0.4\", optional = true }}");
-    println!("");
+    println!();
     println!("[dependencies.serde_json]");
     println!("version = \"1.0\"");
     println!("optional = true");
-    println!("");
+    println!();
     println!("[dependencies.quick-xml]");
     println!("version = \"0.22\"");
     println!("optional = true");
-    println!("");
+    println!();
     println!("[dependencies.csv]");
     println!("version = \"1.1\"");
     println!("optional = true");
-    println!("");
+    println!();
     println!("[dependencies.tokio]");
     println!("version = \"1.0\"");
     println!("optional = true");
     println!("features = [\"full\"]");
-    println!("");
+    println!();
     println!("[features]");
     println!("default = [\"json\"]");
     println!("json = [\"serde_json\"]");
@@ -75,32 +78,32 @@ fn exercise1() {
 
     println!("\nExample implementation with conditional compilation:");
     println!("#[cfg(feature = \"json\")]");
-    println!("pub fn parse_json(data: &str) -> Result<Value, Error> {");
+    println!("pub fn parse_json(data: &str) -> Result<Value, Error> {{");
     println!("    serde_json::from_str(data)");
-    println!("}");
-    println!("");
+    println!("}}");
+    println!();
     println!("#[cfg(feature = \"xml\")]");
-    println!("pub fn parse_xml(data: &str) -> Result<Value, Error> {");
+    println!("pub fn parse_xml(data: &str) -> Result<Value, Error> {{");
     println!("    // XML parsing implementation");
-    println!("}");
-    println!("");
+    println!("}}");
+    println!();
     println!("#[cfg(feature = \"async\")]");
-    println!("pub async fn process_async(data: &str) -> Result<Value, Error> {");
+    println!("pub async fn process_async(data: &str) -> Result<Value, Error> {{");
     println!("    // Async processing implementation");
-    println!("}");
+    println!("}}");
 
     println!("\nUsage example:");
     println!("// With default features");
     println!("data_processor = \"0.1.0\"");
-    println!("");
+    println!();
     println!("// With specific features");
     println!(
         "data_processor = {{ version = \"0.1.0\", features = [\"json\", \"csv\", \"logging\"] }}"
     );
-    println!("");
+    println!();
     println!("// With all features");
     println!("data_processor = {{ version = \"0.1.0\", features = [\"all\"] }}");
-    println!("");
+    println!();
     println!("// Without default features");
     println!("data_processor = {{ version = \"0.1.0\", default-features = false, features = [\"xml\"] }}");
 }
@@ -135,12 +138,12 @@ fn exercise2() {
     println!("    \"web_app_cli\",");
     println!("    \"web_app_common\",");
     println!("]");
-    println!("");
+    println!();
     println!("[workspace.package]");
     println!("version = \"0.1.0\"");
     println!("authors = [\"Your Name <your.email@example.com>\"]");
     println!("edition = \"2021\"");
-    println!("");
+    println!();
     println!("[workspace.dependencies]");
     println!("serde = {{ version = \"1.0\", features = [\"derive\"] }}");
     println!("tokio = {{ version = \"1.0\", features = [\"full\"] }}");
@@ -155,7 +158,7 @@ fn exercise2() {
     println!("version.workspace = true");
     println!("authors.workspace = true");
     println!("edition.workspace = true");
-    println!("");
+    println!();
     println!("[dependencies]");
     println!("serde.workspace = true");
     println!("tokio.workspace = true");
@@ -198,30 +201,30 @@ fn exercise3() {
     println!("   rustup target add x86_64-unknown-linux-gnu");
     println!("   rustup target add x86_64-pc-windows-msvc");
     println!("   rustup target add aarch64-apple-darwin");
-    println!("");
+    println!();
     println!("2. Install cross-compilation tools:");
     println!("   # For Linux target on non-Linux host");
     println!("   apt-get install gcc-x86-64-linux-gnu");
     println!("   # For Windows target on non-Windows host");
     println!("   apt-get install gcc-mingw-w64");
-    println!("");
+    println!();
     println!("3. Configure .cargo/config.toml:");
     println!("[target.x86_64-unknown-linux-gnu]");
     println!("linker = \"x86_64-linux-gnu-gcc\"");
-    println!("");
+    println!();
     println!("[target.x86_64-pc-windows-msvc]");
     println!("linker = \"x86_64-w64-mingw32-gcc\"");
     println!("ar = \"x86_64-w64-mingw32-ar\"");
-    println!("");
+    println!();
     println!("[target.aarch64-apple-darwin]");
     println!("linker = \"aarch64-apple-darwin-clang\"");
     println!("ar = \"aarch64-apple-darwin-ar\"");
-    println!("");
+    println!();
     println!("4. Build for each target:");
     println!("   cargo build --target x86_64-unknown-linux-gnu");
     println!("   cargo build --target x86_64-pc-windows-msvc");
     println!("   cargo build --target aarch64-apple-darwin");
-    println!("");
+    println!();
     println!("5. Create release builds:");
     println!("   cargo build --release --target x86_64-unknown-linux-gnu");
     println!("   cargo build --release --target x86_64-pc-windows-msvc");
@@ -229,19 +232,19 @@ fn exercise3() {
 
     println!("\nHandling platform-specific code:");
     println!("#[cfg(target_os = \"windows\")]");
-    println!("fn get_file_path() -> &'static str {");
+    println!("fn get_file_path() -> &'static str {{");
     println!("    \"C:\\\\path\\\\to\\\\file.txt\"");
-    println!("}");
-    println!("");
+    println!("}}");
+    println!();
     println!("#[cfg(target_os = \"linux\")]");
-    println!("fn get_file_path() -> &'static str {");
+    println!("fn get_file_path() -> &'static str {{");
     println!("    \"/path/to/file.txt\"");
-    println!("}");
-    println!("");
+    println!("}}");
+    println!();
     println!("#[cfg(target_os = \"macos\")]");
-    println!("fn get_file_path() -> &'static str {");
+    println!("fn get_file_path() -> &'static str {{");
     println!("    \"/path/to/file.txt\"");
-    println!("}");
+    println!("}}");
 }
 
 // Exercise 4: Profiling and Optimization
@@ -262,56 +265,56 @@ fn exercise4() {
     println!("Purpose: Process a large dataset and compute statistics");
 
     println!("\nOriginal implementation (pseudocode):");
-    println!("fn process_large_dataset(data: &[u64]) -> Statistics {");
+    println!("fn process_large_dataset(data: &[u64]) -> Statistics {{");
     println!("    let mut result = Statistics::new();");
-    println!("    for &value in data {");
+    println!("    for &value in data {{");
     println!("        result.update(value);");
-    println!("    }");
+    println!("    }}");
     println!("    result");
-    println!("}");
+    println!("}}");
 
     println!("\nProfiling steps:");
     println!("1. Build with debug info:");
     println!("   cargo build --release");
-    println!("");
+    println!();
     println!("2. Run with perf (Linux):");
     println!("   perf record --call-graph dwarf ./target/release/my_program");
     println!("   perf report");
-    println!("");
+    println!();
     println!("3. Generate flamegraph:");
     println!("   cargo install flamegraph");
     println!("   cargo flamegraph");
-    println!("");
+    println!();
     println!("4. Use criterion for benchmarking:");
     println!("   [dev-dependencies]");
     println!("   criterion = \"0.3\"");
-    println!("");
+    println!();
     println!("   [[bench]]");
     println!("   name = \"benchmark\"");
     println!("   harness = false");
 
     println!("\nOptimization techniques:");
     println!("1. Parallelization with rayon:");
-    println!("fn process_large_dataset(data: &[u64]) -> Statistics {");
+    println!("fn process_large_dataset(data: &[u64]) -> Statistics {{");
     println!("    data.par_iter()");
-    println!("        .map(|&value| {");
+    println!("        .map(|&value| {{");
     println!("            let mut local_stats = Statistics::new();");
     println!("            local_stats.update(value);");
     println!("            local_stats");
-    println!("        })");
+    println!("        }})");
     println!("        .reduce(|| Statistics::new(), |a, b| a.combine(b))");
-    println!("}");
-    println!("");
+    println!("}}");
+    println!();
     println!("2. SIMD optimization (for numeric operations):");
     println!("#[cfg(target_arch = \"x86_64\")]");
-    println!("fn sum_array(data: &[f32]) -> f32 {");
-    println!("    if is_x86_feature_detected!(\"avx2\") {");
-    println!("        unsafe { sum_array_avx2(data) }");
-    println!("    } else {");
+    println!("fn sum_array(data: &[f32]) -> f32 {{");
+    println!("    if is_x86_feature_detected!(\"avx2\") {{");
+    println!("        unsafe {{ sum_array_avx2(data) }}");
+    println!("    }} else {{");
     println!("        data.iter().sum()");
-    println!("    }");
-    println!("}");
-    println!("");
+    println!("    }}");
+    println!("}}");
+    println!();
     println!("3. Memory optimization:");
     println!("- Use appropriate data structures");
     println!("- Minimize allocations");
@@ -345,7 +348,7 @@ fn exercise5() {
     println!("\nSteps for publication:");
     println!("1. Choose a name and check availability:");
     println!("   cargo search config_loader");
-    println!("");
+    println!();
     println!("2. Set up metadata in Cargo.toml:");
     println!("[package]");
     println!("name = \"config_loader\"");
@@ -359,7 +362,7 @@ fn exercise5() {
     println!("keywords = [\"config\", \"configuration\", \"loader\"]");
     println!("categories = [\"config\"]");
     println!("readme = \"README.md\"");
-    println!("");
+    println!();
     println!("3. Write comprehensive documentation:");
     println!("//! # Config Loader");
     println!("//!");
@@ -385,43 +388,43 @@ fn exercise5() {
     println!("//!");
     println!("//! let server_port = config.get::<u16>(\"server.port\").unwrap_or(8080);");
     println!("//! ```");
-    println!("");
+    println!();
     println!("4. Write a good README.md:");
     println!("# Config Loader");
-    println!("");
+    println!();
     println!("A library for loading configuration from various sources.");
-    println!("");
+    println!();
     println!("## Features");
-    println!("");
+    println!();
     println!("- Load from files (JSON, YAML, TOML)");
     println!("- Load from environment variables");
     println!("- Load from command-line arguments");
     println!("- Merge configuration from multiple sources");
-    println!("");
+    println!();
     println!("## Usage");
-    println!("");
+    println!();
     println!("```rust");
     println!("use config_loader::ConfigLoader;");
-    println!("");
+    println!();
     println!("let config = ConfigLoader::new()");
     println!("    .add_file(\"config.toml\")");
     println!("    .add_env_prefix(\"APP_\")");
     println!("    .load()");
     println!("    .unwrap();");
-    println!("");
+    println!();
     println!("let server_port = config.get::<u16>(\"server.port\").unwrap_or(8080);");
     println!("```");
-    println!("");
+    println!();
     println!("5. Test thoroughly:");
     println!("   cargo test");
-    println!("");
+    println!();
     println!("6. Check for common issues:");
     println!("   cargo clippy");
     println!("   cargo fmt");
-    println!("");
+    println!();
     println!("7. Verify the package:");
     println!("   cargo package --list");
-    println!("");
+    println!();
     println!("8. Publish the crate:");
     println!("   cargo login");
     println!("   cargo publish");
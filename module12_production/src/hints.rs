@@ -0,0 +1,58 @@
+// Module 12: Hints
+// Progressive hints for the exercises in problems.rs, one list per exercise,
+// shown by `rustpath run 12 --exercise <n> --hint <level>`. Level 1 is the
+// exercise's "This exercise demonstrates" list; these are levels 2 and up,
+// each giving away a little more than the one before.
+
+pub const HINTS: [&[&str]; 5] = [
+    // Exercise 1: Performance Optimization
+    &[
+        "Start with a single iterator chain: `filter` the even values, `map` them to their \
+         squares and `collect`. That already removes the bounds checks and repeated pushes.",
+        "Parallelism only pays off for large inputs. Below a threshold, run the chain \
+         directly; above it, split the data into chunks and process them on \
+         `std::thread::scope` threads.",
+        "In `benchmark`, time both versions over `iterations` runs with `Instant::now()`, and \
+         pass results through `std::hint::black_box` so the optimizer cannot skip the work.",
+    ],
+    // Exercise 2: Error Handling at Scale
+    &[
+        "Give each failure its own variant: `Io(std::io::Error)`, `InvalidRequest(String)`, \
+         `Timeout(Duration)` and `Unauthorized`. Callers then handle them by matching, not by \
+         parsing strings.",
+        "`From<std::io::Error>` lets `?` convert io errors automatically. `source()` returns \
+         `Some(err)` only for the Io variant, which wraps an underlying error.",
+        "`handle_error` maps each variant to a strategy: Io and Timeout are temporary and can \
+         be retried, InvalidRequest needs a fixed request, and Unauthorized needs fresh \
+         credentials.",
+    ],
+    // Exercise 3: Configuration Management
+    &[
+        "Make every field an `Option`, so a source that doesn't mention a setting leaves it \
+         unset. The defaults, the environment and the file then layer cleanly.",
+        "`from_env` reads `APP_*` variables with `std::env::var`. `from_file` parses \
+         `key = value` lines. Both report unparseable values as errors rather than ignoring \
+         them.",
+        "`merge` copies only the fields that are `Some` in `other`. Apply it as `default()`, \
+         then the file, then the environment, and call `validate` last.",
+    ],
+    // Exercise 4: Connection Pooling
+    &[
+        "The idle connections live in a `Mutex<Vec<Connection>>`. `get` pops one, or returns \
+         an error when the pool is exhausted.",
+        "A PooledConnection keeps its Connection in an `Option` and returns it in `Drop`: \
+         `if let Some(conn) = self.connection.take() { self.pool.return_connection(conn) }`.",
+        "PooledConnection needs an `Arc<ConnectionPool>` while `get` only has `&self`, so \
+         keep the pool's state behind an inner `Arc` and give each PooledConnection a handle \
+         of its own. Replace invalidated connections with fresh ones on return.",
+    ],
+    // Exercise 5: Health Checks and Monitoring
+    &[
+        "HealthCheck is a `Vec<(String, Box<dyn Fn() -> bool>)>`. `check_health` is \
+         `iter().all(...)`, and `get_status` maps each entry to `(name, check())`.",
+        "The Metrics methods take `&self`, so the maps go behind a `Mutex`. A BTreeMap keeps \
+         the report in a stable order.",
+        "Counters add, gauges overwrite, and a histogram keeps count, sum, min and max. \
+         `get_metrics` formats all three kinds into one string, one metric per line.",
+    ],
+];
@@ -9,7 +9,7 @@
 // - Web service patterns
 // - Deployment considerations
 
-mod problems;
+use module12::problems;
 
 use std::sync::Arc;
 use std::time::Instant;
@@ -361,7 +361,7 @@ fn main() {
     println!("  WORKDIR /app");
     println!("  COPY . .");
     println!("  RUN cargo build --release");
-    println!("");
+    println!();
     println!("  FROM debian:bullseye-slim");
     println!("  COPY --from=builder /app/target/release/my_app /usr/local/bin/");
     println!("  CMD [\"my_app\"]");
@@ -0,0 +1,151 @@
+// Grading suite for the module 12 exercises
+// These checks are hidden from the exercise walkthrough: `rustpath grade 12`
+// runs them against your implementations in problems.rs. The field layouts
+// are yours to design, so the checks only go through the public methods.
+
+use super::*;
+
+mod exercise1 {
+    use super::*;
+
+    #[test]
+    fn test_optimized_matches_unoptimized() {
+        let processor = DataProcessor::new();
+        let data: Vec<u64> = (0..10_000).collect();
+        assert_eq!(
+            processor.process_batch_optimized(&data),
+            processor.process_batch_unoptimized(&data)
+        );
+        assert!(processor.process_batch_optimized(&[]).is_empty());
+        assert!(processor.process_batch_optimized(&[1, 3, 5]).is_empty());
+    }
+
+    #[test]
+    fn test_benchmark_runs_both_pipelines() {
+        let processor = DataProcessor::new();
+        let data: Vec<u64> = (0..1_000).collect();
+        let (unoptimized, optimized) = processor.benchmark(&data, 3);
+        assert!(unoptimized > std::time::Duration::ZERO);
+        assert!(optimized > std::time::Duration::ZERO);
+    }
+}
+
+mod exercise2 {
+    use super::*;
+
+    #[test]
+    fn test_valid_request_succeeds() {
+        let service = Service::new();
+        assert!(service.process_request("valid request").is_ok());
+    }
+
+    #[test]
+    fn test_failing_requests_are_described() {
+        let service = Service::new();
+        for request in ["invalid request", "timeout request", "unauthorized request"] {
+            let error = service.process_request(request).unwrap_err();
+            assert!(!error.to_string().is_empty(), "{}", request);
+            assert!(!service.handle_error(&error).is_empty(), "{}", request);
+        }
+    }
+
+    #[test]
+    fn test_io_errors_keep_their_source() {
+        let io = std::io::Error::other("disk on fire");
+        let error = ServiceError::from(io);
+        assert_eq!(error.source().unwrap().to_string(), "disk on fire");
+    }
+}
+
+mod exercise3 {
+    use super::*;
+
+    #[test]
+    fn test_defaults_are_valid() {
+        assert!(Config::default().validate().is_ok());
+        assert!(Application::new(Config::default()).is_ok());
+    }
+
+    #[test]
+    fn test_missing_file_is_an_error() {
+        assert!(Config::from_file("/nonexistent/rustpath/config.toml").is_err());
+    }
+
+    #[test]
+    fn test_merging_defaults_stays_valid() {
+        let mut config = Config::default();
+        config.merge(&Config::default());
+        assert!(config.validate().is_ok());
+    }
+}
+
+mod exercise4 {
+    use super::*;
+
+    #[test]
+    fn test_new_pool_is_full() {
+        let pool = ConnectionPool::new(5);
+        assert_eq!(pool.capacity(), 5);
+        assert_eq!(pool.available(), 5);
+    }
+
+    #[test]
+    fn test_connections_return_on_drop() {
+        let pool = Arc::new(ConnectionPool::new(3));
+        let first = pool.get().unwrap();
+        let second = pool.get().unwrap();
+        assert_eq!(pool.available(), 1);
+        assert!(first.execute("SELECT 1").is_ok());
+
+        drop(first);
+        assert_eq!(pool.available(), 2);
+        drop(second);
+        assert_eq!(pool.available(), 3);
+    }
+
+    #[test]
+    fn test_exhausted_pool_errors() {
+        let pool = ConnectionPool::new(1);
+        let _held = pool.get().unwrap();
+        assert!(pool.get().is_err());
+    }
+}
+
+mod exercise5 {
+    use super::*;
+
+    #[test]
+    fn test_health_follows_components() {
+        let mut health = HealthCheck::new();
+        health.register_component("database", Box::new(|| true));
+        assert!(health.check_health());
+        health.register_component("cache", Box::new(|| false));
+        assert!(!health.check_health());
+        assert_eq!(
+            health.get_status(),
+            [("database".to_string(), true), ("cache".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn test_metrics_report_recorded_names() {
+        let metrics = Metrics::new();
+        metrics.increment_counter("requests_total", 2);
+        metrics.set_gauge("queue_depth", 3.0);
+        metrics.observe_histogram("latency_seconds", 0.25);
+        let report = metrics.get_metrics();
+        for name in ["requests_total", "queue_depth", "latency_seconds"] {
+            assert!(report.contains(name), "{} missing from:\n{}", name, report);
+        }
+    }
+
+    #[test]
+    fn test_monitored_service_counts_errors() {
+        let service = MonitoredService::new();
+        assert!(service.health());
+        assert!(service.process_request("ok").is_ok());
+        assert!(service.process_request("error_request").is_err());
+        assert!(service.metrics().contains("errors_total"));
+        assert_eq!(service.health_status().len(), 3);
+    }
+}
@@ -0,0 +1,799 @@
+// Module 12: Reference Solutions
+// Worked answers to the exercises in problems.rs, compiled only with the
+// `solutions` feature. Try solving the exercises yourself first!
+// The types and signatures mirror problems.rs, so the hidden grading suite
+// runs unchanged against these answers (see the tests at the end).
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub fn run_exercises() {
+    println!("Module 12 Reference Solutions - Production Rust");
+    println!("=========================================\n");
+
+    exercise1();
+    exercise2();
+    exercise3();
+    exercise4();
+    exercise5();
+}
+
+// Solution entry points in order, used by `rustpath run <module> --solution`.
+pub const EXERCISES: [fn(); 5] = [exercise1, exercise2, exercise3, exercise4, exercise5];
+
+// Exercise 1 Solution: Performance Optimization
+
+// Below this many items, spawning threads costs more than it saves
+const PARALLEL_THRESHOLD: usize = 50_000;
+
+struct DataProcessor {
+    num_threads: usize,
+}
+
+impl DataProcessor {
+    fn new() -> Self {
+        DataProcessor {
+            num_threads: thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+
+    fn process_batch_unoptimized(&self, data: &[u64]) -> Vec<u64> {
+        let mut result = Vec::with_capacity(data.len());
+
+        for &value in data {
+            if value % 2 == 0 {
+                let transformed = value * value;
+                result.push(transformed);
+            }
+        }
+
+        result
+    }
+
+    // One fused iterator pass per chunk; large batches are split across
+    // scoped threads and the chunk results joined back in order
+    fn process_batch_optimized(&self, data: &[u64]) -> Vec<u64> {
+        fn pipeline(chunk: &[u64]) -> Vec<u64> {
+            chunk
+                .iter()
+                .filter(|&&value| value & 1 == 0)
+                .map(|&value| value * value)
+                .collect()
+        }
+
+        if self.num_threads < 2 || data.len() < PARALLEL_THRESHOLD {
+            return pipeline(data);
+        }
+
+        let chunk_size = data.len().div_ceil(self.num_threads);
+        thread::scope(|scope| {
+            let handles: Vec<_> = data
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || pipeline(chunk)))
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        })
+    }
+
+    // `black_box` keeps the optimiser from discarding the unused results
+    fn benchmark(&self, data: &[u64], iterations: usize) -> (Duration, Duration) {
+        let time = |process: &dyn Fn(&[u64]) -> Vec<u64>| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                std::hint::black_box(process(std::hint::black_box(data)));
+            }
+            start.elapsed()
+        };
+
+        (
+            time(&|data| self.process_batch_unoptimized(data)),
+            time(&|data| self.process_batch_optimized(data)),
+        )
+    }
+}
+
+fn exercise1() {
+    println!("Exercise 1: Performance Optimization");
+    println!("-------------------------------");
+
+    let processor = DataProcessor::new();
+
+    // Generate test data
+    let data: Vec<u64> = (0..100_000).collect();
+
+    // Run benchmark
+    let (unoptimized_time, optimized_time) = processor.benchmark(&data, 10);
+
+    println!("Unoptimized time: {:?}", unoptimized_time);
+    println!("Optimized time: {:?}", optimized_time);
+    println!(
+        "Speedup: {:.2}x",
+        unoptimized_time.as_secs_f64() / optimized_time.as_secs_f64()
+    );
+}
+
+// Exercise 2 Solution: Error Handling at Scale
+
+#[derive(Debug)]
+enum ServiceError {
+    Io(std::io::Error),
+    InvalidRequest(String),
+    Timeout(Duration),
+    Unauthorized,
+}
+
+impl fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ServiceError::Io(_) => write!(f, "I/O failure"),
+            ServiceError::InvalidRequest(reason) => write!(f, "invalid request: {}", reason),
+            ServiceError::Timeout(after) => write!(f, "timed out after {:?}", after),
+            ServiceError::Unauthorized => write!(f, "unauthorized"),
+        }
+    }
+}
+
+// The wrapped error is exposed through `source` rather than repeated in
+// Display, so error reporters do not print it twice
+impl Error for ServiceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ServiceError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ServiceError {
+    fn from(error: std::io::Error) -> Self {
+        ServiceError::Io(error)
+    }
+}
+
+struct Service {
+    timeout: Duration,
+}
+
+impl Service {
+    fn new() -> Self {
+        Service {
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    // The first word of the request stands in for what a real handler
+    // would find out by doing the work
+    fn process_request(&self, request: &str) -> Result<String, ServiceError> {
+        match request.split_whitespace().next() {
+            Some("valid") => Ok(format!("processed '{}'", request)),
+            Some("timeout") => Err(ServiceError::Timeout(self.timeout)),
+            Some("unauthorized") => Err(ServiceError::Unauthorized),
+            Some("io") => Err(std::io::Error::other("storage unavailable").into()),
+            Some(_) => Err(ServiceError::InvalidRequest(format!(
+                "unrecognised request '{}'",
+                request
+            ))),
+            None => Err(ServiceError::InvalidRequest("empty request".to_string())),
+        }
+    }
+
+    // Decides what the caller should do about each kind of failure
+    fn handle_error(&self, error: &ServiceError) -> String {
+        match error {
+            ServiceError::Io(_) | ServiceError::Timeout(_) => {
+                "temporary failure, retry with backoff".to_string()
+            }
+            ServiceError::InvalidRequest(_) => "fix the request before retrying".to_string(),
+            ServiceError::Unauthorized => "refresh credentials and retry".to_string(),
+        }
+    }
+}
+
+fn exercise2() {
+    println!("\nExercise 2: Error Handling at Scale");
+    println!("------------------------------");
+
+    let service = Service::new();
+
+    let requests = vec![
+        "valid request",
+        "invalid request",
+        "timeout request",
+        "unauthorized request",
+        "io request",
+    ];
+
+    for request in requests {
+        match service.process_request(request) {
+            Ok(response) => println!("Response: {}", response),
+            Err(error) => {
+                println!("Error: {} ({})", service.handle_error(&error), error);
+                if let Some(source) = error.source() {
+                    println!("  caused by: {}", source);
+                }
+            }
+        }
+    }
+}
+
+// Exercise 3 Solution: Configuration Management
+
+const LOG_LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+// Every setting is optional so that each source (defaults, file,
+// environment) only holds what it actually sets; `merge` layers them and
+// `validate` checks the result.
+#[derive(Debug, Clone, PartialEq)]
+struct Config {
+    host: Option<String>,
+    port: Option<u16>,
+    workers: Option<usize>,
+    log_level: Option<String>,
+}
+
+impl Config {
+    fn empty() -> Self {
+        Config {
+            host: None,
+            port: None,
+            workers: None,
+            log_level: None,
+        }
+    }
+
+    fn default() -> Self {
+        Config {
+            host: Some("127.0.0.1".to_string()),
+            port: Some(8080),
+            workers: Some(4),
+            log_level: Some("info".to_string()),
+        }
+    }
+
+    // Applies one `key = value` setting, naming the key in any error
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        fn number<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, String> {
+            value
+                .parse()
+                .map_err(|_| format!("{} must be a number, got '{}'", key, value))
+        }
+
+        match key {
+            "host" => self.host = Some(value.to_string()),
+            "port" => self.port = Some(number(key, value)?),
+            "workers" => self.workers = Some(number(key, value)?),
+            "log_level" => self.log_level = Some(value.to_lowercase()),
+            _ => return Err(format!("unknown setting '{}'", key)),
+        }
+        Ok(())
+    }
+
+    // APP_HOST, APP_PORT, APP_WORKERS and APP_LOG_LEVEL
+    fn from_env() -> Result<Self, String> {
+        let mut config = Config::empty();
+        for key in ["host", "port", "workers", "log_level"] {
+            if let Ok(value) = std::env::var(format!("APP_{}", key.to_uppercase())) {
+                config.set(key, &value)?;
+            }
+        }
+        Ok(config)
+    }
+
+    // A flat `key = value` file; blank lines and `#` comments are skipped and
+    // values may be quoted, so simple TOML files load too
+    fn from_file(path: &str) -> Result<Self, String> {
+        let contents =
+            std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path, e))?;
+
+        let mut config = Config::empty();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("{}:{}: expected key = value", path, index + 1))?;
+            let value = value.trim().trim_matches('"');
+            config
+                .set(key.trim(), value)
+                .map_err(|e| format!("{}:{}: {}", path, index + 1, e))?;
+        }
+        Ok(config)
+    }
+
+    // Settings present in `other` win
+    fn merge(&mut self, other: &Config) {
+        fn take<T: Clone>(current: &mut Option<T>, other: &Option<T>) {
+            if other.is_some() {
+                current.clone_from(other);
+            }
+        }
+
+        take(&mut self.host, &other.host);
+        take(&mut self.port, &other.port);
+        take(&mut self.workers, &other.workers);
+        take(&mut self.log_level, &other.log_level);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        match self.host.as_deref() {
+            None => return Err("host is not set".to_string()),
+            Some("") => return Err("host cannot be empty".to_string()),
+            Some(_) => {}
+        }
+        match self.port {
+            None => return Err("port is not set".to_string()),
+            Some(0) => return Err("port cannot be 0".to_string()),
+            Some(_) => {}
+        }
+        match self.workers {
+            None => return Err("workers is not set".to_string()),
+            Some(workers) if !(1..=1024).contains(&workers) => {
+                return Err(format!(
+                    "workers must be between 1 and 1024, got {}",
+                    workers
+                ))
+            }
+            Some(_) => {}
+        }
+        match self.log_level.as_deref() {
+            None => Err("log_level is not set".to_string()),
+            Some(level) if !LOG_LEVELS.contains(&level) => Err(format!(
+                "log_level must be one of {:?}, got '{}'",
+                LOG_LEVELS, level
+            )),
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+struct Application {
+    config: Config,
+}
+
+impl Application {
+    fn new(config: Config) -> Result<Self, String> {
+        config.validate()?;
+
+        Ok(Application { config })
+    }
+
+    fn run(&self) {
+        println!("Running application with config: {:?}", self.config);
+    }
+}
+
+fn exercise3() {
+    println!("\nExercise 3: Configuration Management");
+    println!("-------------------------------");
+
+    // Load configuration from multiple sources, later ones taking priority
+    let mut config = Config::default();
+
+    match Config::from_file("config.toml") {
+        Ok(file_config) => config.merge(&file_config),
+        Err(error) => println!("Skipping file config: {}", error),
+    }
+
+    match Config::from_env() {
+        Ok(env_config) => config.merge(&env_config),
+        Err(error) => println!("Skipping environment config: {}", error),
+    }
+
+    match Application::new(config) {
+        Ok(app) => app.run(),
+        Err(error) => println!("Failed to create application: {}", error),
+    }
+
+    // Invalid values are caught before the application starts
+    let mut bad = Config::default();
+    bad.merge(&Config {
+        port: Some(0),
+        ..Config::empty()
+    });
+    if let Err(error) = Application::new(bad) {
+        println!("Rejected config: {}", error);
+    }
+}
+
+// Exercise 4 Solution: Connection Pooling
+
+struct Connection {
+    id: usize,
+    is_valid: bool,
+}
+
+impl Connection {
+    fn new(id: usize) -> Self {
+        Connection { id, is_valid: true }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    fn invalidate(&mut self) {
+        self.is_valid = false;
+    }
+
+    fn execute(&self, query: &str) -> Result<String, String> {
+        if !self.is_valid {
+            return Err("Connection is invalid".to_string());
+        }
+
+        Ok(format!("Result of '{}' on connection {}", query, self.id))
+    }
+}
+
+// The state every handle to one pool shares
+struct PoolState {
+    idle: Mutex<Vec<Connection>>,
+    capacity: usize,
+    next_id: AtomicUsize,
+}
+
+// A cheap handle onto shared state. `get` takes `&self`, yet each
+// PooledConnection has to keep the pool alive, so it gets a handle of its
+// own pointing at the same state.
+struct ConnectionPool {
+    state: Arc<PoolState>,
+}
+
+impl ConnectionPool {
+    fn new(capacity: usize) -> Self {
+        ConnectionPool {
+            state: Arc::new(PoolState {
+                idle: Mutex::new((0..capacity).map(Connection::new).collect()),
+                capacity,
+                next_id: AtomicUsize::new(capacity),
+            }),
+        }
+    }
+
+    fn get(&self) -> Result<PooledConnection, String> {
+        let connection = self
+            .state
+            .idle
+            .lock()
+            .unwrap()
+            .pop()
+            .ok_or_else(|| format!("all {} connections are in use", self.state.capacity))?;
+
+        let handle = ConnectionPool {
+            state: Arc::clone(&self.state),
+        };
+        Ok(PooledConnection::new(connection, Arc::new(handle)))
+    }
+
+    // A broken connection is replaced, so the pool never shrinks
+    fn return_connection(&self, conn: Connection) {
+        let conn = if conn.is_valid() {
+            conn
+        } else {
+            Connection::new(self.state.next_id.fetch_add(1, Ordering::Relaxed))
+        };
+        self.state.idle.lock().unwrap().push(conn);
+    }
+
+    fn available(&self) -> usize {
+        self.state.idle.lock().unwrap().len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.state.capacity
+    }
+}
+
+struct PooledConnection {
+    connection: Option<Connection>,
+    pool: Arc<ConnectionPool>,
+}
+
+impl PooledConnection {
+    fn new(connection: Connection, pool: Arc<ConnectionPool>) -> Self {
+        PooledConnection {
+            connection: Some(connection),
+            pool,
+        }
+    }
+
+    fn execute(&self, query: &str) -> Result<String, String> {
+        if let Some(conn) = &self.connection {
+            conn.execute(query)
+        } else {
+            Err("Connection has been taken".to_string())
+        }
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        if let Some(conn) = self.connection.take() {
+            self.pool.return_connection(conn);
+        }
+    }
+}
+
+fn exercise4() {
+    println!("\nExercise 4: Connection Pooling");
+    println!("--------------------------");
+
+    let pool = Arc::new(ConnectionPool::new(5));
+    println!("Pool capacity: {}", pool.capacity());
+    println!("Available connections: {}", pool.available());
+
+    // Get connections from the pool
+    let mut connections = Vec::new();
+    for i in 0..3 {
+        match pool.get() {
+            Ok(conn) => {
+                println!("Got connection {}", i);
+                println!(
+                    "Query result: {}",
+                    conn.execute("SELECT * FROM users").unwrap()
+                );
+                connections.push(conn);
+            }
+            Err(e) => println!("Failed to get connection: {}", e),
+        }
+    }
+
+    println!(
+        "Available connections after getting 3: {}",
+        pool.available()
+    );
+
+    // A connection that breaks while checked out is replaced on return
+    if let Some(conn) = connections.last_mut().and_then(|c| c.connection.as_mut()) {
+        conn.invalidate();
+    }
+
+    // Drop a connection to return it to the pool
+    connections.pop();
+    println!(
+        "Available connections after returning 1: {}",
+        pool.available()
+    );
+
+    // Drop the rest of the connections
+    connections.clear();
+    println!(
+        "Available connections after returning all: {}",
+        pool.available()
+    );
+}
+
+// Exercise 5 Solution: Health Checks and Monitoring
+
+// Components are kept in registration order, which `get_status` reports
+struct HealthCheck {
+    components: Vec<(String, Box<dyn Fn() -> bool>)>,
+}
+
+impl HealthCheck {
+    fn new() -> Self {
+        HealthCheck {
+            components: Vec::new(),
+        }
+    }
+
+    fn register_component(&mut self, name: &str, check: Box<dyn Fn() -> bool>) {
+        self.components.push((name.to_string(), check));
+    }
+
+    fn check_health(&self) -> bool {
+        self.components.iter().all(|(_, check)| check())
+    }
+
+    fn get_status(&self) -> Vec<(String, bool)> {
+        self.components
+            .iter()
+            .map(|(name, check)| (name.clone(), check()))
+            .collect()
+    }
+}
+
+// Count, sum and range of the observed values
+#[derive(Default)]
+struct Histogram {
+    count: u64,
+    sum: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Histogram {
+    fn observe(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.count += 1;
+        self.sum += value;
+    }
+}
+
+// Recording takes `&self` so a service can record from shared references;
+// the maps are sorted so the report comes out in a stable order
+#[derive(Default)]
+struct MetricStore {
+    counters: BTreeMap<String, u64>,
+    gauges: BTreeMap<String, f64>,
+    histograms: BTreeMap<String, Histogram>,
+}
+
+struct Metrics {
+    store: Mutex<MetricStore>,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        Metrics {
+            store: Mutex::new(MetricStore::default()),
+        }
+    }
+
+    fn increment_counter(&self, name: &str, value: u64) {
+        *self
+            .store
+            .lock()
+            .unwrap()
+            .counters
+            .entry(name.to_string())
+            .or_default() += value;
+    }
+
+    fn set_gauge(&self, name: &str, value: f64) {
+        self.store
+            .lock()
+            .unwrap()
+            .gauges
+            .insert(name.to_string(), value);
+    }
+
+    fn observe_histogram(&self, name: &str, value: f64) {
+        self.store
+            .lock()
+            .unwrap()
+            .histograms
+            .entry(name.to_string())
+            .or_default()
+            .observe(value);
+    }
+
+    // One `name value` line per series, in the spirit of the Prometheus
+    // text format
+    fn get_metrics(&self) -> String {
+        let store = self.store.lock().unwrap();
+        let mut lines = Vec::new();
+
+        for (name, value) in &store.counters {
+            lines.push(format!("{} {}", name, value));
+        }
+        for (name, value) in &store.gauges {
+            lines.push(format!("{} {}", name, value));
+        }
+        for (name, histogram) in &store.histograms {
+            lines.push(format!("{}_count {}", name, histogram.count));
+            lines.push(format!("{}_sum {}", name, histogram.sum));
+            lines.push(format!("{}_min {}", name, histogram.min));
+            lines.push(format!("{}_max {}", name, histogram.max));
+        }
+
+        lines.join("\n")
+    }
+}
+
+struct MonitoredService {
+    health_check: HealthCheck,
+    metrics: Metrics,
+}
+
+impl MonitoredService {
+    fn new() -> Self {
+        let mut health_check = HealthCheck::new();
+        let metrics = Metrics::new();
+
+        health_check.register_component("database", Box::new(|| true));
+        health_check.register_component("cache", Box::new(|| true));
+        health_check.register_component("api", Box::new(|| true));
+
+        MonitoredService {
+            health_check,
+            metrics,
+        }
+    }
+
+    fn process_request(&self, request: &str) -> Result<(), String> {
+        self.metrics.increment_counter("requests_total", 1);
+
+        let start = std::time::Instant::now();
+
+        if request.contains("error") {
+            self.metrics.increment_counter("errors_total", 1);
+            return Err("Error processing request".to_string());
+        }
+
+        let duration = start.elapsed();
+        self.metrics
+            .observe_histogram("request_duration_seconds", duration.as_secs_f64());
+
+        Ok(())
+    }
+
+    fn health(&self) -> bool {
+        self.health_check.check_health()
+    }
+
+    fn health_status(&self) -> Vec<(String, bool)> {
+        self.health_check.get_status()
+    }
+
+    fn metrics(&self) -> String {
+        self.metrics.get_metrics()
+    }
+}
+
+fn exercise5() {
+    println!("\nExercise 5: Health Checks and Monitoring");
+    println!("-----------------------------------");
+
+    let service = MonitoredService::new();
+
+    // Process some requests
+    for request in &["request1", "error_request", "request2"] {
+        match service.process_request(request) {
+            Ok(()) => println!("Processed request: {}", request),
+            Err(e) => println!("Error processing request {}: {}", request, e),
+        }
+    }
+
+    // Check health
+    println!(
+        "Service health: {}",
+        if service.health() {
+            "healthy"
+        } else {
+            "unhealthy"
+        }
+    );
+    println!("Health status:");
+    for (component, status) in service.health_status() {
+        println!(
+            "  {}: {}",
+            component,
+            if status { "healthy" } else { "unhealthy" }
+        );
+    }
+
+    // Get metrics
+    println!("Metrics:\n{}", service.metrics());
+}
+
+// The grading suite from problems/grading.rs, run against these answers.
+// With the `grading` feature on too, problems.rs loads the same file for the
+// stubs; the two copies are meant to be separate modules.
+#[cfg(test)]
+#[path = "problems/grading.rs"]
+#[allow(clippy::duplicate_mod)]
+mod grading;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_run_exercises() {
+        super::run_exercises();
+    }
+}
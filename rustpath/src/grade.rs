@@ -0,0 +1,554 @@
+// Grading
+// Every module with stubs carries a hidden suite in src/problems/grading.rs,
+// compiled only with the crate's `grading` feature. We build it with
+// `cargo test --no-run`, run the test binary, read libtest's output and sort
+// each check into one of three outcomes:
+// - passed
+// - failed:   the suite's own assertion did not hold (wrong answer)
+// - panicked: the exercise code panicked, usually because a stub is still
+//             in place, or the test process died while running it
+
+use crate::modules::{self, Module};
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Suites live in `problems::grading::exerciseN`
+const SUITE_PREFIX: &str = "problems::grading::";
+
+// A whole-suite run gets this long; a check rerun on its own gets less
+const SUITE_TIMEOUT: Duration = Duration::from_secs(120);
+const CHECK_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    Passed,
+    Failed,
+    Panicked,
+}
+
+impl Outcome {
+    pub fn label(self) -> &'static str {
+        match self {
+            Outcome::Passed => "passed",
+            Outcome::Failed => "failed",
+            Outcome::Panicked => "panicked",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Check {
+    pub exercise: usize,
+    pub name: String,
+    pub outcome: Outcome,
+    pub message: Option<String>,
+}
+
+pub enum Report {
+    // Modules without stubs (01, 11) have nothing to grade
+    NoSuite,
+    CompileError(String),
+    Graded(Vec<Check>),
+}
+
+// Counts per outcome, in Outcome order
+#[derive(Default, Clone, Copy)]
+pub struct Tally {
+    pub passed: usize,
+    pub failed: usize,
+    pub panicked: usize,
+}
+
+impl Tally {
+    pub fn of<'a>(checks: impl IntoIterator<Item = &'a Check>) -> Tally {
+        let mut tally = Tally::default();
+        for check in checks {
+            tally.add(check.outcome);
+        }
+        tally
+    }
+
+    pub fn add(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Passed => self.passed += 1,
+            Outcome::Failed => self.failed += 1,
+            Outcome::Panicked => self.panicked += 1,
+        }
+    }
+
+    pub fn merge(&mut self, other: &Tally) {
+        self.passed += other.passed;
+        self.failed += other.failed;
+        self.panicked += other.panicked;
+    }
+
+    pub fn total(&self) -> usize {
+        self.passed + self.failed + self.panicked
+    }
+}
+
+pub fn has_suite(module: &Module) -> bool {
+    module.path().join("src/problems/grading.rs").is_file()
+}
+
+pub fn grade(module: &Module) -> Result<Report, String> {
+    grade_matching(module, SUITE_PREFIX)
+}
+
+// Only one exercise's checks, for watch mode
+pub fn grade_exercise(module: &Module, exercise: usize) -> Result<Report, String> {
+    grade_matching(module, &format!("{}exercise{}::", SUITE_PREFIX, exercise))
+}
+
+// Runs the checks whose names start with `filter`
+fn grade_matching(module: &Module, filter: &str) -> Result<Report, String> {
+    if !has_suite(module) {
+        return Ok(Report::NoSuite);
+    }
+
+    // Compile errors in the learner's code show up here
+    let binary = match build(module)? {
+        Ok(binary) => binary,
+        Err(errors) => return Ok(Report::CompileError(errors)),
+    };
+    let suite = TestBinary {
+        path: binary,
+        dir: module.path(),
+    };
+
+    let names = parse_list(&suite.run(&["--list", filter], None)?.stdout);
+    let mut results = parse_results(&suite.run(&[filter], Some(SUITE_TIMEOUT))?.stdout);
+
+    // A check that aborts the process (a panic inside `Drop` while unwinding,
+    // or one crossing an `extern "C"` boundary) takes the rest of the run
+    // with it, including the failure messages of earlier checks; rerun
+    // whatever went unreported on its own
+    for name in &names {
+        match results.get(name) {
+            Some((Outcome::Passed, _)) | Some((_, Some(_))) => continue,
+            _ => {}
+        }
+        let single = suite.run(&["--exact", name], Some(CHECK_TIMEOUT))?;
+        let result = parse_results(&single.stdout)
+            .remove(name)
+            .unwrap_or_else(|| {
+                let message = if single.timed_out {
+                    format!("did not finish within {}s", CHECK_TIMEOUT.as_secs())
+                } else {
+                    "the test process aborted".to_string()
+                };
+                (Outcome::Panicked, Some(message))
+            });
+        results.insert(name.clone(), result);
+    }
+
+    let checks = names
+        .iter()
+        .filter_map(|name| {
+            let (outcome, message) = results.remove(name)?;
+            let (exercise, test) = split_name(name)?;
+            Some(Check {
+                exercise,
+                name: test.to_string(),
+                outcome,
+                message,
+            })
+        })
+        .collect();
+    Ok(Report::Graded(checks))
+}
+
+// Builds the module's test binary with the grading suite compiled in and
+// returns its path, or the compiler errors if the build failed
+fn build(module: &Module) -> Result<Result<PathBuf, String>, String> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .args([
+            "test",
+            "--package",
+            module.package,
+            "--lib",
+            "--features",
+            "grading",
+        ])
+        .arg("--no-run")
+        .current_dir(modules::workspace_root())
+        .output()
+        .map_err(|e| format!("failed to run cargo: {}", e))?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if !output.status.success() {
+        return Ok(Err(compile_errors(&stderr)));
+    }
+    // Cargo reports "Executable unittests src/lib.rs (target/debug/deps/...)"
+    let binary = stderr
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("Executable unittests "))
+        .find_map(|rest| rest.split_once(" (")?.1.strip_suffix(')'))
+        .ok_or_else(|| {
+            format!(
+                "cargo did not report the test binary for {}",
+                module.package
+            )
+        })?;
+    Ok(Ok(modules::workspace_root().join(binary)))
+}
+
+// The test binary is run directly rather than through `cargo test`, so a
+// hung check can be killed without leaving the binary running behind cargo
+struct TestBinary {
+    path: PathBuf,
+    dir: PathBuf,
+}
+
+struct TestRun {
+    timed_out: bool,
+    stdout: String,
+}
+
+impl TestBinary {
+    fn run(&self, args: &[&str], timeout: Option<Duration>) -> Result<TestRun, String> {
+        let mut child = Command::new(&self.path)
+            .args(args)
+            .env("RUST_BACKTRACE", "0")
+            .current_dir(&self.dir)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("failed to run {}: {}", self.path.display(), e))?;
+
+        // Drain stdout on its own thread so a chatty check can't block
+        let stdout = drain(child.stdout.take());
+
+        let started = Instant::now();
+        let mut timed_out = false;
+        loop {
+            if child.try_wait().map_err(|e| e.to_string())?.is_some() {
+                break;
+            }
+            if timeout.is_some_and(|limit| started.elapsed() > limit) {
+                timed_out = true;
+                let _ = child.kill();
+                let _ = child.wait();
+                break;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+
+        Ok(TestRun {
+            timed_out,
+            stdout: stdout.join().unwrap_or_default(),
+        })
+    }
+}
+
+fn drain(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut output = String::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut output);
+        }
+        output
+    })
+}
+
+// `--list` prints one "name: test" line per check
+fn parse_list(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .filter_map(|line| line.strip_suffix(": test"))
+        .filter(|name| name.starts_with(SUITE_PREFIX))
+        .map(str::to_string)
+        .collect()
+}
+
+// Reads "test <name> ... ok|FAILED" lines, then the failures section for
+// the panic message and location of every failed check
+fn parse_results(stdout: &str) -> BTreeMap<String, (Outcome, Option<String>)> {
+    let mut results = BTreeMap::new();
+    for line in stdout.lines() {
+        let Some(rest) = line.strip_prefix("test ") else {
+            continue;
+        };
+        if let Some(name) = rest.strip_suffix(" ... ok") {
+            results.insert(name.to_string(), (Outcome::Passed, None));
+        } else if let Some(name) = rest.strip_suffix(" ... FAILED") {
+            results.insert(name.to_string(), (Outcome::Panicked, None));
+        }
+    }
+
+    let mut lines = stdout.lines().peekable();
+    while let Some(line) = lines.next() {
+        let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|rest| rest.strip_suffix(" stdout ----"))
+        else {
+            continue;
+        };
+
+        // Skip anything the check printed before it panicked
+        let mut location = None;
+        while let Some(line) = lines.next_if(|line| !line.starts_with("---- ")) {
+            if let Some(at) = line.split(" panicked at ").nth(1) {
+                location = Some(at.trim_end_matches(':').to_string());
+                break;
+            }
+        }
+        let mut message = Vec::new();
+        while let Some(line) = lines.peek() {
+            if line.is_empty() || line.starts_with("note: ") || line.starts_with("---- ") {
+                break;
+            }
+            message.push(*line);
+            lines.next();
+        }
+
+        if let Some(result) = results.get_mut(name) {
+            // A panic raised by the suite itself is a wrong answer; one from
+            // anywhere else came out of the exercise code
+            let in_suite = location.is_some_and(|at| at.contains("grading.rs"));
+            result.0 = if in_suite {
+                Outcome::Failed
+            } else {
+                Outcome::Panicked
+            };
+            if !message.is_empty() {
+                result.1 = Some(message.join("\n"));
+            }
+        }
+    }
+    results
+}
+
+// "problems::grading::exercise3::test_parses_log" -> (3, "test_parses_log")
+fn split_name(name: &str) -> Option<(usize, &str)> {
+    let (exercise, test) = name.strip_prefix(SUITE_PREFIX)?.split_once("::")?;
+    Some((exercise.strip_prefix("exercise")?.parse().ok()?, test))
+}
+
+// The first few rustc errors are enough to point the learner at the problem
+fn compile_errors(stderr: &str) -> String {
+    let errors: Vec<&str> = stderr
+        .lines()
+        .filter(|line| line.starts_with("error"))
+        .take(5)
+        .collect();
+    if errors.is_empty() {
+        "the grading build failed".to_string()
+    } else {
+        errors.join("\n")
+    }
+}
+
+// One graded module, with exercise titles from problems.rs for the report
+pub struct ModuleReport {
+    pub module: &'static Module,
+    pub titles: BTreeMap<usize, String>,
+    pub report: Report,
+}
+
+impl ModuleReport {
+    pub fn checks(&self) -> &[Check] {
+        match &self.report {
+            Report::Graded(checks) => checks,
+            _ => &[],
+        }
+    }
+
+    pub fn title(&self, exercise: usize) -> &str {
+        self.titles.get(&exercise).map_or("", String::as_str)
+    }
+
+    // Checks grouped by exercise, in exercise order
+    pub fn by_exercise(&self) -> BTreeMap<usize, Vec<&Check>> {
+        let mut exercises: BTreeMap<usize, Vec<&Check>> = BTreeMap::new();
+        for check in self.checks() {
+            exercises.entry(check.exercise).or_default().push(check);
+        }
+        exercises
+    }
+}
+
+// Machine-readable report, for CI or a mentor's dashboard:
+// {"modules": [{"number", "package", "title", "status", "error", <tally>,
+//   "exercises": [{"number", "title", <tally>, "checks": [{"name",
+//   "outcome", "message"}]}]}], "totals": {<tally>}}
+// where status is "graded", "no_suite" or "compile_error"
+pub fn to_json(reports: &[ModuleReport]) -> String {
+    let mut total = Tally::default();
+    let mut modules = Vec::new();
+
+    for report in reports {
+        let (status, error) = match &report.report {
+            Report::NoSuite => ("no_suite", None),
+            Report::CompileError(errors) => ("compile_error", Some(errors.as_str())),
+            Report::Graded(_) => ("graded", None),
+        };
+
+        let exercises: Vec<String> = report
+            .by_exercise()
+            .into_iter()
+            .map(|(exercise, checks)| {
+                let tally = Tally::of(checks.iter().copied());
+                let checks: Vec<String> = checks
+                    .iter()
+                    .map(|check| {
+                        format!(
+                            "{{\"name\": {}, \"outcome\": \"{}\", \"message\": {}}}",
+                            json_string(&check.name),
+                            check.outcome.label(),
+                            json_option(check.message.as_deref())
+                        )
+                    })
+                    .collect();
+                format!(
+                    "{{\"number\": {}, \"title\": {}, {}, \"checks\": [{}]}}",
+                    exercise,
+                    json_string(report.title(exercise)),
+                    json_tally(&tally),
+                    checks.join(", ")
+                )
+            })
+            .collect();
+
+        let tally = Tally::of(report.checks());
+        total.merge(&tally);
+        modules.push(format!(
+            "{{\"number\": {}, \"package\": {}, \"title\": {}, \"status\": \"{}\", \"error\": {}, {}, \"exercises\": [{}]}}",
+            report.module.number,
+            json_string(report.module.package),
+            json_string(report.module.title),
+            status,
+            json_option(error),
+            json_tally(&tally),
+            exercises.join(", ")
+        ));
+    }
+
+    format!(
+        "{{\"modules\": [{}], \"totals\": {{{}}}}}",
+        modules.join(", "),
+        json_tally(&total)
+    )
+}
+
+fn json_tally(tally: &Tally) -> String {
+    format!(
+        "\"passed\": {}, \"failed\": {}, \"panicked\": {}, \"total\": {}",
+        tally.passed,
+        tally.failed,
+        tally.panicked,
+        tally.total()
+    )
+}
+
+fn json_option(value: Option<&str>) -> String {
+    value.map_or_else(|| "null".to_string(), json_string)
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "
+running 4 tests
+test problems::grading::exercise1::test_runs ... ok
+test problems::grading::exercise2::test_stub ... FAILED
+test problems::grading::exercise2::test_wrong_answer ... FAILED
+test problems::grading::exercise3::test_crash ... FAILED
+
+failures:
+
+---- problems::grading::exercise2::test_stub stdout ----
+
+thread 'problems::grading::exercise2::test_stub' (11292) panicked at module03_type_system/src/problems.rs:86:9:
+not implemented: Implement from_bool
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+---- problems::grading::exercise2::test_wrong_answer stdout ----
+printed by the exercise
+
+thread 'problems::grading::exercise2::test_wrong_answer' (11293) panicked at module03_type_system/src/problems/grading.rs:12:9:
+assertion `left == right` failed
+  left: 1
+ right: 2
+
+---- problems::grading::exercise3::test_crash stdout ----
+
+thread 'problems::grading::exercise3::test_crash' panicked at /rustc/library/core/src/slice/index.rs:1:1:
+index out of bounds
+
+failures:
+    problems::grading::exercise2::test_stub
+
+test result: FAILED. 1 passed; 3 failed; 0 ignored; 0 measured; 0 filtered out
+";
+
+    #[test]
+    fn test_parse_results_classifies_outcomes() {
+        let results = parse_results(OUTPUT);
+        assert_eq!(results.len(), 4);
+        assert_eq!(
+            results["problems::grading::exercise1::test_runs"],
+            (Outcome::Passed, None)
+        );
+        assert_eq!(
+            results["problems::grading::exercise2::test_stub"],
+            (
+                Outcome::Panicked,
+                Some("not implemented: Implement from_bool".to_string())
+            )
+        );
+        let (outcome, message) = &results["problems::grading::exercise2::test_wrong_answer"];
+        assert_eq!(*outcome, Outcome::Failed);
+        assert_eq!(
+            message.as_deref(),
+            Some("assertion `left == right` failed\n  left: 1\n right: 2")
+        );
+        assert_eq!(
+            results["problems::grading::exercise3::test_crash"].0,
+            Outcome::Panicked
+        );
+    }
+
+    #[test]
+    fn test_parse_list_and_split_name() {
+        let names = parse_list(
+            "problems::grading::exercise4::test_reject: test\nother::test_x: test\n\n1 test, 0 benchmarks\n",
+        );
+        assert_eq!(names, ["problems::grading::exercise4::test_reject"]);
+        assert_eq!(split_name(&names[0]), Some((4, "test_reject")));
+        assert_eq!(split_name("tests::test_x"), None);
+    }
+
+    #[test]
+    fn test_json_string_escapes() {
+        assert_eq!(json_string("plain"), "\"plain\"");
+        assert_eq!(
+            json_string("left: \"a\"\n\tright: \\b\u{1}"),
+            "\"left: \\\"a\\\"\\n\\tright: \\\\b\\u0001\""
+        );
+    }
+}
@@ -0,0 +1,120 @@
+// Hints
+// Every exercise's hints come in levels, each giving away more than the last.
+// Level 1 is the "This exercise demonstrates:" list under the exercise's
+// header in problems.rs, so every module has at least that; the levels after
+// it are written by hand in the module's src/hints.rs (see `Module::hints`).
+
+use crate::modules::Module;
+
+// All hint levels for one exercise, level 1 first
+pub fn levels(module: &Module, source: &str, exercise: usize) -> Vec<String> {
+    let mut levels = Vec::new();
+    let concepts = concepts(source, exercise);
+    if !concepts.is_empty() {
+        let bullets: Vec<String> = concepts.iter().map(|c| format!("- {}", c)).collect();
+        levels.push(format!(
+            "This exercise demonstrates:\n{}",
+            bullets.join("\n")
+        ));
+    }
+    if let Some(authored) = exercise.checked_sub(1).and_then(|i| module.hints.get(i)) {
+        levels.extend(authored.iter().map(|hint| hint.to_string()));
+    }
+    levels
+}
+
+// The bullet points under "// This exercise demonstrates:" for an exercise
+pub fn concepts(source: &str, exercise: usize) -> Vec<String> {
+    let header = format!("// Exercise {}:", exercise);
+    let mut lines = source
+        .lines()
+        .skip_while(|line| !line.starts_with(&header))
+        .skip(1)
+        .take_while(|line| line.starts_with("//"))
+        .skip_while(|line| !line.starts_with("// This exercise demonstrates:"))
+        .skip(1);
+
+    let mut concepts = Vec::new();
+    while let Some(item) = lines.next().and_then(|line| line.strip_prefix("// - ")) {
+        concepts.push(item.trim().to_string());
+    }
+    concepts
+}
+
+// Wraps a hint to the terminal width, keeping its own line breaks
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() && line.len() + 1 + word.len() > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules;
+
+    const SOURCE: &str = "
+// Exercise 1: Borrowing
+// This exercise demonstrates:
+// - Shared references
+// - Mutable references
+
+fn exercise1() {}
+
+// Exercise 2: No Concepts
+fn exercise2() {
+    // This exercise demonstrates:
+    // - nothing, this is inside the body
+}
+";
+
+    #[test]
+    fn test_concepts_come_from_the_header() {
+        assert_eq!(
+            concepts(SOURCE, 1),
+            ["Shared references", "Mutable references"]
+        );
+        assert!(concepts(SOURCE, 2).is_empty());
+        assert!(concepts(SOURCE, 3).is_empty());
+    }
+
+    #[test]
+    fn test_every_exercise_has_hints() {
+        for module in &modules::MODULES {
+            let source = std::fs::read_to_string(module.problems_path()).unwrap();
+            // Modules 01 and 11 have nothing to implement, so no authored hints
+            let authored = !matches!(module.number, 1 | 11);
+            assert_eq!(!module.hints.is_empty(), authored, "{}", module.dir);
+            for exercise in 1..=module.exercises.len() {
+                let levels = levels(module, &source, exercise);
+                assert!(
+                    levels[0].starts_with("This exercise demonstrates:\n- "),
+                    "{} exercise {}",
+                    module.dir,
+                    exercise
+                );
+                assert_eq!(levels.len() > 1, authored, "{} {}", module.dir, exercise);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(
+            wrap("one two three\n- four", 7),
+            ["one two", "three", "- four"]
+        );
+    }
+}
@@ -0,0 +1,1118 @@
+// rustpath: a single entry point for the Rust tutorial
+// Instead of `cd`-ing into each of the twelve module crates, learners can list
+// the modules, run a module's exercises (or a single one), run the main.rs
+// demos, see which exercises still contain `unimplemented!`/`todo!` stubs, and
+// grade the exercises against each module's hidden test suite. Learners who
+// are stuck can unlock an exercise's hints one level at a time; grading runs
+// and hints are recorded in a local progress file that `progress` summarises.
+// Each module's manifest.toml describes its exercises; `next` uses it to
+// recommend what to work on, and `run`, `next` and `watch` keep an exercise
+// locked until the exercises it builds on are completed (`run --force` looks
+// ahead anyway). `watch` reruns an exercise's checks whenever a file is saved.
+// Built with the `solutions` feature, it can also run the reference answers.
+//
+// Usage:
+//   rustpath list
+//   rustpath run <module> [--exercise <n>] [--solution] [--demos] [--force]
+//   rustpath run <module> --exercise <n> --hint <level> [--force]
+//   rustpath status [<module>]
+//   rustpath grade [<module>] [--json]
+//   rustpath progress [<module>]
+//   rustpath next
+//   rustpath watch <module> [--exercise <n>]
+
+mod grade;
+mod hints;
+mod manifest;
+mod modules;
+mod progress;
+mod stubs;
+mod watch;
+
+use grade::{ModuleReport, Outcome, Report, Tally};
+use manifest::{ExerciseId, Manifest};
+use modules::{Module, Solutions};
+use progress::{Progress, Timestamp};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::IsTerminal;
+use std::panic;
+use std::process::{Command, ExitCode};
+
+const USAGE: &str = "\
+Usage:
+  rustpath list                                  List all modules
+  rustpath run <module> [--exercise <n>]         Run a module's exercises (or just one)
+  rustpath run <module> [...] --solution         Run the reference solutions instead
+  rustpath run <module> -e <n> --hint <level>    Show an exercise's hints up to <level>
+  rustpath run <module> --demos                  Run the module's main.rs demos via cargo
+  rustpath run <module> [...] --force            Run or show hints for a locked exercise anyway
+  rustpath status [<module>]                     Report exercises that still contain stubs
+  rustpath grade [<module>] [--json]             Grade exercises against the hidden test suites
+  rustpath progress [<module>]                   Show completed exercises, attempts and hints used
+  rustpath next                                  Recommend the next exercise to work on
+  rustpath watch <module> [--exercise <n>]       Rerun an exercise's checks every time a file is saved
+
+<module> may be a number (9, 09), `module09` or the directory name.";
+
+#[derive(Debug, PartialEq)]
+enum Cmd {
+    List,
+    Run {
+        module: String,
+        exercise: Option<usize>,
+        hint: Option<usize>,
+        solution: bool,
+        demos: bool,
+        force: bool,
+    },
+    Status {
+        module: Option<String>,
+    },
+    Grade {
+        module: Option<String>,
+        json: bool,
+    },
+    Progress {
+        module: Option<String>,
+    },
+    Next,
+    Watch {
+        module: String,
+        exercise: Option<usize>,
+    },
+    Help,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let cmd = match parse_args(&args) {
+        Ok(cmd) => cmd,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match cmd {
+        Cmd::List => {
+            list();
+            Ok(())
+        }
+        Cmd::Run {
+            module,
+            exercise,
+            hint,
+            solution,
+            demos,
+            force,
+        } => lookup(&module).and_then(|m| match (hint, exercise) {
+            _ if demos => run_demos(m),
+            (Some(level), Some(exercise)) => unlocked_or_forced(m, Some(exercise), force)
+                .and_then(|()| show_hints(m, exercise, level)),
+            _ => unlocked_or_forced(m, exercise, force).and_then(|()| run(m, exercise, solution)),
+        }),
+        Cmd::Status { module } => match module {
+            Some(name) => lookup(&name).and_then(|m| status(std::slice::from_ref(m))),
+            None => status(&modules::MODULES),
+        },
+        Cmd::Grade { module, json } => match module {
+            Some(name) => lookup(&name).and_then(|m| grade(std::slice::from_ref(m), json)),
+            None => grade(&modules::MODULES, json),
+        },
+        Cmd::Progress { module } => match module {
+            Some(name) => lookup(&name).and_then(|m| progress(std::slice::from_ref(m))),
+            None => progress(&modules::MODULES),
+        },
+        Cmd::Next => next(),
+        Cmd::Watch { module, exercise } => lookup(&module).and_then(|m| watch(m, exercise)),
+        Cmd::Help => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Cmd, String> {
+    let mut args = args.iter().map(String::as_str);
+    let cmd = match args.next() {
+        None | Some("help") | Some("--help") | Some("-h") => return Ok(Cmd::Help),
+        Some(cmd) => cmd,
+    };
+
+    match cmd {
+        "list" => Ok(Cmd::List),
+        "run" => {
+            let module = args.next().ok_or("`run` needs a module")?.to_string();
+            let mut exercise = None;
+            let mut hint = None;
+            let mut solution = false;
+            let mut demos = false;
+            let mut force = false;
+            while let Some(flag) = args.next() {
+                match flag {
+                    "--exercise" | "-e" => {
+                        let n = args.next().ok_or("`--exercise` needs a number")?;
+                        let n = n
+                            .parse()
+                            .map_err(|_| format!("invalid exercise number `{}`", n))?;
+                        exercise = Some(n);
+                    }
+                    "--hint" => {
+                        let level = args.next().ok_or("`--hint` needs a level")?;
+                        let level = level
+                            .parse()
+                            .map_err(|_| format!("invalid hint level `{}`", level))?;
+                        hint = Some(level);
+                    }
+                    "--solution" => solution = true,
+                    "--demos" => demos = true,
+                    "--force" => force = true,
+                    other => return Err(format!("unknown option `{}`", other)),
+                }
+            }
+            if demos && exercise.is_some() {
+                return Err("`--demos` and `--exercise` cannot be combined".to_string());
+            }
+            if demos && solution {
+                return Err("`--demos` and `--solution` cannot be combined".to_string());
+            }
+            if hint.is_some() && exercise.is_none() {
+                return Err("`--hint` needs an `--exercise`".to_string());
+            }
+            if hint.is_some() && (demos || solution) {
+                return Err(
+                    "`--hint` cannot be combined with `--demos` or `--solution`".to_string()
+                );
+            }
+            Ok(Cmd::Run {
+                module,
+                exercise,
+                hint,
+                solution,
+                demos,
+                force,
+            })
+        }
+        "status" => {
+            let module = args.next().map(str::to_string);
+            no_more(args)?;
+            Ok(Cmd::Status { module })
+        }
+        "grade" => {
+            let mut module = None;
+            let mut json = false;
+            for arg in args {
+                match arg {
+                    "--json" => json = true,
+                    flag if flag.starts_with('-') => {
+                        return Err(format!("unknown option `{}`", flag))
+                    }
+                    name if module.is_none() => module = Some(name.to_string()),
+                    extra => return Err(format!("unexpected argument `{}`", extra)),
+                }
+            }
+            Ok(Cmd::Grade { module, json })
+        }
+        "progress" => {
+            let module = args.next().map(str::to_string);
+            no_more(args)?;
+            Ok(Cmd::Progress { module })
+        }
+        "next" => {
+            no_more(args)?;
+            Ok(Cmd::Next)
+        }
+        "watch" => {
+            let module = args.next().ok_or("`watch` needs a module")?.to_string();
+            let mut exercise = None;
+            while let Some(flag) = args.next() {
+                match flag {
+                    "--exercise" | "-e" => {
+                        let n = args.next().ok_or("`--exercise` needs a number")?;
+                        let n = n
+                            .parse()
+                            .map_err(|_| format!("invalid exercise number `{}`", n))?;
+                        exercise = Some(n);
+                    }
+                    other => return Err(format!("unknown option `{}`", other)),
+                }
+            }
+            Ok(Cmd::Watch { module, exercise })
+        }
+        other => Err(format!("unknown command `{}`", other)),
+    }
+}
+
+// For commands that have parsed everything they take
+fn no_more<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<(), String> {
+    match args.next() {
+        Some(extra) => Err(format!("unexpected argument `{}`", extra)),
+        None => Ok(()),
+    }
+}
+
+fn lookup(name: &str) -> Result<&'static Module, String> {
+    modules::find(name).ok_or_else(|| format!("no module named `{}` (try `rustpath list`)", name))
+}
+
+fn list() {
+    println!(
+        "{:<4} {:<27} {:<14} {:>9}",
+        "#", "Directory", "Package", "Exercises"
+    );
+    for m in &modules::MODULES {
+        println!(
+            "{:<4} {:<27} {:<14} {:>9}   {}",
+            format!("{:02}", m.number),
+            m.dir,
+            m.package,
+            m.exercises.len(),
+            m.title
+        );
+    }
+}
+
+// Exercises run in-process; a panic (for example from a stub that is called)
+// is reported as a failure instead of tearing down the runner.
+fn run(module: &Module, exercise: Option<usize>, solution: bool) -> Result<(), String> {
+    let (run_exercises, exercises) = if solution {
+        let solutions = solutions(module)?;
+        (solutions.run_exercises, solutions.exercises)
+    } else {
+        (module.run_exercises, module.exercises)
+    };
+
+    let entry = match exercise {
+        None => run_exercises,
+        Some(n) => *n
+            .checked_sub(1)
+            .and_then(|i| exercises.get(i))
+            .ok_or_else(|| {
+                format!(
+                    "module {:02} has exercises 1..={}, not {}",
+                    module.number,
+                    exercises.len(),
+                    n
+                )
+            })?,
+    };
+
+    panic::catch_unwind(entry).map_err(|_| match exercise {
+        Some(n) => format!("module {:02} exercise {} panicked", module.number, n),
+        None => format!("module {:02} exercises panicked", module.number),
+    })
+}
+
+fn solutions(module: &Module) -> Result<&Solutions, String> {
+    if !cfg!(feature = "solutions") {
+        return Err(format!(
+            "the reference solutions are not built in; rerun with \
+             `cargo run -p rustpath --features solutions -- run {:02} --solution`",
+            module.number
+        ));
+    }
+    module.solutions.as_ref().ok_or_else(|| {
+        format!(
+            "module {:02} has no reference solutions (its exercises have nothing to implement)",
+            module.number
+        )
+    })
+}
+
+// An exercise unlocks once everything its manifest lists as prerequisites is
+// completed. Running a whole module only needs the module's own prerequisites,
+// as most exercises just print what is left to do until they are implemented.
+fn unlocked(module: &Module, exercise: Option<usize>) -> Result<(), String> {
+    let manifest = Manifest::load(module)?;
+    let required = match exercise {
+        Some(n) => manifest.prerequisites(n),
+        None => manifest.requires.clone(),
+    };
+    let missing = incomplete(required, &Progress::load()?);
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let mut message = match exercise {
+        Some(n) => format!("module {:02} exercise {} is locked", module.number, n),
+        None => format!("module {:02} is locked", module.number),
+    };
+    message.push_str(" until these exercises are completed:");
+    for id in &missing {
+        message.push_str(&format!("\n  {} {}", id, exercise_title(*id)?));
+    }
+    let modules: BTreeSet<u8> = missing.iter().map(|id| id.module).collect();
+    let commands: Vec<String> = modules
+        .iter()
+        .map(|m| format!("`rustpath grade {:02}`", m))
+        .collect();
+    message.push_str(&format!(
+        "\nOnce they pass {}, this unlocks.",
+        commands.join(" and ")
+    ));
+    Err(message)
+}
+
+// `run --force` looks ahead at a locked exercise, with a warning instead of
+// the error
+fn unlocked_or_forced(module: &Module, exercise: Option<usize>, force: bool) -> Result<(), String> {
+    match unlocked(module, exercise) {
+        Err(message) if force => {
+            eprintln!("warning: {}", message);
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+fn incomplete(required: Vec<ExerciseId>, progress: &Progress) -> Vec<ExerciseId> {
+    required
+        .into_iter()
+        .filter(|id| progress.get(id.module, id.exercise).completed.is_none())
+        .collect()
+}
+
+// "Shared Ownership" for 02.4, as the module's manifest names it
+fn exercise_title(id: ExerciseId) -> Result<String, String> {
+    let manifest = Manifest::load(lookup(&id.module.to_string())?)?;
+    Ok(manifest
+        .exercise(id.exercise)
+        .map(|e| e.title.clone())
+        .unwrap_or_default())
+}
+
+// Hints unlock in order: level N is only shown once level N-1 has been seen.
+// Every level up to the requested one is printed again, so the answer builds
+// up in one place, and the highest level reached goes into the progress file.
+fn show_hints(module: &Module, exercise: usize, level: usize) -> Result<(), String> {
+    let path = module.problems_path();
+    let source = std::fs::read_to_string(&path)
+        .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    let Some(title) = stubs::scan(&source)
+        .into_iter()
+        .find(|e| e.number == exercise)
+        .map(|e| e.title)
+    else {
+        return Err(format!(
+            "module {:02} has exercises 1..={}, not {}",
+            module.number,
+            module.exercises.len(),
+            exercise
+        ));
+    };
+
+    let levels = hints::levels(module, &source, exercise);
+    if level == 0 || level > levels.len() {
+        return Err(format!(
+            "module {:02} exercise {} has hint levels 1..={}, not {}",
+            module.number,
+            exercise,
+            levels.len(),
+            level
+        ));
+    }
+
+    let mut progress = Progress::load()?;
+    let unlocked = progress.get(module.number, exercise).hints;
+    if level > unlocked + 1 {
+        return Err(format!(
+            "hint {} is still locked; read hint {} first with \
+             `rustpath run {:02} --exercise {} --hint {}`",
+            level,
+            unlocked + 1,
+            module.number,
+            exercise,
+            unlocked + 1
+        ));
+    }
+
+    println!(
+        "Module {:02}, exercise {}: {}",
+        module.number, exercise, title
+    );
+    for (i, hint) in levels.iter().take(level).enumerate() {
+        println!("\nHint {} of {}", i + 1, levels.len());
+        for line in hints::wrap(hint, 76) {
+            println!("  {}", line);
+        }
+    }
+    if level < levels.len() {
+        println!("\nStill stuck? Ask for `--hint {}`.", level + 1);
+    }
+
+    if level > unlocked {
+        progress.record_mut(module.number, exercise).hints = level;
+        progress.save()?;
+    }
+    Ok(())
+}
+
+// The demos live in each crate's `main()`, so they run as a separate process
+fn run_demos(module: &Module) -> Result<(), String> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .args(["run", "--quiet", "--package", module.package])
+        .current_dir(modules::workspace_root())
+        .status()
+        .map_err(|e| format!("failed to run cargo: {}", e))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "`cargo run -p {}` failed ({})",
+            module.package, status
+        ))
+    }
+}
+
+fn status(selected: &[Module]) -> Result<(), String> {
+    let progress = Progress::load()?;
+    let mut total = 0;
+    let mut remaining = 0;
+
+    for module in selected {
+        let path = module.problems_path();
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+
+        println!("Module {:02}: {}", module.number, module.title);
+        for exercise in stubs::scan(&source) {
+            total += 1;
+            let hints = match progress.get(module.number, exercise.number).hints {
+                0 => None,
+                used => Some(format!(
+                    "{} of {} hints used",
+                    used,
+                    hints::levels(module, &source, exercise.number).len()
+                )),
+            };
+            if exercise.stubs.is_empty() {
+                let hints = hints.map(|h| format!(" ({})", h)).unwrap_or_default();
+                println!("  [done] {}. {}{}", exercise.number, exercise.title, hints);
+                continue;
+            }
+            remaining += 1;
+            let hints = hints.map(|h| format!(", {}", h)).unwrap_or_default();
+            println!(
+                "  [todo] {}. {} ({} remaining{})",
+                exercise.number,
+                exercise.title,
+                exercise.stubs.len(),
+                hints
+            );
+            for (line, message) in &exercise.stubs {
+                println!(
+                    "           {}/src/problems.rs:{}  {}",
+                    module.dir, line, message
+                );
+            }
+        }
+        println!();
+    }
+
+    println!("{} of {} exercises still contain stubs", remaining, total);
+    Ok(())
+}
+
+// Builds and runs every selected module's grading suite, records the results
+// in the progress file, then prints either a scoreboard or, with `--json`,
+// the machine-readable report
+fn grade(selected: &'static [Module], json: bool) -> Result<(), String> {
+    let mut reports = Vec::new();
+    let mut stubbed = BTreeSet::new();
+    for module in selected {
+        if grade::has_suite(module) {
+            eprintln!("Grading module {:02}...", module.number);
+        }
+        let path = module.problems_path();
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        let mut titles = BTreeMap::new();
+        for exercise in stubs::scan(&source) {
+            if !exercise.stubs.is_empty() {
+                stubbed.insert((module.number, exercise.number));
+            }
+            titles.insert(exercise.number, exercise.title);
+        }
+        reports.push(ModuleReport {
+            module,
+            titles,
+            report: grade::grade(module)?,
+        });
+    }
+    record_attempts(&reports, &stubbed)?;
+
+    if json {
+        println!("{}", grade::to_json(&reports));
+    } else {
+        scoreboard(&reports);
+    }
+    Ok(())
+}
+
+// A graded exercise counts as an attempt once the learner has started on it:
+// its stubs are gone, or at least one check got past them. Grading runs that
+// do not compile cannot be pinned on an exercise and are not recorded.
+fn record_attempts(
+    reports: &[ModuleReport],
+    stubbed: &BTreeSet<(u8, usize)>,
+) -> Result<(), String> {
+    let mut progress = Progress::load()?;
+    let now = Timestamp::now();
+    let mut changed = false;
+
+    for report in reports {
+        let number = report.module.number;
+        for (exercise, checks) in report.by_exercise() {
+            let started = !stubbed.contains(&(number, exercise))
+                || checks.iter().any(|c| c.outcome != Outcome::Panicked);
+            if started {
+                let passed = checks.iter().all(|c| c.outcome == Outcome::Passed);
+                progress.record_mut(number, exercise).attempt(passed, now);
+                changed = true;
+            }
+        }
+    }
+
+    if changed {
+        progress.save()?;
+    }
+    Ok(())
+}
+
+fn scoreboard(reports: &[ModuleReport]) {
+    let mut total = Tally::default();
+
+    for report in reports {
+        let module = report.module;
+        println!("Module {:02}: {}", module.number, module.title);
+        match &report.report {
+            Report::NoSuite => println!("  no grading suite (nothing to implement)"),
+            Report::CompileError(errors) => {
+                println!("  does not compile with the grading suite:");
+                for line in errors.lines() {
+                    println!("    {}", line);
+                }
+            }
+            Report::Graded(checks) => {
+                for (exercise, checks) in report.by_exercise() {
+                    let tally = Tally::of(checks.iter().copied());
+                    println!(
+                        "  {}. {:<40} {}",
+                        exercise,
+                        report.title(exercise),
+                        score(&tally)
+                    );
+                    for check in checks.iter().filter(|c| c.outcome != Outcome::Passed) {
+                        let message = check.message.as_deref().unwrap_or("");
+                        println!(
+                            "       [{}] {}: {}",
+                            check.outcome.label(),
+                            check.name,
+                            message.lines().next().unwrap_or("")
+                        );
+                    }
+                }
+                let tally = Tally::of(checks);
+                println!("  Score: {}", score(&tally));
+                total.merge(&tally);
+            }
+        }
+        println!();
+    }
+
+    println!("Total: {}", score(&total));
+}
+
+// One row per module, then every exercise that has been attempted but not
+// completed yet, which is where a learner is stuck
+fn progress(selected: &[Module]) -> Result<(), String> {
+    let progress = Progress::load()?;
+    let mut stuck = Vec::new();
+    let mut completed_total = 0;
+    let mut graded_total = 0;
+
+    println!(
+        "{:<44} {:>9} {:>8} {:>5}  Last activity",
+        "Module", "Completed", "Attempts", "Hints"
+    );
+    for module in selected {
+        let path = module.problems_path();
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        let exercises = stubs::scan(&source);
+        let graded = exercises.len();
+
+        let mut completed = 0;
+        let mut attempts = 0;
+        let mut hints = 0;
+        let mut last_activity = None;
+        for exercise in exercises {
+            let record = progress.get(module.number, exercise.number);
+            completed += usize::from(record.completed.is_some());
+            attempts += record.attempts;
+            hints += record.hints;
+            last_activity = last_activity.max(record.last_activity());
+            if record.attempts > 0 && record.completed.is_none() {
+                let levels = hints::levels(module, &source, exercise.number).len();
+                stuck.push((module, exercise, record, levels));
+            }
+        }
+
+        // Modules without a grading suite have nothing to complete
+        let completed = if grade::has_suite(module) {
+            completed_total += completed;
+            graded_total += graded;
+            format!("{}/{}", completed, graded)
+        } else {
+            "-".to_string()
+        };
+        println!(
+            "{:<44} {:>9} {:>8} {:>5}  {}",
+            format!("{:02} {}", module.number, module.title),
+            completed,
+            attempts,
+            hints,
+            last_activity.map_or_else(|| "-".to_string(), Timestamp::date)
+        );
+    }
+    println!(
+        "\n{} of {} graded exercises completed",
+        completed_total, graded_total
+    );
+
+    if !stuck.is_empty() {
+        println!("\nIn progress:");
+        for (module, exercise, record, levels) in stuck {
+            let since = record
+                .first_attempt
+                .map_or_else(|| "-".to_string(), Timestamp::date);
+            println!(
+                "  {:02}.{} {:<40} {} since {}, {} of {} hints",
+                module.number,
+                exercise.number,
+                exercise.title,
+                plural(record.attempts, "attempt"),
+                since,
+                record.hints,
+                levels
+            );
+        }
+    }
+    Ok(())
+}
+
+// Recommends one exercise: the most recently attempted one that is not
+// completed yet, or else the first unlocked one in tutorial order. Modules
+// without a grading suite can never be completed, so they are left out.
+fn next() -> Result<(), String> {
+    let progress = Progress::load()?;
+    let mut manifests = Vec::new();
+    for module in modules::MODULES.iter().filter(|m| grade::has_suite(m)) {
+        manifests.push((module, Manifest::load(module)?));
+    }
+
+    let mut open = Vec::new();
+    let mut remaining = 0;
+    let mut minutes = 0;
+    for (module, manifest) in &manifests {
+        for exercise in &manifest.exercises {
+            let record = progress.get(module.number, exercise.number);
+            if record.completed.is_some() {
+                continue;
+            }
+            remaining += 1;
+            minutes += exercise.minutes;
+            if incomplete(manifest.prerequisites(exercise.number), &progress).is_empty() {
+                open.push((*module, manifest, exercise, record));
+            }
+        }
+    }
+
+    let started = open
+        .iter()
+        .filter(|(_, _, _, record)| record.attempts > 0)
+        .max_by_key(|(_, _, _, record)| record.last_activity());
+    let Some((module, manifest, exercise, record)) = started.or(open.first()) else {
+        println!("Every graded exercise is completed. Well done!");
+        return Ok(());
+    };
+
+    println!(
+        "Next: module {:02}, exercise {}: {}",
+        module.number, exercise.number, exercise.title
+    );
+    println!(
+        "  Difficulty:  {} of 5, about {} minutes",
+        exercise.difficulty, exercise.minutes
+    );
+    println!("  Concepts:    {}", exercise.concepts.join(", "));
+    let required = manifest.prerequisites(exercise.number);
+    if !required.is_empty() {
+        let titles = required
+            .iter()
+            .map(|id| exercise_title(*id).map(|title| format!("{} {}", id, title)))
+            .collect::<Result<Vec<_>, _>>()?;
+        println!("  Builds on:   {}", titles.join(", "));
+    }
+    if record.attempts > 0 || record.hints > 0 {
+        let path = module.problems_path();
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        let since = record
+            .first_attempt
+            .map(|t| format!(" since {}", t.date()))
+            .unwrap_or_default();
+        println!(
+            "  So far:      {}{}, {} of {} hints used",
+            plural(record.attempts, "attempt"),
+            since,
+            record.hints,
+            hints::levels(module, &source, exercise.number).len()
+        );
+    }
+
+    println!("\nModule {:02} objectives:", module.number);
+    for objective in &manifest.objectives {
+        println!("  - {}", objective);
+    }
+    println!(
+        "\nWork on it with `rustpath run {:02} --exercise {}` and check it with `rustpath grade {:02}`.",
+        module.number, exercise.number, module.number
+    );
+    let estimate = if minutes < 90 {
+        format!("{} minutes", minutes)
+    } else {
+        format!("{} hours", (minutes + 30) / 60)
+    };
+    println!(
+        "{} left in the graded modules, about {} of work.",
+        plural(remaining, "exercise"),
+        estimate
+    );
+    Ok(())
+}
+
+// Reruns one exercise's checks whenever a .rs file under the module's src/
+// changes. Only the module's own package is rebuilt, and every run counts as
+// a grading attempt. Without `--exercise` it starts at the module's first
+// exercise that is not completed and moves on once all its checks pass.
+fn watch(module: &'static Module, exercise: Option<usize>) -> Result<(), String> {
+    if !grade::has_suite(module) {
+        return Err(format!(
+            "module {:02} has nothing to implement, so there is nothing to watch",
+            module.number
+        ));
+    }
+    let manifest = Manifest::load(module)?;
+    let first_incomplete = |progress: &Progress| {
+        manifest
+            .exercises
+            .iter()
+            .find(|e| progress.get(module.number, e.number).completed.is_none())
+            .map(|e| e.number)
+    };
+    let mut current = match exercise {
+        Some(n) if manifest.exercise(n).is_none() => {
+            return Err(format!(
+                "module {:02} has exercises 1..={}, not {}",
+                module.number,
+                manifest.exercises.len(),
+                n
+            ))
+        }
+        Some(n) => n,
+        None => match first_incomplete(&Progress::load()?) {
+            Some(n) => n,
+            None => {
+                println!(
+                    "Every exercise in module {:02} is completed.",
+                    module.number
+                );
+                return Ok(());
+            }
+        },
+    };
+    unlocked(module, Some(current))?;
+
+    let src = module.path().join("src");
+    let mut snapshot = watch::Snapshot::take(&src);
+    loop {
+        let passed = check_exercise(module, &manifest, current)?;
+        if passed && exercise.is_none() {
+            let Some(next) = first_incomplete(&Progress::load()?) else {
+                println!(
+                    "\nEvery exercise in module {:02} is completed. Well done!",
+                    module.number
+                );
+                return Ok(());
+            };
+            unlocked(module, Some(next))?;
+            current = next;
+            println!(
+                "\nNext up is exercise {}: {}. Its checks run on your next save.",
+                current,
+                manifest.exercise(current).map_or("", |e| e.title.as_str())
+            );
+        }
+        println!(
+            "\nWatching {}/src for changes. Press Ctrl-C to stop.",
+            module.dir
+        );
+        snapshot.wait_for_change(&src);
+    }
+}
+
+// One watch run: rebuild, rerun the exercise's checks, record the attempt and
+// show what still fails. Returns whether every check passed.
+fn check_exercise(
+    module: &'static Module,
+    manifest: &Manifest,
+    exercise: usize,
+) -> Result<bool, String> {
+    if std::io::stdout().is_terminal() {
+        // Clear the screen, so only the latest run is visible
+        print!("\x1b[2J\x1b[H");
+    }
+    let title = manifest.exercise(exercise).map_or("", |e| e.title.as_str());
+    println!(
+        "Module {:02}, exercise {}: {}",
+        module.number, exercise, title
+    );
+
+    let path = module.problems_path();
+    let source = std::fs::read_to_string(&path)
+        .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    let stubbed: BTreeSet<(u8, usize)> = stubs::scan(&source)
+        .into_iter()
+        .filter(|e| e.number == exercise && !e.stubs.is_empty())
+        .map(|e| (module.number, e.number))
+        .collect();
+    let report = ModuleReport {
+        module,
+        titles: BTreeMap::from([(exercise, title.to_string())]),
+        report: grade::grade_exercise(module, exercise)?,
+    };
+    record_attempts(std::slice::from_ref(&report), &stubbed)?;
+
+    println!();
+    let checks = match &report.report {
+        Report::Graded(checks) => checks,
+        Report::NoSuite => return Ok(false),
+        Report::CompileError(errors) => {
+            println!("Does not compile yet:");
+            for line in errors.lines() {
+                println!("  {}", line);
+            }
+            return Ok(false);
+        }
+    };
+    for check in checks {
+        println!("  [{}] {}", check.outcome.label(), check.name);
+        if let (Outcome::Failed | Outcome::Panicked, Some(message)) =
+            (check.outcome, &check.message)
+        {
+            for line in watch::failure_lines(message) {
+                println!("      {}", line);
+            }
+        }
+    }
+    let tally = Tally::of(checks);
+    println!("\n{}", score(&tally));
+    let passed = tally.total() > 0 && tally.passed == tally.total();
+    let level = Progress::load()?.get(module.number, exercise).hints + 1;
+    if !passed && level <= hints::levels(module, &source, exercise).len() {
+        println!(
+            "Stuck? Try `rustpath run {:02} --exercise {} --hint {}`.",
+            module.number, exercise, level
+        );
+    }
+    Ok(passed)
+}
+
+// "1 attempt", "3 attempts"
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {}", noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+// "7/13 passed (2 failed, 4 panicked)"
+fn score(tally: &Tally) -> String {
+    let mut score = format!("{}/{} passed", tally.passed, tally.total());
+    if tally.failed + tally.panicked > 0 {
+        score.push_str(&format!(
+            " ({} failed, {} panicked)",
+            tally.failed, tally.panicked
+        ));
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(
+            parse_args(&args("run 09 --exercise 2")),
+            Ok(Cmd::Run {
+                module: "09".to_string(),
+                exercise: Some(2),
+                hint: None,
+                solution: false,
+                demos: false,
+                force: false,
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run 3 --demos")),
+            Ok(Cmd::Run {
+                module: "3".to_string(),
+                exercise: None,
+                hint: None,
+                solution: false,
+                demos: true,
+                force: false,
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run 4 --solution -e 5")),
+            Ok(Cmd::Run {
+                module: "4".to_string(),
+                exercise: Some(5),
+                hint: None,
+                solution: true,
+                demos: false,
+                force: false,
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run 2 -e 3 --hint 2")),
+            Ok(Cmd::Run {
+                module: "2".to_string(),
+                exercise: Some(3),
+                hint: Some(2),
+                solution: false,
+                demos: false,
+                force: false,
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run 8 -e 1 --force")),
+            Ok(Cmd::Run {
+                module: "8".to_string(),
+                exercise: Some(1),
+                hint: None,
+                solution: false,
+                demos: false,
+                force: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_grade() {
+        assert_eq!(
+            parse_args(&args("grade")),
+            Ok(Cmd::Grade {
+                module: None,
+                json: false,
+            })
+        );
+        assert_eq!(
+            parse_args(&args("grade --json 3")),
+            Ok(Cmd::Grade {
+                module: Some("3".to_string()),
+                json: true,
+            })
+        );
+        assert!(parse_args(&args("grade 3 4")).is_err());
+        assert_eq!(
+            parse_args(&args("progress 8")),
+            Ok(Cmd::Progress {
+                module: Some("8".to_string()),
+            })
+        );
+        assert!(parse_args(&args("grade --html")).is_err());
+        assert_eq!(parse_args(&args("next")), Ok(Cmd::Next));
+        assert_eq!(
+            parse_args(&args("watch 2 -e 3")),
+            Ok(Cmd::Watch {
+                module: "2".to_string(),
+                exercise: Some(3),
+            })
+        );
+        assert!(parse_args(&args("watch")).is_err());
+        assert!(parse_args(&args("watch 2 --json")).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_extra_arguments() {
+        assert_eq!(
+            parse_args(&args("status 3")),
+            Ok(Cmd::Status {
+                module: Some("3".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_args(&args("status 3 4")),
+            Err("unexpected argument `4`".to_string())
+        );
+        assert_eq!(
+            parse_args(&args("progress 3 4")),
+            Err("unexpected argument `4`".to_string())
+        );
+        assert_eq!(
+            parse_args(&args("next 3")),
+            Err("unexpected argument `3`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run 1 --exercise x")).is_err());
+        assert!(parse_args(&args("run 1 --demos --exercise 1")).is_err());
+        assert!(parse_args(&args("run 1 --demos --solution")).is_err());
+        assert!(parse_args(&args("run 1 --hint 1")).is_err());
+        assert!(parse_args(&args("run 1 -e 1 --hint")).is_err());
+        assert!(parse_args(&args("run 1 -e 1 --hint 1 --solution")).is_err());
+        assert!(parse_args(&args("frobnicate")).is_err());
+        assert_eq!(parse_args(&[]), Ok(Cmd::Help));
+    }
+
+    #[test]
+    fn test_run_rejects_out_of_range_exercise() {
+        let module = modules::find("1").unwrap();
+        assert!(run(module, Some(0), false).is_err());
+        assert!(run(module, Some(99), false).is_err());
+    }
+
+    #[test]
+    fn test_run_solution_needs_solutions() {
+        // Module 11 has nothing to solve, with or without the feature
+        assert!(run(modules::find("11").unwrap(), None, true).is_err());
+        let module = modules::find("2").unwrap();
+        assert_eq!(
+            run(module, Some(1), true).is_ok(),
+            cfg!(feature = "solutions")
+        );
+    }
+}
@@ -0,0 +1,515 @@
+// Exercise manifests
+// Each module describes its exercises in a manifest.toml next to its
+// Cargo.toml: the module's learning objectives, and for every exercise its
+// difficulty (1-5), an estimated time, the concepts it practises and the
+// exercises it builds on. Prerequisites are written "MM.E" ("02.4" is module
+// 02's exercise 4) and may sit on the module, where they apply to every
+// exercise in it, or on a single exercise:
+//
+//   objectives = ["Manage heap data with Box, Rc and RefCell"]
+//   requires = ["02.1", "02.4"]
+//
+//   [[exercise]]
+//   number = 1
+//   title = "Custom Smart Pointer"
+//   difficulty = 3
+//   minutes = 40
+//   concepts = ["Deref", "Drop"]
+//   requires = ["05.1"]
+//
+// The runner reads only this TOML subset (strings, integers and arrays of
+// strings), parsed by hand like the progress file.
+
+use crate::modules::Module;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub struct Manifest {
+    pub objectives: Vec<String>,
+    // Prerequisites shared by every exercise in the module
+    pub requires: Vec<ExerciseId>,
+    pub exercises: Vec<Exercise>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Exercise {
+    pub number: usize,
+    pub title: String,
+    // 1 (a warm-up) to 5 (the hardest in the tutorial)
+    pub difficulty: u8,
+    // Rough time for a first attempt, in minutes
+    pub minutes: u32,
+    pub concepts: Vec<String>,
+    pub requires: Vec<ExerciseId>,
+}
+
+// An exercise anywhere in the tutorial, written "02.4"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExerciseId {
+    pub module: u8,
+    pub exercise: usize,
+}
+
+impl ExerciseId {
+    fn parse(text: &str) -> Option<ExerciseId> {
+        let (module, exercise) = text.split_once('.')?;
+        Some(ExerciseId {
+            module: module.parse().ok()?,
+            exercise: exercise.parse().ok()?,
+        })
+    }
+}
+
+impl fmt::Display for ExerciseId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}.{}", self.module, self.exercise)
+    }
+}
+
+impl Manifest {
+    pub fn load(module: &Module) -> Result<Manifest, String> {
+        let path = module.manifest_path();
+        let text = std::fs::read_to_string(&path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        Manifest::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn exercise(&self, number: usize) -> Option<&Exercise> {
+        self.exercises.iter().find(|e| e.number == number)
+    }
+
+    // Everything that must be completed before `exercise` unlocks
+    pub fn prerequisites(&self, exercise: usize) -> Vec<ExerciseId> {
+        let mut required = self.requires.clone();
+        if let Some(exercise) = self.exercise(exercise) {
+            required.extend(&exercise.requires);
+        }
+        required.sort();
+        required.dedup();
+        required
+    }
+
+    pub fn parse(text: &str) -> Result<Manifest, String> {
+        let mut parser = Parser { text, pos: 0 };
+        let mut top = Table::new(1);
+        let mut exercises: Vec<Table> = Vec::new();
+
+        loop {
+            parser.skip(true);
+            if parser.rest().is_empty() {
+                break;
+            }
+            let line = parser.line();
+            if parser.eat("[[") {
+                let name = parser.key()?;
+                if !parser.eat("]]") {
+                    return Err(parser.error("expected `]]`"));
+                }
+                if name != "exercise" {
+                    return Err(format!("line {}: unknown table `[[{}]]`", line, name));
+                }
+                parser.end_of_line()?;
+                exercises.push(Table::new(line));
+                continue;
+            }
+
+            let key = parser.key()?;
+            parser.skip(false);
+            if !parser.eat("=") {
+                return Err(parser.error("expected `key = value`"));
+            }
+            parser.skip(false);
+            let value = parser.value()?;
+            parser.end_of_line()?;
+
+            let table = exercises.last_mut().unwrap_or(&mut top);
+            if table.entries.iter().any(|(k, _, _)| *k == key) {
+                return Err(format!("line {}: `{}` is set twice", line, key));
+            }
+            table.entries.push((key, value, line));
+        }
+
+        let manifest = Manifest {
+            objectives: top.list("objectives")?,
+            requires: top.ids("requires")?,
+            exercises: exercises
+                .iter_mut()
+                .map(Table::exercise)
+                .collect::<Result<_, _>>()?,
+        };
+        top.finish()?;
+        for table in &exercises {
+            table.finish()?;
+        }
+        for (i, exercise) in manifest.exercises.iter().enumerate() {
+            if exercise.number != i + 1 {
+                return Err(format!(
+                    "line {}: exercise {} is out of order; expected exercise {}",
+                    exercises[i].line,
+                    exercise.number,
+                    i + 1
+                ));
+            }
+        }
+        Ok(manifest)
+    }
+}
+
+enum Value {
+    Str(String),
+    Int(u64),
+    List(Vec<String>),
+}
+
+// The keys of the top level or of one `[[exercise]]`, in file order, with
+// the line each was set on; keys are taken out as they are used, so anything
+// left over at the end is unknown
+struct Table {
+    line: usize,
+    entries: Vec<(String, Value, usize)>,
+}
+
+impl Table {
+    fn new(line: usize) -> Table {
+        Table {
+            line,
+            entries: Vec::new(),
+        }
+    }
+
+    fn take(&mut self, key: &str) -> Option<(Value, usize)> {
+        let index = self.entries.iter().position(|(k, _, _)| k == key)?;
+        let (_, value, line) = self.entries.remove(index);
+        Some((value, line))
+    }
+
+    fn required(&mut self, key: &str) -> Result<(Value, usize), String> {
+        self.take(key)
+            .ok_or_else(|| format!("line {}: missing `{}`", self.line, key))
+    }
+
+    fn string(&mut self, key: &str) -> Result<String, String> {
+        match self.required(key)? {
+            (Value::Str(s), _) => Ok(s),
+            (_, line) => Err(format!("line {}: `{}` must be a string", line, key)),
+        }
+    }
+
+    fn number(&mut self, key: &str, range: (u64, u64)) -> Result<u64, String> {
+        match self.required(key)? {
+            (Value::Int(n), _) if (range.0..=range.1).contains(&n) => Ok(n),
+            (_, line) => Err(format!(
+                "line {}: `{}` must be a number from {} to {}",
+                line, key, range.0, range.1
+            )),
+        }
+    }
+
+    fn list(&mut self, key: &str) -> Result<Vec<String>, String> {
+        match self.required(key)? {
+            (Value::List(items), _) => Ok(items),
+            (_, line) => Err(format!(
+                "line {}: `{}` must be a list of strings",
+                line, key
+            )),
+        }
+    }
+
+    // Prerequisites are optional; an absent `requires` means none
+    fn ids(&mut self, key: &str) -> Result<Vec<ExerciseId>, String> {
+        let (items, line) = match self.take(key) {
+            None => return Ok(Vec::new()),
+            Some((Value::List(items), line)) => (items, line),
+            Some((_, line)) => {
+                return Err(format!(
+                    "line {}: `{}` must be a list of strings",
+                    line, key
+                ))
+            }
+        };
+        items
+            .iter()
+            .map(|item| {
+                ExerciseId::parse(item).ok_or_else(|| {
+                    format!(
+                        "line {}: `{}` is not an exercise; write it like \"02.4\"",
+                        line, item
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn exercise(&mut self) -> Result<Exercise, String> {
+        Ok(Exercise {
+            number: self.number("number", (1, 99))? as usize,
+            title: self.string("title")?,
+            difficulty: self.number("difficulty", (1, 5))? as u8,
+            minutes: self.number("minutes", (1, 600))? as u32,
+            concepts: self.list("concepts")?,
+            requires: self.ids("requires")?,
+        })
+    }
+
+    fn finish(&self) -> Result<(), String> {
+        match self.entries.first() {
+            Some((key, _, line)) => Err(format!("line {}: unknown key `{}`", line, key)),
+            None => Ok(()),
+        }
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn line(&self) -> usize {
+        self.text[..self.pos].matches('\n').count() + 1
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("line {}: {}", self.line(), message)
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    // Skips spaces and comments, and line breaks too when `newlines` is set
+    fn skip(&mut self, newlines: bool) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start_matches(|c: char| {
+                c == ' ' || c == '\t' || (newlines && (c == '\n' || c == '\r'))
+            });
+            self.pos += rest.len() - trimmed.len();
+            if !trimmed.starts_with('#') {
+                break;
+            }
+            self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            if !newlines {
+                break;
+            }
+        }
+    }
+
+    fn end_of_line(&mut self) -> Result<(), String> {
+        self.skip(false);
+        if self.rest().is_empty() || self.eat("\n") || self.eat("\r\n") {
+            Ok(())
+        } else {
+            Err(self.error("expected the end of the line"))
+        }
+    }
+
+    fn key(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected a key"));
+        }
+        self.pos += len;
+        Ok(rest[..len].to_string())
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        if self.rest().starts_with('"') {
+            return self.string().map(Value::Str);
+        }
+        if self.eat("[") {
+            let mut items = Vec::new();
+            loop {
+                self.skip(true);
+                if self.eat("]") {
+                    return Ok(Value::List(items));
+                }
+                if !self.rest().starts_with('"') {
+                    return Err(self.error("lists may only hold strings"));
+                }
+                items.push(self.string()?);
+                self.skip(true);
+                if !self.eat(",") && !self.rest().starts_with(']') {
+                    return Err(self.error("expected `,` or `]`"));
+                }
+            }
+        }
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let number = rest[..len]
+            .parse()
+            .map_err(|_| self.error("expected a string, a number or a list"))?;
+        self.pos += len;
+        Ok(Value::Int(number))
+    }
+
+    // A basic string: only `\"` and `\\` are escapes, and it ends on its line
+    fn string(&mut self) -> Result<String, String> {
+        self.eat("\"");
+        let mut value = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += i + 1;
+                    return Ok(value);
+                }
+                '\\' => match chars.next() {
+                    Some((_, c @ ('"' | '\\'))) => value.push(c),
+                    _ => return Err(self.error("only `\\\"` and `\\\\` escapes are supported")),
+                },
+                '\n' => break,
+                c => value.push(c),
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{grade, modules, stubs};
+
+    const SAMPLE: &str = r#"
+# A comment
+objectives = [
+    "Share data with Rc", # trailing comments are fine
+    "Say \"no\" to cycles",
+]
+requires = ["02.4"]
+
+[[exercise]]
+number = 1
+title = "Shared Cache"
+difficulty = 2
+minutes = 30
+concepts = ["Rc", "RefCell"]
+
+[[exercise]]
+number = 2
+title = "Object Graph"
+difficulty = 4
+minutes = 45
+concepts = ["Weak"]
+requires = ["08.1", "02.4"]
+"#;
+
+    fn id(module: u8, exercise: usize) -> ExerciseId {
+        ExerciseId { module, exercise }
+    }
+
+    #[test]
+    fn test_parse() {
+        let manifest = Manifest::parse(SAMPLE).unwrap();
+        assert_eq!(
+            manifest.objectives,
+            ["Share data with Rc", "Say \"no\" to cycles"]
+        );
+        assert_eq!(manifest.exercises.len(), 2);
+        assert_eq!(
+            manifest.exercises[0],
+            Exercise {
+                number: 1,
+                title: "Shared Cache".to_string(),
+                difficulty: 2,
+                minutes: 30,
+                concepts: vec!["Rc".to_string(), "RefCell".to_string()],
+                requires: Vec::new(),
+            }
+        );
+        assert_eq!(manifest.prerequisites(1), [id(2, 4)]);
+        assert_eq!(manifest.prerequisites(2), [id(2, 4), id(8, 1)]);
+        assert_eq!(id(2, 4).to_string(), "02.4");
+    }
+
+    #[test]
+    fn test_parse_errors_name_the_line() {
+        let error = |text: &str| Manifest::parse(text).unwrap_err();
+        assert_eq!(
+            error("objectives = []\ncolour = \"red\""),
+            "line 2: unknown key `colour`"
+        );
+        assert_eq!(error("requires = []"), "line 1: missing `objectives`");
+        assert_eq!(
+            error("objectives = []\n[[exercise]]\nnumber = 1\ntitle = \"T\""),
+            "line 2: missing `difficulty`"
+        );
+        assert!(error("objectives = []\n[[exercises]]").starts_with("line 2:"));
+        assert!(error("objectives = [\"open").starts_with("line 1: unterminated"));
+        assert!(error("objectives = []\nrequires = [\"2-4\"]").starts_with("line 2:"));
+        assert!(error("objectives = [] []").starts_with("line 1:"));
+        let sample = SAMPLE.replace("difficulty = 4", "difficulty = 6");
+        assert!(error(&sample).contains("`difficulty` must be a number from 1 to 5"));
+        let sample = SAMPLE.replace("number = 2", "number = 3");
+        assert!(error(&sample).contains("out of order"));
+    }
+
+    // The manifests are hand-written, so check them against the exercises
+    // they describe. Prerequisites must come earlier in the tutorial (which
+    // rules out cycles, so there is always something to recommend) and must
+    // be gradable, or they could never be completed.
+    #[test]
+    fn test_every_module_has_a_consistent_manifest() {
+        for module in &modules::MODULES {
+            let manifest = Manifest::load(module).unwrap();
+            let source = std::fs::read_to_string(module.problems_path()).unwrap();
+            let titles: Vec<String> = stubs::scan(&source).into_iter().map(|e| e.title).collect();
+            let listed: Vec<&str> = manifest
+                .exercises
+                .iter()
+                .map(|e| e.title.as_str())
+                .collect();
+            assert_eq!(listed, titles, "{}", module.dir);
+            assert!(!manifest.objectives.is_empty(), "{}", module.dir);
+
+            for exercise in &manifest.exercises {
+                assert!(
+                    !exercise.concepts.is_empty(),
+                    "{} {}",
+                    module.dir,
+                    exercise.number
+                );
+                let this = id(module.number, exercise.number);
+                for required in manifest.prerequisites(exercise.number) {
+                    assert!(required < this, "{} needs {}", this, required);
+                    let other = modules::find(&required.module.to_string()).unwrap();
+                    assert!(grade::has_suite(other), "{} needs {}", this, required);
+                    let other = Manifest::load(other).unwrap();
+                    assert!(other.exercise(required.exercise).is_some(), "{}", required);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_smart_pointers_need_ownership() {
+        let module = modules::find("8").unwrap();
+        let manifest = Manifest::load(module).unwrap();
+        for exercise in &manifest.exercises {
+            let required = manifest.prerequisites(exercise.number);
+            for n in 1..=5 {
+                assert!(
+                    required.contains(&id(2, n)),
+                    "08.{} needs 02.{}",
+                    exercise.number,
+                    n
+                );
+            }
+        }
+    }
+}
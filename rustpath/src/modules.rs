@@ -0,0 +1,242 @@
+// Module registry
+// Every tutorial crate is listed here once, so the rest of the runner can look
+// modules up by number or directory name without knowing about the crates.
+
+use std::path::{Path, PathBuf};
+
+pub struct Module {
+    pub number: u8,
+    pub dir: &'static str,
+    pub package: &'static str,
+    pub title: &'static str,
+    pub run_exercises: fn(),
+    pub exercises: &'static [fn()],
+    // Authored hints per exercise (the module's src/hints.rs), levels 2 and up
+    pub hints: &'static [&'static [&'static str]],
+    pub solutions: Option<Solutions>,
+}
+
+// Entry points of a module's reference answers (its src/solutions.rs)
+pub struct Solutions {
+    pub run_exercises: fn(),
+    pub exercises: &'static [fn()],
+}
+
+impl Module {
+    // Directory of the module inside the workspace
+    pub fn path(&self) -> PathBuf {
+        workspace_root().join(self.dir)
+    }
+
+    // The learner-facing exercise file
+    pub fn problems_path(&self) -> PathBuf {
+        self.path().join("src").join("problems.rs")
+    }
+
+    // Objectives, difficulty and prerequisites of the exercises (see manifest.rs)
+    pub fn manifest_path(&self) -> PathBuf {
+        self.path().join("manifest.toml")
+    }
+}
+
+// The reference answers are only linked in when rustpath is built with the
+// `solutions` feature; without it every module reports none
+#[cfg(feature = "solutions")]
+macro_rules! solutions {
+    ($krate:ident) => {
+        Some(Solutions {
+            run_exercises: $krate::solutions::run_exercises,
+            exercises: &$krate::solutions::EXERCISES,
+        })
+    };
+}
+
+#[cfg(not(feature = "solutions"))]
+macro_rules! solutions {
+    ($krate:ident) => {
+        None
+    };
+}
+
+pub static MODULES: [Module; 12] = [
+    Module {
+        number: 1,
+        dir: "module01_fundamentals",
+        package: "rust_basics",
+        title: "Rust Fundamentals",
+        run_exercises: rust_basics::problems::run_exercises,
+        exercises: &rust_basics::problems::EXERCISES,
+        hints: &[],
+        solutions: solutions!(rust_basics),
+    },
+    Module {
+        number: 2,
+        dir: "module02_ownership",
+        package: "module2",
+        title: "Ownership and Memory Management",
+        run_exercises: module2::problems::run_exercises,
+        exercises: &module2::problems::EXERCISES,
+        hints: &module2::hints::HINTS,
+        solutions: solutions!(module2),
+    },
+    Module {
+        number: 3,
+        dir: "module03_type_system",
+        package: "module3",
+        title: "Type System Deep Dive",
+        run_exercises: module3::problems::run_exercises,
+        exercises: &module3::problems::EXERCISES,
+        hints: &module3::hints::HINTS,
+        solutions: solutions!(module3),
+    },
+    Module {
+        number: 4,
+        dir: "module04_collections",
+        package: "module4",
+        title: "Collections and Error Handling",
+        run_exercises: module4::problems::run_exercises,
+        exercises: &module4::problems::EXERCISES,
+        hints: &module4::hints::HINTS,
+        solutions: solutions!(module4),
+    },
+    Module {
+        number: 5,
+        dir: "module05_traits",
+        package: "module5",
+        title: "Traits and Generics",
+        run_exercises: module5::problems::run_exercises,
+        exercises: &module5::problems::EXERCISES,
+        hints: &module5::hints::HINTS,
+        solutions: solutions!(module5),
+    },
+    Module {
+        number: 6,
+        dir: "module06_testing",
+        package: "module6",
+        title: "Testing and Documentation",
+        run_exercises: module6::problems::run_exercises,
+        exercises: &module6::problems::EXERCISES,
+        hints: &module6::hints::HINTS,
+        solutions: solutions!(module6),
+    },
+    Module {
+        number: 7,
+        dir: "module07_concurrency",
+        package: "module7",
+        title: "Concurrency and Parallelism",
+        run_exercises: module7::problems::run_exercises,
+        exercises: &module7::problems::EXERCISES,
+        hints: &module7::hints::HINTS,
+        solutions: solutions!(module7),
+    },
+    Module {
+        number: 8,
+        dir: "module08_smart_pointers",
+        package: "module8",
+        title: "Smart Pointers and Interior Mutability",
+        run_exercises: module8::problems::run_exercises,
+        exercises: &module8::problems::EXERCISES,
+        hints: &module8::hints::HINTS,
+        solutions: solutions!(module8),
+    },
+    Module {
+        number: 9,
+        dir: "module09_pattern_matching",
+        package: "module9",
+        title: "Advanced Pattern Matching",
+        run_exercises: module9::problems::run_exercises,
+        exercises: &module9::problems::EXERCISES,
+        hints: &module9::hints::HINTS,
+        solutions: solutions!(module9),
+    },
+    Module {
+        number: 10,
+        dir: "module10_unsafe",
+        package: "module10",
+        title: "Advanced Features and Unsafe Rust",
+        run_exercises: module10::problems::run_exercises,
+        exercises: &module10::problems::EXERCISES,
+        hints: &module10::hints::HINTS,
+        solutions: solutions!(module10),
+    },
+    Module {
+        number: 11,
+        dir: "module11_tooling",
+        package: "module11",
+        title: "Rust Tooling and Ecosystem",
+        run_exercises: module11::problems::run_exercises,
+        exercises: &module11::problems::EXERCISES,
+        hints: &[],
+        solutions: None,
+    },
+    Module {
+        number: 12,
+        dir: "module12_production",
+        package: "module12",
+        title: "Production Rust",
+        run_exercises: module12::problems::run_exercises,
+        exercises: &module12::problems::EXERCISES,
+        hints: &module12::hints::HINTS,
+        solutions: solutions!(module12),
+    },
+];
+
+// The runner lives one directory below the workspace root
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("rustpath is a workspace member")
+}
+
+// Accepts "9", "09", "module09" or the full directory name "module09_pattern_matching"
+pub fn find(name: &str) -> Option<&'static Module> {
+    let digits = name.strip_prefix("module").unwrap_or(name);
+    let digits = digits.split('_').next().unwrap_or(digits);
+    if let Ok(number) = digits.parse::<u8>() {
+        return MODULES.iter().find(|m| m.number == number);
+    }
+    MODULES.iter().find(|m| m.dir == name || m.package == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_accepts_common_spellings() {
+        for name in [
+            "9",
+            "09",
+            "module09",
+            "module09_pattern_matching",
+            "module9",
+        ] {
+            assert_eq!(find(name).map(|m| m.number), Some(9), "{}", name);
+        }
+        assert_eq!(find("rust_basics").map(|m| m.number), Some(1));
+        assert!(find("13").is_none());
+        assert!(find("nonsense").is_none());
+    }
+
+    #[test]
+    fn test_registry_matches_workspace() {
+        for (i, module) in MODULES.iter().enumerate() {
+            assert_eq!(module.number as usize, i + 1);
+            assert!(module.problems_path().is_file(), "{}", module.dir);
+            assert!(!module.exercises.is_empty());
+        }
+    }
+
+    #[cfg(feature = "solutions")]
+    #[test]
+    fn test_solutions_mirror_exercises() {
+        for module in &MODULES {
+            // Module 11 has no stubs to solve
+            let expected = module.number != 11;
+            assert_eq!(module.solutions.is_some(), expected, "{}", module.dir);
+            if let Some(solutions) = &module.solutions {
+                assert_eq!(solutions.exercises.len(), module.exercises.len());
+            }
+        }
+    }
+}
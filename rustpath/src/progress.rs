@@ -0,0 +1,330 @@
+// Progress store
+// The runner remembers what each learner has done in .rustpath/progress.toml
+// at the workspace root. The directory is git-ignored, so progress belongs to
+// the checkout rather than the repository. The file is a small TOML subset,
+// read and written by hand: one table per exercise with anything recorded,
+//
+//   [module02.exercise3]
+//   hints = 2
+//   attempts = 3
+//   first_attempt = 2026-10-15T09:12:44Z
+//   last_attempt = 2026-10-16T14:03:10Z
+//   completed = 2026-10-16T14:03:10Z
+//
+// `grade` records the attempts; `run --hint` records the hints.
+
+use crate::modules;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Record {
+    // Highest hint level unlocked so far (0 when no hint has been shown)
+    pub hints: usize,
+    // Grading runs that reached the learner's code, up to the first pass
+    pub attempts: usize,
+    pub first_attempt: Option<Timestamp>,
+    pub last_attempt: Option<Timestamp>,
+    // When every check first passed
+    pub completed: Option<Timestamp>,
+}
+
+impl Record {
+    // Once an exercise is completed its record stays as it was, so the
+    // attempt count is the number of tries it took
+    pub fn attempt(&mut self, passed: bool, now: Timestamp) {
+        if self.completed.is_some() {
+            return;
+        }
+        self.attempts += 1;
+        self.first_attempt.get_or_insert(now);
+        self.last_attempt = Some(now);
+        if passed {
+            self.completed = Some(now);
+        }
+    }
+
+    pub fn last_activity(&self) -> Option<Timestamp> {
+        self.last_attempt.max(self.completed)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Progress {
+    // Keyed by (module number, exercise number)
+    records: BTreeMap<(u8, usize), Record>,
+}
+
+impl Progress {
+    pub fn path() -> PathBuf {
+        modules::workspace_root()
+            .join(".rustpath")
+            .join("progress.toml")
+    }
+
+    // A missing file is simply a learner who has not started yet
+    pub fn load() -> Result<Progress, String> {
+        let path = Progress::path();
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Progress::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Progress::default()),
+            Err(e) => Err(format!("cannot read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = Progress::path();
+        let write = || {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(&path, self.render())
+        };
+        write().map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, module: u8, exercise: usize) -> Record {
+        self.records
+            .get(&(module, exercise))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn record_mut(&mut self, module: u8, exercise: usize) -> &mut Record {
+        self.records.entry((module, exercise)).or_default()
+    }
+
+    pub fn parse(text: &str) -> Result<Progress, String> {
+        let mut progress = Progress::default();
+        let mut current = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| format!("line {}: {}", index + 1, message);
+
+            if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let key = table_key(table).ok_or_else(|| error("expected [moduleNN.exerciseN]"))?;
+                progress.records.entry(key).or_default();
+                current = Some(key);
+                continue;
+            }
+
+            let key = current.ok_or_else(|| error("value outside of an exercise table"))?;
+            let (name, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `key = value`"))?;
+            let (name, value) = (name.trim(), value.trim());
+            let number = || {
+                value
+                    .parse()
+                    .map_err(|_| error(&format!("`{}` must be a number", name)))
+            };
+            let timestamp = || {
+                Timestamp::parse(value)
+                    .map(Some)
+                    .ok_or_else(|| error(&format!("`{}` must be a UTC date-time", name)))
+            };
+
+            let record = progress.record_mut(key.0, key.1);
+            match name {
+                "hints" => record.hints = number()?,
+                "attempts" => record.attempts = number()?,
+                "first_attempt" => record.first_attempt = timestamp()?,
+                "last_attempt" => record.last_attempt = timestamp()?,
+                "completed" => record.completed = timestamp()?,
+                other => return Err(error(&format!("unknown key `{}`", other))),
+            }
+        }
+        Ok(progress)
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::from("# Written by rustpath; safe to delete to start over\n");
+        for ((module, exercise), record) in &self.records {
+            if *record == Record::default() {
+                continue;
+            }
+            out.push_str(&format!("\n[module{:02}.exercise{}]\n", module, exercise));
+            out.push_str(&format!("hints = {}\n", record.hints));
+            out.push_str(&format!("attempts = {}\n", record.attempts));
+            let timestamps = [
+                ("first_attempt", record.first_attempt),
+                ("last_attempt", record.last_attempt),
+                ("completed", record.completed),
+            ];
+            for (name, timestamp) in timestamps {
+                if let Some(timestamp) = timestamp {
+                    out.push_str(&format!("{} = {}\n", name, timestamp));
+                }
+            }
+        }
+        out
+    }
+}
+
+// "module02.exercise3" -> (2, 3)
+fn table_key(table: &str) -> Option<(u8, usize)> {
+    let (module, exercise) = table.trim().split_once('.')?;
+    Some((
+        module.strip_prefix("module")?.parse().ok()?,
+        exercise.strip_prefix("exercise")?.parse().ok()?,
+    ))
+}
+
+// Seconds since the Unix epoch, written as a UTC date-time such as
+// 2026-10-17T09:30:00Z (a TOML offset date-time)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp(pub u64);
+
+impl Timestamp {
+    pub fn now() -> Timestamp {
+        let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH);
+        Timestamp(since_epoch.map_or(0, |d| d.as_secs()))
+    }
+
+    // Just the "2026-10-17" part
+    pub fn date(self) -> String {
+        let (year, month, day) = civil_from_days(self.0 / 86_400);
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+
+    fn parse(text: &str) -> Option<Timestamp> {
+        let (date, time) = text.strip_suffix('Z')?.split_once('T')?;
+        let fields = |text: &str, sep| -> Option<Vec<u64>> {
+            text.split(sep).map(|n| n.parse().ok()).collect()
+        };
+        let (date, time) = (fields(date, '-')?, fields(time, ':')?);
+        let (&[year, month, day], &[hour, minute, second]) = (&date[..], &time[..]) else {
+            return None;
+        };
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) || year < 1970 {
+            return None;
+        }
+        if hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+        let days = days_from_civil(year, month, day);
+        Some(Timestamp(
+            days * 86_400 + hour * 3_600 + minute * 60 + second,
+        ))
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let seconds = self.0 % 86_400;
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}Z",
+            self.date(),
+            seconds / 3_600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+}
+
+// Days since 1970-01-01 to a (year, month, day) date and back, after Howard
+// Hinnant's `civil_from_days`/`days_from_civil`; years are shifted to start
+// in March so the leap day falls at the end. Only dates from 1970 on occur.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = year - u64::from(month <= 2);
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_and_parse_round_trip() {
+        let mut progress = Progress::default();
+        progress.record_mut(2, 3).hints = 2;
+        progress.record_mut(2, 3).attempt(false, Timestamp(1_000));
+        progress.record_mut(2, 3).attempt(true, Timestamp(2_000));
+        progress.record_mut(12, 1).hints = 1;
+        // Untouched exercises are left out of the file
+        progress.record_mut(4, 4);
+
+        let text = progress.render();
+        assert!(text.contains(
+            "[module02.exercise3]\nhints = 2\nattempts = 2\n\
+             first_attempt = 1970-01-01T00:16:40Z\n"
+        ));
+        assert!(!text.contains("module04"));
+
+        let parsed = Progress::parse(&text).unwrap();
+        assert_eq!(parsed, {
+            progress.records.remove(&(4, 4));
+            progress
+        });
+    }
+
+    #[test]
+    fn test_attempts_stop_counting_once_completed() {
+        let mut record = Record::default();
+        record.attempt(false, Timestamp(10));
+        record.attempt(true, Timestamp(20));
+        record.attempt(false, Timestamp(30));
+        assert_eq!(record.attempts, 2);
+        assert_eq!(record.first_attempt, Some(Timestamp(10)));
+        assert_eq!(record.completed, Some(Timestamp(20)));
+        assert_eq!(record.last_activity(), Some(Timestamp(20)));
+    }
+
+    #[test]
+    fn test_timestamps() {
+        for (seconds, text) in [
+            (0, "1970-01-01T00:00:00Z"),
+            (951_782_400, "2000-02-29T00:00:00Z"),
+            (1_792_229_545, "2026-10-17T09:32:25Z"),
+            (4_107_542_399, "2100-02-28T23:59:59Z"),
+        ] {
+            assert_eq!(Timestamp(seconds).to_string(), text);
+            assert_eq!(Timestamp::parse(text), Some(Timestamp(seconds)));
+        }
+        assert_eq!(Timestamp(1_792_229_545).date(), "2026-10-17");
+        assert_eq!(Timestamp::parse("2026-10-16"), None);
+        assert_eq!(Timestamp::parse("2026-13-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn test_parse_errors_name_the_line() {
+        assert_eq!(
+            Progress::parse("hints = 1"),
+            Err("line 1: value outside of an exercise table".to_string())
+        );
+        assert!(Progress::parse("[module02]").is_err());
+        assert!(Progress::parse("[module02.exercise1]\n\nhints = many").is_err());
+        assert!(Progress::parse("[module02.exercise1]\ncompleted = yesterday").is_err());
+        assert!(Progress::parse("[module02.exercise1]\ncolour = 3").is_err());
+    }
+}
@@ -0,0 +1,127 @@
+// Stub detection
+// Most exercises only define their functions and leave the calls commented
+// out, so running them never reaches the `unimplemented!` bodies. Instead we
+// scan each `fn exerciseN()` in problems.rs for stubs that are still in place.
+
+pub struct ExerciseStubs {
+    pub number: usize,
+    pub title: String,
+    // (line number, stub message) for every remaining stub
+    pub stubs: Vec<(usize, String)>,
+}
+
+pub fn scan(source: &str) -> Vec<ExerciseStubs> {
+    let mut exercises: Vec<ExerciseStubs> = Vec::new();
+    let mut in_exercise = false;
+
+    for (index, line) in source.lines().enumerate() {
+        // An exercise starts at its "// Exercise N: Title" header, so types
+        // declared between the header and `fn exerciseN()` count towards it
+        let started = header(line)
+            .map(|(number, title)| (number, title.to_string()))
+            .or_else(|| {
+                exercise_number(line)
+                    .filter(|n| exercises.last().map(|e| e.number) != Some(*n))
+                    .map(|n| (n, String::new()))
+            });
+        if let Some((number, title)) = started {
+            exercises.push(ExerciseStubs {
+                number,
+                title,
+                stubs: Vec::new(),
+            });
+            in_exercise = true;
+            continue;
+        }
+
+        // The reference solutions are not part of the learner's work
+        if line.starts_with("/*") {
+            in_exercise = false;
+        }
+
+        if !in_exercise || line.trim_start().starts_with("//") {
+            continue;
+        }
+        if let Some(message) = stub_message(line) {
+            if let Some(exercise) = exercises.last_mut() {
+                exercise.stubs.push((index + 1, message));
+            }
+        }
+    }
+
+    exercises
+}
+
+fn header(line: &str) -> Option<(usize, &str)> {
+    let (number, title) = line.strip_prefix("// Exercise ")?.split_once(':')?;
+    Some((number.parse().ok()?, title.trim()))
+}
+
+fn exercise_number(line: &str) -> Option<usize> {
+    line.strip_prefix("fn exercise")?
+        .strip_suffix("() {")?
+        .parse()
+        .ok()
+}
+
+fn stub_message(line: &str) -> Option<String> {
+    let start = ["unimplemented!(", "todo!("]
+        .iter()
+        .find_map(|marker| line.find(marker).map(|i| i + marker.len()))?;
+    let rest = &line[start..];
+    let message = match (rest.find('"'), rest.rfind('"')) {
+        (Some(open), Some(close)) if close > open => &rest[open + 1..close],
+        _ => "not implemented",
+    };
+    Some(message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+pub fn run_exercises() {
+    exercise1();
+    exercise2();
+}
+
+// Exercise 1: Done Already
+fn exercise1() {
+    println!("nothing left to do");
+}
+
+// Exercise 2: Still Stubbed
+fn exercise2() {
+    fn parse() {
+        unimplemented!("Implement parse");
+    }
+    fn evaluate() {
+        todo!()
+    }
+    // unimplemented!("commented out stubs are ignored");
+}
+
+/* Example Solutions
+fn parse() {
+    unimplemented!("solutions are ignored too");
+}
+*/
+"#;
+
+    #[test]
+    fn test_scan_finds_stubs_per_exercise() {
+        let exercises = scan(SOURCE);
+        assert_eq!(exercises.len(), 2);
+
+        assert_eq!(exercises[0].number, 1);
+        assert_eq!(exercises[0].title, "Done Already");
+        assert!(exercises[0].stubs.is_empty());
+
+        assert_eq!(exercises[1].number, 2);
+        assert_eq!(exercises[1].title, "Still Stubbed");
+        let messages: Vec<&str> = exercises[1].stubs.iter().map(|(_, m)| m.as_str()).collect();
+        assert_eq!(messages, ["Implement parse", "not implemented"]);
+        assert_eq!(exercises[1].stubs[0].0, 15);
+    }
+}
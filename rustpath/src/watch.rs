@@ -0,0 +1,149 @@
+// Watch mode
+// `rustpath watch` reruns an exercise's checks every time the learner saves.
+// Changes are found by polling the modification times of the module's source
+// files, which needs nothing beyond std and behaves the same on every
+// platform; a module has a handful of files, so a scan is cheap.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+// Editors often write a file in several steps; let them finish first
+const SETTLE_TIME: Duration = Duration::from_millis(150);
+
+// Modification time and length of every .rs file below a directory
+#[derive(Debug, PartialEq)]
+pub struct Snapshot {
+    files: BTreeMap<PathBuf, (Option<SystemTime>, u64)>,
+}
+
+impl Snapshot {
+    pub fn take(root: &Path) -> Snapshot {
+        let mut files = BTreeMap::new();
+        scan(root, &mut files);
+        Snapshot { files }
+    }
+
+    // Blocks until a file below `root` is added, removed or modified
+    pub fn wait_for_change(&mut self, root: &Path) {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            if Snapshot::take(root) != *self {
+                thread::sleep(SETTLE_TIME);
+                *self = Snapshot::take(root);
+                return;
+            }
+        }
+    }
+}
+
+fn scan(dir: &Path, files: &mut BTreeMap<PathBuf, (Option<SystemTime>, u64)>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            scan(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.insert(path, (metadata.modified().ok(), metadata.len()));
+        }
+    }
+}
+
+// A check's failure message, shortened for the watch screen. The grading
+// suites write `assert_eq!(actual, expected)`, so a failed assertion
+//
+//   assertion `left == right` failed: <optional note>
+//     left: None
+//    right: Some("a")
+//
+// becomes an expected/got pair with a caret under the first difference.
+// Any other message is cut down to its first line.
+pub fn failure_lines(message: &str) -> Vec<String> {
+    let mut lines = message.lines();
+    let first = lines.next().unwrap_or("").to_string();
+    let Some(note) = first.strip_prefix("assertion `left == right` failed") else {
+        return vec![first];
+    };
+
+    let (mut got, mut expected) = (None, None);
+    for line in lines {
+        if let Some(value) = line.strip_prefix("  left: ") {
+            got = Some(value);
+        } else if let Some(value) = line.strip_prefix(" right: ") {
+            expected = Some(value);
+        }
+    }
+    let (Some(got), Some(expected)) = (got, expected) else {
+        return vec![first];
+    };
+
+    let mut out = Vec::new();
+    if let Some(note) = note.strip_prefix(": ") {
+        out.push(note.to_string());
+    }
+    let same = expected
+        .chars()
+        .zip(got.chars())
+        .take_while(|(a, b)| a == b)
+        .count();
+    out.push(format!("expected: {}", expected));
+    out.push(format!("     got: {}", got));
+    out.push(format!("          {}^", " ".repeat(same)));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failure_lines_diff_assertions() {
+        assert_eq!(
+            failure_lines(
+                "assertion `left == right` failed: pool is empty\n  left: None\n right: Some(\"a\")"
+            ),
+            [
+                "pool is empty",
+                "expected: Some(\"a\")",
+                "     got: None",
+                "          ^",
+            ]
+        );
+        assert_eq!(
+            failure_lines("assertion `left == right` failed\n  left: [1, 2]\n right: [1, 3]")[2],
+            "              ^"
+        );
+        assert_eq!(
+            failure_lines("not implemented: Implement take_resource\nmore"),
+            ["not implemented: Implement take_resource"]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_notices_changes() {
+        let dir = std::env::temp_dir().join(format!("rustpath-watch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src/problems")).unwrap();
+        std::fs::write(dir.join("src/problems.rs"), "fn a() {}").unwrap();
+        let before = Snapshot::take(&dir);
+        assert_eq!(before.files.len(), 1);
+
+        // Other files are not watched
+        std::fs::write(dir.join("src/notes.txt"), "todo").unwrap();
+        assert_eq!(Snapshot::take(&dir), before);
+
+        std::fs::write(dir.join("src/problems/grading.rs"), "").unwrap();
+        assert_ne!(Snapshot::take(&dir), before);
+        let before = Snapshot::take(&dir);
+        std::fs::write(dir.join("src/problems.rs"), "fn a() { todo!() }").unwrap();
+        assert_ne!(Snapshot::take(&dir), before);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}